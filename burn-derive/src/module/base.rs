@@ -11,6 +11,9 @@ pub(crate) fn module_derive_impl(ast: &syn::DeriveInput) -> TokenStream {
 
     let param = Param::from_ast(ast);
     let num_params_fn = param.gen_num_params_fn();
+    let summary_fn = param.gen_summary_fn();
+    let visit_params_fn = param.gen_visit_params_fn();
+    let map_params_fn = param.gen_map_params_fn();
     let update_params_fn = param.gen_update_params_fn();
     let load_optim_state = param.gen_load_optim_state_fn();
     let register_optim_state = param.gen_register_optim_state_fn();
@@ -33,6 +36,9 @@ pub(crate) fn module_derive_impl(ast: &syn::DeriveInput) -> TokenStream {
             #load_fn
 
             #num_params_fn
+            #summary_fn
+            #visit_params_fn
+            #map_params_fn
             #update_params_fn
 
             #load_optim_state
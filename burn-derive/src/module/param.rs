@@ -47,6 +47,57 @@ impl Param {
         }
     }
 
+    pub fn gen_summary_fn(&self) -> TokenStream {
+        let mut body = quote! {
+            let mut summary = burn::module::ModuleSummary::new();
+        };
+        for field in self.fields_param.iter() {
+            let name = field.ident();
+            body.extend(quote! {
+                summary.register(self.#name.name().unwrap_or(stringify!(#name)), self.#name.summary());
+            });
+        }
+
+        quote! {
+            fn summary(&self) -> burn::module::ModuleSummary {
+                #body
+                summary
+            }
+        }
+    }
+
+    pub fn gen_visit_params_fn(&self) -> TokenStream {
+        let mut body = quote! {};
+        for field in self.fields_param.iter() {
+            let name = field.ident();
+            body.extend(quote! {
+                self.#name.visit_params(visitor);
+            });
+        }
+
+        quote! {
+            fn visit_params<V: burn::module::ParamVisitor<B>>(&self, visitor: &mut V) {
+                #body
+            }
+        }
+    }
+
+    pub fn gen_map_params_fn(&self) -> TokenStream {
+        let mut body = quote! {};
+        for field in self.fields_param.iter() {
+            let name = field.ident();
+            body.extend(quote! {
+                self.#name.map_params(mapper);
+            });
+        }
+
+        quote! {
+            fn map_params<M: burn::module::ParamMapper<B>>(&mut self, mapper: &mut M) {
+                #body
+            }
+        }
+    }
+
     pub fn gen_update_params_fn(&self) -> TokenStream {
         let mut body = quote! {};
         for field in self.fields_param.iter() {
@@ -193,7 +244,7 @@ impl Param {
         for field in self.fields_param.iter() {
             let name = field.ident();
             body.extend(quote! {
-                state.register_state(stringify!(#name), self.#name.state());
+                state.register_state(self.#name.name().unwrap_or(stringify!(#name)), self.#name.state());
             });
         }
 
@@ -211,13 +262,14 @@ impl Param {
         for field in self.fields_param.iter() {
             let name = field.ident();
             body.extend(quote! {
-                let state_mod = state.get(stringify!(#name)).ok_or(
+                let key = self.#name.name().unwrap_or(stringify!(#name)).to_string();
+                let state_mod = state.get(&key).ok_or(
                     burn::module::LoadingError::new(format!(
                         "Missing module '{}' from state",
-                        stringify!(#name),
+                        key,
                     )))?;
                 self.#name.load(state_mod).map_err(|err| {
-                    burn::module::LoadingError::new(format!("Can't load module {}: {}", stringify!(#name), err))
+                    burn::module::LoadingError::new(format!("Can't load module {}: {}", key, err))
                 })?;
             });
         }
@@ -0,0 +1,69 @@
+use std::cell::Cell;
+
+thread_local! {
+    static ANOMALY_DETECTION: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Execute the given function with anomaly detection enabled in the autodiff graph.
+///
+/// During the backward passes run inside the scope, every operation checks its output and the
+/// gradients it computes, and panics with its name when it is the first one to produce a NaN or
+/// an infinite value. This is useful to find the origin of exploding gradients, but makes the
+/// backward pass much slower since every tensor is copied to check its values.
+///
+/// Scopes can be nested, the previous mode is restored when the scope ends.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::ADBackend;
+/// use burn_tensor::{detect_anomaly, Gradients, Tensor};
+///
+/// fn example<B: ADBackend>(x: Tensor<B, 2>) -> Gradients {
+///     detect_anomaly(|| x.log().sum().backward())
+/// }
+/// ```
+pub fn detect_anomaly<R, F: FnOnce() -> R>(func: F) -> R {
+    let _guard = AnomalyGuard::new();
+    func()
+}
+
+/// Returns if anomaly detection is currently enabled on this thread.
+pub fn is_anomaly_detection_enabled() -> bool {
+    ANOMALY_DETECTION.with(|enabled| enabled.get())
+}
+
+struct AnomalyGuard {
+    previous: bool,
+}
+
+impl AnomalyGuard {
+    fn new() -> Self {
+        let previous = ANOMALY_DETECTION.with(|enabled| enabled.replace(true));
+        Self { previous }
+    }
+}
+
+impl Drop for AnomalyGuard {
+    fn drop(&mut self) {
+        ANOMALY_DETECTION.with(|enabled| enabled.set(self.previous));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_restore_anomaly_detection_after_scope() {
+        assert!(!is_anomaly_detection_enabled());
+
+        detect_anomaly(|| {
+            assert!(is_anomaly_detection_enabled());
+            detect_anomaly(|| assert!(is_anomaly_detection_enabled()));
+            assert!(is_anomaly_detection_enabled());
+        });
+
+        assert!(!is_anomaly_detection_enabled());
+    }
+}
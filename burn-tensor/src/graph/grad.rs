@@ -5,11 +5,47 @@ use crate::{
     },
     tensor::ops::Zeros,
 };
-use std::{any::Any, collections::HashMap, ops::Add};
+use std::{
+    any::Any,
+    collections::{HashMap, HashSet},
+    ops::Add,
+};
+
+type GradValue = Box<dyn Any + Send + Sync>;
 
 #[derive(Default)]
 pub struct Gradients {
-    grads: HashMap<String, Box<dyn Any + Send + Sync>>,
+    grads: HashMap<String, GradEntry>,
+}
+
+struct GradEntry {
+    value: GradValue,
+    ops: Option<GradOps>,
+}
+
+/// Type erased operations on a gradient, only available for values registered as gradients
+/// and not with [register_any](Gradients::register_any).
+#[derive(Clone, Copy)]
+struct GradOps {
+    add: fn(&GradValue, &GradValue) -> GradValue,
+    zeros: fn(&GradValue) -> GradValue,
+}
+
+impl GradOps {
+    fn new<T>() -> Self
+    where
+        T: Zeros<T> + Clone + Add<Output = T>,
+        T: 'static + Send + Sync,
+    {
+        Self {
+            add: |lhs, rhs| {
+                let lhs = lhs.downcast_ref::<T>().unwrap().clone();
+                let rhs = rhs.downcast_ref::<T>().unwrap().clone();
+                Box::new(lhs + rhs)
+            },
+            zeros: |value| Box::new(value.downcast_ref::<T>().unwrap().zeros()),
+        }
+    }
 }
 
 impl Gradients {
@@ -25,14 +61,67 @@ impl Gradients {
         T: std::fmt::Debug + 'static + Send + Sync,
     {
         let grad = node.state.grad();
-        self.grads.insert(node.id.clone(), Box::new(grad));
+        self.register_grad(node.id.clone(), grad);
     }
 
+    /// Register the gradient of the node with the given id, replacing the current one.
+    pub fn register_grad<T>(&mut self, id: String, grad: T)
+    where
+        T: Zeros<T> + Clone + Add<Output = T>,
+        T: std::fmt::Debug + 'static + Send + Sync,
+    {
+        let entry = GradEntry {
+            value: Box::new(grad),
+            ops: Some(GradOps::new::<T>()),
+        };
+        self.grads.insert(id, entry);
+    }
+
+    /// Register any value with the given id, replacing the current one.
+    ///
+    /// The value isn't considered a gradient, so it is left unchanged by [zero](Self::zero) and
+    /// replaced by [merge](Self::merge).
     pub fn register_any<V>(&mut self, id: String, value: V)
     where
         V: std::fmt::Debug + 'static + Send + Sync,
     {
-        self.grads.insert(id, Box::new(value));
+        let entry = GradEntry {
+            value: Box::new(value),
+            ops: None,
+        };
+        self.grads.insert(id, entry);
+    }
+
+    /// Set all gradients to zero, keeping their shapes.
+    pub fn zero(&mut self) {
+        for entry in self.grads.values_mut() {
+            if let Some(ops) = entry.ops {
+                entry.value = (ops.zeros)(&entry.value);
+            }
+        }
+    }
+
+    /// Merge the other gradients into the current ones, summing the gradients of the tensors
+    /// present in both.
+    ///
+    /// This is useful to accumulate gradients over multiple backward passes before updating the
+    /// parameters, since the gradients of a parameter are registered under the same tensor as
+    /// long as it isn't updated.
+    pub fn merge(&mut self, other: Gradients) {
+        for (id, entry) in other.grads {
+            let current = match self.grads.get_mut(&id) {
+                Some(current) => current,
+                None => {
+                    self.grads.insert(id, entry);
+                    continue;
+                }
+            };
+
+            match (current.ops, entry.ops) {
+                (Some(ops), Some(_)) => current.value = (ops.add)(&current.value, &entry.value),
+                _ => *current = entry,
+            }
+        }
     }
 
     pub fn from<T>(node: &BackwardNode<T>) -> Self
@@ -51,6 +140,28 @@ impl Gradients {
         grads
     }
 
+    /// Create the gradients of the nodes with the given ids only, see
+    /// [backward_for](BackwardNode::backward_for).
+    pub fn from_subset<T>(node: &BackwardNode<T>, ids: &HashSet<String>) -> Self
+    where
+        T: Zeros<T> + Clone + Add<Output = T>,
+        T: std::fmt::Debug + 'static + Send + Sync,
+    {
+        let mut grads = Self::empty();
+        let traversal = BreadthFirstSearch::new(node);
+        if ids.contains(&node.id) {
+            grads.register(node);
+        }
+
+        traversal.traverse(|node| {
+            if ids.contains(node.id()) {
+                node.register_grad(&mut grads);
+            }
+        });
+
+        grads
+    }
+
     pub fn wrt<T: 'static, V: AsNode<T>>(&self, variable: &V) -> Option<&T> {
         let node = variable.as_node();
         let grad = match self.grads.get(&node.id) {
@@ -58,7 +169,7 @@ impl Gradients {
             None => return None,
         };
 
-        grad.downcast_ref()
+        grad.value.downcast_ref()
     }
 
     pub fn get<V: 'static>(&self, id: &str) -> Option<&V> {
@@ -67,7 +178,7 @@ impl Gradients {
             None => return None,
         };
 
-        grad.downcast_ref()
+        grad.value.downcast_ref()
     }
 }
 
@@ -1,5 +1,7 @@
+pub mod anomaly;
 pub mod converter;
 pub mod grad;
+pub mod no_grad;
 pub mod node;
 pub mod ops;
 pub mod traversal;
@@ -0,0 +1,76 @@
+use std::cell::Cell;
+
+thread_local! {
+    static GRAD_ENABLED: Cell<bool> = const { Cell::new(true) };
+}
+
+/// Execute the given function without recording the operations in the autodiff graph.
+///
+/// Every tensor created inside the scope is a leaf of the graph, so calling backward on it
+/// won't compute any gradient for the tensors used to create it. This is useful during
+/// inference, where building the graph is only overhead.
+///
+/// Scopes can be nested, the previous mode is restored when the scope ends.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::ADBackend;
+/// use burn_tensor::{no_grad, Tensor};
+///
+/// fn example<B: ADBackend>(x: Tensor<B, 2>, weights: Tensor<B, 2>) -> Tensor<B, 2> {
+///     no_grad(|| x.matmul(&weights))
+/// }
+/// ```
+pub fn no_grad<R, F: FnOnce() -> R>(func: F) -> R {
+    let _guard = NoGradGuard::new();
+    func()
+}
+
+/// Returns if the operations are currently recorded in the autodiff graph on this thread.
+pub fn is_grad_enabled() -> bool {
+    GRAD_ENABLED.with(|enabled| enabled.get())
+}
+
+struct NoGradGuard {
+    previous: bool,
+}
+
+impl NoGradGuard {
+    fn new() -> Self {
+        let previous = GRAD_ENABLED.with(|enabled| enabled.replace(false));
+        Self { previous }
+    }
+}
+
+impl Drop for NoGradGuard {
+    fn drop(&mut self) {
+        GRAD_ENABLED.with(|enabled| enabled.set(self.previous));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_restore_grad_mode_after_nested_scopes() {
+        assert!(is_grad_enabled());
+
+        no_grad(|| {
+            assert!(!is_grad_enabled());
+            no_grad(|| assert!(!is_grad_enabled()));
+            assert!(!is_grad_enabled());
+        });
+
+        assert!(is_grad_enabled());
+    }
+
+    #[test]
+    fn should_restore_grad_mode_after_panic() {
+        let result = std::panic::catch_unwind(|| no_grad(|| panic!("Failure in scope")));
+
+        assert!(result.is_err());
+        assert!(is_grad_enabled());
+    }
+}
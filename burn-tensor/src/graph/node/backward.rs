@@ -1,14 +1,17 @@
-use super::{BackwardNodeState, ForwardNodeRef};
+use super::{BackwardNodeState, ForwardNode, ForwardNodeRef, ForwardNodeState};
 use crate::{
     graph::{
         converter::Forward2BackwardGraphConverter,
         grad::Gradients,
-        ops::{BackwardRecordedOpsRef, RecordedOpsParent, RecordedOpsParentRef},
+        ops::{
+            BackwardRecordedOpsRef, ForwardLeafRef, InitRecordedOps, RecordedOpsParent,
+            RecordedOpsParentRef,
+        },
         traversal::{BreadthFirstSearch, GraphTraversal},
     },
     tensor::ops::{Ones, Zeros},
 };
-use std::{ops::Add, sync::Arc};
+use std::{collections::HashSet, ops::Add, sync::Arc};
 
 #[derive(Debug)]
 pub struct BackwardNode<Out> {
@@ -16,6 +19,7 @@ pub struct BackwardNode<Out> {
     pub order: usize,
     pub state: BackwardNodeState<Out>,
     pub ops: BackwardRecordedOpsRef<Out>,
+    pub require_grad: bool,
 }
 pub type BackwardNodeRef<Out> = Arc<BackwardNode<Out>>;
 
@@ -29,6 +33,7 @@ impl<Out: Clone + Zeros<Out>> BackwardNode<Out> {
             order: node.order,
             state: BackwardNodeState::new(node.state.value()),
             ops: node.ops.to_backward(converter),
+            require_grad: node.require_grad,
         }
     }
 }
@@ -39,10 +44,58 @@ where
     Out: std::fmt::Debug + 'static + Send + Sync,
 {
     pub fn backward(&mut self) -> Gradients {
+        let tape = self.tape();
+
         let grad = self.state.value().ones();
         self.state.update_grad(grad);
         self.ops.backward_step(&self.state);
 
+        for nodes in tape.iter().rev() {
+            for node in nodes {
+                node.backward_step();
+            }
+        }
+
+        Gradients::from(self)
+    }
+
+    /// Compute the gradients of the nodes with the given ids only.
+    ///
+    /// The operations that don't lead to any of the nodes are skipped, and the gradients of the
+    /// other nodes aren't registered.
+    pub fn backward_for(&mut self, ids: &[String]) -> Gradients {
+        let tape = self.tape();
+        let mut relevant: HashSet<String> = ids.iter().cloned().collect();
+        let targets = relevant.clone();
+
+        // The parents of a node always have a lower order, so they are visited first.
+        for nodes in tape.iter() {
+            for node in nodes {
+                if is_relevant(node.backward_parents(), &relevant) {
+                    relevant.insert(node.id().clone());
+                }
+            }
+        }
+
+        let grad = self.state.value().ones();
+        self.state.update_grad(grad);
+        if is_relevant(self.ops.backward_parents(), &relevant) {
+            self.ops.backward_step(&self.state);
+        }
+
+        for nodes in tape.iter().rev() {
+            for node in nodes {
+                if relevant.contains(node.id()) {
+                    node.backward_step();
+                }
+            }
+        }
+
+        Gradients::from_subset(self, &targets)
+    }
+
+    /// Group the nodes of the graph by their order, excluding the current node and the leaves.
+    fn tape(&self) -> Vec<Vec<RecordedOpsParentRef>> {
         let traversal = BreadthFirstSearch::new(self);
         let mut tape = vec![Vec::new(); self.order];
 
@@ -57,21 +110,14 @@ where
             };
         });
 
-        for i in (1..self.order).rev() {
-            let nodes = match tape.get(i) {
-                Some(nodes) => nodes,
-                None => continue,
-            };
-
-            for node in nodes {
-                node.backward_step();
-            }
-        }
-
-        Gradients::from(self)
+        tape
     }
 }
 
+fn is_relevant(parents: Vec<RecordedOpsParentRef>, relevant: &HashSet<String>) -> bool {
+    parents.iter().any(|parent| relevant.contains(parent.id()))
+}
+
 impl<T> RecordedOpsParent for BackwardNode<T>
 where
     T: Zeros<T> + Clone + Add<Output = T>,
@@ -91,6 +137,24 @@ where
         &self.id
     }
     fn register_grad(&self, grads: &mut Gradients) {
-        grads.register(self)
+        if self.require_grad {
+            grads.register(self)
+        }
+    }
+    fn update_grad_from(&self, grads: &Gradients) {
+        if let Some(grad) = grads.get::<T>(&self.id) {
+            self.state.update_grad(grad.clone());
+        }
+    }
+    fn to_leaf(&self) -> ForwardLeafRef {
+        let node = ForwardNode {
+            id: self.id.clone(),
+            order: 0,
+            state: ForwardNodeState::new(self.state.value()),
+            ops: Arc::new(InitRecordedOps::new()),
+            require_grad: self.require_grad,
+        };
+
+        Box::new(Arc::new(node))
     }
 }
@@ -1,6 +1,10 @@
 use super::ForwardNodeState;
-use crate::graph::ops::ForwardRecordedOpsRef;
-use std::sync::Arc;
+use crate::graph::{
+    converter::Forward2BackwardGraphConverter,
+    ops::{ForwardLeaf, ForwardRecordedOpsRef, RecordedOpsParentRef},
+};
+use crate::tensor::ops::Zeros;
+use std::{ops::Add, sync::Arc};
 
 #[derive(Debug)]
 pub struct ForwardNode<Out> {
@@ -8,6 +12,7 @@ pub struct ForwardNode<Out> {
     pub order: usize,
     pub state: ForwardNodeState<Out>,
     pub ops: ForwardRecordedOpsRef<Out>,
+    pub require_grad: bool,
 }
 pub type ForwardNodeRef<Out> = Arc<ForwardNode<Out>>;
 
@@ -47,6 +52,17 @@ impl<Out> ForwardNode<Out> {
             order,
             state,
             ops,
+            require_grad: true,
         }
     }
 }
+
+impl<Out> ForwardLeaf for ForwardNodeRef<Out>
+where
+    Out: Zeros<Out> + Clone + Add<Output = Out>,
+    Out: std::fmt::Debug + 'static + Send + Sync,
+{
+    fn to_backward(&self, graph: &mut Forward2BackwardGraphConverter) -> RecordedOpsParentRef {
+        graph.from(self)
+    }
+}
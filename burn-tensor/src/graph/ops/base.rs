@@ -31,8 +31,19 @@ pub trait RecordedOpsParent: std::fmt::Debug {
     fn backward_step(&self);
     fn backward_parents(&self) -> Vec<RecordedOpsParentRef>;
     fn register_grad(&self, grads: &mut Gradients);
+    /// Add the gradient registered for the node in the given gradients, if any.
+    fn update_grad_from(&self, grads: &Gradients);
+    /// Create a leaf with the same id and value as the node, which can be linked into another
+    /// graph.
+    fn to_leaf(&self) -> ForwardLeafRef;
+}
+
+/// A leaf of the forward graph, with the type of its value erased.
+pub trait ForwardLeaf: std::fmt::Debug + Send + Sync {
+    fn to_backward(&self, graph: &mut Forward2BackwardGraphConverter) -> RecordedOpsParentRef;
 }
 
 pub type ForwardRecordedOpsRef<T> = Arc<dyn ForwardRecordedOps<T>>;
 pub type BackwardRecordedOpsRef<T> = Arc<dyn BackwardRecordedOps<T>>;
 pub type RecordedOpsParentRef = Arc<dyn RecordedOpsParent>;
+pub type ForwardLeafRef = Box<dyn ForwardLeaf>;
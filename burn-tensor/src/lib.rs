@@ -2,7 +2,9 @@
 extern crate derive_new;
 
 pub(crate) mod graph;
+pub use graph::anomaly::{detect_anomaly, is_anomaly_detection_enabled};
 pub use graph::grad::Gradients;
+pub use graph::no_grad::{is_grad_enabled, no_grad};
 
 mod tensor;
 
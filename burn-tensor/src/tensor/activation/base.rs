@@ -8,12 +8,32 @@ pub fn relu<const D: usize, B: Backend>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
 }
 
 /// Applies the Gaussian Error Linear Units function as described in the paper in [Gaussian Error Linear Units (GELUs)](https://arxiv.org/pdf/1606.08415v3.pdf).
+///
+/// `y = 0.5 * x * (1 + erf(x / sqrt(2)))`
 pub fn gelu<const D: usize, B: Backend>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
     let x = tensor.div_scalar(2.0_f32.sqrt()).erf().add_scalar(1.0_f32);
 
     tensor.mul(&x) / 2
 }
 
+/// Applies the sigmoid function.
+///
+/// `y = 1 / (1 + exp(-x))`
+///
+/// Neither the output nor the gradient `y * (1 - y)` overflow for large inputs.
+pub fn sigmoid<const D: usize, B: Backend>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+    tensor.sigmoid()
+}
+
+/// Applies the hyperbolic tangent function.
+///
+/// `y = 2 * sigmoid(2x) - 1`
+pub fn tanh<const D: usize, B: Backend>(tensor: &Tensor<B, D>) -> Tensor<B, D> {
+    sigmoid(&tensor.mul_scalar(2.0_f32))
+        .mul_scalar(2.0_f32)
+        .sub_scalar(1.0_f32)
+}
+
 /// Applies the softmax function.
 pub fn softmax<const D: usize, B: Backend>(tensor: &Tensor<B, D>, dim: usize) -> Tensor<B, D> {
     log_softmax(tensor, dim).exp()
@@ -1,6 +1,6 @@
 use super::ADTensor;
 use crate::graph::grad::Gradients;
-use crate::tensor::backend::{ADBackend, Backend};
+use crate::tensor::backend::{ADBackend, Backend, CheckpointFn, MatmulPrecision};
 use crate::tensor::{Data, Distribution, Shape};
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -59,6 +59,14 @@ impl<B: Backend> Backend for ADBackendDecorator<B> {
     fn seed(seed: u64) {
         B::seed(seed)
     }
+
+    fn set_matmul_precision(precision: MatmulPrecision) {
+        B::set_matmul_precision(precision)
+    }
+
+    fn set_deterministic(deterministic: bool) {
+        B::set_deterministic(deterministic)
+    }
 }
 
 impl<B: Backend> ADBackend for ADBackendDecorator<B> {
@@ -67,6 +75,15 @@ impl<B: Backend> ADBackend for ADBackendDecorator<B> {
     fn backward<const D: usize>(tensor: &Self::TensorPrimitive<D>) -> Gradients {
         tensor.backward()
     }
+    fn backward_for<const D: usize>(
+        tensor: &Self::TensorPrimitive<D>,
+        ids: &[String],
+    ) -> Gradients {
+        tensor.backward_for(ids)
+    }
+    fn node_id<const D: usize>(tensor: &Self::TensorPrimitive<D>) -> String {
+        tensor.node.id.clone()
+    }
     fn grad<const D: usize>(
         tensor: &Self::TensorPrimitive<D>,
         grads: &Gradients,
@@ -74,6 +91,14 @@ impl<B: Backend> ADBackend for ADBackendDecorator<B> {
         grads.wrt(tensor).cloned()
     }
 
+    fn grad_replace<const D: usize>(
+        tensor: &Self::TensorPrimitive<D>,
+        grads: &mut Gradients,
+        grad: B::TensorPrimitive<D>,
+    ) {
+        grads.register_grad(tensor.node.id.clone(), grad);
+    }
+
     fn inner<const D: usize>(
         tensor: &Self::TensorPrimitive<D>,
     ) -> <Self::InnerBackend as Backend>::TensorPrimitive<D> {
@@ -85,6 +110,13 @@ impl<B: Backend> ADBackend for ADBackendDecorator<B> {
     ) -> Self::TensorPrimitive<D> {
         ADTensor::from_tensor(tensor)
     }
+
+    fn checkpoint<const D: usize, const D2: usize>(
+        tensor: &Self::TensorPrimitive<D>,
+        func: CheckpointFn<D, D2, Self>,
+    ) -> Self::TensorPrimitive<D2> {
+        tensor.checkpoint(func)
+    }
 }
 
 #[cfg(feature = "ndarray")]
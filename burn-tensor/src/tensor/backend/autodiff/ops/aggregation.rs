@@ -28,6 +28,25 @@ define_ops! {
     state (Shape<D>, usize),
 }
 
+define_ops! {
+    name ADTensorOpsMeanDims,
+    state (Shape<D>, Vec<usize>),
+}
+
+define_ops! {
+    name ADTensorOpsSumDims,
+    state (Shape<D>, Vec<usize>),
+}
+
+define_ops! {
+    name ADTensorOpsProd
+}
+
+define_ops! {
+    name ADTensorOpsProdDim,
+    state usize,
+}
+
 impl<B: Backend, const D: usize> UnaryOps<B::TensorPrimitive<D>, B::TensorPrimitive<1>>
     for ADTensorOpsMean<B, D>
 {
@@ -98,6 +117,109 @@ impl<B: Backend, const D: usize> UnaryOps<B::TensorPrimitive<D>, B::TensorPrimit
     }
 }
 
+impl<B: Backend, const D: usize> UnaryOps<B::TensorPrimitive<D>, B::TensorPrimitive<D>>
+    for ADTensorOpsMeanDims<B, D>
+{
+    fn partial(
+        &self,
+        state: &UnaryOpsNodeState<B::TensorPrimitive<D>, B::TensorPrimitive<D>>,
+    ) -> B::TensorPrimitive<D> {
+        let (shape, dims) = &self.state;
+
+        let grad = state.output.grad();
+        let ones = B::ones(*shape, B::device(&grad));
+
+        let num_elements: usize = dims.iter().map(|dim| shape.dims[*dim]).product();
+        let val = 1_f64 / num_elements as f64;
+        let ones = B::mul_scalar(&ones, &B::Elem::from_elem(val));
+
+        B::mul(&ones, &grad)
+    }
+}
+
+impl<B: Backend, const D: usize> UnaryOps<B::TensorPrimitive<D>, B::TensorPrimitive<D>>
+    for ADTensorOpsSumDims<B, D>
+{
+    fn partial(
+        &self,
+        state: &UnaryOpsNodeState<B::TensorPrimitive<D>, B::TensorPrimitive<D>>,
+    ) -> B::TensorPrimitive<D> {
+        let (shape, _dims) = &self.state;
+
+        let grad = state.output.grad();
+        let ones = B::ones(*shape, B::device(&grad));
+
+        B::mul(&ones, &grad)
+    }
+}
+
+impl<B: Backend, const D: usize> UnaryOps<B::TensorPrimitive<D>, B::TensorPrimitive<1>>
+    for ADTensorOpsProd<B, D>
+{
+    fn partial(
+        &self,
+        state: &UnaryOpsNodeState<B::TensorPrimitive<D>, B::TensorPrimitive<1>>,
+    ) -> B::TensorPrimitive<D> {
+        let grad = B::reshape(&state.output.grad(), Shape::new([1; D]));
+
+        prod_partial::<B, D>(&state.input.value(), &grad, None)
+    }
+}
+
+impl<B: Backend, const D: usize> UnaryOps<B::TensorPrimitive<D>, B::TensorPrimitive<D>>
+    for ADTensorOpsProdDim<B, D>
+{
+    fn partial(
+        &self,
+        state: &UnaryOpsNodeState<B::TensorPrimitive<D>, B::TensorPrimitive<D>>,
+    ) -> B::TensorPrimitive<D> {
+        prod_partial::<B, D>(&state.input.value(), &state.output.grad(), Some(self.state))
+    }
+}
+
+/// The gradient of a product over all elements, or along a dimension, given the gradient of its
+/// output with the reduced dimensions kept with a size of 1.
+///
+/// The partial derivative for each factor is the product of the other factors. Dividing the
+/// product by the factor doesn't work when the factor is zero, so the product of the non-zero
+/// factors is divided instead, and the partial derivative is zero when any other factor is zero.
+fn prod_partial<B: Backend, const D: usize>(
+    input: &B::TensorPrimitive<D>,
+    grad: &B::TensorPrimitive<D>,
+    dim: Option<usize>,
+) -> B::TensorPrimitive<D> {
+    let zero = B::Elem::zeros(&B::Elem::default());
+    let one = B::Elem::ones(&B::Elem::default());
+    let ones = input.ones();
+
+    let is_zero = input.equal_scalar(&zero);
+    let non_zero = B::mask_fill(input, &is_zero, one);
+    let zeros = B::mask_fill(&input.zeros(), &is_zero, one);
+
+    let (prod, num_zeros) = match dim {
+        Some(dim) => (non_zero.prod_dim(dim), zeros.sum_dim(dim)),
+        None => (
+            B::reshape(&non_zero.prod(), Shape::new([1; D])),
+            B::reshape(&zeros.sum(), Shape::new([1; D])),
+        ),
+    };
+    let prod = B::mul(&ones, &prod);
+    let num_zeros_others = B::sub(&B::mul(&ones, &num_zeros), &zeros);
+
+    let partial = B::div(&prod, &non_zero);
+    let partial = B::mask_fill(&partial, &num_zeros_others.greater_scalar(&zero), zero);
+
+    B::mul(&B::mul(&ones, grad), &partial)
+}
+
+/// Remove the duplicated dimensions, which are only reduced once.
+fn unique_dims(dims: &[usize]) -> Vec<usize> {
+    let mut dims = dims.to_vec();
+    dims.sort_unstable();
+    dims.dedup();
+    dims
+}
+
 impl<B: Backend, const D: usize> TensorOpsAggregation<ADBackendDecorator<B>, D>
     for <ADBackendDecorator<B> as Backend>::TensorPrimitive<D>
 {
@@ -132,6 +254,42 @@ impl<B: Backend, const D: usize> TensorOpsAggregation<ADBackendDecorator<B>, D>
             ops ADTensorOpsSumDim::<B, D>::new((self.shape, dim)),
         )
     }
+
+    fn mean_dims(&self, dims: &[usize]) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        let dims = unique_dims(dims);
+
+        execute_ops!(
+            input self.node.clone(),
+            out TensorOpsAggregation::mean_dims(&self.tensor(), &dims),
+            ops ADTensorOpsMeanDims::<B, D>::new((self.shape, dims)),
+        )
+    }
+
+    fn sum_dims(&self, dims: &[usize]) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        let dims = unique_dims(dims);
+
+        execute_ops!(
+            input self.node.clone(),
+            out TensorOpsAggregation::sum_dims(&self.tensor(), &dims),
+            ops ADTensorOpsSumDims::<B, D>::new((self.shape, dims)),
+        )
+    }
+
+    fn prod(&self) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<1> {
+        execute_ops!(
+            input self.node.clone(),
+            out TensorOpsAggregation::prod(&self.tensor()),
+            ops ADTensorOpsProd::<B, D>::new(),
+        )
+    }
+
+    fn prod_dim(&self, dim: usize) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        execute_ops!(
+            input self.node.clone(),
+            out TensorOpsAggregation::prod_dim(&self.tensor(), dim),
+            ops ADTensorOpsProdDim::<B, D>::new(dim),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -1,10 +1,16 @@
 use crate::{
     backend::{autodiff::ADTensor, Backend},
     graph::{
+        anomaly::is_anomaly_detection_enabled,
+        no_grad::is_grad_enabled,
         node::{ForwardNode, ForwardNodeRef, ForwardNodeState},
-        ops::{BinaryOps, ForwardBinaryRecordedOps, ForwardUnaryRecordedOps, UnaryOps},
+        ops::{
+            BinaryOps, BinaryOpsNodeState, ForwardBinaryRecordedOps, ForwardUnaryRecordedOps,
+            UnaryOps, UnaryOpsNodeState,
+        },
     },
 };
+use num_traits::ToPrimitive;
 use std::sync::Arc;
 
 pub fn unary_ops_wrapper<B, O, const D1: usize, const D2: usize>(
@@ -16,10 +22,14 @@ where
     B: Backend,
     O: UnaryOps<B::TensorPrimitive<D1>, B::TensorPrimitive<D2>> + 'static,
 {
+    if !is_grad_enabled() {
+        return ADTensor::from_tensor(output);
+    }
+
     let shape = *B::shape(&output);
     let state = ForwardNodeState::new(output);
 
-    let ops = Arc::new(ops);
+    let ops = Arc::new(UnaryAnomalyOps::<B, O, D1, D2>::new(ops));
     let ops = ForwardUnaryRecordedOps::new(input.clone(), ops);
     let ops = Arc::new(ops);
 
@@ -39,10 +49,14 @@ where
     B: Backend,
     O: BinaryOps<B::TensorPrimitive<D1>, B::TensorPrimitive<D2>, B::TensorPrimitive<D3>> + 'static,
 {
+    if !is_grad_enabled() {
+        return ADTensor::from_tensor(output);
+    }
+
     let shape = *B::shape(&output);
     let state = ForwardNodeState::new(output);
 
-    let ops = Arc::new(ops);
+    let ops = Arc::new(BinaryAnomalyOps::<B, O, D1, D2, D3>::new(ops));
     let ops = ForwardBinaryRecordedOps::new(lhs.clone(), rhs.clone(), ops);
     let ops = Arc::new(ops);
 
@@ -51,3 +65,140 @@ where
 
     ADTensor { node, shape }
 }
+
+/// Decorate the backward of an operation to check for NaN and infinite values when
+/// [anomaly detection](crate::detect_anomaly) is enabled.
+#[derive(Debug)]
+struct UnaryAnomalyOps<B: Backend, O, const D1: usize, const D2: usize> {
+    ops: O,
+    _b: B,
+}
+
+#[derive(Debug)]
+struct BinaryAnomalyOps<B: Backend, O, const D1: usize, const D2: usize, const D3: usize> {
+    ops: O,
+    _b: B,
+}
+
+impl<B: Backend, O, const D1: usize, const D2: usize> UnaryAnomalyOps<B, O, D1, D2> {
+    fn new(ops: O) -> Self {
+        Self {
+            ops,
+            _b: B::default(),
+        }
+    }
+}
+
+impl<B: Backend, O, const D1: usize, const D2: usize, const D3: usize>
+    BinaryAnomalyOps<B, O, D1, D2, D3>
+{
+    fn new(ops: O) -> Self {
+        Self {
+            ops,
+            _b: B::default(),
+        }
+    }
+}
+
+impl<B, O, const D1: usize, const D2: usize>
+    UnaryOps<B::TensorPrimitive<D1>, B::TensorPrimitive<D2>> for UnaryAnomalyOps<B, O, D1, D2>
+where
+    B: Backend,
+    O: UnaryOps<B::TensorPrimitive<D1>, B::TensorPrimitive<D2>>,
+{
+    fn partial(
+        &self,
+        state: &UnaryOpsNodeState<B::TensorPrimitive<D1>, B::TensorPrimitive<D2>>,
+    ) -> B::TensorPrimitive<D1> {
+        let partial = self.ops.partial(state);
+
+        if is_anomaly_detection_enabled() {
+            let inputs_finite = is_finite::<B, D1>(&state.input.value);
+            check_anomaly::<B, O, D1, D2>(inputs_finite, state.output, &partial);
+        }
+
+        partial
+    }
+}
+
+impl<B, O, const D1: usize, const D2: usize, const D3: usize>
+    BinaryOps<B::TensorPrimitive<D1>, B::TensorPrimitive<D2>, B::TensorPrimitive<D3>>
+    for BinaryAnomalyOps<B, O, D1, D2, D3>
+where
+    B: Backend,
+    O: BinaryOps<B::TensorPrimitive<D1>, B::TensorPrimitive<D2>, B::TensorPrimitive<D3>>,
+{
+    fn partial_left(
+        &self,
+        state: &BinaryOpsNodeState<
+            B::TensorPrimitive<D1>,
+            B::TensorPrimitive<D2>,
+            B::TensorPrimitive<D3>,
+        >,
+    ) -> B::TensorPrimitive<D1> {
+        let partial = self.ops.partial_left(state);
+
+        if is_anomaly_detection_enabled() {
+            let inputs_finite =
+                is_finite::<B, D1>(&state.left.value) && is_finite::<B, D2>(&state.right.value);
+            check_anomaly::<B, O, D1, D3>(inputs_finite, state.output, &partial);
+        }
+
+        partial
+    }
+
+    fn partial_right(
+        &self,
+        state: &BinaryOpsNodeState<
+            B::TensorPrimitive<D1>,
+            B::TensorPrimitive<D2>,
+            B::TensorPrimitive<D3>,
+        >,
+    ) -> B::TensorPrimitive<D2> {
+        let partial = self.ops.partial_right(state);
+
+        if is_anomaly_detection_enabled() {
+            let inputs_finite =
+                is_finite::<B, D1>(&state.left.value) && is_finite::<B, D2>(&state.right.value);
+            check_anomaly::<B, O, D2, D3>(inputs_finite, state.output, &partial);
+        }
+
+        partial
+    }
+}
+
+/// Panics when the operation is the first one to produce NaN or infinite values, either in its
+/// output from finite inputs or in its gradients from a finite output gradient.
+fn check_anomaly<B: Backend, O, const DI: usize, const DO: usize>(
+    inputs_finite: bool,
+    output: &crate::graph::node::BackwardNodeState<B::TensorPrimitive<DO>>,
+    partial: &B::TensorPrimitive<DI>,
+) {
+    if inputs_finite && !is_finite::<B, DO>(&output.value) {
+        panic!(
+            "Anomaly detected: NaN or infinite values in the output of {}",
+            ops_name::<O>()
+        );
+    }
+    if is_finite::<B, DO>(&output.grad()) && !is_finite::<B, DI>(partial) {
+        panic!(
+            "Anomaly detected: NaN or infinite values in the gradients computed by {}",
+            ops_name::<O>()
+        );
+    }
+}
+
+fn is_finite<B: Backend, const D: usize>(tensor: &B::TensorPrimitive<D>) -> bool {
+    B::to_data(tensor)
+        .value
+        .iter()
+        .all(|value| value.to_f64().map(f64::is_finite).unwrap_or(true))
+}
+
+/// The name of the operation type, without its module path and generic arguments.
+fn ops_name<O>() -> &'static str {
+    let name = std::any::type_name::<O>();
+    let name = name.split('<').next().unwrap_or(name);
+
+    name.rsplit("::").next().unwrap_or(name)
+}
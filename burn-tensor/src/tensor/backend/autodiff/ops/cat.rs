@@ -47,10 +47,13 @@ impl<const D: usize, B: Backend> BackwardRecordedOps<B::TensorPrimitive<D>>
         let indexes: Vec<_> = B::shape(&grad).dims.iter().map(|v| 0..*v).collect();
         let indexes: [std::ops::Range<usize>; D] = indexes.try_into().unwrap();
 
-        self.nodes.iter().enumerate().for_each(|(i, node)| {
+        let mut start = 0;
+        self.nodes.iter().for_each(|node| {
+            let size = B::shape(&node.state.value).dims[self.dim];
             let mut indexes = indexes.clone();
-            indexes[self.dim] = i..i + 1;
+            indexes[self.dim] = start..start + size;
             node.state.update_grad(B::index(&grad, indexes));
+            start += size;
         });
     }
 
@@ -156,4 +159,22 @@ mod tests {
             .to_data()
             .assert_approx_eq(&grad_2_list_2.to_data(), 3);
     }
+
+    #[test]
+    fn should_diff_cat_with_different_sizes() {
+        let tensor_1 = TestADTensor::from_data(Data::<_, 2>::from([[1.0, 2.0], [3.0, 4.0]]));
+        let tensor_2 = TestADTensor::from_data(Data::<_, 2>::from([[5.0, 6.0]]));
+
+        let tensor_3 = TestADTensor::cat(vec![tensor_1.clone(), tensor_2.clone()], 0);
+        assert_eq!(tensor_3.shape().dims, [3, 2]);
+
+        let weights = TestADTensor::from_data(Data::<_, 2>::from([[1.0], [2.0]]));
+        let grads = tensor_3.matmul(&weights).backward();
+
+        let grad_1 = tensor_1.grad(&grads).unwrap();
+        let grad_2 = tensor_2.grad(&grads).unwrap();
+
+        assert_eq!(grad_1.to_data(), Data::from([[1.0, 2.0], [1.0, 2.0]]));
+        assert_eq!(grad_2.to_data(), Data::from([[1.0, 2.0]]));
+    }
 }
@@ -0,0 +1,155 @@
+use crate::graph::converter::Forward2BackwardGraphConverter;
+use crate::graph::no_grad::is_grad_enabled;
+use crate::graph::node::{
+    BackwardNode, BackwardNodeRef, BackwardNodeState, ForwardNode, ForwardNodeRef, ForwardNodeState,
+};
+use crate::graph::ops::{
+    BackwardRecordedOps, BackwardRecordedOpsRef, ForwardLeafRef, ForwardRecordedOps,
+    RecordedOpsParent, RecordedOpsParentRef,
+};
+use crate::graph::traversal::{BreadthFirstSearch, GraphTraversal};
+use crate::tensor::backend::autodiff::{ADBackendDecorator, ADTensor};
+use crate::tensor::backend::{Backend, CheckpointFn};
+use crate::tensor::ops::*;
+use std::sync::Arc;
+
+/// The recorded function only keeps its input, the leaves it uses and its output, so its
+/// intermediate values are recomputed during the backward pass.
+pub struct ForwardCheckpointOps<const D: usize, const D2: usize, B: Backend> {
+    input: ForwardNodeRef<B::TensorPrimitive<D>>,
+    leaves: Vec<ForwardLeafRef>,
+    func: CheckpointFn<D, D2, ADBackendDecorator<B>>,
+}
+
+pub struct BackwardCheckpointOps<const D: usize, const D2: usize, B: Backend> {
+    input: BackwardNodeRef<B::TensorPrimitive<D>>,
+    leaves: Vec<RecordedOpsParentRef>,
+    func: CheckpointFn<D, D2, ADBackendDecorator<B>>,
+}
+
+impl<const D: usize, const D2: usize, B: Backend> std::fmt::Debug
+    for ForwardCheckpointOps<D, D2, B>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ForwardCheckpointOps")
+            .field("input", &self.input)
+            .field("leaves", &self.leaves)
+            .finish()
+    }
+}
+
+impl<const D: usize, const D2: usize, B: Backend> std::fmt::Debug
+    for BackwardCheckpointOps<D, D2, B>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BackwardCheckpointOps")
+            .field("input", &self.input)
+            .field("leaves", &self.leaves)
+            .finish()
+    }
+}
+
+impl<const D: usize, const D2: usize, B: Backend> ForwardRecordedOps<B::TensorPrimitive<D2>>
+    for ForwardCheckpointOps<D, D2, B>
+{
+    fn to_backward(
+        &self,
+        graph: &mut Forward2BackwardGraphConverter,
+    ) -> BackwardRecordedOpsRef<B::TensorPrimitive<D2>> {
+        let input = graph.from(&self.input);
+        let leaves = self
+            .leaves
+            .iter()
+            .map(|leaf| leaf.to_backward(graph))
+            .collect();
+
+        Arc::new(BackwardCheckpointOps::<D, D2, B> {
+            input,
+            leaves,
+            func: self.func.clone(),
+        })
+    }
+}
+
+impl<const D: usize, const D2: usize, B: Backend> BackwardRecordedOps<B::TensorPrimitive<D2>>
+    for BackwardCheckpointOps<D, D2, B>
+{
+    fn backward_step(&self, state: &BackwardNodeState<B::TensorPrimitive<D2>>) {
+        let input = ADTensor::<D, B>::from_tensor(self.input.state.value());
+        let output = (self.func)(input.clone());
+
+        // The gradients of the output weighted by its upstream gradient are the gradients
+        // flowing through the function.
+        let grad = ADTensor::from_tensor_require_grad(state.grad(), false);
+        let output = ADBackendDecorator::<B>::mul(&output, &grad).sum();
+
+        let mut ids: Vec<String> = self.leaves.iter().map(|leaf| leaf.id().clone()).collect();
+        ids.push(input.node.id.clone());
+
+        let mut converter = Forward2BackwardGraphConverter::new();
+        let mut node = BackwardNode::from_node(&output.node, &mut converter);
+        std::mem::drop(converter);
+        let grads = node.backward_for(&ids);
+
+        if let Some(grad) = grads.get::<B::TensorPrimitive<D>>(&input.node.id) {
+            self.input.state.update_grad(grad.clone());
+        }
+        for leaf in self.leaves.iter() {
+            leaf.update_grad_from(&grads);
+        }
+    }
+
+    fn backward_parents(&self) -> Vec<RecordedOpsParentRef> {
+        let mut parents = self.leaves.clone();
+        parents.push(self.input.clone());
+        parents
+    }
+}
+
+impl<B: Backend, const D: usize> ADTensor<D, B> {
+    /// Apply the function without keeping its intermediate values in the graph, which are
+    /// recomputed when the gradients flow through it.
+    ///
+    /// The leaves used by the function, like the parameters of a module, receive their gradients
+    /// as if the function was recorded normally.
+    pub fn checkpoint<const D2: usize>(
+        &self,
+        func: CheckpointFn<D, D2, ADBackendDecorator<B>>,
+    ) -> ADTensor<D2, B> {
+        if !is_grad_enabled() {
+            return func(self.clone());
+        }
+
+        let input = ADTensor::<D, B>::from_tensor(self.tensor());
+        let output = func(input.clone());
+
+        // The graph of the function is only converted to find its leaves and dropped with its
+        // intermediate values right after.
+        let mut converter = Forward2BackwardGraphConverter::new();
+        let node = BackwardNode::from_node(&output.node, &mut converter);
+        std::mem::drop(converter);
+
+        let mut leaves = Vec::new();
+        if node.order == 0 && node.id != input.node.id {
+            leaves.push(node.to_leaf());
+        }
+        BreadthFirstSearch::new(&node).traverse(|parent| {
+            if parent.order() == 0 && parent.id() != &input.node.id {
+                leaves.push(parent.to_leaf());
+            }
+        });
+
+        let ops = ForwardCheckpointOps::<D, D2, B> {
+            input: self.node.clone(),
+            leaves,
+            func,
+        };
+        let state = ForwardNodeState::new(output.tensor());
+        let node = ForwardNode::from_unary(&self.node, state, Arc::new(ops));
+
+        ADTensor {
+            node: Arc::new(node),
+            shape: output.shape,
+        }
+    }
+}
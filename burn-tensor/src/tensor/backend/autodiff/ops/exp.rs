@@ -10,6 +10,7 @@ register_ops!(
     ops UnaryOps,
     name ADTensorExpOps,
     partial |state: &UnaryOpsNodeState<B::TensorPrimitive<D>, B::TensorPrimitive<D>>|{
+        // The derivative of exp is exp itself, so the forward output is reused.
         B::mul(&state.output.grad(), &state.output.value())
     },
 );
@@ -1,4 +1,5 @@
 use crate::tensor::backend::Backend;
+use crate::ElementConversion;
 use crate::{
     execute_ops,
     graph::ops::{UnaryOps, UnaryOpsNodeState},
@@ -16,6 +17,16 @@ register_ops!(
     },
 );
 
+register_ops!(
+    ops UnaryOps,
+    name ADTensorLog1pOps,
+    partial |state: &UnaryOpsNodeState<B::TensorPrimitive<D>, B::TensorPrimitive<D>>|{
+        let value = state.input.value();
+        let value = B::div(&value.ones(), &B::add_scalar(&value, &1.to_elem()));
+        B::mul(&state.output.grad(), &value)
+    },
+);
+
 impl<B: Backend, const D: usize> TensorOpsLog<B::Elem, D> for ADTensor<D, B> {
     fn log(&self) -> Self {
         execute_ops!(
@@ -24,6 +35,14 @@ impl<B: Backend, const D: usize> TensorOpsLog<B::Elem, D> for ADTensor<D, B> {
             ops ADTensorLogOps::<B, D>::new(),
         )
     }
+
+    fn log1p(&self) -> Self {
+        execute_ops!(
+            input self.node.clone(),
+            out TensorOpsLog::log1p(&self.tensor()),
+            ops ADTensorLog1pOps::<B, D>::new(),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -52,4 +71,17 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::from([[22.8614, 24.5043], [24.5729, 26.8507]]), 3);
     }
+
+    #[test]
+    fn should_diff_log_of_zero_to_inf() {
+        let data = Data::<f64, 1>::from([0.0]);
+        let tensor = TestADTensor::from_data(data);
+
+        let output = tensor.log();
+        let grads = output.backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        assert_eq!(output.into_data().value, vec![f64::NEG_INFINITY]);
+        assert_eq!(grad.into_data().value, vec![f64::INFINITY]);
+    }
 }
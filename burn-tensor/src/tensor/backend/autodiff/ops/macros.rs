@@ -116,40 +116,14 @@ macro_rules! execute_ops {
         out $out:expr,
         ops $ops:expr,
     ) => {{
-        let callback = || {
-            let shape = B::shape(&$out).clone();
-            let state = $crate::graph::node::ForwardNodeState::new($out);
-
-            let ops = std::sync::Arc::new($ops);
-            let ops = $crate::graph::ops::ForwardBinaryRecordedOps::new($lhs, $rhs, ops.clone());
-            let ops = std::sync::Arc::new(ops);
-
-            let node = $crate::graph::node::ForwardNode::from_binary(&$lhs, &$rhs, state, ops);
-            let node = std::sync::Arc::new(node);
-
-            $crate::tensor::backend::autodiff::ADTensor { node, shape }
-        };
-        callback()
+        $crate::tensor::backend::autodiff::ops::binary_ops_wrapper($lhs, $rhs, $out, $ops)
     }};
     (
         input $input:expr,
         out $out:expr,
         ops $ops:expr,
     ) => {{
-        let callback = || {
-            let shape = B::shape(&$out).clone();
-            let state = $crate::graph::node::ForwardNodeState::new($out);
-
-            let ops = std::sync::Arc::new($ops);
-            let ops = $crate::graph::ops::ForwardUnaryRecordedOps::new($input, ops.clone());
-            let ops = std::sync::Arc::new(ops);
-
-            let node = $crate::graph::node::ForwardNode::from_unary(&$input, state, ops);
-            let node = std::sync::Arc::new(node);
-
-            $crate::tensor::backend::autodiff::ADTensor { node, shape }
-        };
-        callback()
+        $crate::tensor::backend::autodiff::ops::unary_ops_wrapper($input, $out, $ops)
     }};
     (
         input $input:expr,
@@ -158,6 +132,10 @@ macro_rules! execute_ops {
         shape $shape:expr,
     ) => {{
         let callback = || {
+            if !$crate::graph::no_grad::is_grad_enabled() {
+                return $crate::tensor::backend::autodiff::ADTensor::from_tensor($out);
+            }
+
             let shape = $shape;
             let state = $crate::graph::node::ForwardNodeState::new($out);
 
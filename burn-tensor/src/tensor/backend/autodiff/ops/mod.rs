@@ -2,6 +2,7 @@ mod aggregation;
 mod arg;
 mod base;
 mod cat;
+mod checkpoint;
 mod creation;
 mod detach;
 mod erf;
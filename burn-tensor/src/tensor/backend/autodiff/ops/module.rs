@@ -1,10 +1,10 @@
-use super::unary_ops_wrapper;
+use super::{binary_ops_wrapper, unary_ops_wrapper};
 use crate::{
     backend::{
         autodiff::{ADBackendDecorator, ADTensor},
         Backend,
     },
-    graph::ops::{UnaryOps, UnaryOpsNodeState},
+    graph::ops::{BinaryOps, BinaryOpsNodeState, UnaryOps, UnaryOpsNodeState},
     ops::ModuleOps,
 };
 
@@ -22,6 +22,95 @@ impl<B: Backend> UnaryOps<B::TensorPrimitive<2>, B::TensorPrimitive<3>> for Embe
     }
 }
 
+#[derive(new, Debug)]
+struct Conv1dBackward<B: Backend> {
+    stride: usize,
+    padding: usize,
+    dilation: usize,
+    _b: B,
+}
+
+impl<B: Backend> BinaryOps<B::TensorPrimitive<3>, B::TensorPrimitive<3>, B::TensorPrimitive<3>>
+    for Conv1dBackward<B>
+{
+    fn partial_left(
+        &self,
+        state: &BinaryOpsNodeState<
+            B::TensorPrimitive<3>,
+            B::TensorPrimitive<3>,
+            B::TensorPrimitive<3>,
+        >,
+    ) -> B::TensorPrimitive<3> {
+        B::conv1d_x_backward(
+            &state.left.value(),
+            &state.right.value(),
+            &state.output.grad(),
+            self.stride,
+            self.padding,
+            self.dilation,
+        )
+    }
+
+    fn partial_right(
+        &self,
+        state: &BinaryOpsNodeState<
+            B::TensorPrimitive<3>,
+            B::TensorPrimitive<3>,
+            B::TensorPrimitive<3>,
+        >,
+    ) -> B::TensorPrimitive<3> {
+        B::conv1d_weight_backward(
+            &state.left.value(),
+            &state.right.value(),
+            &state.output.grad(),
+            self.stride,
+            self.padding,
+            self.dilation,
+        )
+    }
+}
+
+#[derive(new, Debug)]
+struct AvgPool2dBackward<B: Backend> {
+    kernel_size: [usize; 2],
+    stride: [usize; 2],
+    padding: [usize; 2],
+    count_include_pad: bool,
+    _b: B,
+}
+
+impl<B: Backend> UnaryOps<B::TensorPrimitive<4>, B::TensorPrimitive<4>> for AvgPool2dBackward<B> {
+    fn partial(
+        &self,
+        state: &UnaryOpsNodeState<B::TensorPrimitive<4>, B::TensorPrimitive<4>>,
+    ) -> B::TensorPrimitive<4> {
+        B::avg_pool2d_backward(
+            &state.input.value,
+            &state.output.grad(),
+            self.kernel_size,
+            self.stride,
+            self.padding,
+            self.count_include_pad,
+        )
+    }
+}
+
+#[derive(new, Debug)]
+struct AdaptiveAvgPool2dBackward<B: Backend> {
+    _b: B,
+}
+
+impl<B: Backend> UnaryOps<B::TensorPrimitive<4>, B::TensorPrimitive<4>>
+    for AdaptiveAvgPool2dBackward<B>
+{
+    fn partial(
+        &self,
+        state: &UnaryOpsNodeState<B::TensorPrimitive<4>, B::TensorPrimitive<4>>,
+    ) -> B::TensorPrimitive<4> {
+        B::adaptive_avg_pool2d_backward(&state.input.value, &state.output.grad())
+    }
+}
+
 impl<B: Backend> ModuleOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
     fn embedding(
         weights: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<2>,
@@ -46,4 +135,123 @@ impl<B: Backend> ModuleOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         let tensor = B::embedding_backward(weights.tensor_ref(), output.tensor_ref(), indexes);
         ADTensor::from_tensor(tensor)
     }
+
+    fn conv1d(
+        x: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<3>,
+        weight: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<3>,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<3> {
+        let output = B::conv1d(
+            x.tensor_ref(),
+            weight.tensor_ref(),
+            stride,
+            padding,
+            dilation,
+        );
+        let ops = Conv1dBackward::<B>::new(stride, padding, dilation, B::default());
+
+        binary_ops_wrapper(x.node.clone(), weight.node.clone(), output, ops)
+    }
+
+    fn conv1d_x_backward(
+        x: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<3>,
+        weight: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<3>,
+        output_grad: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<3>,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<3> {
+        let tensor = B::conv1d_x_backward(
+            x.tensor_ref(),
+            weight.tensor_ref(),
+            output_grad.tensor_ref(),
+            stride,
+            padding,
+            dilation,
+        );
+        ADTensor::from_tensor(tensor)
+    }
+
+    fn conv1d_weight_backward(
+        x: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<3>,
+        weight: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<3>,
+        output_grad: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<3>,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<3> {
+        let tensor = B::conv1d_weight_backward(
+            x.tensor_ref(),
+            weight.tensor_ref(),
+            output_grad.tensor_ref(),
+            stride,
+            padding,
+            dilation,
+        );
+        ADTensor::from_tensor(tensor)
+    }
+
+    fn avg_pool2d(
+        x: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<4> {
+        let output = B::avg_pool2d(
+            x.tensor_ref(),
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+        );
+        let ops = AvgPool2dBackward::<B>::new(
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+            B::default(),
+        );
+
+        unary_ops_wrapper(x.node.clone(), output, ops)
+    }
+
+    fn avg_pool2d_backward(
+        x: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<4>,
+        output_grad: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<4> {
+        let tensor = B::avg_pool2d_backward(
+            x.tensor_ref(),
+            output_grad.tensor_ref(),
+            kernel_size,
+            stride,
+            padding,
+            count_include_pad,
+        );
+        ADTensor::from_tensor(tensor)
+    }
+
+    fn adaptive_avg_pool2d(
+        x: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<4>,
+        output_size: [usize; 2],
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<4> {
+        let output = B::adaptive_avg_pool2d(x.tensor_ref(), output_size);
+        let ops = AdaptiveAvgPool2dBackward::<B>::new(B::default());
+
+        unary_ops_wrapper(x.node.clone(), output, ops)
+    }
+
+    fn adaptive_avg_pool2d_backward(
+        x: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<4>,
+        output_grad: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<4>,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<4> {
+        let tensor = B::adaptive_avg_pool2d_backward(x.tensor_ref(), output_grad.tensor_ref());
+        ADTensor::from_tensor(tensor)
+    }
 }
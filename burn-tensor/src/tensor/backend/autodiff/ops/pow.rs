@@ -22,6 +22,21 @@ register_ops!(
     },
 );
 
+register_ops!(
+    ops UnaryOps,
+    name ADTensorPowiOps state i32,
+    partial |
+        value: &i32,
+        state: &UnaryOpsNodeState<B::TensorPrimitive<D>, B::TensorPrimitive<D>>
+    | {
+        let value = B::mul_scalar(&state.input
+            .value()
+            .powi(value - 1)
+            , &value.to_elem());
+        B::mul(&state.output.grad(), &value)
+    },
+);
+
 impl<B: Backend, const D: usize> TensorOpsPow<B::Elem, D> for ADTensor<D, B> {
     fn powf(&self, value: f32) -> Self {
         execute_ops!(
@@ -30,6 +45,14 @@ impl<B: Backend, const D: usize> TensorOpsPow<B::Elem, D> for ADTensor<D, B> {
             ops ADTensorPowOps::<B, D>::new(value),
         )
     }
+
+    fn powi(&self, value: i32) -> Self {
+        execute_ops!(
+            input self.node.clone(),
+            out TensorOpsPow::powi(&self.tensor(), value),
+            ops ADTensorPowiOps::<B, D>::new(value),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -66,6 +66,10 @@ impl<B: Backend, const D: usize> TensorOpsPrecision<ADBackendDecorator<B>, D>
         tensor_full: ADTensor<D, <B as Backend>::FullPrecisionBackend>,
     ) -> ADTensor<D, B> {
         let tensor = <B as Backend>::TensorPrimitive::from_full_precision(tensor_full.tensor());
+        if !crate::graph::no_grad::is_grad_enabled() {
+            return ADTensor::from_tensor(tensor);
+        }
+
         let shape = *B::shape(&tensor);
         let state = crate::graph::node::ForwardNodeState::new(tensor);
 
@@ -5,8 +5,8 @@ use crate::{
         Backend,
     },
     graph::ops::{BinaryOps, BinaryOpsNodeState, UnaryOps, UnaryOpsNodeState},
-    ops::{Ones, TensorOps, TensorOpsAggregation, Zeros},
-    Data, Shape,
+    ops::{Ones, TensorOps, TensorOpsAggregation, TensorOpsMapComparison, Zeros},
+    Data, ElementConversion, Shape,
 };
 use std::ops::Range;
 
@@ -37,6 +37,12 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         B::into_data(tensor.tensor())
     }
 
+    fn count_nonzero<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+    ) -> usize {
+        B::count_nonzero(tensor.tensor_ref())
+    }
+
     fn bool_shape<const D: usize>(
         tensor: &<ADBackendDecorator<B> as Backend>::BoolTensorPrimitive<D>,
     ) -> &Shape<D> {
@@ -55,12 +61,51 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         B::bool_into_data(tensor)
     }
 
+    fn bool_all<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::BoolTensorPrimitive<D>,
+    ) -> bool {
+        B::bool_all(tensor)
+    }
+
+    fn bool_any<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::BoolTensorPrimitive<D>,
+    ) -> bool {
+        B::bool_any(tensor)
+    }
+
+    fn bool_all_dim<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::BoolTensorPrimitive<D>,
+        dim: usize,
+    ) -> <ADBackendDecorator<B> as Backend>::BoolTensorPrimitive<D> {
+        B::bool_all_dim(tensor, dim)
+    }
+
+    fn bool_any_dim<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::BoolTensorPrimitive<D>,
+        dim: usize,
+    ) -> <ADBackendDecorator<B> as Backend>::BoolTensorPrimitive<D> {
+        B::bool_any_dim(tensor, dim)
+    }
+
+    fn bool_to_float<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::BoolTensorPrimitive<D>,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        ADTensor::from_tensor(B::bool_to_float(tensor))
+    }
+
     fn device<const D: usize>(
         tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
     ) -> <ADBackendDecorator<B> as Backend>::Device {
         B::device(tensor.tensor_ref())
     }
 
+    fn set_require_grad<const D: usize>(
+        tensor: <ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+        require_grad: bool,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        ADTensor::from_tensor_require_grad(tensor.tensor(), require_grad)
+    }
+
     fn to_device<const D: usize>(
         tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
         device: <ADBackendDecorator<B> as Backend>::Device,
@@ -89,6 +134,31 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         unary_ops_wrapper(input, output, ops)
     }
 
+    fn deep_clone<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        #[derive(Default, Debug)]
+        struct DeepCloneBackward<B: Backend, const D: usize> {
+            _b: B,
+        }
+
+        impl<B: Backend, const D: usize> UnaryOps<B::TensorPrimitive<D>, B::TensorPrimitive<D>>
+            for DeepCloneBackward<B, D>
+        {
+            fn partial(
+                &self,
+                state: &UnaryOpsNodeState<B::TensorPrimitive<D>, B::TensorPrimitive<D>>,
+            ) -> B::TensorPrimitive<D> {
+                state.output.grad()
+            }
+        }
+
+        let output = B::deep_clone(tensor.tensor_ref());
+        let ops = DeepCloneBackward::<B, D>::default();
+
+        unary_ops_wrapper(tensor.node.clone(), output, ops)
+    }
+
     fn empty<const D: usize>(
         shape: Shape<D>,
         device: <ADBackendDecorator<B> as Backend>::Device,
@@ -164,6 +234,13 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         unary_ops_wrapper(lhs.node.clone(), output, ops)
     }
 
+    fn add_assign<const D: usize>(
+        lhs: &mut <ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+        rhs: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+    ) {
+        assign_ops(lhs, rhs, B::add_assign);
+    }
+
     fn sub<const D: usize>(
         lhs: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
         rhs: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
@@ -232,6 +309,13 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         unary_ops_wrapper(lhs.node.clone(), output, ops)
     }
 
+    fn sub_assign<const D: usize>(
+        lhs: &mut <ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+        rhs: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+    ) {
+        assign_ops(lhs, rhs, B::sub_assign);
+    }
+
     fn mul<const D: usize>(
         lhs: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
         rhs: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
@@ -300,6 +384,13 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         unary_ops_wrapper(lhs.node.clone(), output, ops)
     }
 
+    fn mul_assign<const D: usize>(
+        lhs: &mut <ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+        rhs: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+    ) {
+        assign_ops(lhs, rhs, B::mul_assign);
+    }
+
     fn div<const D: usize>(
         lhs: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
         rhs: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
@@ -400,8 +491,11 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
                 >,
             ) -> B::TensorPrimitive<D> {
                 let out_grad = state.output.grad();
+                let lhs = state.left.value();
                 let rhs = B::transpose(&state.right.value());
-                B::matmul(&out_grad, &rhs)
+                let grad = B::matmul(&out_grad, &rhs);
+
+                reduce_broadcasted_batch::<B, D>(grad, B::shape(&lhs))
             }
 
             fn partial_right(
@@ -413,11 +507,30 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
                 >,
             ) -> B::TensorPrimitive<D> {
                 let out_grad = state.output.grad();
+                let rhs = state.right.value();
                 let lhs = B::transpose(&state.left.value());
-                B::matmul(&lhs, &out_grad)
+                let grad = B::matmul(&lhs, &out_grad);
+
+                reduce_broadcasted_batch::<B, D>(grad, B::shape(&rhs))
             }
         }
 
+        /// Sum the gradient over the batch dimensions that were broadcasted.
+        fn reduce_broadcasted_batch<B: Backend, const D: usize>(
+            grad: B::TensorPrimitive<D>,
+            shape: &Shape<D>,
+        ) -> B::TensorPrimitive<D> {
+            let mut grad = grad;
+
+            for i in 0..D.saturating_sub(2) {
+                if shape.dims[i] == 1 && B::shape(&grad).dims[i] != 1 {
+                    grad = grad.sum_dim(i);
+                }
+            }
+
+            grad
+        }
+
         let output = B::matmul(lhs.tensor_ref(), rhs.tensor_ref());
         let ops = MatmulBackward::<B, D>::default();
 
@@ -449,6 +562,240 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         unary_ops_wrapper(tensor.node.clone(), output, ops)
     }
 
+    fn abs<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        #[derive(Default, Debug)]
+        struct AbsBackward<B: Backend, const D: usize> {
+            _b: B,
+        }
+
+        impl<B: Backend, const D: usize> UnaryOps<B::TensorPrimitive<D>, B::TensorPrimitive<D>>
+            for AbsBackward<B, D>
+        {
+            fn partial(
+                &self,
+                state: &UnaryOpsNodeState<B::TensorPrimitive<D>, B::TensorPrimitive<D>>,
+            ) -> B::TensorPrimitive<D> {
+                let input = state.input.value();
+                let zero = B::Elem::zeros(&B::Elem::default());
+                let ones = B::ones(*B::shape(&input), B::device(&input));
+
+                // The sign of the input, with a gradient of zero where the input is zero.
+                let sign = B::mask_fill(&ones, &input.lower_scalar(&zero), (-1f32).to_elem());
+                let sign = B::mask_fill(&sign, &input.equal_scalar(&zero), zero);
+
+                B::mul(&state.output.grad(), &sign)
+            }
+        }
+
+        let output = B::abs(tensor.tensor_ref());
+        let ops = AbsBackward::<B, D>::default();
+
+        unary_ops_wrapper(tensor.node.clone(), output, ops)
+    }
+
+    fn sin<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        #[derive(Default, Debug)]
+        struct SinBackward<B: Backend, const D: usize> {
+            _b: B,
+        }
+
+        impl<B: Backend, const D: usize> UnaryOps<B::TensorPrimitive<D>, B::TensorPrimitive<D>>
+            for SinBackward<B, D>
+        {
+            fn partial(
+                &self,
+                state: &UnaryOpsNodeState<B::TensorPrimitive<D>, B::TensorPrimitive<D>>,
+            ) -> B::TensorPrimitive<D> {
+                let input = state.input.value();
+
+                B::mul(&state.output.grad(), &B::cos(&input))
+            }
+        }
+
+        let output = B::sin(tensor.tensor_ref());
+        let ops = SinBackward::<B, D>::default();
+
+        unary_ops_wrapper(tensor.node.clone(), output, ops)
+    }
+
+    fn cos<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        #[derive(Default, Debug)]
+        struct CosBackward<B: Backend, const D: usize> {
+            _b: B,
+        }
+
+        impl<B: Backend, const D: usize> UnaryOps<B::TensorPrimitive<D>, B::TensorPrimitive<D>>
+            for CosBackward<B, D>
+        {
+            fn partial(
+                &self,
+                state: &UnaryOpsNodeState<B::TensorPrimitive<D>, B::TensorPrimitive<D>>,
+            ) -> B::TensorPrimitive<D> {
+                let input = state.input.value();
+
+                B::mul(&state.output.grad(), &B::neg(&B::sin(&input)))
+            }
+        }
+
+        let output = B::cos(tensor.tensor_ref());
+        let ops = CosBackward::<B, D>::default();
+
+        unary_ops_wrapper(tensor.node.clone(), output, ops)
+    }
+
+    fn tan<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        #[derive(Default, Debug)]
+        struct TanBackward<B: Backend, const D: usize> {
+            _b: B,
+        }
+
+        impl<B: Backend, const D: usize> UnaryOps<B::TensorPrimitive<D>, B::TensorPrimitive<D>>
+            for TanBackward<B, D>
+        {
+            fn partial(
+                &self,
+                state: &UnaryOpsNodeState<B::TensorPrimitive<D>, B::TensorPrimitive<D>>,
+            ) -> B::TensorPrimitive<D> {
+                // Computed from the output, so the gradient is infinite only when the output is.
+                let output = state.output.value();
+                let derivative = B::add_scalar(&B::mul(&output, &output), &1f32.to_elem());
+
+                B::mul(&state.output.grad(), &derivative)
+            }
+        }
+
+        let output = B::tan(tensor.tensor_ref());
+        let ops = TanBackward::<B, D>::default();
+
+        unary_ops_wrapper(tensor.node.clone(), output, ops)
+    }
+
+    fn sign<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        zero_grad_ops(tensor, B::sign(tensor.tensor_ref()))
+    }
+
+    fn round<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        zero_grad_ops(tensor, B::round(tensor.tensor_ref()))
+    }
+
+    fn floor<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        zero_grad_ops(tensor, B::floor(tensor.tensor_ref()))
+    }
+
+    fn ceil<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        zero_grad_ops(tensor, B::ceil(tensor.tensor_ref()))
+    }
+
+    fn trunc<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        zero_grad_ops(tensor, B::trunc(tensor.tensor_ref()))
+    }
+
+    fn reciprocal<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        #[derive(Default, Debug)]
+        struct ReciprocalBackward<B: Backend, const D: usize> {
+            _b: B,
+        }
+
+        impl<B: Backend, const D: usize> UnaryOps<B::TensorPrimitive<D>, B::TensorPrimitive<D>>
+            for ReciprocalBackward<B, D>
+        {
+            fn partial(
+                &self,
+                state: &UnaryOpsNodeState<B::TensorPrimitive<D>, B::TensorPrimitive<D>>,
+            ) -> B::TensorPrimitive<D> {
+                // `-1 / x^2` computed from the output `1 / x`.
+                let output = state.output.value();
+                let derivative = B::neg(&B::mul(&output, &output));
+
+                B::mul(&state.output.grad(), &derivative)
+            }
+        }
+
+        let output = B::reciprocal(tensor.tensor_ref());
+        let ops = ReciprocalBackward::<B, D>::default();
+
+        unary_ops_wrapper(tensor.node.clone(), output, ops)
+    }
+
+    fn rsqrt<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        #[derive(Default, Debug)]
+        struct RsqrtBackward<B: Backend, const D: usize> {
+            _b: B,
+        }
+
+        impl<B: Backend, const D: usize> UnaryOps<B::TensorPrimitive<D>, B::TensorPrimitive<D>>
+            for RsqrtBackward<B, D>
+        {
+            fn partial(
+                &self,
+                state: &UnaryOpsNodeState<B::TensorPrimitive<D>, B::TensorPrimitive<D>>,
+            ) -> B::TensorPrimitive<D> {
+                // `-0.5 * x^(-1.5)` computed from the output `x^(-0.5)`.
+                let output = state.output.value();
+                let cube = B::mul(&B::mul(&output, &output), &output);
+                let derivative = B::mul_scalar(&cube, &(-0.5f32).to_elem());
+
+                B::mul(&state.output.grad(), &derivative)
+            }
+        }
+
+        let output = B::rsqrt(tensor.tensor_ref());
+        let ops = RsqrtBackward::<B, D>::default();
+
+        unary_ops_wrapper(tensor.node.clone(), output, ops)
+    }
+
+    fn sigmoid<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        #[derive(Default, Debug)]
+        struct SigmoidBackward<B: Backend, const D: usize> {
+            _b: B,
+        }
+
+        impl<B: Backend, const D: usize> UnaryOps<B::TensorPrimitive<D>, B::TensorPrimitive<D>>
+            for SigmoidBackward<B, D>
+        {
+            fn partial(
+                &self,
+                state: &UnaryOpsNodeState<B::TensorPrimitive<D>, B::TensorPrimitive<D>>,
+            ) -> B::TensorPrimitive<D> {
+                // `s * (1 - s)` computed from the output `s = sigmoid(x)`.
+                let output = state.output.value();
+                let derivative = B::mul(&output, &B::add_scalar(&B::neg(&output), &1f32.to_elem()));
+
+                B::mul(&state.output.grad(), &derivative)
+            }
+        }
+
+        let output = B::sigmoid(tensor.tensor_ref());
+        let ops = SigmoidBackward::<B, D>::default();
+
+        unary_ops_wrapper(tensor.node.clone(), output, ops)
+    }
+
     fn swap_dims<const D: usize>(
         tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
         dim1: usize,
@@ -478,6 +825,65 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         unary_ops_wrapper(tensor.node.clone(), output, ops)
     }
 
+    fn permute<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+        axes: [usize; D],
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        #[derive(new, Debug)]
+        struct PermuteBackward<B: Backend, const D: usize> {
+            _b: B,
+            axes_inverse: [usize; D],
+        }
+
+        impl<B: Backend, const D: usize> UnaryOps<B::TensorPrimitive<D>, B::TensorPrimitive<D>>
+            for PermuteBackward<B, D>
+        {
+            fn partial(
+                &self,
+                state: &UnaryOpsNodeState<B::TensorPrimitive<D>, B::TensorPrimitive<D>>,
+            ) -> B::TensorPrimitive<D> {
+                B::permute(&state.output.grad(), self.axes_inverse)
+            }
+        }
+
+        let mut axes_inverse = [0; D];
+        for (i, axis) in axes.iter().enumerate() {
+            axes_inverse[*axis] = i;
+        }
+
+        let output = B::permute(tensor.tensor_ref(), axes);
+        let ops = PermuteBackward::<B, D>::new(B::default(), axes_inverse);
+
+        unary_ops_wrapper(tensor.node.clone(), output, ops)
+    }
+
+    fn flip<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+        dims: &[usize],
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        #[derive(new, Debug)]
+        struct FlipBackward<B: Backend, const D: usize> {
+            dims: Vec<usize>,
+            _b: B,
+        }
+
+        impl<B: Backend, const D: usize> UnaryOps<B::TensorPrimitive<D>, B::TensorPrimitive<D>>
+            for FlipBackward<B, D>
+        {
+            fn partial(
+                &self,
+                state: &UnaryOpsNodeState<B::TensorPrimitive<D>, B::TensorPrimitive<D>>,
+            ) -> B::TensorPrimitive<D> {
+                B::flip(&state.output.grad(), &self.dims)
+            }
+        }
+
+        let output = B::flip(tensor.tensor_ref(), dims);
+        let ops = FlipBackward::<B, D>::new(dims.to_vec(), B::default());
+
+        unary_ops_wrapper(tensor.node.clone(), output, ops)
+    }
+
     fn reshape<const D1: usize, const D2: usize>(
         tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D1>,
         shape: Shape<D2>,
@@ -523,6 +929,43 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         unary_ops_wrapper(tensor.node.clone(), output, ops)
     }
 
+    fn expand<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+        shape: Shape<D>,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        #[derive(new, Debug)]
+        struct ExpandBackward<B: Backend, const D: usize> {
+            shape: Shape<D>,
+            _b: B,
+        }
+
+        impl<B: Backend, const D: usize> UnaryOps<B::TensorPrimitive<D>, B::TensorPrimitive<D>>
+            for ExpandBackward<B, D>
+        {
+            fn partial(
+                &self,
+                state: &UnaryOpsNodeState<B::TensorPrimitive<D>, B::TensorPrimitive<D>>,
+            ) -> B::TensorPrimitive<D> {
+                let mut grad = state.output.grad();
+
+                // Sum the gradient over the dimensions that were expanded.
+                for i in 0..D {
+                    if self.shape.dims[i] == 1 && B::shape(&grad).dims[i] != 1 {
+                        grad = grad.sum_dim(i);
+                    }
+                }
+
+                grad
+            }
+        }
+
+        let shape_old = B::shape(tensor.tensor_ref());
+        let output = B::expand(tensor.tensor_ref(), shape);
+        let ops = ExpandBackward::<B, D>::new(*shape_old, B::default());
+
+        unary_ops_wrapper(tensor.node.clone(), output, ops)
+    }
+
     fn index<const D1: usize, const D2: usize>(
         tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D1>,
         indexes: [std::ops::Range<usize>; D2],
@@ -592,7 +1035,17 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
                     B::TensorPrimitive<D1>,
                 >,
             ) -> B::TensorPrimitive<D1> {
-                B::index(&state.output.grad(), self.indexes.clone())
+                let shape = *B::shape(&state.right.value);
+                let mut grad = B::index(&state.output.grad(), self.indexes.clone());
+
+                // Sum the gradient over the dimensions where the value was broadcasted.
+                for i in 0..D1 {
+                    if shape.dims[i] == 1 && B::shape(&grad).dims[i] != 1 {
+                        grad = grad.sum_dim(i);
+                    }
+                }
+
+                grad
             }
         }
 
@@ -602,6 +1055,84 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
         binary_ops_wrapper(tensor.node.clone(), value.node.clone(), output, ops)
     }
 
+    fn index_select<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+        dim: usize,
+        indexes: &<B::IntegerBackend as Backend>::TensorPrimitive<1>,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        #[derive(new, Debug)]
+        struct IndexSelectBackward<B: Backend, const D: usize> {
+            dim: usize,
+            indexes: <B::IntegerBackend as Backend>::TensorPrimitive<1>,
+        }
+
+        impl<B: Backend, const D: usize> UnaryOps<B::TensorPrimitive<D>, B::TensorPrimitive<D>>
+            for IndexSelectBackward<B, D>
+        {
+            fn partial(
+                &self,
+                state: &UnaryOpsNodeState<B::TensorPrimitive<D>, B::TensorPrimitive<D>>,
+            ) -> B::TensorPrimitive<D> {
+                B::index_select_add(
+                    &state.input.value().zeros(),
+                    self.dim,
+                    &self.indexes,
+                    &state.output.grad(),
+                )
+            }
+        }
+
+        let output = B::index_select(tensor.tensor_ref(), dim, indexes);
+        let ops = IndexSelectBackward::<B, D>::new(dim, indexes.clone());
+
+        unary_ops_wrapper(tensor.node.clone(), output, ops)
+    }
+
+    fn index_select_add<const D: usize>(
+        tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+        dim: usize,
+        indexes: &<B::IntegerBackend as Backend>::TensorPrimitive<1>,
+        value: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        #[derive(new, Debug)]
+        struct IndexSelectAddBackward<B: Backend, const D: usize> {
+            dim: usize,
+            indexes: <B::IntegerBackend as Backend>::TensorPrimitive<1>,
+        }
+
+        impl<B: Backend, const D: usize>
+            BinaryOps<B::TensorPrimitive<D>, B::TensorPrimitive<D>, B::TensorPrimitive<D>>
+            for IndexSelectAddBackward<B, D>
+        {
+            fn partial_left(
+                &self,
+                state: &BinaryOpsNodeState<
+                    B::TensorPrimitive<D>,
+                    B::TensorPrimitive<D>,
+                    B::TensorPrimitive<D>,
+                >,
+            ) -> B::TensorPrimitive<D> {
+                state.output.grad()
+            }
+
+            fn partial_right(
+                &self,
+                state: &BinaryOpsNodeState<
+                    B::TensorPrimitive<D>,
+                    B::TensorPrimitive<D>,
+                    B::TensorPrimitive<D>,
+                >,
+            ) -> B::TensorPrimitive<D> {
+                B::index_select(&state.output.grad(), self.dim, &self.indexes)
+            }
+        }
+
+        let output = B::index_select_add(tensor.tensor_ref(), dim, indexes, value.tensor_ref());
+        let ops = IndexSelectAddBackward::<B, D>::new(dim, indexes.clone());
+
+        binary_ops_wrapper(tensor.node.clone(), value.node.clone(), output, ops)
+    }
+
     fn mask_fill<const D: usize>(
         tensor: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
         mask: &<ADBackendDecorator<B> as Backend>::BoolTensorPrimitive<D>,
@@ -632,4 +1163,234 @@ impl<B: Backend> TensorOps<ADBackendDecorator<B>> for ADBackendDecorator<B> {
 
         unary_ops_wrapper(tensor.node.clone(), output, ops)
     }
+
+    fn maximum<const D: usize>(
+        lhs: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+        rhs: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        #[derive(Default, Debug)]
+        struct MaximumBackward<B: Backend, const D: usize> {
+            _b: B,
+        }
+
+        impl<B: Backend, const D: usize>
+            BinaryOps<B::TensorPrimitive<D>, B::TensorPrimitive<D>, B::TensorPrimitive<D>>
+            for MaximumBackward<B, D>
+        {
+            fn partial_left(
+                &self,
+                state: &BinaryOpsNodeState<
+                    B::TensorPrimitive<D>,
+                    B::TensorPrimitive<D>,
+                    B::TensorPrimitive<D>,
+                >,
+            ) -> B::TensorPrimitive<D> {
+                let left = state.left.value();
+                let right = state.right.value();
+                let share =
+                    selection_share::<B, D>(&left, &left.lower(&right), &left.equal(&right));
+
+                B::mul(&state.output.grad(), &share)
+            }
+
+            fn partial_right(
+                &self,
+                state: &BinaryOpsNodeState<
+                    B::TensorPrimitive<D>,
+                    B::TensorPrimitive<D>,
+                    B::TensorPrimitive<D>,
+                >,
+            ) -> B::TensorPrimitive<D> {
+                let left = state.left.value();
+                let right = state.right.value();
+                let share =
+                    selection_share::<B, D>(&right, &right.lower(&left), &right.equal(&left));
+
+                B::mul(&state.output.grad(), &share)
+            }
+        }
+
+        let output = B::maximum(lhs.tensor_ref(), rhs.tensor_ref());
+        let ops = MaximumBackward::<B, D>::default();
+
+        binary_ops_wrapper(lhs.node.clone(), rhs.node.clone(), output, ops)
+    }
+
+    fn maximum_scalar<const D: usize>(
+        lhs: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+        rhs: &<ADBackendDecorator<B> as Backend>::Elem,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        #[derive(new, Debug)]
+        struct MaximumScalarBackward<B: Backend, const D: usize> {
+            elem: B::Elem,
+        }
+
+        impl<B: Backend, const D: usize> UnaryOps<B::TensorPrimitive<D>, B::TensorPrimitive<D>>
+            for MaximumScalarBackward<B, D>
+        {
+            fn partial(
+                &self,
+                state: &UnaryOpsNodeState<B::TensorPrimitive<D>, B::TensorPrimitive<D>>,
+            ) -> B::TensorPrimitive<D> {
+                let input = state.input.value();
+                let share = selection_share::<B, D>(
+                    &input,
+                    &input.lower_scalar(&self.elem),
+                    &input.equal_scalar(&self.elem),
+                );
+
+                B::mul(&state.output.grad(), &share)
+            }
+        }
+
+        let output = B::maximum_scalar(lhs.tensor_ref(), rhs);
+        let ops = MaximumScalarBackward::<B, D>::new(*rhs);
+
+        unary_ops_wrapper(lhs.node.clone(), output, ops)
+    }
+
+    fn minimum<const D: usize>(
+        lhs: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+        rhs: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        #[derive(Default, Debug)]
+        struct MinimumBackward<B: Backend, const D: usize> {
+            _b: B,
+        }
+
+        impl<B: Backend, const D: usize>
+            BinaryOps<B::TensorPrimitive<D>, B::TensorPrimitive<D>, B::TensorPrimitive<D>>
+            for MinimumBackward<B, D>
+        {
+            fn partial_left(
+                &self,
+                state: &BinaryOpsNodeState<
+                    B::TensorPrimitive<D>,
+                    B::TensorPrimitive<D>,
+                    B::TensorPrimitive<D>,
+                >,
+            ) -> B::TensorPrimitive<D> {
+                let left = state.left.value();
+                let right = state.right.value();
+                let share =
+                    selection_share::<B, D>(&left, &left.greater(&right), &left.equal(&right));
+
+                B::mul(&state.output.grad(), &share)
+            }
+
+            fn partial_right(
+                &self,
+                state: &BinaryOpsNodeState<
+                    B::TensorPrimitive<D>,
+                    B::TensorPrimitive<D>,
+                    B::TensorPrimitive<D>,
+                >,
+            ) -> B::TensorPrimitive<D> {
+                let left = state.left.value();
+                let right = state.right.value();
+                let share =
+                    selection_share::<B, D>(&right, &right.greater(&left), &right.equal(&left));
+
+                B::mul(&state.output.grad(), &share)
+            }
+        }
+
+        let output = B::minimum(lhs.tensor_ref(), rhs.tensor_ref());
+        let ops = MinimumBackward::<B, D>::default();
+
+        binary_ops_wrapper(lhs.node.clone(), rhs.node.clone(), output, ops)
+    }
+
+    fn minimum_scalar<const D: usize>(
+        lhs: &<ADBackendDecorator<B> as Backend>::TensorPrimitive<D>,
+        rhs: &<ADBackendDecorator<B> as Backend>::Elem,
+    ) -> <ADBackendDecorator<B> as Backend>::TensorPrimitive<D> {
+        #[derive(new, Debug)]
+        struct MinimumScalarBackward<B: Backend, const D: usize> {
+            elem: B::Elem,
+        }
+
+        impl<B: Backend, const D: usize> UnaryOps<B::TensorPrimitive<D>, B::TensorPrimitive<D>>
+            for MinimumScalarBackward<B, D>
+        {
+            fn partial(
+                &self,
+                state: &UnaryOpsNodeState<B::TensorPrimitive<D>, B::TensorPrimitive<D>>,
+            ) -> B::TensorPrimitive<D> {
+                let input = state.input.value();
+                let share = selection_share::<B, D>(
+                    &input,
+                    &input.greater_scalar(&self.elem),
+                    &input.equal_scalar(&self.elem),
+                );
+
+                B::mul(&state.output.grad(), &share)
+            }
+        }
+
+        let output = B::minimum_scalar(lhs.tensor_ref(), rhs);
+        let ops = MinimumScalarBackward::<B, D>::new(*rhs);
+
+        unary_ops_wrapper(lhs.node.clone(), output, ops)
+    }
+}
+
+/// The share of the gradient routed to a tensor by an elementwise selection between two values:
+/// one where it was selected, zero where it wasn't and one half where both values are equal.
+fn selection_share<B: Backend, const D: usize>(
+    tensor: &B::TensorPrimitive<D>,
+    not_selected: &B::BoolTensorPrimitive<D>,
+    tie: &B::BoolTensorPrimitive<D>,
+) -> B::TensorPrimitive<D> {
+    let zero = B::Elem::zeros(&B::Elem::default());
+    let share = B::mask_fill(&tensor.ones(), not_selected, zero);
+
+    B::mask_fill(&share, tie, 0.5f32.to_elem())
+}
+
+/// Record a piecewise constant operation, like rounding, whose gradient is zero almost
+/// everywhere, so no gradient flows back to the input.
+fn zero_grad_ops<B: Backend, const D: usize>(
+    tensor: &ADTensor<D, B>,
+    output: B::TensorPrimitive<D>,
+) -> ADTensor<D, B> {
+    #[derive(Default, Debug)]
+    struct ZeroGradBackward<B: Backend, const D: usize> {
+        _b: B,
+    }
+
+    impl<B: Backend, const D: usize> UnaryOps<B::TensorPrimitive<D>, B::TensorPrimitive<D>>
+        for ZeroGradBackward<B, D>
+    {
+        fn partial(
+            &self,
+            state: &UnaryOpsNodeState<B::TensorPrimitive<D>, B::TensorPrimitive<D>>,
+        ) -> B::TensorPrimitive<D> {
+            state.output.grad().zeros()
+        }
+    }
+
+    let ops = ZeroGradBackward::<B, D>::default();
+
+    unary_ops_wrapper(tensor.node.clone(), output, ops)
+}
+
+/// In-place operations can't be recorded in the graph, so they are only allowed on tensors that
+/// aren't the result of a tracked operation. The output is a new leaf of the graph.
+fn assign_ops<B: Backend, const D: usize>(
+    lhs: &mut ADTensor<D, B>,
+    rhs: &ADTensor<D, B>,
+    ops: fn(&mut B::TensorPrimitive<D>, &B::TensorPrimitive<D>),
+) {
+    debug_assert_eq!(
+        lhs.node.order, 0,
+        "In-place operations can't be applied on tensors tracked by the autodiff graph"
+    );
+
+    let mut tensor = lhs.tensor();
+    // Release the node holding the value, so that the backend can mutate it in place instead of
+    // copying it, unless it's still shared with another tensor.
+    *lhs = ADTensor::from_tensor(rhs.tensor());
+    ops(&mut tensor, rhs.tensor_ref());
+    *lhs = ADTensor::from_tensor(tensor);
 }
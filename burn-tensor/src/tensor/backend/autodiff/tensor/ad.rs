@@ -14,6 +14,14 @@ impl<B: Backend, const D: usize> ADTensor<D, B> {
 
         node.backward()
     }
+
+    pub fn backward_for(&self, ids: &[String]) -> Gradients {
+        let mut converter = Forward2BackwardGraphConverter::new();
+        let mut node = BackwardNode::from_node(&self.node, &mut converter);
+        std::mem::drop(converter);
+
+        node.backward_for(ids)
+    }
 }
 
 impl<B: Backend, const D: usize> AsNode<B::TensorPrimitive<D>> for ADTensor<D, B> {
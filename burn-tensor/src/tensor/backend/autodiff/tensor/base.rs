@@ -1,8 +1,12 @@
 use crate::{
     execute_ops,
-    graph::node::ForwardNodeRef,
+    graph::{
+        node::{ForwardNode, ForwardNodeRef, ForwardNodeState},
+        ops::InitRecordedOps,
+    },
     tensor::{backend::Backend, Shape},
 };
+use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub struct ADTensor<const D: usize, B: Backend> {
@@ -19,6 +23,22 @@ impl<B: Backend, const D: usize> ADTensor<D, B> {
         let shape = *B::shape(&tensor);
         Self { node, shape }
     }
+
+    /// Create a new leaf of the graph, for which the gradient is only registered when
+    /// `require_grad` is true.
+    pub fn from_tensor_require_grad(tensor: B::TensorPrimitive<D>, require_grad: bool) -> Self {
+        let shape = *B::shape(&tensor);
+        let state = ForwardNodeState::new(tensor);
+        let ops = Arc::new(InitRecordedOps::new());
+
+        let mut node = ForwardNode::from_root(state, ops);
+        node.require_grad = require_grad;
+
+        Self {
+            node: Arc::new(node),
+            shape,
+        }
+    }
 }
 
 impl<B: Backend, const D: usize> ADTensor<D, B> {
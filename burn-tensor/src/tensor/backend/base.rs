@@ -56,7 +56,20 @@ pub trait Backend:
 
     fn ad_enabled() -> bool;
     fn name() -> String;
+    /// Seed the random number generator used by all the random operations of the backend, such
+    /// as [random](Backend::random).
     fn seed(seed: u64);
+    /// Set the precision of the matrix multiplications of `f32` tensors, for all the tensors of
+    /// the backend.
+    ///
+    /// The default is [High](MatmulPrecision::High), backends without faster modes ignore the
+    /// setting.
+    fn set_matmul_precision(_precision: MatmulPrecision) {}
+    /// Force the selection of deterministic algorithms, so that the same program with the same
+    /// [seed](Backend::seed) computes bit identical results, at the cost of speed.
+    ///
+    /// Disabled by default, backends that are always deterministic ignore the setting.
+    fn set_deterministic(_deterministic: bool) {}
 
     fn random<const D: usize>(
         shape: Shape<D>,
@@ -73,6 +86,21 @@ pub trait Backend:
     }
 }
 
+/// The precision of the matrix multiplications, see
+/// [set_matmul_precision](Backend::set_matmul_precision).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MatmulPrecision {
+    /// Full `f32` precision.
+    #[default]
+    High,
+    /// Lower precision computations when they are faster, e.g. TF32 on the tensor cores of
+    /// GPUs.
+    ///
+    /// The tch backend can't toggle TF32, which tch doesn't expose, so this only enables the
+    /// cuDNN benchmark mode and the matrix multiplications keep the default of libtorch.
+    Medium,
+}
+
 pub(crate) type ADBackendTensorPrimitive<const D: usize, B> =
     <<B as ADBackend>::InnerBackend as Backend>::TensorPrimitive<D>;
 
@@ -80,14 +108,33 @@ pub trait ADBackend: Backend {
     type InnerBackend: Backend<Device = Self::Device, Elem = Self::Elem>;
 
     fn backward<const D: usize>(tensor: &Self::TensorPrimitive<D>) -> Gradients;
+    fn backward_for<const D: usize>(tensor: &Self::TensorPrimitive<D>, ids: &[String])
+        -> Gradients;
+    fn node_id<const D: usize>(tensor: &Self::TensorPrimitive<D>) -> String;
     fn grad<const D: usize>(
         tensor: &Self::TensorPrimitive<D>,
         grads: &Gradients,
     ) -> Option<ADBackendTensorPrimitive<D, Self>>;
+    fn grad_replace<const D: usize>(
+        tensor: &Self::TensorPrimitive<D>,
+        grads: &mut Gradients,
+        grad: ADBackendTensorPrimitive<D, Self>,
+    );
     fn inner<const D: usize>(
         tensor: &Self::TensorPrimitive<D>,
     ) -> <Self::InnerBackend as Backend>::TensorPrimitive<D>;
     fn from_inner<const D: usize>(
         tensor: <Self::InnerBackend as Backend>::TensorPrimitive<D>,
     ) -> Self::TensorPrimitive<D>;
+    /// Apply the function without keeping its intermediate values, see
+    /// [checkpoint](crate::checkpoint).
+    fn checkpoint<const D: usize, const D2: usize>(
+        tensor: &Self::TensorPrimitive<D>,
+        func: CheckpointFn<D, D2, Self>,
+    ) -> Self::TensorPrimitive<D2>;
 }
+
+/// A function recorded by [checkpoint](ADBackend::checkpoint).
+pub type CheckpointFn<const D: usize, const D2: usize, B> = std::sync::Arc<
+    dyn Fn(<B as Backend>::TensorPrimitive<D>) -> <B as Backend>::TensorPrimitive<D2> + Send + Sync,
+>;
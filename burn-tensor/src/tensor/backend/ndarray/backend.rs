@@ -8,7 +8,7 @@ use std::sync::Mutex;
 
 static SEED: Mutex<Option<StdRng>> = Mutex::new(None);
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum NdArrayDevice {
     Cpu,
 }
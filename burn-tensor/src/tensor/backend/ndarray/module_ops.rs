@@ -1,7 +1,8 @@
 use std::ops::Add;
 
 use super::{NdArrayBackend, NdArrayTensor};
-use crate::{ops::*, NdArrayElement, Shape};
+use crate::{module::conv1d_output_length, ops::*, NdArrayElement, Shape};
+use ndarray::{Array3, Array4, Ix3, Ix4};
 
 impl<E: NdArrayElement> ModuleOps<NdArrayBackend<E>> for NdArrayBackend<E> {
     fn embedding(
@@ -61,4 +62,372 @@ impl<E: NdArrayElement> ModuleOps<NdArrayBackend<E>> for NdArrayBackend<E> {
 
         weights_grad
     }
+
+    fn conv1d(
+        x: &NdArrayTensor<E, 3>,
+        weight: &NdArrayTensor<E, 3>,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+    ) -> NdArrayTensor<E, 3> {
+        let [channels_out, channels_in, kernel_size] = weight.shape.dims;
+
+        let columns = im2col(x, kernel_size, stride, padding, dilation);
+        let weight = NdArrayBackend::reshape(
+            weight,
+            Shape::new([1, channels_out, channels_in * kernel_size]),
+        );
+
+        NdArrayBackend::matmul(&weight, &columns)
+    }
+
+    fn conv1d_x_backward(
+        x: &NdArrayTensor<E, 3>,
+        weight: &NdArrayTensor<E, 3>,
+        output_grad: &NdArrayTensor<E, 3>,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+    ) -> NdArrayTensor<E, 3> {
+        let [channels_out, channels_in, kernel_size] = weight.shape.dims;
+
+        let weight = NdArrayBackend::reshape(
+            weight,
+            Shape::new([1, channels_out, channels_in * kernel_size]),
+        );
+        let columns = NdArrayBackend::matmul(&NdArrayBackend::transpose(&weight), output_grad);
+
+        col2im(&columns, x.shape, kernel_size, stride, padding, dilation)
+    }
+
+    fn conv1d_weight_backward(
+        x: &NdArrayTensor<E, 3>,
+        weight: &NdArrayTensor<E, 3>,
+        output_grad: &NdArrayTensor<E, 3>,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+    ) -> NdArrayTensor<E, 3> {
+        let [_channels_out, _channels_in, kernel_size] = weight.shape.dims;
+
+        let columns = im2col(x, kernel_size, stride, padding, dilation);
+        let weight_grad =
+            NdArrayBackend::matmul(output_grad, &NdArrayBackend::transpose(&columns)).sum_dim(0);
+
+        NdArrayBackend::reshape(&weight_grad, weight.shape)
+    }
+
+    fn avg_pool2d(
+        x: &NdArrayTensor<E, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> NdArrayTensor<E, 4> {
+        let [batch_size, channels, height, width] = x.shape.dims;
+        let [height_out, width_out] =
+            pool2d_output_size([height, width], kernel_size, stride, padding);
+
+        let x = x.array.view().into_dimensionality::<Ix4>().unwrap();
+        let mut output = Array4::zeros((batch_size, channels, height_out, width_out));
+
+        for b in 0..batch_size {
+            for c in 0..channels {
+                for oh in 0..height_out {
+                    for ow in 0..width_out {
+                        let window = PoolWindow2d::new(
+                            [oh, ow],
+                            [height, width],
+                            kernel_size,
+                            stride,
+                            padding,
+                        );
+                        let mut sum = E::zeros(&E::default());
+                        for ih in window.rows.clone() {
+                            for iw in window.cols.clone() {
+                                sum = sum + x[[b, c, ih, iw]];
+                            }
+                        }
+                        output[[b, c, oh, ow]] = sum / window.divisor::<E>(count_include_pad);
+                    }
+                }
+            }
+        }
+
+        NdArrayTensor {
+            array: output.into_dyn().into_shared(),
+            shape: Shape::new([batch_size, channels, height_out, width_out]),
+        }
+    }
+
+    fn avg_pool2d_backward(
+        x: &NdArrayTensor<E, 4>,
+        output_grad: &NdArrayTensor<E, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> NdArrayTensor<E, 4> {
+        let [batch_size, channels, height, width] = x.shape.dims;
+        let [_, _, height_out, width_out] = output_grad.shape.dims;
+
+        let output_grad = output_grad
+            .array
+            .view()
+            .into_dimensionality::<Ix4>()
+            .unwrap();
+        let mut x_grad = Array4::zeros((batch_size, channels, height, width));
+
+        for b in 0..batch_size {
+            for c in 0..channels {
+                for oh in 0..height_out {
+                    for ow in 0..width_out {
+                        let window = PoolWindow2d::new(
+                            [oh, ow],
+                            [height, width],
+                            kernel_size,
+                            stride,
+                            padding,
+                        );
+                        let grad =
+                            output_grad[[b, c, oh, ow]] / window.divisor::<E>(count_include_pad);
+                        for ih in window.rows.clone() {
+                            for iw in window.cols.clone() {
+                                x_grad[[b, c, ih, iw]] = x_grad[[b, c, ih, iw]] + grad;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        NdArrayTensor {
+            array: x_grad.into_dyn().into_shared(),
+            shape: x.shape,
+        }
+    }
+
+    fn adaptive_avg_pool2d(
+        x: &NdArrayTensor<E, 4>,
+        output_size: [usize; 2],
+    ) -> NdArrayTensor<E, 4> {
+        let [batch_size, channels, height, width] = x.shape.dims;
+        let [height_out, width_out] = output_size;
+
+        let x = x.array.view().into_dimensionality::<Ix4>().unwrap();
+        let mut output = Array4::zeros((batch_size, channels, height_out, width_out));
+
+        for b in 0..batch_size {
+            for c in 0..channels {
+                for oh in 0..height_out {
+                    for ow in 0..width_out {
+                        let window = PoolWindow2d::adaptive([oh, ow], [height, width], output_size);
+                        let mut sum = E::zeros(&E::default());
+                        for ih in window.rows.clone() {
+                            for iw in window.cols.clone() {
+                                sum = sum + x[[b, c, ih, iw]];
+                            }
+                        }
+                        output[[b, c, oh, ow]] = sum / window.divisor::<E>(false);
+                    }
+                }
+            }
+        }
+
+        NdArrayTensor {
+            array: output.into_dyn().into_shared(),
+            shape: Shape::new([batch_size, channels, height_out, width_out]),
+        }
+    }
+
+    fn adaptive_avg_pool2d_backward(
+        x: &NdArrayTensor<E, 4>,
+        output_grad: &NdArrayTensor<E, 4>,
+    ) -> NdArrayTensor<E, 4> {
+        let [batch_size, channels, height, width] = x.shape.dims;
+        let [_, _, height_out, width_out] = output_grad.shape.dims;
+
+        let output_grad = output_grad
+            .array
+            .view()
+            .into_dimensionality::<Ix4>()
+            .unwrap();
+        let mut x_grad = Array4::zeros((batch_size, channels, height, width));
+
+        for b in 0..batch_size {
+            for c in 0..channels {
+                for oh in 0..height_out {
+                    for ow in 0..width_out {
+                        let window = PoolWindow2d::adaptive(
+                            [oh, ow],
+                            [height, width],
+                            [height_out, width_out],
+                        );
+                        let grad = output_grad[[b, c, oh, ow]] / window.divisor::<E>(false);
+                        for ih in window.rows.clone() {
+                            for iw in window.cols.clone() {
+                                x_grad[[b, c, ih, iw]] = x_grad[[b, c, ih, iw]] + grad;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        NdArrayTensor {
+            array: x_grad.into_dyn().into_shared(),
+            shape: x.shape,
+        }
+    }
+}
+
+fn pool2d_output_size(
+    size: [usize; 2],
+    kernel_size: [usize; 2],
+    stride: [usize; 2],
+    padding: [usize; 2],
+) -> [usize; 2] {
+    [0, 1].map(|i| (size[i] + 2 * padding[i] - kernel_size[i]) / stride[i] + 1)
+}
+
+/// Positions of the input covered by a pooling window, excluding the padding.
+struct PoolWindow2d {
+    rows: std::ops::Range<usize>,
+    cols: std::ops::Range<usize>,
+    kernel_size: [usize; 2],
+}
+
+impl PoolWindow2d {
+    fn new(
+        position: [usize; 2],
+        size: [usize; 2],
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+    ) -> Self {
+        let [rows, cols] = [0, 1].map(|i| {
+            let start = position[i] * stride[i];
+            let end = start + kernel_size[i];
+
+            start.saturating_sub(padding[i])..usize::min(end - padding[i], size[i])
+        });
+
+        Self {
+            rows,
+            cols,
+            kernel_size,
+        }
+    }
+
+    fn adaptive(position: [usize; 2], size: [usize; 2], output_size: [usize; 2]) -> Self {
+        let [rows, cols] = [0, 1].map(|i| {
+            let start = position[i] * size[i] / output_size[i];
+            let end = ((position[i] + 1) * size[i]).div_ceil(output_size[i]);
+
+            start..end
+        });
+        let kernel_size = [rows.len(), cols.len()];
+
+        Self {
+            rows,
+            cols,
+            kernel_size,
+        }
+    }
+
+    /// The number of elements averaged by the window.
+    fn divisor<E: NdArrayElement>(&self, count_include_pad: bool) -> E {
+        let count = match count_include_pad {
+            true => self.kernel_size[0] * self.kernel_size[1],
+            false => self.rows.len() * self.cols.len(),
+        };
+
+        E::from_usize(count).unwrap()
+    }
+}
+
+/// Unfold the input of shape `[batch_size, channels_in, length]` into columns of shape
+/// `[batch_size, channels_in * kernel_size, length_out]`, where each column holds the elements
+/// covered by the kernel at one output position.
+fn im2col<E: NdArrayElement>(
+    x: &NdArrayTensor<E, 3>,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+    dilation: usize,
+) -> NdArrayTensor<E, 3> {
+    let [batch_size, channels_in, length] = x.shape.dims;
+    let length_out = conv1d_output_length(length, kernel_size, stride, padding, dilation);
+
+    let x = x.array.view().into_dimensionality::<Ix3>().unwrap();
+    let mut columns = Array3::zeros((batch_size, channels_in * kernel_size, length_out));
+
+    for b in 0..batch_size {
+        for c in 0..channels_in {
+            for k in 0..kernel_size {
+                for t in 0..length_out {
+                    if let Some(i) = input_position(t, k, stride, padding, dilation, length) {
+                        columns[[b, c * kernel_size + k, t]] = x[[b, c, i]];
+                    }
+                }
+            }
+        }
+    }
+
+    NdArrayTensor {
+        array: columns.into_dyn().into_shared(),
+        shape: Shape::new([batch_size, channels_in * kernel_size, length_out]),
+    }
+}
+
+/// Fold the columns created by [im2col](im2col) back into a tensor of the given shape, summing
+/// the elements covered by more than one kernel position.
+fn col2im<E: NdArrayElement>(
+    columns: &NdArrayTensor<E, 3>,
+    shape: Shape<3>,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+    dilation: usize,
+) -> NdArrayTensor<E, 3> {
+    let [batch_size, channels_in, length] = shape.dims;
+    let [_, _, length_out] = columns.shape.dims;
+
+    let columns = columns.array.view().into_dimensionality::<Ix3>().unwrap();
+    let mut x = Array3::zeros((batch_size, channels_in, length));
+
+    for b in 0..batch_size {
+        for c in 0..channels_in {
+            for k in 0..kernel_size {
+                for t in 0..length_out {
+                    if let Some(i) = input_position(t, k, stride, padding, dilation, length) {
+                        x[[b, c, i]] = x[[b, c, i]] + columns[[b, c * kernel_size + k, t]];
+                    }
+                }
+            }
+        }
+    }
+
+    NdArrayTensor {
+        array: x.into_dyn().into_shared(),
+        shape,
+    }
+}
+
+/// Position in the input of the kernel element `k` at the output position `t`, or `None` when it
+/// falls in the padding.
+fn input_position(
+    t: usize,
+    k: usize,
+    stride: usize,
+    padding: usize,
+    dilation: usize,
+    length: usize,
+) -> Option<usize> {
+    let position = (t * stride + k * dilation).checked_sub(padding)?;
+
+    match position < length {
+        true => Some(position),
+        false => None,
+    }
 }
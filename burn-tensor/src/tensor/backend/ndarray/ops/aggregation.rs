@@ -31,6 +31,17 @@ macro_rules! keepdim {
         shape.dims[$dim] = 1;
         NdArrayBackend::reshape(&tensor, shape)
     }};
+    (
+        $D:expr,
+        $dim:expr,
+        $self:expr,
+        prod
+    ) => {{
+        let tensor: NdArrayTensor<E, $D> = prod_dim(&$self, $dim);
+        let mut shape = $self.shape.clone();
+        shape.dims[$dim] = 1;
+        NdArrayBackend::reshape(&tensor, shape)
+    }};
 }
 
 impl<E: NdArrayElement, const D: usize> TensorOpsAggregation<NdArrayBackend<E>, D>
@@ -69,6 +80,34 @@ impl<E: NdArrayElement, const D: usize> TensorOpsAggregation<NdArrayBackend<E>,
             _ => panic!("Dim not supported {}", D),
         }
     }
+
+    fn mean_dims(&self, dims: &[usize]) -> Self {
+        dims.iter()
+            .fold(self.clone(), |tensor, dim| tensor.mean_dim(*dim))
+    }
+
+    fn sum_dims(&self, dims: &[usize]) -> Self {
+        dims.iter()
+            .fold(self.clone(), |tensor, dim| tensor.sum_dim(*dim))
+    }
+
+    fn prod(&self) -> NdArrayTensor<E, 1> {
+        let one = E::ones(&E::default());
+        let data = Data::from([self.array.fold(one, |acc, value| acc * *value)]);
+        NdArrayTensor::from_data(data)
+    }
+
+    fn prod_dim(&self, dim: usize) -> Self {
+        match D {
+            1 => keepdim!(0, dim, self, prod),
+            2 => keepdim!(1, dim, self, prod),
+            3 => keepdim!(2, dim, self, prod),
+            4 => keepdim!(3, dim, self, prod),
+            5 => keepdim!(4, dim, self, prod),
+            6 => keepdim!(5, dim, self, prod),
+            _ => panic!("Dim not supported {}", D),
+        }
+    }
 }
 
 fn mean_dim<E: NdArrayElement, const D1: usize, const D2: usize>(
@@ -90,3 +129,17 @@ fn sum_dim<E: NdArrayElement, const D1: usize, const D2: usize>(
 
     NdArrayTensor { array, shape }
 }
+
+fn prod_dim<E: NdArrayElement, const D1: usize, const D2: usize>(
+    tensor: &NdArrayTensor<E, D1>,
+    dim: usize,
+) -> NdArrayTensor<E, D2> {
+    let one = E::ones(&E::default());
+    let array = tensor
+        .array
+        .fold_axis(Axis(dim), one, |acc, value| *acc * *value)
+        .into_shared();
+    let shape = tensor.shape.remove_dim(dim);
+
+    NdArrayTensor { array, shape }
+}
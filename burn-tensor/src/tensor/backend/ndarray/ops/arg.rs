@@ -1,6 +1,7 @@
 use crate::backend::ndarray::NdArrayBackend;
 use crate::tensor::{backend::ndarray::NdArrayTensor, ops::*};
-use crate::{Data, NdArrayElement};
+use crate::NdArrayElement;
+use ndarray::Axis;
 use std::cmp::Ordering;
 
 impl<E, const D: usize> TensorOpsArg<NdArrayBackend<E>, D> for NdArrayTensor<E, D>
@@ -24,35 +25,29 @@ fn arg<E: NdArrayElement, F, const D: usize>(
 where
     F: Fn(&f64, &f64) -> Ordering,
 {
-    let mut data = <NdArrayBackend<E> as TensorOps<NdArrayBackend<E>>>::to_data::<D>(tensor);
-    let batch_size = tensor.shape.dims[dim];
-    let mut start = 0;
-    let mut end = tensor.shape.dims[dim];
-    let mut output = Vec::new();
+    let array = tensor
+        .array
+        .map_axis(Axis(dim), |lane| {
+            let mut index = 0;
+            let mut selected: f64 = lane[0].to_elem();
 
-    while end <= data.value.len() {
-        let data_dim = &mut data.value[start..end];
-        let mut sorted: Vec<f64> = data_dim.iter().map(|a| a.to_elem()).collect();
-        sorted.sort_by(&cmp);
+            for (i, elem) in lane.iter().enumerate() {
+                let as_float: f64 = elem.to_elem();
+                if cmp(&as_float, &selected) == Ordering::Less {
+                    selected = as_float;
+                    index = i;
+                }
+            }
 
-        let max = sorted[0];
+            index as i64
+        })
+        .insert_axis(Axis(dim))
+        .into_shared();
 
-        let data_dim = &mut data.value[start..end];
-        let mut index: i64 = 0;
-        for elem in data_dim {
-            let as_float: f64 = elem.to_elem();
-            if as_float == max {
-                break;
-            }
-            index += 1;
-        }
-        output.push(index);
-        start += batch_size;
-        end += batch_size;
-    }
     let mut shape = tensor.shape;
     shape.dims[dim] = 1;
-    NdArrayTensor::from_data(Data::new(output, shape))
+
+    NdArrayTensor { array, shape }
 }
 
 fn cmp_max(a: &f64, b: &f64) -> Ordering {
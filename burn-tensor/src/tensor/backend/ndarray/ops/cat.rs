@@ -6,8 +6,8 @@ use ndarray::{Axis, IxDyn};
 
 impl<P: NdArrayElement, const D: usize> TensorOpsCat<P, D> for NdArrayTensor<P, D> {
     fn cat(tensors: Vec<&Self>, dim: usize) -> Self {
-        let mut shape = tensors.get(0).unwrap().shape;
-        shape.dims[dim] = tensors.len();
+        let mut shape = tensors.first().unwrap().shape;
+        shape.dims[dim] = tensors.iter().map(|tensor| tensor.shape.dims[dim]).sum();
 
         let arrays: Vec<ndarray::ArrayView<P, IxDyn>> =
             tensors.into_iter().map(|t| t.array.view()).collect();
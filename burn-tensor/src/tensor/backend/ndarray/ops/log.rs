@@ -13,4 +13,11 @@ where
 
         Self { array, shape }
     }
+
+    fn log1p(&self) -> Self {
+        let array = self.array.mapv(|a| a.log1p_elem()).into_shared();
+        let shape = self.shape;
+
+        Self { array, shape }
+    }
 }
@@ -13,4 +13,11 @@ where
 
         Self { array, shape }
     }
+
+    fn powi(&self, value: i32) -> Self {
+        let array = self.array.mapv(|a| a.powi_elem(value)).into_shared();
+        let shape = self.shape;
+
+        Self { array, shape }
+    }
 }
@@ -4,9 +4,9 @@ use super::{BatchMatrix, NdArrayBackend, NdArrayTensor};
 use crate::{
     backend::{Backend, NdArrayDevice},
     ops::TensorOps,
-    to_nd_array_tensor, Data, ElementConversion, NdArrayElement, Shape,
+    to_nd_array_tensor, Data, ElementConversion, ElementValue, NdArrayElement, Shape,
 };
-use ndarray::{Dim, SliceInfoElem};
+use ndarray::{Axis, Dim, SliceInfoElem, Zip};
 
 impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
     fn shape<const D: usize>(
@@ -29,6 +29,13 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
         Data::new(values, tensor.shape)
     }
 
+    fn count_nonzero<const D: usize>(
+        tensor: &<NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
+    ) -> usize {
+        let zero = <E as ElementValue>::zero();
+        tensor.array.iter().filter(|value| **value != zero).count()
+    }
+
     fn bool_shape<const D: usize>(
         tensor: &<NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D>,
     ) -> &Shape<D> {
@@ -48,6 +55,53 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
         let values = tensor.array.into_iter().collect();
         Data::new(values, tensor.shape)
     }
+
+    fn bool_all<const D: usize>(
+        tensor: &<NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D>,
+    ) -> bool {
+        tensor.array.iter().all(|value| *value)
+    }
+
+    fn bool_any<const D: usize>(
+        tensor: &<NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D>,
+    ) -> bool {
+        tensor.array.iter().any(|value| *value)
+    }
+
+    fn bool_all_dim<const D: usize>(
+        tensor: &<NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D>,
+        dim: usize,
+    ) -> <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D> {
+        bool_reduce_dim(tensor, dim, true, |acc, value| acc && value)
+    }
+
+    fn bool_any_dim<const D: usize>(
+        tensor: &<NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D>,
+        dim: usize,
+    ) -> <NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D> {
+        bool_reduce_dim(tensor, dim, false, |acc, value| acc || value)
+    }
+
+    fn bool_to_float<const D: usize>(
+        tensor: &<NdArrayBackend<E> as Backend>::BoolTensorPrimitive<D>,
+    ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D> {
+        let array = tensor
+            .array
+            .mapv(|value| {
+                if value {
+                    <E as ElementValue>::one()
+                } else {
+                    <E as ElementValue>::zero()
+                }
+            })
+            .into_shared();
+
+        NdArrayTensor {
+            array,
+            shape: tensor.shape,
+        }
+    }
+
     fn device<const D: usize>(_tensor: &NdArrayTensor<E, D>) -> NdArrayDevice {
         NdArrayDevice::Cpu
     }
@@ -56,9 +110,17 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
         tensor: &NdArrayTensor<E, D>,
         _device: NdArrayDevice,
     ) -> NdArrayTensor<E, D> {
+        // Only the CPU device exists, cloning the shared array never copies the data.
         tensor.clone()
     }
 
+    fn deep_clone<const D: usize>(tensor: &NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
+        let array = tensor.array.to_owned().into_shared();
+        let shape = tensor.shape;
+
+        NdArrayTensor { array, shape }
+    }
+
     fn empty<const D: usize>(
         shape: Shape<D>,
         device: <NdArrayBackend<E> as Backend>::Device,
@@ -88,6 +150,13 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
         NdArrayTensor { array, shape }
     }
 
+    fn add_assign<const D: usize>(
+        lhs: &mut <NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
+        rhs: &<NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
+    ) {
+        lhs.array.zip_mut_with(&rhs.array, |a, b| *a = *a + *b);
+    }
+
     fn sub<const D: usize>(
         lhs: &<NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
         rhs: &<NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
@@ -110,6 +179,13 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
         NdArrayTensor { array, shape }
     }
 
+    fn sub_assign<const D: usize>(
+        lhs: &mut <NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
+        rhs: &<NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
+    ) {
+        lhs.array.zip_mut_with(&rhs.array, |a, b| *a = *a - *b);
+    }
+
     fn mul<const D: usize>(
         lhs: &<NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
         rhs: &<NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
@@ -132,6 +208,13 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
         NdArrayTensor { array, shape }
     }
 
+    fn mul_assign<const D: usize>(
+        lhs: &mut <NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
+        rhs: &<NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
+    ) {
+        lhs.array.zip_mut_with(&rhs.array, |a, b| *a = *a * *b);
+    }
+
     fn div<const D: usize>(
         lhs: &<NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
         rhs: &<NdArrayBackend<E> as Backend>::TensorPrimitive<D>,
@@ -161,16 +244,21 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
         let batch_self = BatchMatrix::from_ndarray(lhs.array.clone(), lhs.shape);
         let batch_other = BatchMatrix::from_ndarray(rhs.array.clone(), rhs.shape);
 
-        let self_iter = batch_self.arrays.iter();
-        let other_iter = batch_other.arrays.iter();
-        let arrays = self_iter
-            .zip(other_iter)
-            .map(|(lhs, rhs)| lhs.dot(rhs))
-            .map(|output| output.into_shared())
-            .collect();
-
         let mut shape = lhs.shape;
+        for i in 0..D.saturating_sub(2) {
+            shape.dims[i] = usize::max(lhs.shape.dims[i], rhs.shape.dims[i]);
+        }
         shape.dims[D - 1] = rhs.shape.dims[D - 1];
+
+        let num_batch = shape.dims[..D.saturating_sub(2)].iter().product::<usize>();
+        let arrays = (0..num_batch)
+            .map(|b| {
+                let lhs = &batch_self.arrays[broadcast_batch_index(b, &shape, &lhs.shape)];
+                let rhs = &batch_other.arrays[broadcast_batch_index(b, &shape, &rhs.shape)];
+                lhs.dot(rhs).into_shared()
+            })
+            .collect();
+
         let output = BatchMatrix::new(arrays, shape);
 
         NdArrayTensor::from_bmatrix(output)
@@ -181,6 +269,102 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
     ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D> {
         Self::mul_scalar(tensor, &(-1f32).to_elem::<E>())
     }
+    fn abs<const D: usize>(
+        tensor: &NdArrayTensor<E, D>,
+    ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D> {
+        let array = tensor.array.mapv(|a| a.abs_elem()).into_shared();
+        let shape = tensor.shape;
+
+        NdArrayTensor { array, shape }
+    }
+    fn sin<const D: usize>(
+        tensor: &NdArrayTensor<E, D>,
+    ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D> {
+        let array = tensor.array.mapv(|a| a.sin_elem()).into_shared();
+        let shape = tensor.shape;
+
+        NdArrayTensor { array, shape }
+    }
+    fn cos<const D: usize>(
+        tensor: &NdArrayTensor<E, D>,
+    ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D> {
+        let array = tensor.array.mapv(|a| a.cos_elem()).into_shared();
+        let shape = tensor.shape;
+
+        NdArrayTensor { array, shape }
+    }
+    fn tan<const D: usize>(
+        tensor: &NdArrayTensor<E, D>,
+    ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D> {
+        let array = tensor.array.mapv(|a| a.tan_elem()).into_shared();
+        let shape = tensor.shape;
+
+        NdArrayTensor { array, shape }
+    }
+    fn sign<const D: usize>(
+        tensor: &NdArrayTensor<E, D>,
+    ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D> {
+        let array = tensor.array.mapv(|a| a.sign_elem()).into_shared();
+        let shape = tensor.shape;
+
+        NdArrayTensor { array, shape }
+    }
+    fn round<const D: usize>(
+        tensor: &NdArrayTensor<E, D>,
+    ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D> {
+        let array = tensor.array.mapv(|a| a.round_elem()).into_shared();
+        let shape = tensor.shape;
+
+        NdArrayTensor { array, shape }
+    }
+    fn floor<const D: usize>(
+        tensor: &NdArrayTensor<E, D>,
+    ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D> {
+        let array = tensor.array.mapv(|a| a.floor_elem()).into_shared();
+        let shape = tensor.shape;
+
+        NdArrayTensor { array, shape }
+    }
+    fn ceil<const D: usize>(
+        tensor: &NdArrayTensor<E, D>,
+    ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D> {
+        let array = tensor.array.mapv(|a| a.ceil_elem()).into_shared();
+        let shape = tensor.shape;
+
+        NdArrayTensor { array, shape }
+    }
+    fn trunc<const D: usize>(
+        tensor: &NdArrayTensor<E, D>,
+    ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D> {
+        let array = tensor.array.mapv(|a| a.trunc_elem()).into_shared();
+        let shape = tensor.shape;
+
+        NdArrayTensor { array, shape }
+    }
+    fn reciprocal<const D: usize>(
+        tensor: &NdArrayTensor<E, D>,
+    ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D> {
+        let array = tensor.array.mapv(|a| a.recip_elem()).into_shared();
+        let shape = tensor.shape;
+
+        NdArrayTensor { array, shape }
+    }
+    fn rsqrt<const D: usize>(
+        tensor: &NdArrayTensor<E, D>,
+    ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D> {
+        let array = tensor.array.mapv(|a| a.rsqrt_elem()).into_shared();
+        let shape = tensor.shape;
+
+        NdArrayTensor { array, shape }
+    }
+    fn sigmoid<const D: usize>(
+        tensor: &NdArrayTensor<E, D>,
+    ) -> <NdArrayBackend<E> as Backend>::TensorPrimitive<D> {
+        let array = tensor.array.mapv(|a| a.sigmoid_elem()).into_shared();
+        let shape = tensor.shape;
+
+        NdArrayTensor { array, shape }
+    }
     fn swap_dims<const D: usize>(
         tensor: &NdArrayTensor<E, D>,
         dim1: usize,
@@ -199,6 +383,30 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
         NdArrayTensor { array, shape }
     }
 
+    fn permute<const D: usize>(
+        tensor: &NdArrayTensor<E, D>,
+        axes: [usize; D],
+    ) -> NdArrayTensor<E, D> {
+        let mut shape = tensor.shape;
+        for (i, axis) in axes.iter().enumerate() {
+            shape.dims[i] = tensor.shape.dims[*axis];
+        }
+
+        let array = tensor.array.clone().permuted_axes(axes.to_vec());
+
+        NdArrayTensor { array, shape }
+    }
+
+    fn flip<const D: usize>(tensor: &NdArrayTensor<E, D>, dims: &[usize]) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array.clone();
+        for dim in dims {
+            array.invert_axis(Axis(*dim));
+        }
+        let shape = tensor.shape;
+
+        NdArrayTensor { array, shape }
+    }
+
     fn reshape<const D1: usize, const D2: usize>(
         tensor: &NdArrayTensor<E, D1>,
         shape: Shape<D2>,
@@ -214,6 +422,19 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
         }
     }
 
+    fn expand<const D: usize>(
+        tensor: &NdArrayTensor<E, D>,
+        shape: Shape<D>,
+    ) -> NdArrayTensor<E, D> {
+        let array = tensor
+            .array
+            .broadcast(shape.dims.as_slice())
+            .unwrap()
+            .to_shared();
+
+        NdArrayTensor { array, shape }
+    }
+
     fn index<const D1: usize, const D2: usize>(
         tensor: &NdArrayTensor<E, D1>,
         indexes: [Range<usize>; D2],
@@ -244,6 +465,41 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
         NdArrayTensor { array, shape }
     }
 
+    fn index_select<const D: usize>(
+        tensor: &NdArrayTensor<E, D>,
+        dim: usize,
+        indexes: &NdArrayTensor<i64, 1>,
+    ) -> NdArrayTensor<E, D> {
+        let indexes: Vec<usize> = indexes.array.iter().map(|i| *i as usize).collect();
+        let array = tensor.array.select(Axis(dim), &indexes).into_shared();
+
+        let mut shape = tensor.shape;
+        shape.dims[dim] = indexes.len();
+
+        NdArrayTensor { array, shape }
+    }
+
+    fn index_select_add<const D: usize>(
+        tensor: &NdArrayTensor<E, D>,
+        dim: usize,
+        indexes: &NdArrayTensor<i64, 1>,
+        value: &NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array.to_owned();
+
+        for (index_value, index) in indexes.array.iter().enumerate() {
+            let mut view = array.index_axis_mut(Axis(dim), *index as usize);
+            let value = value.array.index_axis(Axis(dim), index_value);
+
+            view.zip_mut_with(&value, |a, b| *a = *a + *b);
+        }
+
+        let array = array.into_shared();
+        let shape = tensor.shape;
+
+        NdArrayTensor { array, shape }
+    }
+
     fn mask_fill<const D: usize>(
         tensor: &NdArrayTensor<E, D>,
         mask: &NdArrayTensor<bool, D>,
@@ -265,6 +521,85 @@ impl<E: NdArrayElement> TensorOps<NdArrayBackend<E>> for NdArrayBackend<E> {
             shape: tensor.shape,
         }
     }
+
+    fn maximum<const D: usize>(
+        lhs: &NdArrayTensor<E, D>,
+        rhs: &NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        select(lhs, rhs, |a, b| a >= b)
+    }
+
+    fn maximum_scalar<const D: usize>(lhs: &NdArrayTensor<E, D>, rhs: &E) -> NdArrayTensor<E, D> {
+        select_scalar(lhs, *rhs, |a, b| a >= b)
+    }
+
+    fn minimum<const D: usize>(
+        lhs: &NdArrayTensor<E, D>,
+        rhs: &NdArrayTensor<E, D>,
+    ) -> NdArrayTensor<E, D> {
+        select(lhs, rhs, |a, b| a <= b)
+    }
+
+    fn minimum_scalar<const D: usize>(lhs: &NdArrayTensor<E, D>, rhs: &E) -> NdArrayTensor<E, D> {
+        select_scalar(lhs, *rhs, |a, b| a <= b)
+    }
+}
+
+/// Reduce a boolean tensor along a dimension, which is kept with a size of 1.
+fn bool_reduce_dim<const D: usize>(
+    tensor: &NdArrayTensor<bool, D>,
+    dim: usize,
+    init: bool,
+    reduce: fn(bool, bool) -> bool,
+) -> NdArrayTensor<bool, D> {
+    let array = tensor
+        .array
+        .fold_axis(Axis(dim), init, |acc, value| reduce(*acc, *value))
+        .insert_axis(Axis(dim))
+        .into_shared();
+    let mut shape = tensor.shape;
+    shape.dims[dim] = 1;
+
+    NdArrayTensor { array, shape }
+}
+
+/// Select elementwise the value of `lhs` when `keep_lhs` is true and the value of `rhs` otherwise.
+fn select<E: NdArrayElement, const D: usize>(
+    lhs: &NdArrayTensor<E, D>,
+    rhs: &NdArrayTensor<E, D>,
+    keep_lhs: fn(E, E) -> bool,
+) -> NdArrayTensor<E, D> {
+    let array = Zip::from(&lhs.array)
+        .and(&rhs.array)
+        .map_collect(|a, b| match keep_lhs(*a, *b) {
+            true => *a,
+            false => *b,
+        })
+        .into_shared();
+
+    NdArrayTensor {
+        array,
+        shape: lhs.shape,
+    }
+}
+
+fn select_scalar<E: NdArrayElement, const D: usize>(
+    lhs: &NdArrayTensor<E, D>,
+    rhs: E,
+    keep_lhs: fn(E, E) -> bool,
+) -> NdArrayTensor<E, D> {
+    let array = lhs
+        .array
+        .mapv(|a| match keep_lhs(a, rhs) {
+            true => a,
+            false => rhs,
+        })
+        .into_shared();
+
+    NdArrayTensor {
+        array,
+        shape: lhs.shape,
+    }
 }
 
 fn to_slice_args<const D1: usize, const D2: usize>(
@@ -288,3 +623,61 @@ fn to_slice_args<const D1: usize, const D2: usize>(
     }
     slices
 }
+
+/// Map the index of a batch in the broadcasted output to the index of the batch in a tensor that
+/// might have batch dimensions of size 1.
+fn broadcast_batch_index<const D: usize>(
+    index: usize,
+    output: &Shape<D>,
+    shape: &Shape<D>,
+) -> usize {
+    let mut remainder = index;
+    let mut index = 0;
+    let mut stride = 1;
+
+    for i in (0..D.saturating_sub(2)).rev() {
+        let position = remainder % output.dims[i];
+        remainder /= output.dims[i];
+
+        if shape.dims[i] != 1 {
+            index += position * stride;
+        }
+        stride *= shape.dims[i];
+    }
+
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::backend::autodiff::ADBackendNdArray;
+    use crate::tensor::backend::ADBackend;
+
+    #[test]
+    fn should_reuse_storage_when_moving_to_the_same_device() {
+        let data = Data::<f32, 2>::from([[0.0, 1.0], [2.0, 3.0]]);
+        let tensor = NdArrayBackend::<f32>::from_data(data.clone(), NdArrayDevice::Cpu);
+
+        let tensor_device = NdArrayBackend::to_device(&tensor, NdArrayDevice::Cpu);
+
+        assert_eq!(tensor.array.as_ptr(), tensor_device.array.as_ptr());
+        assert_eq!(NdArrayBackend::into_data(tensor_device), data);
+    }
+
+    #[test]
+    fn should_assign_untracked_ad_tensor_in_place() {
+        type B = ADBackendNdArray<f32>;
+        let mut tensor = B::from_data(Data::from([[0.0, 1.0], [2.0, 3.0]]), NdArrayDevice::Cpu);
+        let other = B::from_data(Data::from([[4.0, 5.0], [6.0, 7.0]]), NdArrayDevice::Cpu);
+        let pointer = B::inner(&tensor).array.as_ptr();
+
+        B::sub_assign(&mut tensor, &other);
+
+        assert_eq!(B::inner(&tensor).array.as_ptr(), pointer);
+        assert_eq!(
+            B::into_data(tensor),
+            Data::from([[-4.0, -4.0], [-4.0, -4.0]])
+        );
+    }
+}
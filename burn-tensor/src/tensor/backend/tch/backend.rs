@@ -1,12 +1,58 @@
 use super::TchTensor;
-use crate::tensor::{backend::Backend, TchElement};
+use crate::tensor::backend::{Backend, MatmulPrecision};
+use crate::tensor::TchElement;
 use crate::tensor::{Data, Distribution, Shape};
+use std::sync::atomic::{AtomicBool, Ordering};
 
-#[derive(Clone, Copy, Debug)]
+static MATMUL_PRECISION_MEDIUM: AtomicBool = AtomicBool::new(false);
+static DETERMINISTIC: AtomicBool = AtomicBool::new(false);
+
+/// Lock taken by the tests changing the global settings of the backend.
+#[cfg(test)]
+pub(crate) static SETTINGS: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// The precision of the matrix multiplications set by
+/// [set_matmul_precision](Backend::set_matmul_precision).
+pub(crate) fn matmul_precision() -> MatmulPrecision {
+    match MATMUL_PRECISION_MEDIUM.load(Ordering::Relaxed) {
+        true => MatmulPrecision::Medium,
+        false => MatmulPrecision::High,
+    }
+}
+
+/// If the deterministic mode is set by [set_deterministic](Backend::set_deterministic).
+pub(crate) fn is_deterministic() -> bool {
+    DETERMINISTIC.load(Ordering::Relaxed)
+}
+
+fn configure_cudnn() {
+    let deterministic = is_deterministic();
+
+    // tch doesn't expose the deterministic flag of cuDNN, so cuDNN is disabled instead and the
+    // native kernels are used.
+    tch::Cuda::set_user_enabled_cudnn(!deterministic);
+    // The benchmark mode selects the fastest cuDNN algorithms, which aren't deterministic.
+    tch::Cuda::cudnn_set_benchmark(!deterministic && matmul_precision() == MatmulPrecision::Medium);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum TchDevice {
     Cpu,
     Cuda(usize),
 }
+
+impl TchDevice {
+    /// The CPU device.
+    pub fn cpu() -> Self {
+        Self::Cpu
+    }
+
+    /// The CUDA device with the given index.
+    pub fn cuda(index: usize) -> Self {
+        Self::Cuda(index)
+    }
+}
+
 impl From<TchDevice> for tch::Device {
     fn from(device: TchDevice) -> Self {
         match device {
@@ -16,6 +62,15 @@ impl From<TchDevice> for tch::Device {
     }
 }
 
+impl From<tch::Device> for TchDevice {
+    fn from(device: tch::Device) -> Self {
+        match device {
+            tch::Device::Cpu => TchDevice::Cpu,
+            tch::Device::Cuda(num) => TchDevice::Cuda(num),
+        }
+    }
+}
+
 impl Default for TchDevice {
     fn default() -> Self {
         Self::Cpu
@@ -40,22 +95,14 @@ impl<E: TchElement> Backend for TchBackend<E> {
         data: Data<Self::Elem, D>,
         device: Self::Device,
     ) -> TchTensor<E, D> {
-        let device = match device {
-            TchDevice::Cpu => tch::Device::Cpu,
-            TchDevice::Cuda(num) => tch::Device::Cuda(num),
-        };
-        TchTensor::from_data(data, device)
+        TchTensor::from_data(data, device.into())
     }
 
     fn from_data_bool<const D: usize>(
         data: Data<bool, D>,
         device: Self::Device,
     ) -> Self::BoolTensorPrimitive<D> {
-        let device = match device {
-            TchDevice::Cpu => tch::Device::Cpu,
-            TchDevice::Cuda(num) => tch::Device::Cuda(num),
-        };
-        TchTensor::from_data(data, device)
+        TchTensor::from_data(data, device.into())
     }
 
     fn random<const D: usize>(
@@ -74,6 +121,11 @@ impl<E: TchElement> Backend for TchBackend<E> {
                 tensor.tensor = tensor.tensor.f_bernoulli_float_(prob).unwrap();
                 tensor
             }
+            Distribution::Normal(mean, std) => {
+                let mut tensor = TchTensor::<Self::Elem, D>::empty(shape, device);
+                tensor.tensor = tensor.tensor.normal_(mean, std);
+                tensor
+            }
             Distribution::Uniform(from, to) => {
                 let mut tensor = TchTensor::<Self::Elem, D>::empty(shape, device);
                 tensor.tensor = tensor
@@ -94,6 +146,22 @@ impl<E: TchElement> Backend for TchBackend<E> {
         tch::manual_seed(seed as i64);
     }
 
+    fn set_matmul_precision(precision: MatmulPrecision) {
+        MATMUL_PRECISION_MEDIUM.store(precision == MatmulPrecision::Medium, Ordering::Relaxed);
+        configure_cudnn();
+    }
+
+    /// Disable cuDNN and its benchmark mode, and run the additions of
+    /// [index_select_add](crate::ops::TensorOps::index_select_add) on the CPU.
+    ///
+    /// tch doesn't expose the global deterministic mode of libtorch, so the other CUDA kernels
+    /// using atomics, e.g. the backward passes of some indexing operations, aren't made
+    /// deterministic.
+    fn set_deterministic(deterministic: bool) {
+        DETERMINISTIC.store(deterministic, Ordering::Relaxed);
+        configure_cudnn();
+    }
+
     fn ones<const D: usize>(shape: Shape<D>, device: Self::Device) -> Self::TensorPrimitive<D> {
         let mut tensor = TchTensor::<Self::Elem, D>::empty(shape, device);
         tensor.tensor = tensor.tensor.ones_like();
@@ -35,4 +35,173 @@ impl<E: TchElement> ModuleOps<TchBackend<E>> for TchBackend<E> {
             shape,
         }
     }
+
+    fn conv1d(
+        x: &TchTensor<E, 3>,
+        weight: &TchTensor<E, 3>,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+    ) -> TchTensor<E, 3> {
+        let tensor = tch::Tensor::conv1d(
+            &x.tensor,
+            &weight.tensor,
+            None::<tch::Tensor>,
+            &[stride as i64],
+            &[padding as i64],
+            &[dilation as i64],
+            1,
+        );
+        let shape = Shape::from(tensor.size());
+
+        TchTensor {
+            kind: x.kind,
+            tensor,
+            shape,
+        }
+    }
+
+    fn conv1d_x_backward(
+        x: &TchTensor<E, 3>,
+        weight: &TchTensor<E, 3>,
+        output_grad: &TchTensor<E, 3>,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+    ) -> TchTensor<E, 3> {
+        let [_batch_size, _channels_in, length] = x.shape.dims;
+        let [_channels_out, _channels_in, kernel_size] = weight.shape.dims;
+        let [_batch_size, _channels_out, length_out] = output_grad.shape.dims;
+
+        // The positions at the end of the input not covered by any kernel are lost by the
+        // transposed convolution unless added back as output padding.
+        let output_padding =
+            length + 2 * padding - (length_out - 1) * stride - dilation * (kernel_size - 1) - 1;
+
+        let tensor = tch::Tensor::conv_transpose1d(
+            &output_grad.tensor,
+            &weight.tensor,
+            None::<tch::Tensor>,
+            &[stride as i64],
+            &[padding as i64],
+            &[output_padding as i64],
+            1,
+            &[dilation as i64],
+        );
+
+        TchTensor {
+            kind: x.kind,
+            tensor,
+            shape: x.shape,
+        }
+    }
+
+    fn conv1d_weight_backward(
+        x: &TchTensor<E, 3>,
+        weight: &TchTensor<E, 3>,
+        output_grad: &TchTensor<E, 3>,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+    ) -> TchTensor<E, 3> {
+        let [_channels_out, _channels_in, kernel_size] = weight.shape.dims;
+
+        // Correlate the input with the output gradient, using the batch as the channels and the
+        // stride as the dilation.
+        let tensor = tch::Tensor::conv1d(
+            &x.tensor.transpose(0, 1),
+            &output_grad.tensor.transpose(0, 1),
+            None::<tch::Tensor>,
+            &[dilation as i64],
+            &[padding as i64],
+            &[stride as i64],
+            1,
+        )
+        .narrow(2, 0, kernel_size as i64)
+        .transpose(0, 1)
+        .contiguous();
+
+        TchTensor {
+            kind: weight.kind,
+            tensor,
+            shape: weight.shape,
+        }
+    }
+
+    fn avg_pool2d(
+        x: &TchTensor<E, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> TchTensor<E, 4> {
+        let tensor = x.tensor.avg_pool2d(
+            &kernel_size.map(|size| size as i64),
+            &stride.map(|size| size as i64),
+            &padding.map(|size| size as i64),
+            false,
+            count_include_pad,
+            None::<i64>,
+        );
+        let shape = Shape::from(tensor.size());
+
+        TchTensor {
+            kind: x.kind,
+            tensor,
+            shape,
+        }
+    }
+
+    fn avg_pool2d_backward(
+        x: &TchTensor<E, 4>,
+        output_grad: &TchTensor<E, 4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> TchTensor<E, 4> {
+        let tensor = x.tensor.avg_pool2d_backward(
+            &output_grad.tensor,
+            &kernel_size.map(|size| size as i64),
+            &stride.map(|size| size as i64),
+            &padding.map(|size| size as i64),
+            false,
+            count_include_pad,
+            None::<i64>,
+        );
+
+        TchTensor {
+            kind: x.kind,
+            tensor,
+            shape: x.shape,
+        }
+    }
+
+    fn adaptive_avg_pool2d(x: &TchTensor<E, 4>, output_size: [usize; 2]) -> TchTensor<E, 4> {
+        let tensor = x
+            .tensor
+            .adaptive_avg_pool2d(&output_size.map(|size| size as i64));
+        let shape = Shape::from(tensor.size());
+
+        TchTensor {
+            kind: x.kind,
+            tensor,
+            shape,
+        }
+    }
+
+    fn adaptive_avg_pool2d_backward(
+        x: &TchTensor<E, 4>,
+        output_grad: &TchTensor<E, 4>,
+    ) -> TchTensor<E, 4> {
+        let tensor = x
+            .tensor
+            .internal_adaptive_avg_pool2d_backward(&output_grad.tensor);
+
+        TchTensor {
+            kind: x.kind,
+            tensor,
+            shape: x.shape,
+        }
+    }
 }
@@ -58,4 +58,64 @@ impl<E: TchElement, const D: usize> TensorOpsAggregation<TchBackend<E>, D> for T
             shape,
         }
     }
+
+    fn mean_dims(&self, dims: &[usize]) -> <TchBackend<E> as Backend>::TensorPrimitive<D> {
+        // Libtorch reduces all dimensions when none are given.
+        if dims.is_empty() {
+            return self.clone();
+        }
+
+        let kind = self.kind;
+        let dims: Vec<i64> = dims.iter().map(|dim| *dim as i64).collect();
+        let tensor = self.tensor.mean_dim(&dims, true, kind.kind());
+        let shape = Shape::from(tensor.size());
+
+        TchTensor {
+            tensor,
+            kind,
+            shape,
+        }
+    }
+
+    fn sum_dims(&self, dims: &[usize]) -> <TchBackend<E> as Backend>::TensorPrimitive<D> {
+        // Libtorch reduces all dimensions when none are given.
+        if dims.is_empty() {
+            return self.clone();
+        }
+
+        let kind = self.kind;
+        let dims: Vec<i64> = dims.iter().map(|dim| *dim as i64).collect();
+        let tensor = self.tensor.sum_dim_intlist(&dims, true, kind.kind());
+        let shape = Shape::from(tensor.size());
+
+        TchTensor {
+            tensor,
+            kind,
+            shape,
+        }
+    }
+
+    fn prod(&self) -> <TchBackend<E> as Backend>::TensorPrimitive<1> {
+        let kind = self.kind;
+        let tensor = self.tensor.prod(kind.kind());
+        let shape = Shape::new([1]);
+
+        TchTensor {
+            tensor,
+            kind,
+            shape,
+        }
+    }
+
+    fn prod_dim(&self, dim: usize) -> <TchBackend<E> as Backend>::TensorPrimitive<D> {
+        let kind = self.kind;
+        let tensor = self.tensor.prod_dim_int(dim as i64, true, kind.kind());
+        let shape = Shape::from(tensor.size());
+
+        TchTensor {
+            tensor,
+            kind,
+            shape,
+        }
+    }
 }
@@ -18,4 +18,16 @@ where
             kind,
         }
     }
+
+    fn log1p(&self) -> Self {
+        let tensor = self.tensor.log1p();
+        let kind = self.kind;
+        let shape = self.shape;
+
+        Self {
+            tensor,
+            shape,
+            kind,
+        }
+    }
 }
@@ -18,4 +18,16 @@ where
             kind,
         }
     }
+
+    fn powi(&self, value: i32) -> Self {
+        let tensor = self.tensor.pow_tensor_scalar(value as i64);
+        let kind = self.kind;
+        let shape = self.shape;
+
+        Self {
+            tensor,
+            shape,
+            kind,
+        }
+    }
 }
@@ -1,3 +1,4 @@
+use super::backend::is_deterministic;
 use super::{TchBackend, TchDevice, TchKind, TchShape, TchTensor};
 use crate::{backend::Backend, ops::TensorOps, Data, ElementConversion, Shape, TchElement};
 use std::ops::{Add, Div, Mul, Range, Sub};
@@ -21,6 +22,13 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         Data::new(values, tensor.shape)
     }
 
+    fn count_nonzero<const D: usize>(
+        tensor: &<TchBackend<E> as Backend>::TensorPrimitive<D>,
+    ) -> usize {
+        let values: Vec<i64> = tensor.tensor.count_nonzero(None).into();
+        values[0] as usize
+    }
+
     fn bool_shape<const D: usize>(
         tensor: &<TchBackend<E> as Backend>::BoolTensorPrimitive<D>,
     ) -> &Shape<D> {
@@ -40,21 +48,79 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         let values: Vec<bool> = tensor.tensor.into();
         Data::new(values, tensor.shape)
     }
-    fn device<const D: usize>(tensor: &TchTensor<E, D>) -> TchDevice {
-        match tensor.tensor.device() {
-            tch::Device::Cpu => TchDevice::Cpu,
-            tch::Device::Cuda(num) => TchDevice::Cuda(num),
+
+    fn bool_all<const D: usize>(
+        tensor: &<TchBackend<E> as Backend>::BoolTensorPrimitive<D>,
+    ) -> bool {
+        let values: Vec<bool> = tensor.tensor.all().into();
+        values[0]
+    }
+
+    fn bool_any<const D: usize>(
+        tensor: &<TchBackend<E> as Backend>::BoolTensorPrimitive<D>,
+    ) -> bool {
+        let values: Vec<bool> = tensor.tensor.any().into();
+        values[0]
+    }
+
+    fn bool_all_dim<const D: usize>(
+        tensor: &<TchBackend<E> as Backend>::BoolTensorPrimitive<D>,
+        dim: usize,
+    ) -> <TchBackend<E> as Backend>::BoolTensorPrimitive<D> {
+        let tensor = tensor.tensor.all_dim(dim as i64, true);
+        let shape = Shape::from(tensor.size());
+
+        TchTensor {
+            tensor,
+            kind: TchKind::<bool>::new(),
+            shape,
+        }
+    }
+
+    fn bool_any_dim<const D: usize>(
+        tensor: &<TchBackend<E> as Backend>::BoolTensorPrimitive<D>,
+        dim: usize,
+    ) -> <TchBackend<E> as Backend>::BoolTensorPrimitive<D> {
+        let tensor = tensor.tensor.any_dim(dim as i64, true);
+        let shape = Shape::from(tensor.size());
+
+        TchTensor {
+            tensor,
+            kind: TchKind::<bool>::new(),
+            shape,
         }
     }
 
+    fn bool_to_float<const D: usize>(
+        tensor: &<TchBackend<E> as Backend>::BoolTensorPrimitive<D>,
+    ) -> <TchBackend<E> as Backend>::TensorPrimitive<D> {
+        let kind = TchKind::<E>::new();
+        to_tensor(tensor.tensor.to_kind(kind.kind()))
+    }
+    fn device<const D: usize>(tensor: &TchTensor<E, D>) -> TchDevice {
+        tensor.tensor.device().into()
+    }
+
     fn to_device<const D: usize>(tensor: &TchTensor<E, D>, device: TchDevice) -> TchTensor<E, D> {
-        let device = match device {
-            TchDevice::Cpu => tch::Device::Cpu,
-            TchDevice::Cuda(num) => tch::Device::Cuda(num),
+        let device: tch::Device = device.into();
+
+        // Avoid going through libtorch when the tensor is already on the requested device.
+        let tensor_device = match tensor.tensor.device() == device {
+            true => tensor.tensor.shallow_clone(),
+            false => tensor.tensor.to(device),
         };
+
         TchTensor {
             kind: tensor.kind,
-            tensor: tensor.tensor.to(device),
+            tensor: tensor_device,
+            shape: tensor.shape,
+        }
+    }
+
+    fn deep_clone<const D: usize>(tensor: &TchTensor<E, D>) -> TchTensor<E, D> {
+        TchTensor {
+            kind: tensor.kind,
+            tensor: tensor.tensor.copy(),
             shape: tensor.shape,
         }
     }
@@ -83,6 +149,10 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         to_tensor(tensor)
     }
 
+    fn add_assign<const D: usize>(lhs: &mut TchTensor<E, D>, rhs: &TchTensor<E, D>) {
+        lhs.tensor += &rhs.tensor;
+    }
+
     fn sub<const D: usize>(lhs: &TchTensor<E, D>, rhs: &TchTensor<E, D>) -> TchTensor<E, D> {
         let tensor = (&lhs.tensor).sub(&rhs.tensor);
         to_tensor(tensor)
@@ -95,6 +165,10 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         to_tensor(tensor)
     }
 
+    fn sub_assign<const D: usize>(lhs: &mut TchTensor<E, D>, rhs: &TchTensor<E, D>) {
+        lhs.tensor -= &rhs.tensor;
+    }
+
     fn mul<const D: usize>(lhs: &TchTensor<E, D>, rhs: &TchTensor<E, D>) -> TchTensor<E, D> {
         let tensor = (&lhs.tensor).mul(&rhs.tensor);
         to_tensor(tensor)
@@ -107,6 +181,10 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         to_tensor(tensor)
     }
 
+    fn mul_assign<const D: usize>(lhs: &mut TchTensor<E, D>, rhs: &TchTensor<E, D>) {
+        lhs.tensor *= &rhs.tensor;
+    }
+
     fn div<const D: usize>(lhs: &TchTensor<E, D>, rhs: &TchTensor<E, D>) -> TchTensor<E, D> {
         let tensor = (&lhs.tensor).div(&rhs.tensor);
         to_tensor(tensor)
@@ -128,6 +206,54 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         Self::mul_scalar(tensor, &(-1f32).to_elem::<E>())
     }
 
+    fn abs<const D: usize>(tensor: &TchTensor<E, D>) -> TchTensor<E, D> {
+        to_tensor(tensor.tensor.abs())
+    }
+
+    fn sin<const D: usize>(tensor: &TchTensor<E, D>) -> TchTensor<E, D> {
+        to_tensor(tensor.tensor.sin())
+    }
+
+    fn cos<const D: usize>(tensor: &TchTensor<E, D>) -> TchTensor<E, D> {
+        to_tensor(tensor.tensor.cos())
+    }
+
+    fn tan<const D: usize>(tensor: &TchTensor<E, D>) -> TchTensor<E, D> {
+        to_tensor(tensor.tensor.tan())
+    }
+
+    fn sign<const D: usize>(tensor: &TchTensor<E, D>) -> TchTensor<E, D> {
+        to_tensor(tensor.tensor.sign())
+    }
+
+    fn round<const D: usize>(tensor: &TchTensor<E, D>) -> TchTensor<E, D> {
+        to_tensor(tensor.tensor.round())
+    }
+
+    fn floor<const D: usize>(tensor: &TchTensor<E, D>) -> TchTensor<E, D> {
+        to_tensor(tensor.tensor.floor())
+    }
+
+    fn ceil<const D: usize>(tensor: &TchTensor<E, D>) -> TchTensor<E, D> {
+        to_tensor(tensor.tensor.ceil())
+    }
+
+    fn trunc<const D: usize>(tensor: &TchTensor<E, D>) -> TchTensor<E, D> {
+        to_tensor(tensor.tensor.trunc())
+    }
+
+    fn reciprocal<const D: usize>(tensor: &TchTensor<E, D>) -> TchTensor<E, D> {
+        to_tensor(tensor.tensor.reciprocal())
+    }
+
+    fn rsqrt<const D: usize>(tensor: &TchTensor<E, D>) -> TchTensor<E, D> {
+        to_tensor(tensor.tensor.rsqrt())
+    }
+
+    fn sigmoid<const D: usize>(tensor: &TchTensor<E, D>) -> TchTensor<E, D> {
+        to_tensor(tensor.tensor.sigmoid())
+    }
+
     fn swap_dims<const D: usize>(
         tensor: &TchTensor<E, D>,
         dim1: usize,
@@ -137,6 +263,18 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         to_tensor(tensor)
     }
 
+    fn permute<const D: usize>(tensor: &TchTensor<E, D>, axes: [usize; D]) -> TchTensor<E, D> {
+        let axes: Vec<i64> = axes.iter().map(|axis| *axis as i64).collect();
+        let tensor = tensor.tensor.permute(&axes);
+        to_tensor(tensor)
+    }
+
+    fn flip<const D: usize>(tensor: &TchTensor<E, D>, dims: &[usize]) -> TchTensor<E, D> {
+        let dims: Vec<i64> = dims.iter().map(|dim| *dim as i64).collect();
+        let tensor = tensor.tensor.flip(&dims);
+        to_tensor(tensor)
+    }
+
     fn reshape<const D1: usize, const D2: usize>(
         tensor: &TchTensor<E, D1>,
         shape: Shape<D2>,
@@ -147,6 +285,13 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         to_tensor(tensor)
     }
 
+    fn expand<const D: usize>(tensor: &TchTensor<E, D>, shape: Shape<D>) -> TchTensor<E, D> {
+        let shape_tch: TchShape<D> = shape.into();
+        let tensor = tensor.tensor.expand(&shape_tch.dims, false);
+
+        to_tensor(tensor)
+    }
+
     fn index<const D1: usize, const D2: usize>(
         tensor: &TchTensor<E, D1>,
         indexes: [Range<usize>; D2],
@@ -197,6 +342,42 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
         }
     }
 
+    fn index_select<const D: usize>(
+        tensor: &TchTensor<E, D>,
+        dim: usize,
+        indexes: &TchTensor<i64, 1>,
+    ) -> TchTensor<E, D> {
+        let tensor = tensor.tensor.index_select(dim as i64, &indexes.tensor);
+        to_tensor(tensor)
+    }
+
+    fn index_select_add<const D: usize>(
+        tensor: &TchTensor<E, D>,
+        dim: usize,
+        indexes: &TchTensor<i64, 1>,
+        value: &TchTensor<E, D>,
+    ) -> TchTensor<E, D> {
+        let device = tensor.tensor.device();
+
+        // The additions are done with atomics on CUDA, so their order changes between runs.
+        let tensor = match is_deterministic() && device != tch::Device::Cpu {
+            true => tensor
+                .tensor
+                .to(tch::Device::Cpu)
+                .index_add(
+                    dim as i64,
+                    &indexes.tensor.to(tch::Device::Cpu),
+                    &value.tensor.to(tch::Device::Cpu),
+                )
+                .to(device),
+            false => tensor
+                .tensor
+                .index_add(dim as i64, &indexes.tensor, &value.tensor),
+        };
+
+        to_tensor(tensor)
+    }
+
     fn mask_fill<const D: usize>(
         tensor: &TchTensor<E, D>,
         mask: &TchTensor<bool, D>,
@@ -207,6 +388,24 @@ impl<E: TchElement> TensorOps<TchBackend<E>> for TchBackend<E> {
 
         to_tensor(tensor)
     }
+
+    fn maximum<const D: usize>(lhs: &TchTensor<E, D>, rhs: &TchTensor<E, D>) -> TchTensor<E, D> {
+        to_tensor(lhs.tensor.maximum(&rhs.tensor))
+    }
+
+    fn maximum_scalar<const D: usize>(lhs: &TchTensor<E, D>, rhs: &E) -> TchTensor<E, D> {
+        let other: f64 = (*rhs).to_elem();
+        to_tensor(lhs.tensor.clamp_min(other))
+    }
+
+    fn minimum<const D: usize>(lhs: &TchTensor<E, D>, rhs: &TchTensor<E, D>) -> TchTensor<E, D> {
+        to_tensor(lhs.tensor.minimum(&rhs.tensor))
+    }
+
+    fn minimum_scalar<const D: usize>(lhs: &TchTensor<E, D>, rhs: &E) -> TchTensor<E, D> {
+        let other: f64 = (*rhs).to_elem();
+        to_tensor(lhs.tensor.clamp_max(other))
+    }
 }
 
 fn to_tensor<const D: usize, E: TchElement>(tensor: tch::Tensor) -> TchTensor<E, D> {
@@ -218,3 +417,74 @@ fn to_tensor<const D: usize, E: TchElement>(tensor: tch::Tensor) -> TchTensor<E,
         kind: TchKind::new(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::MatmulPrecision;
+    use crate::tensor::backend::tch::backend::SETTINGS;
+
+    #[test]
+    fn should_reuse_storage_when_moving_to_the_same_device() {
+        let data = Data::<f32, 2>::from([[0.0, 1.0], [2.0, 3.0]]);
+        let tensor = TchBackend::<f32>::from_data(data.clone(), TchDevice::Cpu);
+
+        let tensor_device = TchBackend::to_device(&tensor, TchDevice::Cpu);
+
+        assert_eq!(tensor.tensor.data_ptr(), tensor_device.tensor.data_ptr());
+        assert_eq!(TchBackend::into_data(tensor_device), data);
+    }
+
+    #[test]
+    #[ignore = "requires a CUDA device"]
+    fn should_match_cpu_reference_with_each_matmul_precision() {
+        let _settings = SETTINGS.lock().unwrap();
+        let lhs = Data::<f32, 2>::from([[0.1, 1.2, -2.3], [3.4, -4.5, 5.6]]);
+        let rhs = Data::<f32, 2>::from([[1.0, -0.5], [0.25, 2.0], [-1.5, 0.75]]);
+        let matmul = |device| {
+            let lhs = TchBackend::<f32>::from_data(lhs.clone(), device);
+            let rhs = TchBackend::<f32>::from_data(rhs.clone(), device);
+            TchBackend::into_data(TchBackend::matmul(&lhs, &rhs))
+        };
+        let reference = matmul(TchDevice::Cpu);
+
+        TchBackend::<f32>::set_matmul_precision(MatmulPrecision::Medium);
+        let output_medium = matmul(TchDevice::Cuda(0));
+        TchBackend::<f32>::set_matmul_precision(MatmulPrecision::High);
+        let output_high = matmul(TchDevice::Cuda(0));
+
+        output_high.assert_approx_eq(&reference, 4);
+        output_medium.assert_within_tolerance(&reference, 0.1);
+    }
+
+    #[test]
+    #[ignore = "requires a CUDA device"]
+    fn index_select_add_should_be_deterministic_on_cuda() {
+        let _settings = SETTINGS.lock().unwrap();
+        let device = TchDevice::Cuda(0);
+        let num_values = 4096;
+        let values = (0..num_values)
+            .map(|i| (i as f32).sin() * 1000.0)
+            .collect::<Vec<_>>();
+        let indexes = (0..num_values).map(|i| i % 4).collect::<Vec<_>>();
+        let index_select_add = || {
+            let tensor = TchBackend::<f32>::zeros(Shape::new([4, 1]), device);
+            let value = TchBackend::<f32>::from_data(
+                Data::new(values.clone(), Shape::new([num_values as usize, 1])),
+                device,
+            );
+            let indexes = TchBackend::<i64>::from_data(
+                Data::new(indexes.clone(), Shape::new([num_values as usize])),
+                device,
+            );
+            TchBackend::into_data(TchBackend::index_select_add(&tensor, 0, &indexes, &value))
+        };
+
+        TchBackend::<f32>::set_deterministic(true);
+        let output_1 = index_select_add();
+        let output_2 = index_select_add();
+        TchBackend::<f32>::set_deterministic(false);
+
+        assert_eq!(output_1, output_2);
+    }
+}
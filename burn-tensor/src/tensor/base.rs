@@ -4,13 +4,20 @@ use crate::tensor::backend::Backend;
 use crate::tensor::ops::activation::*;
 use crate::tensor::ops::*;
 use crate::tensor::stats;
-use crate::tensor::ElementConversion;
 use crate::tensor::{Data, Distribution, Shape};
+use crate::tensor::{ElementConversion, ElementValue};
 use crate::BoolTensor;
+use num_traits::ToPrimitive;
 use std::convert::TryInto;
 use std::ops::Range;
 
 /// A tensor or a *n-dimensional* array.
+///
+/// Cloning a tensor is cheap: backends share the underlying storage when they can, for
+/// instance with reference counting, so that no data is copied. Operations never modify their
+/// inputs, but in-place operations like [sub_assign](Tensor::sub_assign) may be visible through
+/// clones on backends that share storage. Use [deep_clone](Tensor::deep_clone) to get a snapshot
+/// that is guaranteed to be independent.
 #[derive(Debug, Clone)]
 pub struct Tensor<B: Backend, const D: usize> {
     pub(crate) value: B::TensorPrimitive<D>,
@@ -28,6 +35,45 @@ where
     pub fn arange_device(range: Range<usize>, device: B::Device) -> Tensor<B::IntegerBackend, 1> {
         Tensor::new(B::arange(range, device))
     }
+
+    /// Returns the values of the tensor as a vector.
+    pub fn to_vec(&self) -> Vec<B::Elem> {
+        self.to_data().value
+    }
+}
+
+impl<B> Tensor<B, 2>
+where
+    B: Backend,
+{
+    /// Create an identity matrix of the given size on the given device, with ones on the
+    /// diagonal and zeros elsewhere.
+    pub fn eye(size: usize, device: B::Device) -> Self {
+        Tensor::<B, 3>::eye_batch(1, size, device).reshape([size, size])
+    }
+}
+
+impl<B> Tensor<B, 3>
+where
+    B: Backend,
+{
+    /// Create a batch of identity matrices of the given size on the given device, with the shape
+    /// `[batch_size, size, size]`.
+    pub fn eye_batch(batch_size: usize, size: usize, device: B::Device) -> Self {
+        let value = (0..batch_size * size * size)
+            .map(|i| i % (size * size))
+            .map(|i| {
+                if i / size == i % size {
+                    B::Elem::one()
+                } else {
+                    B::Elem::zero()
+                }
+            })
+            .collect();
+        let shape = Shape::new([batch_size, size, size]);
+
+        Self::from_data_device(Data::new(value, shape), device)
+    }
 }
 
 impl<const D: usize, B> Tensor<B, D>
@@ -47,11 +93,56 @@ where
         Tensor::new(B::reshape(&self.value, shape.into()))
     }
 
+    /// Reshape the tensor to have the given dimensions, where a dimension of `-1` is inferred from
+    /// the number of elements of the tensor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 3>::zeros([2, 3, 4]);
+    ///     let reshaped = tensor.reshape_infer([2, -1]);
+    ///     assert_eq!(reshaped.shape().dims, [2, 12]);
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If more than one dimension is inferred, if a dimension is negative without being `-1`, or
+    /// if the number of elements isn't a multiple of the product of the other dimensions.
+    pub fn reshape_infer<const D2: usize>(&self, dims: [isize; D2]) -> Tensor<B, D2> {
+        let shape = infer_shape(self.shape().num_elements(), dims);
+        self.reshape(shape)
+    }
+
     /// Returns a new tensor on the given device.
+    ///
+    /// With autodiff, the transfer is recorded in the graph and the gradient is moved back to the
+    /// device of the current tensor during the backward pass.
     pub fn to_device(&self, device: B::Device) -> Self {
         Self::new(B::to_device(&self.value, device))
     }
 
+    /// Returns a copy of the tensor that doesn't share its storage with the current tensor,
+    /// unlike [clone](Clone::clone).
+    ///
+    /// The copy stays in the autodiff graph, its gradient flowing back to the current tensor.
+    pub fn deep_clone(&self) -> Self {
+        Self::new(B::deep_clone(&self.value))
+    }
+
+    /// Returns a new leaf tensor for which the gradient is only computed when `require_grad`
+    /// is true.
+    ///
+    /// The tensor is detached from the autodiff graph. This function does nothing when autodiff
+    /// is not enabled.
+    pub fn set_require_grad(self, require_grad: bool) -> Self {
+        Self::new(B::set_require_grad(self.value, require_grad))
+    }
+
     /// Returns the device of the current tensor.
     pub fn device(&self) -> B::Device {
         B::device(&self.value)
@@ -71,6 +162,15 @@ where
         Self::new(self.value.log())
     }
 
+    /// Applies the natural logarithm of one plus the input tensor, element-wise.
+    ///
+    /// `y = log(x+1)`
+    ///
+    /// It is more accurate than [log](Self::log) for small values of x.
+    pub fn log1p(&self) -> Self {
+        Self::new(self.value.log1p())
+    }
+
     /// Applies the [error function](https://en.wikipedia.org/wiki/Error_function) element wise.
     ///
     /// `y = erf(x)`
@@ -85,6 +185,15 @@ where
         Self::new(self.value.powf(value))
     }
 
+    /// Applies element wise power operation with an integer exponent.
+    ///
+    /// `y = x^n`
+    ///
+    /// Contrary to [powf](Self::powf), negative values are supported.
+    pub fn powi(&self, value: i32) -> Self {
+        Self::new(self.value.powi(value))
+    }
+
     /// Returns the shape of the current tensor.
     pub fn shape(&self) -> &Shape<D> {
         B::shape(&self.value)
@@ -107,6 +216,46 @@ where
         B::to_data(&self.value)
     }
 
+    /// Returns the single value of the tensor, such as the result of a reduction.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor doesn't have exactly one element.
+    pub fn into_scalar(self) -> B::Elem {
+        let data = self.into_data();
+
+        if data.value.len() != 1 {
+            panic!(
+                "Can't convert a tensor of shape {:?} with {} elements into a scalar",
+                data.shape.dims,
+                data.value.len()
+            );
+        }
+
+        data.value[0]
+    }
+
+    /// Returns the number of elements different from zero.
+    pub fn count_nonzero(&self) -> usize {
+        B::count_nonzero(&self.value)
+    }
+
+    /// Returns if any element of the tensor is NaN.
+    pub fn any_nan(&self) -> bool {
+        self.to_data()
+            .value
+            .iter()
+            .any(|value| value.to_f64().map(f64::is_nan).unwrap_or(false))
+    }
+
+    /// Returns if any element of the tensor is positive or negative infinity.
+    pub fn any_inf(&self) -> bool {
+        self.to_data()
+            .value
+            .iter()
+            .any(|value| value.to_f64().map(f64::is_infinite).unwrap_or(false))
+    }
+
     /// Create a tensor from the given data.
     pub fn from_data(data: Data<B::Elem, D>) -> Self {
         let tensor = B::from_data(data, B::Device::default());
@@ -119,6 +268,29 @@ where
         Tensor::new(tensor)
     }
 
+    /// Create a tensor from floats (f32), the shape is inferred from the nested arrays.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]]);
+    ///     println!("{:?}", tensor.shape());
+    ///     // Shape { dims: [2, 2] }
+    /// }
+    /// ```
+    pub fn from_floats<A: Into<Data<f32, D>>>(floats: A) -> Self {
+        Self::from_data(floats.into().convert())
+    }
+
+    /// Create a tensor from integers (i32), the shape is inferred from the nested arrays.
+    pub fn from_ints<A: Into<Data<i32, D>>>(ints: A) -> Self {
+        Self::from_data(ints.into().convert())
+    }
+
     /// Returns a new tensor with the same shape and device as the current tensor filled with zeros.
     pub fn zeros_like(&self) -> Self {
         Tensor::new(B::zeros(*self.shape(), self.device()))
@@ -175,6 +347,17 @@ where
         Self::new(B::add_scalar(&self.value, &other.to_elem()))
     }
 
+    /// Applies element wise addition operation in place.
+    ///
+    /// `x1 = x1 + x2`
+    ///
+    /// # Panics
+    ///
+    /// In debug mode, if the tensor is tracked by the autodiff graph.
+    pub fn add_assign(&mut self, other: &Self) {
+        B::add_assign(&mut self.value, &other.value);
+    }
+
     /// Applies element wise substraction operation.
     ///
     /// `y = x2 - x1`
@@ -189,6 +372,17 @@ where
         Self::new(B::sub_scalar(&self.value, &other.to_elem()))
     }
 
+    /// Applies element wise substraction operation in place.
+    ///
+    /// `x1 = x1 - x2`
+    ///
+    /// # Panics
+    ///
+    /// In debug mode, if the tensor is tracked by the autodiff graph.
+    pub fn sub_assign(&mut self, other: &Self) {
+        B::sub_assign(&mut self.value, &other.value);
+    }
+
     /// Applies the transpose operation.
     ///
     /// On matrix and higher dimension tensor, it swap the last two dimensions.
@@ -206,25 +400,490 @@ where
     ///
     /// If the dimensions exceed the shape of than the tensor.
     pub fn swap_dims(&self, dim1: usize, dim2: usize) -> Self {
+        if dim1 >= D || dim2 >= D {
+            panic!(
+                "Can't swap dimensions {} and {} of a tensor with {} dimensions",
+                dim1, dim2, D
+            );
+        }
+
         Self::new(B::swap_dims(&self.value, dim1, dim2))
     }
 
+    /// Reorder the dimensions of the tensor, the dimension `i` of the output is the dimension
+    /// `axes[i]` of the input.
+    ///
+    /// # Panics
+    ///
+    /// If the axes are not a permutation of `0..D`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 3>::ones(Shape::new([2, 3, 4]));
+    ///     let tensor = tensor.permute([2, 0, 1]);
+    ///     println!("{:?}", tensor.shape());
+    ///     // Shape { dims: [4, 2, 3] }
+    /// }
+    /// ```
+    pub fn permute(&self, axes: [usize; D]) -> Self {
+        let mut seen = [false; D];
+        for axis in axes {
+            if axis >= D || seen[axis] {
+                panic!(
+                    "Axes {:?} are not a permutation of the {} dimensions of the tensor",
+                    axes, D
+                );
+            }
+            seen[axis] = true;
+        }
+
+        Self::new(B::permute(&self.value, axes))
+    }
+
+    /// Reverse the order of the elements along the given dimensions.
+    ///
+    /// # Panics
+    ///
+    /// If a dimension is out of bounds or repeated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// /// Flip horizontally a batch of images with the shape `[batch, channels, height, width]`.
+    /// fn example<B: Backend>(images: Tensor<B, 4>) -> Tensor<B, 4> {
+    ///     images.flip(&[3])
+    /// }
+    /// ```
+    pub fn flip(&self, dims: &[usize]) -> Self {
+        let mut seen = [false; D];
+        for dim in dims {
+            if *dim >= D || seen[*dim] {
+                panic!(
+                    "Can't flip the dimensions {:?} of a tensor with {} dimensions",
+                    dims, D
+                );
+            }
+            seen[*dim] = true;
+        }
+
+        Self::new(B::flip(&self.value, dims))
+    }
+
+    /// Circularly shift the elements along the given dimensions, the elements shifted beyond the
+    /// last position being moved back at the first ones.
+    ///
+    /// A negative shift moves the elements toward the first positions, and shifts larger than
+    /// the size of their dimension wrap around it.
+    ///
+    /// # Panics
+    ///
+    /// If the shifts and dimensions don't have the same length, or if a dimension is out of
+    /// bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// /// Move the last element of each sequence to the first position.
+    /// fn example<B: Backend>(sequences: Tensor<B, 2>) -> Tensor<B, 2> {
+    ///     sequences.roll(&[1], &[1])
+    /// }
+    /// ```
+    pub fn roll(&self, shifts: &[isize], dims: &[usize]) -> Self {
+        if shifts.len() != dims.len() {
+            panic!(
+                "Can't roll with {} shifts and {} dimensions, expected the same number",
+                shifts.len(),
+                dims.len()
+            );
+        }
+
+        let mut tensor = self.clone();
+
+        for (shift, dim) in shifts.iter().zip(dims.iter()) {
+            if *dim >= D {
+                panic!(
+                    "Can't roll dimension {} of a tensor with {} dimensions",
+                    dim, D
+                );
+            }
+
+            let size = self.shape().dims[*dim];
+            if size == 0 {
+                continue;
+            }
+            let shift = shift.rem_euclid(size as isize) as usize;
+            if shift == 0 {
+                continue;
+            }
+
+            let ranges: Vec<_> = tensor.shape().dims.iter().map(|dim| 0..*dim).collect();
+            let mut ranges_end: [std::ops::Range<usize>; D] = ranges.try_into().unwrap();
+            let mut ranges_start = ranges_end.clone();
+            ranges_end[*dim] = size - shift..size;
+            ranges_start[*dim] = 0..size - shift;
+
+            tensor = Tensor::cat(
+                vec![tensor.index(ranges_end), tensor.index(ranges_start)],
+                *dim,
+            );
+        }
+
+        tensor
+    }
+
+    /// Pad each dimension with the given number of elements before and after the current ones,
+    /// all set to the given value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::ones(Shape::new([2, 3]));
+    ///     let tensor = tensor.pad([(1, 1), (0, 2)], 0.0);
+    ///     println!("{:?}", tensor.shape());
+    ///     // Shape { dims: [4, 5] }
+    /// }
+    /// ```
+    pub fn pad<E: ElementConversion>(&self, padding: [(usize, usize); D], value: E) -> Self {
+        let mut dims = self.shape().dims;
+        let mut ranges = Vec::with_capacity(D);
+
+        for (i, (before, after)) in padding.iter().enumerate() {
+            ranges.push(*before..before + dims[i]);
+            dims[i] += before + after;
+        }
+
+        let ranges: [std::ops::Range<usize>; D] = ranges.try_into().unwrap();
+        let padded = Tensor::zeros_device(Shape::new(dims), self.device()).add_scalar(value);
+
+        padded.index_assign(ranges, self)
+    }
+
+    /// Returns the `length` elements starting at `start` along a dimension, the other dimensions
+    /// being kept entirely.
+    ///
+    /// This is a simpler form of [index](Tensor::index) for a single dimension. Backends that
+    /// support it, such as ndarray, return a view sharing the data of the tensor. The gradient of
+    /// the elements outside the narrowed region is zero.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is out of bounds or if the region exceeds the size of the dimension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::ones(Shape::new([2, 5]));
+    ///     let narrowed = tensor.narrow(1, 1, 2);
+    ///     println!("{:?}", narrowed.shape());
+    ///     // Shape { dims: [2, 2] }
+    /// }
+    /// ```
+    pub fn narrow(&self, dim: usize, start: usize, length: usize) -> Self {
+        self.check_dim(dim);
+
+        let size = self.shape().dims[dim];
+        if start + length > size {
+            panic!(
+                "Can't narrow dimension {} of size {} from {} with a length of {}",
+                dim, size, start, length
+            );
+        }
+
+        self.index_dim(dim, start..start + length)
+    }
+
+    /// Split the tensor into the given number of chunks along a dimension.
+    ///
+    /// When the size of the dimension isn't divisible by the number of chunks, the first chunks
+    /// get one more element than the last ones. There are fewer chunks than requested when the
+    /// dimension is smaller than the number of chunks, since empty chunks aren't returned.
+    ///
+    /// # Panics
+    ///
+    /// If the number of chunks is zero or if the dimension is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::ones(Shape::new([7, 4]));
+    ///     let chunks = tensor.chunk(3, 0);
+    ///     for chunk in chunks {
+    ///         println!("{:?}", chunk.shape());
+    ///     }
+    ///     // Shape { dims: [3, 4] }
+    ///     // Shape { dims: [2, 4] }
+    ///     // Shape { dims: [2, 4] }
+    /// }
+    /// ```
+    pub fn chunk(&self, chunks: usize, dim: usize) -> Vec<Self> {
+        if chunks == 0 {
+            panic!("Can't split a tensor into zero chunks");
+        }
+        self.check_dim(dim);
+
+        let size = self.shape().dims[dim];
+        let chunk_size = size / chunks;
+        let remainder = size % chunks;
+
+        let mut tensors = Vec::with_capacity(chunks);
+        let mut start = 0;
+
+        for i in 0..usize::min(chunks, size) {
+            let end = match i < remainder {
+                true => start + chunk_size + 1,
+                false => start + chunk_size,
+            };
+            tensors.push(self.index_dim(dim, start..end));
+            start = end;
+        }
+
+        tensors
+    }
+
+    /// Iterate over the slices of size one along a dimension, each keeping that dimension.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// /// Sum the items of a sequence with shape `[batch_size, seq_length, d_model]`.
+    /// fn example<B: Backend>(sequence: Tensor<B, 3>) -> Tensor<B, 3> {
+    ///     sequence
+    ///         .iter_dim(1)
+    ///         .reduce(|acc, item| acc.add(&item))
+    ///         .unwrap()
+    /// }
+    /// ```
+    pub fn iter_dim(&self, dim: usize) -> impl Iterator<Item = Self> {
+        self.check_dim(dim);
+
+        let tensor = self.clone();
+        let size = self.shape().dims[dim];
+
+        (0..size).map(move |i| tensor.index_dim(dim, i..i + 1))
+    }
+
+    fn index_dim(&self, dim: usize, range: Range<usize>) -> Self {
+        let ranges: Vec<_> = self.shape().dims.iter().map(|dim| 0..*dim).collect();
+        let mut ranges: [Range<usize>; D] = ranges.try_into().unwrap();
+        ranges[dim] = range;
+
+        self.index(ranges)
+    }
+
+    fn check_dim(&self, dim: usize) {
+        if dim >= D {
+            panic!(
+                "Can't use dimension {} of a tensor with {} dimensions",
+                dim, D
+            );
+        }
+    }
+
     /// Applies the matrix multiplication operation.
     ///
     /// `C = AB`
     ///
+    /// The last two dimensions are the matrices, while the leading ones are batch dimensions that
+    /// are broadcasted when one of the two tensors has a size of 1.
+    ///
     /// # Panics
     ///
     /// If the two tensors dont' have a compatible shape.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let lhs = Tensor::<B, 3>::ones(Shape::new([2, 3, 4]));
+    ///     let rhs = Tensor::<B, 3>::ones(Shape::new([1, 4, 5]));
+    ///     let output = lhs.matmul(&rhs);
+    ///     println!("{:?}", output.shape());
+    ///     // Shape { dims: [2, 3, 5] }
+    /// }
+    /// ```
     pub fn matmul(&self, other: &Self) -> Self {
+        let shape_lhs = self.shape();
+        let shape_rhs = other.shape();
+
+        if D >= 2 {
+            if shape_lhs.dims[D - 1] != shape_rhs.dims[D - 2] {
+                panic!(
+                    "Incompatible inner dimensions for matmul, got shapes {:?} and {:?}",
+                    shape_lhs.dims, shape_rhs.dims
+                );
+            }
+
+            for i in 0..D - 2 {
+                let (lhs, rhs) = (shape_lhs.dims[i], shape_rhs.dims[i]);
+                if lhs != rhs && lhs != 1 && rhs != 1 {
+                    panic!(
+                        "Can't broadcast batch dimension {} for matmul, got shapes {:?} and {:?}",
+                        i, shape_lhs.dims, shape_rhs.dims
+                    );
+                }
+            }
+        }
+
         Self::new(B::matmul(&self.value, &other.value))
     }
 
     /// Switch sign of each element in the tensor.
     ///
-    /// `y = -x`
-    pub fn neg(&self) -> Self {
-        Self::new(B::neg(&self.value))
+    /// `y = -x`
+    pub fn neg(&self) -> Self {
+        Self::new(B::neg(&self.value))
+    }
+
+    /// Applies element wise absolute value operation.
+    ///
+    /// `y = |x|`
+    pub fn abs(&self) -> Self {
+        Self::new(B::abs(&self.value))
+    }
+
+    /// Applies element wise sine operation.
+    ///
+    /// `y = sin(x)`
+    pub fn sin(&self) -> Self {
+        Self::new(B::sin(&self.value))
+    }
+
+    /// Applies element wise cosine operation.
+    ///
+    /// `y = cos(x)`
+    pub fn cos(&self) -> Self {
+        Self::new(B::cos(&self.value))
+    }
+
+    /// Applies element wise tangent operation.
+    ///
+    /// `y = tan(x)`
+    pub fn tan(&self) -> Self {
+        Self::new(B::tan(&self.value))
+    }
+
+    /// Applies element wise reciprocal operation, the reciprocal of zero being infinite.
+    ///
+    /// `y = 1 / x`
+    pub fn reciprocal(&self) -> Self {
+        Self::new(B::reciprocal(&self.value))
+    }
+
+    /// Applies element wise reciprocal square root operation, faster than dividing by the
+    /// square root, e.g. to normalize by the standard deviation.
+    ///
+    /// `y = 1 / sqrt(x)`
+    pub fn rsqrt(&self) -> Self {
+        Self::new(B::rsqrt(&self.value))
+    }
+
+    /// Applies element wise sign operation, with a sign of zero for zero.
+    ///
+    /// `y = sign(x)`
+    ///
+    /// # Notes
+    ///
+    /// The gradient is zero.
+    pub fn sign(&self) -> Self {
+        Self::new(B::sign(&self.value))
+    }
+
+    /// Applies element wise [sign](Tensor::sign) operation with a straight-through estimator,
+    /// where the gradient flows through the operation unchanged, as if it was the identity.
+    pub fn sign_ste(&self) -> Self {
+        self.straight_through(self.sign())
+    }
+
+    /// The output in the forward pass, with the gradient of the identity in the backward pass.
+    fn straight_through(&self, output: Self) -> Self {
+        self.sub(&self.clone().detach()).add(&output.detach())
+    }
+
+    /// Applies element wise rounding to the nearest integer, the halfway values being rounded to
+    /// the nearest even integer.
+    ///
+    /// `y = round(x)`
+    ///
+    /// # Notes
+    ///
+    /// The gradient is zero.
+    pub fn round(&self) -> Self {
+        Self::new(B::round(&self.value))
+    }
+
+    /// Applies element wise [rounding](Tensor::round) with a straight-through estimator, where
+    /// the gradient flows through the operation unchanged, as if it was the identity.
+    ///
+    /// Useful for quantization-aware training, where the gradient of rounding would otherwise be
+    /// zero.
+    pub fn round_ste(&self) -> Self {
+        self.straight_through(self.round())
+    }
+
+    /// Applies element wise rounding to the largest integer less than or equal to the value.
+    ///
+    /// `y = floor(x)`
+    ///
+    /// # Notes
+    ///
+    /// The gradient is zero.
+    pub fn floor(&self) -> Self {
+        Self::new(B::floor(&self.value))
+    }
+
+    /// Applies element wise rounding to the smallest integer greater than or equal to the value.
+    ///
+    /// `y = ceil(x)`
+    ///
+    /// # Notes
+    ///
+    /// The gradient is zero.
+    pub fn ceil(&self) -> Self {
+        Self::new(B::ceil(&self.value))
+    }
+
+    /// Applies element wise rounding toward zero, keeping the integer part of the value.
+    ///
+    /// `y = trunc(x)`
+    ///
+    /// # Notes
+    ///
+    /// The gradient is zero.
+    pub fn trunc(&self) -> Self {
+        Self::new(B::trunc(&self.value))
     }
 
     /// Applies element wise multiplication operation.
@@ -234,6 +893,17 @@ where
         Self::new(B::mul(&self.value, &other.value))
     }
 
+    /// Applies element wise multiplication operation in place.
+    ///
+    /// `x1 = x1 * x2`
+    ///
+    /// # Panics
+    ///
+    /// In debug mode, if the tensor is tracked by the autodiff graph.
+    pub fn mul_assign(&mut self, other: &Self) {
+        B::mul_assign(&mut self.value, &other.value);
+    }
+
     /// Applies element wise multiplication operation with scalar.
     ///
     /// `y = x2 * x1`
@@ -241,6 +911,32 @@ where
         Self::new(B::mul_scalar(&self.value, &other.to_elem()))
     }
 
+    /// Applies element wise maximum operation, selecting the larger value at each position.
+    ///
+    /// The gradient flows to the selected value, and is split equally between both values when
+    /// they are equal.
+    pub fn maximum(&self, other: &Self) -> Self {
+        Self::new(B::maximum(&self.value, &other.value))
+    }
+
+    /// Applies element wise maximum operation with scalar, which clamps the tensor from below.
+    pub fn maximum_scalar<E: ElementConversion>(&self, other: E) -> Self {
+        Self::new(B::maximum_scalar(&self.value, &other.to_elem()))
+    }
+
+    /// Applies element wise minimum operation, selecting the smaller value at each position.
+    ///
+    /// The gradient flows to the selected value, and is split equally between both values when
+    /// they are equal.
+    pub fn minimum(&self, other: &Self) -> Self {
+        Self::new(B::minimum(&self.value, &other.value))
+    }
+
+    /// Applies element wise minimum operation with scalar, which clamps the tensor from above.
+    pub fn minimum_scalar<E: ElementConversion>(&self, other: E) -> Self {
+        Self::new(B::minimum_scalar(&self.value, &other.to_elem()))
+    }
+
     /// Applies element wise division operation.
     ///
     /// `y = x2 / x1`
@@ -248,6 +944,20 @@ where
         Self::new(B::div(&self.value, &other.value))
     }
 
+    /// Applies element wise division operation, adding epsilon to the denominator to avoid
+    /// dividing by zero.
+    ///
+    /// `y = x2 / (x1 + epsilon)`
+    ///
+    /// # Notes
+    ///
+    /// Epsilon is a constant, so the gradients are those of the division by `x1 + epsilon`. The
+    /// denominator should be positive, like a variance or a norm, since a denominator equal to
+    /// `-epsilon` still divides by zero.
+    pub fn div_safe<E: ElementConversion>(&self, other: &Self, epsilon: E) -> Self {
+        self.div(&other.add_scalar(epsilon))
+    }
+
     /// Applies element wise division operation with scalar.
     ///
     /// `y = x2 / x1`
@@ -265,6 +975,24 @@ where
         Tensor::new(self.value.sum())
     }
 
+    /// Aggregate all elements in the tensor with the mean operation, accumulating in full
+    /// precision.
+    ///
+    /// See [sum_precise](Tensor::sum_precise).
+    pub fn mean_precise(&self) -> Tensor<B, 1> {
+        Tensor::from_full_precision(self.to_full_precision().mean())
+    }
+
+    /// Aggregate all elements in the tensor with the sum operation, accumulating in full
+    /// precision.
+    ///
+    /// The sum is computed on the [full precision backend](Backend::FullPrecisionBackend) and
+    /// cast back, so that summing many half precision values doesn't accumulate their rounding
+    /// errors. It is the same as [sum](Tensor::sum) for full precision tensors.
+    pub fn sum_precise(&self) -> Tensor<B, 1> {
+        Tensor::from_full_precision(self.to_full_precision().sum())
+    }
+
     /// Aggregate all elements along the given *dimension* or *axis* in the tensor with the mean operation.
     pub fn mean_dim(&self, dim: usize) -> Self {
         Self::new(self.value.mean_dim(dim))
@@ -275,6 +1003,70 @@ where
         Self::new(self.value.sum_dim(dim))
     }
 
+    /// Aggregate all elements in the tensor with the product operation.
+    pub fn prod(&self) -> Tensor<B, 1> {
+        Tensor::new(self.value.prod())
+    }
+
+    /// Aggregate all elements along the given *dimension* or *axis* in the tensor with the product operation.
+    pub fn prod_dim(&self, dim: usize) -> Self {
+        Self::new(self.value.prod_dim(dim))
+    }
+
+    /// Aggregate all elements along the given *dimension* or *axis* in the tensor with the mean
+    /// operation, removing the reduced dimension instead of keeping it with a size of 1.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is out of bounds or if `D2` isn't `D - 1`.
+    pub fn mean_dim_squeeze<const D2: usize>(&self, dim: usize) -> Tensor<B, D2> {
+        check_squeeze::<D, D2>(dim);
+        let shape = self.shape().remove_dim(dim);
+
+        self.mean_dim(dim).reshape(shape)
+    }
+
+    /// Aggregate all elements along the given *dimension* or *axis* in the tensor with the sum
+    /// operation, removing the reduced dimension instead of keeping it with a size of 1.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is out of bounds or if `D2` isn't `D - 1`.
+    pub fn sum_dim_squeeze<const D2: usize>(&self, dim: usize) -> Tensor<B, D2> {
+        check_squeeze::<D, D2>(dim);
+        let shape = self.shape().remove_dim(dim);
+
+        self.sum_dim(dim).reshape(shape)
+    }
+
+    /// Aggregate all elements along the given *dimensions* or *axes* in the tensor with the mean
+    /// operation, the reduced dimensions are kept with a size of 1.
+    ///
+    /// # Panics
+    ///
+    /// If a dimension is out of bounds.
+    pub fn mean_dims(&self, dims: &[usize]) -> Self {
+        for dim in dims {
+            check_dim::<D>(*dim);
+        }
+
+        Self::new(self.value.mean_dims(dims))
+    }
+
+    /// Aggregate all elements along the given *dimensions* or *axes* in the tensor with the sum
+    /// operation, the reduced dimensions are kept with a size of 1.
+    ///
+    /// # Panics
+    ///
+    /// If a dimension is out of bounds.
+    pub fn sum_dims(&self, dims: &[usize]) -> Self {
+        for dim in dims {
+            check_dim::<D>(*dim);
+        }
+
+        Self::new(self.value.sum_dims(dims))
+    }
+
     /// Calculate the variance along the given dimension.
     pub fn var(&self, dim: usize) -> Self {
         stats::var(self, dim)
@@ -299,6 +1091,35 @@ where
         (var, mean)
     }
 
+    /// Calculate the median of all elements, which is the mean of the two middle elements when
+    /// their number is even.
+    ///
+    /// See [quantile](Tensor::quantile).
+    pub fn median(&self) -> Tensor<B, 1> {
+        let num_elements = self.shape().num_elements();
+        self.reshape([num_elements]).quantile(0.5, 0)
+    }
+
+    /// Calculate the quantile `q` of the elements along the given dimension, the reduced
+    /// dimension is kept with a size of 1.
+    ///
+    /// When the position of the quantile falls between two elements, their values are linearly
+    /// interpolated. The elements are sorted on the host to find their ranks, and the gradients
+    /// flow only to the elements at the position of the quantile. Infinite values outside this
+    /// position result in NaN, like with [select_max](Tensor::select_max).
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is out of bounds or empty, or if `q` isn't between 0 and 1.
+    pub fn quantile(&self, q: f64, dim: usize) -> Self {
+        check_dim::<D>(dim);
+        if !(0.0..=1.0).contains(&q) {
+            panic!("The quantile {} must be between 0 and 1", q);
+        }
+
+        stats::quantile(self, q, dim)
+    }
+
     /// Applies element wise equal comparison and returns a boolean tensor.
     ///
     /// # Panics
@@ -308,6 +1129,21 @@ where
         BoolTensor::new(self.value.equal(&other.value))
     }
 
+    /// Applies element wise equal comparison within a tolerance and returns a boolean tensor.
+    ///
+    /// `y = |x1 - x2| <= tolerance`
+    ///
+    /// # Panics
+    ///
+    /// If the two tensors don't have the same shape.
+    pub fn equal_approx<E: ElementConversion>(
+        &self,
+        other: &Self,
+        tolerance: E,
+    ) -> BoolTensor<B, D> {
+        self.sub(other).abs().lower_equal_scalar(tolerance)
+    }
+
     /// Applies element wise greater comparison and returns a boolean tensor.
     ///
     /// # Panics
@@ -376,13 +1212,24 @@ where
         Self::new(tensor)
     }
 
+    /// Create a random tensor of the given shape on the given device where each element is
+    /// sampled from the given distribution.
+    pub fn random_device<S: Into<Shape<D>>>(
+        shape: S,
+        distribution: Distribution<B::Elem>,
+        device: B::Device,
+    ) -> Self {
+        let tensor = B::random(shape.into(), distribution, device);
+        Self::new(tensor)
+    }
+
     /// Create a tensor of the given shape where each element is zero.
     pub fn zeros<S: Into<Shape<D>>>(shape: S) -> Self {
         let tensor = B::zeros(shape.into(), B::Device::default());
         Self::new(tensor)
     }
 
-    /// Create a tensor of the given shape where each element is zero.
+    /// Create a tensor of the given shape on the given device where each element is zero.
     pub fn zeros_device<S: Into<Shape<D>>>(shape: S, device: B::Device) -> Self {
         let tensor = B::zeros(shape.into(), device);
         Self::new(tensor)
@@ -394,6 +1241,12 @@ where
         Self::new(tensor)
     }
 
+    /// Create a tensor of the given shape on the given device where each element is one.
+    pub fn ones_device<S: Into<Shape<D>>>(shape: S, device: B::Device) -> Self {
+        let tensor = B::ones(shape.into(), device);
+        Self::new(tensor)
+    }
+
     /// Returns a tensor containing the elements selected from the given ranges.
     ///
     /// # Panics
@@ -420,10 +1273,13 @@ where
     /// Returns a copy of the current tensor with the selected elements changed to the new ones at
     /// the selected indexes.
     ///
+    /// The values are broadcasted on the dimensions where they have a size of 1, for instance a
+    /// single row can be assigned to multiple rows.
+    ///
     /// # Panics
     ///
     /// - If a range exceeds the number of elements on a dimension.
-    /// - If the given values don't match the given ranges.
+    /// - If the given values can't be broadcasted to the given ranges.
     ///
     /// # Example
     ///
@@ -444,14 +1300,209 @@ where
         indexes: [std::ops::Range<usize>; D2],
         values: &Self,
     ) -> Self {
+        let shape_indexed = self.shape().index(indexes.clone());
+        let shape_values = values.shape();
+
+        if !shape_values.is_broadcastable_to(&shape_indexed) {
+            panic!(
+                "Can't assign values of shape {:?} to the indexed shape {:?}",
+                shape_values.dims, shape_indexed.dims
+            );
+        }
+
         Self::new(B::index_assign(&self.value, indexes, &values.value))
     }
 
+    /// Select the elements of the given dimension at the given indexes.
+    ///
+    /// Indexes can be repeated, in which case the gradients of the selected elements are
+    /// accumulated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Data, Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::ones(Shape::new([5, 4]));
+    ///     let indexes = Tensor::<B::IntegerBackend, 1>::from_data(Data::from([2, 0, 2]));
+    ///     let tensor = tensor.index_select(0, &indexes);
+    ///     println!("{:?}", tensor.shape());
+    ///     // Shape { dims: [3, 4] }
+    /// }
+    /// ```
+    pub fn index_select(&self, dim: usize, indexes: &Tensor<B::IntegerBackend, 1>) -> Self {
+        Self::new(B::index_select(&self.value, dim, &indexes.value))
+    }
+
     /// Fill each element with the given value based on the given mask.
+    ///
+    /// The gradient is zero at the masked positions, since they were replaced by a constant, and
+    /// flows through unchanged everywhere else.
     pub fn mask_fill<E: ElementConversion>(&self, mask: &BoolTensor<B, D>, value: E) -> Self {
         Self::new(B::mask_fill(&self.value, &mask.value, value.to_elem()))
     }
 
+    /// Keep the elements on and below the given diagonal of the last two dimensions, setting the
+    /// other elements to zero.
+    ///
+    /// The diagonal `0` is the main diagonal, positive diagonals are above it and negative ones
+    /// below it. The gradient flows only to the kept elements.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor has less than 2 dimensions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     // The mask of a causal attention, where each position only attends to itself and to
+    ///     // the previous ones.
+    ///     let mask = Tensor::<B, 2>::ones(Shape::new([4, 4])).tril(0);
+    ///     println!("{:?}", mask.to_data());
+    /// }
+    /// ```
+    pub fn tril(&self, diagonal: isize) -> Self {
+        let mask = self.matrix_mask("take the triangular part of", |row, col| {
+            col - row > diagonal
+        });
+        self.mask_fill(&mask, 0.0)
+    }
+
+    /// Keep the elements on and above the given diagonal of the last two dimensions, setting the
+    /// other elements to zero.
+    ///
+    /// See [tril](Tensor::tril).
+    ///
+    /// # Panics
+    ///
+    /// If the tensor has less than 2 dimensions.
+    pub fn triu(&self, diagonal: isize) -> Self {
+        let mask = self.matrix_mask("take the triangular part of", |row, col| {
+            col - row < diagonal
+        });
+        self.mask_fill(&mask, 0.0)
+    }
+
+    /// Extract the main diagonal of the last two dimensions, which are replaced by a single
+    /// dimension with the size of the diagonal.
+    ///
+    /// The gradient flows back to the diagonal positions only.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor has less than 2 dimensions or if `D2` isn't `D - 1`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 3>::ones(Shape::new([2, 3, 4]));
+    ///     let diagonal = tensor.diagonal::<2>();
+    ///     println!("{:?}", diagonal.shape());
+    ///     // Shape { dims: [2, 3] }
+    /// }
+    /// ```
+    pub fn diagonal<const D2: usize>(&self) -> Tensor<B, D2> {
+        let mask = self.matrix_mask("take the diagonal of", |row, col| row != col);
+        if D2 + 1 != D {
+            panic!(
+                "Can't take the diagonal of a tensor with {} dimensions into {} dimensions, expected {}",
+                D,
+                D2,
+                D - 1
+            );
+        }
+
+        let shape = *self.shape();
+        let size = usize::min(shape.dims[D - 2], shape.dims[D - 1]);
+        let mut dims = [0; D2];
+        dims.copy_from_slice(&shape.dims[..D2]);
+        dims[D2 - 1] = size;
+
+        // Each row only has its diagonal element left, if any, which is kept by the sum.
+        self.mask_fill(&mask, 0.0)
+            .sum_dim(D - 1)
+            .narrow(D - 2, 0, size)
+            .reshape(dims)
+    }
+
+    /// Create diagonal matrices from the last dimension, which is replaced by two dimensions of
+    /// the same size with the values on their main diagonal and zeros elsewhere.
+    ///
+    /// # Panics
+    ///
+    /// If `D2` isn't `D + 1`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 2>::ones(Shape::new([2, 3]));
+    ///     let matrices = tensor.diag_embed::<3>();
+    ///     println!("{:?}", matrices.shape());
+    ///     // Shape { dims: [2, 3, 3] }
+    /// }
+    /// ```
+    pub fn diag_embed<const D2: usize>(&self) -> Tensor<B, D2> {
+        if D2 != D + 1 {
+            panic!(
+                "Can't embed the diagonal of a tensor with {} dimensions into {} dimensions, expected {}",
+                D,
+                D2,
+                D + 1
+            );
+        }
+
+        let shape = *self.shape();
+        let size = shape.dims[D - 1];
+        let mut dims = [1; D2];
+        dims[..D - 1].copy_from_slice(&shape.dims[..D - 1]);
+        dims[D2 - 1] = size;
+        let rows: Tensor<B, D2> = self.reshape(dims);
+
+        dims[D2 - 2] = size;
+        let matrices = rows.expand(dims);
+        let mask = matrices.matrix_mask("embed the diagonal of", |row, col| row != col);
+
+        matrices.mask_fill(&mask, 0.0)
+    }
+
+    /// The mask of the elements whose row and column in the last two dimensions satisfy the
+    /// given condition.
+    fn matrix_mask<F: Fn(isize, isize) -> bool>(
+        &self,
+        operation: &str,
+        masked: F,
+    ) -> BoolTensor<B, D> {
+        if D < 2 {
+            panic!(
+                "Can't {} a tensor with {} dimensions, at least 2 are required",
+                operation, D
+            );
+        }
+
+        let shape = *self.shape();
+        let rows = shape.dims[D - 2];
+        let cols = shape.dims[D - 1];
+        let value = (0..shape.num_elements())
+            .map(|i| masked(((i / cols) % rows) as isize, (i % cols) as isize))
+            .collect();
+
+        BoolTensor::new(B::from_data_bool(Data::new(value, shape), self.device()))
+    }
+
     /// Returns a tensor with full precision based on the selected backend.
     pub fn to_full_precision(&self) -> Tensor<B::FullPrecisionBackend, D> {
         Tensor::new(self.value.to_full_precision())
@@ -465,6 +1516,9 @@ where
 
     /// Applies the argmax function along the given dimension and returns an integer tensor.
     ///
+    /// The indexes aren't differentiable, so the output isn't part of the autodiff graph. Use
+    /// [select_max](Tensor::select_max) to get the maximum values with their gradients.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -484,6 +1538,8 @@ where
 
     /// Applies the argmin function along the given dimension and returns an integer tensor.
     ///
+    /// The indexes aren't differentiable, so the output isn't part of the autodiff graph.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -501,6 +1557,40 @@ where
         Tensor::new(self.value.argmin(dim))
     }
 
+    /// Select the maximum values along the given dimension.
+    ///
+    /// The positions are found with [argmax](Tensor::argmax), and the values are gathered from
+    /// the current tensor, so the gradients flow only to the selected positions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let tensor = Tensor::<B, 3>::ones(Shape::new([2, 3, 3]));
+    ///     let tensor = tensor.select_max(1);
+    ///     println!("{:?}", tensor.shape());
+    ///     // Shape { dims: [2, 1, 3] }
+    /// }
+    /// ```
+    pub fn select_max(&self, dim: usize) -> Self {
+        let shape = *self.shape();
+        let size_dim = shape.dims[dim];
+        let size_outer: usize = shape.dims[..dim].iter().product();
+        let size_inner: usize = shape.dims[dim + 1..].iter().product();
+
+        let indexes = self.argmax(dim).reshape([size_outer * size_inner]);
+        let mask = Tensor::<B, 2>::eye(size_dim, self.device())
+            .index_select(0, &indexes)
+            .reshape([size_outer, size_inner, size_dim])
+            .swap_dims(1, 2)
+            .reshape(shape);
+
+        self.mul(&mask).sum_dim(dim)
+    }
+
     /// Concatenates all tensors into a new one along the given dimension.
     ///
     /// # Panics
@@ -518,6 +1608,27 @@ where
     /// This function does nothing when autodiff is not enabled.
     /// This can be used in batchers or elsewere to ensure that previous operations are not
     /// considered in the autodiff graph.
+    ///
+    /// The returned tensor has the same values as the current one, but is a new leaf of the
+    /// graph: no gradient flows through it back to the operations that created the current
+    /// tensor. This is useful to compute targets with the model being trained, e.g. target
+    /// networks or teacher forcing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::ADBackend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: ADBackend>(x: Tensor<B, 1>) {
+    ///     let target = x.mul_scalar(2.0).detach();
+    ///     let loss = x.sub(&target).powf(2.0).sum();
+    ///     let grads = loss.backward();
+    ///
+    ///     // The gradient of `x` only comes from the first term of the subtraction.
+    ///     println!("{:?}", x.grad(&grads));
+    /// }
+    /// ```
     pub fn detach(self) -> Self {
         Self::new(self.value.detach())
     }
@@ -559,11 +1670,34 @@ where
         self.reshape(shape)
     }
 
-    /// Repeat the tensor along the given dimension.
+    /// Broadcast the tensor to the given shape, the dimensions of size 1 being expanded to the
+    /// size of the same dimension in the shape.
+    ///
+    /// Backends that support it, such as tch, don't copy the data. The gradient is summed over
+    /// the expanded dimensions.
     ///
     /// # Panics
     ///
-    /// If the selected dimension more than one item.
+    /// If a dimension that doesn't have a size of 1 differs from the shape.
+    pub fn expand<S: Into<Shape<D>>>(&self, shape: S) -> Self {
+        let shape = shape.into();
+
+        if !self.shape().is_broadcastable_to(&shape) {
+            panic!(
+                "Can't expand the shape {:?} to {:?}, only dimensions of size 1 can be expanded",
+                self.shape().dims,
+                shape.dims
+            );
+        }
+
+        Self::new(B::expand(&self.value, shape))
+    }
+
+    /// Repeat the tensor along the given dimension.
+    ///
+    /// The tensor is tiled `times` along the dimension, like NumPy `tile`, so the size of the
+    /// dimension is multiplied by `times`. The gradient of each element is the sum of the
+    /// gradients of its copies.
     pub fn repeat(&self, dim: usize, times: usize) -> Self {
         Self::new(B::repeat(&self.value, dim, times))
     }
@@ -571,6 +1705,20 @@ where
     pub(crate) fn relu(&self) -> Self {
         Self::new(self.value.relu())
     }
+
+    pub(crate) fn sigmoid(&self) -> Self {
+        Self::new(B::sigmoid(&self.value))
+    }
+}
+
+/// Format the tensor like its [data](Data), with nested brackets and aligned columns.
+impl<const D: usize, B> std::fmt::Display for Tensor<B, D>
+where
+    B: Backend,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_data())
+    }
 }
 
 impl<const D: usize, B> std::ops::Add<Self> for Tensor<B, D>
@@ -670,10 +1818,48 @@ impl<const D: usize, B: ADBackend> Tensor<B, D> {
         B::backward::<D>(&self.value)
     }
 
+    /// Compute the gradients of the tensors with the given [node ids](Tensor::node_id) only,
+    /// which can have any number of dimensions.
+    ///
+    /// The gradients of the given tensors are the same as with [backward](Tensor::backward), but
+    /// the operations that don't lead to any of them are skipped, and the gradients of the other
+    /// tensors aren't registered.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::ADBackend;
+    /// use burn_tensor::{Gradients, Tensor};
+    ///
+    /// fn grads_of_linear<B: ADBackend>(
+    ///     x: &Tensor<B, 2>,
+    ///     weight: &Tensor<B, 2>,
+    ///     bias: &Tensor<B, 1>,
+    /// ) -> Gradients {
+    ///     let output = x.matmul(weight).add(&bias.clone().unsqueeze());
+    ///     output.backward_for(&[weight.node_id(), bias.node_id()])
+    /// }
+    /// ```
+    pub fn backward_for(&self, ids: &[String]) -> Gradients {
+        B::backward_for::<D>(&self.value, ids)
+    }
+
+    /// The id of the node of the tensor in the autodiff graph, used to request its gradient with
+    /// [backward_for](Tensor::backward_for).
+    pub fn node_id(&self) -> String {
+        B::node_id(&self.value)
+    }
+
     pub fn grad(&self, grads: &Gradients) -> Option<Tensor<B::InnerBackend, D>> {
         B::grad(&self.value, grads).map(Tensor::new)
     }
 
+    /// Replace the gradient of the tensor in the given [gradients](Gradients), registering it if
+    /// the tensor had none.
+    pub fn grad_replace(&self, grads: &mut Gradients, grad: Tensor<B::InnerBackend, D>) {
+        B::grad_replace(&self.value, grads, grad.value);
+    }
+
     pub fn inner(&self) -> Tensor<B::InnerBackend, D> {
         Tensor::new(B::inner(&self.value))
     }
@@ -686,3 +1872,62 @@ impl<const D: usize, B: ADBackend> Tensor<B, D> {
         Self::new(B::from_inner(inner.value))
     }
 }
+
+/// Panics if the dimension to reduce is out of bounds.
+pub(crate) fn check_dim<const D: usize>(dim: usize) {
+    if dim >= D {
+        panic!(
+            "Can't reduce dimension {} of a tensor with {} dimensions",
+            dim, D
+        );
+    }
+}
+
+fn check_squeeze<const D: usize, const D2: usize>(dim: usize) {
+    check_dim::<D>(dim);
+    if D2 + 1 != D {
+        panic!(
+            "Can't squeeze a tensor with {} dimensions into {} dimensions, expected {}",
+            D,
+            D2,
+            D - 1
+        );
+    }
+}
+
+fn infer_shape<const D: usize>(num_elements: usize, dims: [isize; D]) -> Shape<D> {
+    let mut inferred = None;
+    let mut shape = [0; D];
+    let mut num_elements_known = 1;
+
+    for (i, dim) in dims.iter().enumerate() {
+        match *dim {
+            -1 => {
+                if inferred.is_some() {
+                    panic!(
+                        "Can't infer more than one dimension of the shape {:?}",
+                        dims
+                    );
+                }
+                inferred = Some(i);
+            }
+            dim if dim < 0 => panic!("Invalid dimension {} in the shape {:?}", dim, dims),
+            dim => {
+                shape[i] = dim as usize;
+                num_elements_known *= dim as usize;
+            }
+        }
+    }
+
+    if let Some(i) = inferred {
+        if num_elements_known == 0 || !num_elements.is_multiple_of(num_elements_known) {
+            panic!(
+                "Can't reshape a tensor of {} elements to the shape {:?}",
+                num_elements, dims
+            );
+        }
+        shape[i] = num_elements / num_elements_known;
+    }
+
+    Shape::new(shape)
+}
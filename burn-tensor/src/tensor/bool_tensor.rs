@@ -1,3 +1,4 @@
+use super::base::check_dim;
 use super::Tensor;
 use crate::tensor::backend::Backend;
 use crate::tensor::{Data, Shape};
@@ -31,6 +32,44 @@ where
         Self::new(value)
     }
 
+    /// Returns true if all the elements are true.
+    pub fn all(&self) -> bool {
+        B::bool_all(&self.value)
+    }
+
+    /// Returns true if any element is true.
+    pub fn any(&self) -> bool {
+        B::bool_any(&self.value)
+    }
+
+    /// Returns if all the elements along the given dimension are true, the dimension being kept
+    /// with a size of 1.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is out of bounds.
+    pub fn all_dim(&self, dim: usize) -> Self {
+        check_dim::<D>(dim);
+        Self::new(B::bool_all_dim(&self.value, dim))
+    }
+
+    /// Returns if any element along the given dimension is true, the dimension being kept with a
+    /// size of 1.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is out of bounds.
+    pub fn any_dim(&self, dim: usize) -> Self {
+        check_dim::<D>(dim);
+        Self::new(B::bool_any_dim(&self.value, dim))
+    }
+
+    /// Cast the tensor into a float tensor of the same shape, with 1 where the value is true and 0
+    /// elsewhere.
+    pub fn to_float(&self) -> Tensor<B, D> {
+        Tensor::new(B::bool_to_float(&self.value))
+    }
+
     pub fn to_int(&self) -> Tensor<B::IntegerBackend, D> {
         let data = B::bool_to_data(&self.value);
         Tensor::from_data(data.convert())
@@ -0,0 +1,51 @@
+use crate::tensor::backend::ADBackend;
+use crate::tensor::Tensor;
+use std::sync::Arc;
+
+/// Apply the function on the input without keeping its intermediate values in the autodiff graph,
+/// recomputing them during the backward pass instead.
+///
+/// Only the input, the output and the leaves used by the function, like the parameters of a
+/// module, are kept until the backward pass. The gradients are the same as when calling the
+/// function directly, including those of the parameters it uses.
+///
+/// # Memory and compute
+///
+/// The function is executed twice, once during the forward pass and once when the gradients
+/// flow through it, so training is slower by about the cost of one more forward pass of the
+/// checkpointed computations. In exchange, the memory of their intermediate values is only used
+/// while they are executed, instead of being kept from the forward pass until the end of the
+/// backward pass. Wrapping each block of a deep model reduces the memory of its activations to
+/// the outputs of the blocks and the intermediate values of a single block.
+///
+/// # Notes
+///
+/// The function must be deterministic, since random operations like dropout would compute
+/// different values when executed again. The tensors it uses that aren't leaves of the graph
+/// are differentiated through their own operations when it is executed again.
+///
+/// When the operations aren't recorded, see [no_grad](crate::no_grad), the function is only
+/// executed once.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::ADBackend;
+/// use burn_tensor::{activation, checkpoint, Tensor};
+///
+/// fn block<B: ADBackend>(x: &Tensor<B, 2>, weights: Tensor<B, 2>) -> Tensor<B, 2> {
+///     checkpoint(move |x| activation::relu(&x.matmul(&weights)).matmul(&weights), x)
+/// }
+/// ```
+pub fn checkpoint<B, F, const D: usize, const D2: usize>(
+    func: F,
+    input: &Tensor<B, D>,
+) -> Tensor<B, D2>
+where
+    B: ADBackend,
+    F: Fn(Tensor<B, D>) -> Tensor<B, D2> + Send + Sync + 'static,
+{
+    let func = move |tensor: B::TensorPrimitive<D>| func(Tensor::new(tensor)).value;
+
+    Tensor::new(B::checkpoint(&input.value, Arc::new(func)))
+}
@@ -1,8 +1,9 @@
 use super::ops::{Ones, Zeros};
 use crate::{tensor::Shape, Element, ElementConversion};
+use half::f16;
 use rand::{distributions::Standard, prelude::StdRng, Rng};
 
-#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Eq)]
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct DataSerialize<P> {
     pub value: Vec<P>,
     pub shape: Vec<usize>,
@@ -19,6 +20,8 @@ pub enum Distribution<P> {
     Standard,
     Bernoulli(f64),
     Uniform(P, P),
+    /// Normal distribution with the given mean and standard deviation.
+    Normal(f64, f64),
 }
 
 #[derive(new)]
@@ -39,6 +42,7 @@ where
     Standard(rand::distributions::Standard),
     Uniform(rand::distributions::Uniform<P>),
     Bernoulli(rand::distributions::Bernoulli),
+    Normal(f64, f64),
 }
 
 impl<'a, P> DistributionSampler<'a, P>
@@ -58,6 +62,14 @@ where
                     P::zeros(&P::default())
                 }
             }
+            DistributionSamplerKind::Normal(mean, std) => {
+                // Box-Muller transform, the first sample is in (0, 1] to avoid ln(0).
+                let u1: f64 = 1.0 - self.rng.sample::<f64, _>(Standard);
+                let u2 = self.rng.sample::<f64, _>(Standard);
+                let z = f64::sqrt(-2.0 * u1.ln()) * f64::cos(2.0 * std::f64::consts::PI * u2);
+
+                P::from_elem(mean + std * z)
+            }
         }
     }
 }
@@ -78,6 +90,7 @@ where
             Distribution::Bernoulli(prob) => DistributionSamplerKind::Bernoulli(
                 rand::distributions::Bernoulli::new(prob).unwrap(),
             ),
+            Distribution::Normal(mean, std) => DistributionSamplerKind::Normal(mean, std),
         };
 
         DistributionSampler::new(kind, rng)
@@ -93,6 +106,7 @@ where
             Distribution::Standard => Distribution::Standard,
             Distribution::Uniform(a, b) => Distribution::Uniform(E::from_elem(a), E::from_elem(b)),
             Distribution::Bernoulli(prob) => Distribution::Bernoulli(prob),
+            Distribution::Normal(mean, std) => Distribution::Normal(mean, std),
         }
     }
 }
@@ -108,6 +122,25 @@ impl<const D: usize, P: Element> Data<P, D> {
     }
 }
 
+impl<const D: usize> Data<f32, D> {
+    /// Convert the data to half precision storage.
+    ///
+    /// The conversion is lossy: each value is rounded to the nearest representable `f16`, values
+    /// too small become subnormal or zero and values too large become infinite.
+    pub fn to_f16(&self) -> Data<f16, D> {
+        let value = self.value.iter().map(|a| f16::from_f32(*a)).collect();
+
+        Data::new(value, self.shape)
+    }
+
+    /// Create full precision data from half precision storage.
+    pub fn from_f16(data: &Data<f16, D>) -> Self {
+        let value = data.value.iter().map(|a| a.to_f32()).collect();
+
+        Data::new(value, data.shape)
+    }
+}
+
 impl<P: Element> DataSerialize<P> {
     pub fn convert<E: Element>(self) -> DataSerialize<E> {
         let value: Vec<E> = self.value.into_iter().map(|a| a.to_elem()).collect();
@@ -224,6 +257,58 @@ impl<P: Into<f64> + Clone + std::fmt::Debug + PartialEq, const D: usize> Data<P,
     }
 }
 
+impl<P: Into<f64> + Clone + std::fmt::Debug, const D: usize> Data<P, D> {
+    /// Assert that each element is equal to the corresponding element of the other data within
+    /// the given tolerance.
+    ///
+    /// The tolerance is absolute for values smaller than one and relative for larger values, so
+    /// two elements `a` and `b` are equal when `|a - b| <= tolerance * max(1, |b|)`.
+    ///
+    /// # Panics
+    ///
+    /// When the shapes differ or when an element differs, reporting the position of the first
+    /// mismatching element.
+    pub fn assert_within_tolerance(&self, other: &Self, tolerance: f64) {
+        assert_eq!(self.shape, other.shape);
+
+        let iter = self.value.iter().zip(other.value.iter()).enumerate();
+
+        for (index, (a, b)) in iter {
+            let a: f64 = a.clone().into();
+            let b: f64 = b.clone().into();
+
+            if a.is_nan() && b.is_nan() {
+                continue;
+            }
+            if a == b || (a - b).abs() <= tolerance * f64::max(1.0, b.abs()) {
+                continue;
+            }
+
+            panic!(
+                "Data differs at position {:?}: {} != {} with a tolerance of {}\n  left: {:?}\n right: {:?}",
+                self.position(index),
+                a,
+                b,
+                tolerance,
+                self.value,
+                other.value
+            );
+        }
+    }
+
+    fn position(&self, index: usize) -> [usize; D] {
+        let mut position = [0; D];
+        let mut index = index;
+
+        for i in (0..D).rev() {
+            position[i] = index % self.shape.dims[i];
+            index /= self.shape.dims[i];
+        }
+
+        position
+    }
+}
+
 impl<const D: usize> Data<usize, D> {
     pub fn from_usize<O: num_traits::FromPrimitive>(self) -> Data<O, D> {
         let value: Vec<O> = self
@@ -297,11 +382,137 @@ impl<P: std::fmt::Debug + Copy, const A: usize, const B: usize, const C: usize>
     }
 }
 
+impl<P: std::fmt::Debug + Copy, const A: usize, const B: usize, const C: usize, const D: usize>
+    From<[[[[P; D]; C]; B]; A]> for Data<P, 4>
+{
+    fn from(elems: [[[[P; D]; C]; B]; A]) -> Self {
+        let mut data = Vec::with_capacity(A * B * C * D);
+
+        for elem in elems.into_iter().take(A) {
+            for elem in elem.into_iter().take(B) {
+                for elem in elem.into_iter().take(C) {
+                    for elem in elem.into_iter().take(D) {
+                        data.push(elem);
+                    }
+                }
+            }
+        }
+
+        Data::new(data, Shape::new([A, B, C, D]))
+    }
+}
+
+/// Number of elements above which the [display](std::fmt::Display) of data is truncated.
+const DISPLAY_MAX_ELEMENTS: usize = 1000;
+/// Number of items shown at the start and at the end of each dimension of truncated data.
+const DISPLAY_EDGE_ITEMS: usize = 3;
+
+impl<P: std::fmt::Debug, const D: usize> Data<P, D> {
+    /// Format the data with nested brackets and aligned columns.
+    ///
+    /// When there are more than `max_elements` elements, only the first and last three items of
+    /// each dimension are shown, the others being replaced by an ellipsis.
+    pub fn format_with_max_elements(&self, max_elements: usize) -> String {
+        let truncated = self.value.len() > max_elements;
+        let indexes: Vec<Vec<Option<usize>>> = self
+            .shape
+            .dims
+            .iter()
+            .map(|size| displayed_indexes(*size, truncated))
+            .collect();
+
+        let mut strides = [1; D];
+        for dim in (0..D.saturating_sub(1)).rev() {
+            strides[dim] = strides[dim + 1] * self.shape.dims[dim + 1];
+        }
+
+        let mut positions = Vec::new();
+        displayed_positions(&indexes, &strides, 0, 0, &mut positions);
+        let width = positions
+            .iter()
+            .map(|position| format!("{:?}", self.value[*position]).len())
+            .max()
+            .unwrap_or(0);
+
+        let mut output = String::new();
+        self.format_dim(&mut output, &indexes, &strides, 0, 0, width);
+        output
+    }
+
+    fn format_dim(
+        &self,
+        output: &mut String,
+        indexes: &[Vec<Option<usize>>],
+        strides: &[usize; D],
+        dim: usize,
+        offset: usize,
+        width: usize,
+    ) {
+        let innermost = dim + 1 == D;
+        let separator = match innermost {
+            true => ", ".to_string(),
+            false => format!(",{}{}", "\n".repeat(D - dim - 1), " ".repeat(dim + 1)),
+        };
+
+        output.push('[');
+        for (i, index) in indexes[dim].iter().enumerate() {
+            if i > 0 {
+                output.push_str(&separator);
+            }
+
+            match index {
+                Some(index) if innermost => {
+                    let value = format!("{:?}", self.value[offset + index]);
+                    output.push_str(&format!("{:>width$}", value, width = width));
+                }
+                Some(index) => {
+                    let offset = offset + index * strides[dim];
+                    self.format_dim(output, indexes, strides, dim + 1, offset, width);
+                }
+                None => output.push_str("..."),
+            }
+        }
+        output.push(']');
+    }
+}
+
+/// The indexes of a dimension that are displayed, `None` standing for the truncated ones.
+fn displayed_indexes(size: usize, truncated: bool) -> Vec<Option<usize>> {
+    if !truncated || size <= 2 * DISPLAY_EDGE_ITEMS {
+        return (0..size).map(Some).collect();
+    }
+
+    (0..DISPLAY_EDGE_ITEMS)
+        .map(Some)
+        .chain([None])
+        .chain((size - DISPLAY_EDGE_ITEMS..size).map(Some))
+        .collect()
+}
+
+fn displayed_positions(
+    indexes: &[Vec<Option<usize>>],
+    strides: &[usize],
+    dim: usize,
+    offset: usize,
+    positions: &mut Vec<usize>,
+) {
+    if dim == indexes.len() {
+        positions.push(offset);
+        return;
+    }
+
+    for index in indexes[dim].iter().flatten() {
+        let offset = offset + index * strides[dim];
+        displayed_positions(indexes, strides, dim + 1, offset, positions);
+    }
+}
+
 impl<P: std::fmt::Debug, const D: usize> std::fmt::Display for Data<P, D> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str(format!("{:?}", &self.value).as_str())
+        f.write_str(&self.format_with_max_elements(DISPLAY_MAX_ELEMENTS))
     }
 }
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -315,6 +526,23 @@ mod tests {
         assert_eq!(shape.num_elements(), data.value.len());
     }
 
+    #[test]
+    fn should_be_equal_within_tolerance() {
+        let data = Data::<f32, 2>::from([[1.0, 100.0], [0.0, -3.0]]);
+        let other = Data::<f32, 2>::from([[1.0005, 100.05], [-0.0005, -3.0015]]);
+
+        data.assert_within_tolerance(&other, 1e-3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Data differs at position [1, 0]")]
+    fn should_report_the_first_mismatching_position() {
+        let data = Data::<f32, 2>::from([[1.0, 100.0], [0.0, -3.0]]);
+        let other = Data::<f32, 2>::from([[1.0, 100.0], [0.01, -3.1]]);
+
+        data.assert_within_tolerance(&other, 1e-3);
+    }
+
     #[test]
     fn should_have_right_shape() {
         let data = Data::from([[3.0, 5.0, 6.0]]);
@@ -326,4 +554,66 @@ mod tests {
         let data = Data::from([3.0, 5.0, 6.0]);
         assert_eq!(data.shape, Shape::new([3]));
     }
+
+    #[test]
+    fn should_display_with_aligned_columns() {
+        let data = Data::<f32, 2>::from([[1.0, -2.5, 3.0], [4.0, 5.0, 60.0]]);
+
+        assert_eq!(
+            format!("{}", data),
+            "[[ 1.0, -2.5,  3.0],\n [ 4.0,  5.0, 60.0]]"
+        );
+    }
+
+    #[test]
+    fn should_display_blank_lines_between_matrices() {
+        let data = Data::<i32, 3>::from([[[1, 2], [3, 4]], [[5, 6], [7, 8]]]);
+
+        assert_eq!(
+            format!("{}", data),
+            "[[[1, 2],\n  [3, 4]],\n\n [[5, 6],\n  [7, 8]]]"
+        );
+    }
+
+    #[test]
+    fn should_display_truncated_data() {
+        let values = (0..100).map(|value| value as f32).collect();
+        let data = Data::new(values, Shape::new([10, 10]));
+
+        let expected = [
+            "[[ 0.0,  1.0,  2.0, ...,  7.0,  8.0,  9.0],",
+            " [10.0, 11.0, 12.0, ..., 17.0, 18.0, 19.0],",
+            " [20.0, 21.0, 22.0, ..., 27.0, 28.0, 29.0],",
+            " ...,",
+            " [70.0, 71.0, 72.0, ..., 77.0, 78.0, 79.0],",
+            " [80.0, 81.0, 82.0, ..., 87.0, 88.0, 89.0],",
+            " [90.0, 91.0, 92.0, ..., 97.0, 98.0, 99.0]]",
+        ];
+        assert_eq!(data.format_with_max_elements(20), expected.join("\n"));
+        assert!(!format!("{}", data).contains("..."));
+    }
+
+    #[test]
+    fn should_round_trip_f16_within_tolerance() {
+        let data = Data::from([[0.1, -1.5, 3.2713], [1000.3, -0.00042, 65000.0]]);
+
+        let data_half = data.to_f16();
+        let data_full = Data::from_f16(&data_half);
+
+        assert_eq!(data_half.shape, data.shape);
+        assert_eq!(data_full.shape, data.shape);
+        for (a, b) in data.value.iter().zip(data_full.value.iter()) {
+            assert!(f32::abs(a - b) <= f32::abs(*a) / 2048.0, "{} != {}", a, b);
+        }
+    }
+
+    #[test]
+    fn should_map_subnormal_and_overflow_to_f16() {
+        let data = Data::from([1e-7, 1e-9, 1e6, -1e6, 65504.0]);
+
+        let data_half = data.to_f16();
+
+        let bits: Vec<u16> = data_half.value.iter().map(|a| a.to_bits()).collect();
+        assert_eq!(bits, vec![0x0002, 0x0000, 0x7C00, 0xFC00, 0x7BFF]);
+    }
 }
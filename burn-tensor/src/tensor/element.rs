@@ -28,7 +28,21 @@ pub(crate) trait TchElement: Element + tch::kind::Element {}
 pub(crate) trait ExpElement {
     fn exp_elem(self) -> Self;
     fn log_elem(self) -> Self;
+    fn log1p_elem(self) -> Self;
     fn pow_elem(self, value: f32) -> Self;
+    fn powi_elem(self, value: i32) -> Self;
+    fn abs_elem(self) -> Self;
+    fn sin_elem(self) -> Self;
+    fn cos_elem(self) -> Self;
+    fn tan_elem(self) -> Self;
+    fn sign_elem(self) -> Self;
+    fn round_elem(self) -> Self;
+    fn floor_elem(self) -> Self;
+    fn ceil_elem(self) -> Self;
+    fn trunc_elem(self) -> Self;
+    fn recip_elem(self) -> Self;
+    fn rsqrt_elem(self) -> Self;
+    fn sigmoid_elem(self) -> Self;
 }
 
 pub trait ElementConversion {
@@ -234,9 +248,66 @@ mod ndarray_elem {
                 fn log_elem(self) -> Self {
                     $elem::ln(self)
                 }
+                fn log1p_elem(self) -> Self {
+                    $elem::ln_1p(self)
+                }
                 fn pow_elem(self, value: f32) -> Self {
                     $elem::powf(self, value.into())
                 }
+                fn powi_elem(self, value: i32) -> Self {
+                    $elem::powi(self, value)
+                }
+                fn abs_elem(self) -> Self {
+                    $elem::abs(self)
+                }
+                fn sin_elem(self) -> Self {
+                    $elem::sin(self)
+                }
+                fn cos_elem(self) -> Self {
+                    $elem::cos(self)
+                }
+                fn tan_elem(self) -> Self {
+                    $elem::tan(self)
+                }
+                fn sign_elem(self) -> Self {
+                    // Zero and NaN are kept, like PyTorch.
+                    if self > 0.0 {
+                        1.0
+                    } else if self < 0.0 {
+                        -1.0
+                    } else {
+                        self
+                    }
+                }
+                fn round_elem(self) -> Self {
+                    // Halfway values are rounded to the nearest even integer, like PyTorch.
+                    match $elem::abs(self - $elem::trunc(self)) == 0.5 {
+                        true => 2.0 * $elem::round(self / 2.0),
+                        false => $elem::round(self),
+                    }
+                }
+                fn floor_elem(self) -> Self {
+                    $elem::floor(self)
+                }
+                fn ceil_elem(self) -> Self {
+                    $elem::ceil(self)
+                }
+                fn trunc_elem(self) -> Self {
+                    $elem::trunc(self)
+                }
+                fn recip_elem(self) -> Self {
+                    $elem::recip(self)
+                }
+                fn rsqrt_elem(self) -> Self {
+                    $elem::recip($elem::sqrt(self))
+                }
+                fn sigmoid_elem(self) -> Self {
+                    // The exponential of a negative value is used so that it doesn't overflow.
+                    match self >= 0.0 {
+                        true => 1.0 / (1.0 + $elem::exp(-self)),
+                        false => $elem::exp(self) / (1.0 + $elem::exp(self)),
+                    }
+                }
             }
         };
         ($elem:ident, $tmp:ident) => {
@@ -249,10 +320,60 @@ mod ndarray_elem {
                     let tmp = $tmp::ln(self as $tmp);
                     tmp as $elem
                 }
+                fn log1p_elem(self) -> Self {
+                    let tmp = $tmp::ln_1p(self as $tmp);
+                    tmp as $elem
+                }
                 fn pow_elem(self, value: f32) -> Self {
                     let tmp = $tmp::powf(self as $tmp, value as $tmp);
                     tmp as $elem
                 }
+                fn powi_elem(self, value: i32) -> Self {
+                    let tmp = $tmp::powi(self as $tmp, value);
+                    tmp as $elem
+                }
+                fn abs_elem(self) -> Self {
+                    let tmp = $tmp::abs(self as $tmp);
+                    tmp as $elem
+                }
+                fn sin_elem(self) -> Self {
+                    let tmp = $tmp::sin(self as $tmp);
+                    tmp as $elem
+                }
+                fn cos_elem(self) -> Self {
+                    let tmp = $tmp::cos(self as $tmp);
+                    tmp as $elem
+                }
+                fn tan_elem(self) -> Self {
+                    let tmp = $tmp::tan(self as $tmp);
+                    tmp as $elem
+                }
+                fn sign_elem(self) -> Self {
+                    (self as $tmp).sign_elem() as $elem
+                }
+                fn round_elem(self) -> Self {
+                    self
+                }
+                fn floor_elem(self) -> Self {
+                    self
+                }
+                fn ceil_elem(self) -> Self {
+                    self
+                }
+                fn trunc_elem(self) -> Self {
+                    self
+                }
+                fn recip_elem(self) -> Self {
+                    let tmp = $tmp::recip(self as $tmp);
+                    tmp as $elem
+                }
+                fn rsqrt_elem(self) -> Self {
+                    let tmp = $tmp::recip($tmp::sqrt(self as $tmp));
+                    tmp as $elem
+                }
+                fn sigmoid_elem(self) -> Self {
+                    (self as $tmp).sigmoid_elem() as $elem
+                }
             }
         };
     }
@@ -3,14 +3,18 @@ pub(crate) mod stats;
 
 mod base;
 mod bool_tensor;
+mod checkpoint;
 mod data;
 mod element;
+mod npy;
 mod shape;
 
 pub use base::*;
 pub use bool_tensor::*;
+pub use checkpoint::*;
 pub use data::*;
 pub use element::*;
+pub use npy::*;
 pub use shape::*;
 
 pub mod activation;
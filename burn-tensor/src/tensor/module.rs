@@ -6,3 +6,136 @@ where
 {
     Tensor::new(B::embedding(&weights.value, &indexes.value))
 }
+
+/// Applies a 1D convolution over an input of shape `[batch_size, channels_in, length]` with
+/// weights of shape `[channels_out, channels_in, kernel_size]`.
+///
+/// The input is padded with zeros on both sides, and the elements of the kernel are spaced by
+/// the dilation. The output has a shape of `[batch_size, channels_out, length_out]`, see
+/// [conv1d_output_length](conv1d_output_length).
+pub fn conv1d<B>(
+    x: &Tensor<B, 3>,
+    weight: &Tensor<B, 3>,
+    bias: Option<&Tensor<B, 1>>,
+    stride: usize,
+    padding: usize,
+    dilation: usize,
+) -> Tensor<B, 3>
+where
+    B: Backend,
+{
+    let [_batch_size, channels_in, length] = x.shape().dims;
+    let [channels_out, channels_in_weight, kernel_size] = weight.shape().dims;
+
+    if channels_in != channels_in_weight {
+        panic!(
+            "Can't convolve an input with {} channels using weights expecting {} channels",
+            channels_in, channels_in_weight
+        );
+    }
+    if stride == 0 || dilation == 0 {
+        panic!(
+            "The stride and the dilation must be at least 1, got {} and {}",
+            stride, dilation
+        );
+    }
+    if length + 2 * padding < dilation * (kernel_size - 1) + 1 {
+        panic!(
+            "The padded input of length {} is shorter than the dilated kernel of size {}",
+            length + 2 * padding,
+            dilation * (kernel_size - 1) + 1
+        );
+    }
+
+    let output = Tensor::new(B::conv1d(
+        &x.value,
+        &weight.value,
+        stride,
+        padding,
+        dilation,
+    ));
+
+    match bias {
+        Some(bias) => output.add(&bias.reshape([1, channels_out, 1])),
+        None => output,
+    }
+}
+
+/// Computes the length of the output of a [1D convolution](conv1d).
+///
+/// `length_out = (length + 2 * padding - dilation * (kernel_size - 1) - 1) / stride + 1`
+pub fn conv1d_output_length(
+    length: usize,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+    dilation: usize,
+) -> usize {
+    (length + 2 * padding - dilation * (kernel_size - 1) - 1) / stride + 1
+}
+
+/// Applies a 2D average pooling over an input of shape `[batch_size, channels, height, width]`.
+///
+/// The input is padded with zeros on both sides of each spatial dimension. When
+/// `count_include_pad` is false, the padded zeros aren't counted in the average of the windows
+/// overlapping the padding.
+pub fn avg_pool2d<B>(
+    x: &Tensor<B, 4>,
+    kernel_size: [usize; 2],
+    stride: [usize; 2],
+    padding: [usize; 2],
+    count_include_pad: bool,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    let [_batch_size, _channels, height, width] = x.shape().dims;
+
+    for i in 0..2 {
+        if kernel_size[i] == 0 || stride[i] == 0 {
+            panic!(
+                "The kernel size and the stride must be at least 1, got {:?} and {:?}",
+                kernel_size, stride
+            );
+        }
+        if padding[i] > kernel_size[i] / 2 {
+            panic!(
+                "The padding {:?} must be at most half of the kernel size {:?}",
+                padding, kernel_size
+            );
+        }
+    }
+    if height + 2 * padding[0] < kernel_size[0] || width + 2 * padding[1] < kernel_size[1] {
+        panic!(
+            "The padded input of size {:?} is smaller than the kernel of size {:?}",
+            [height + 2 * padding[0], width + 2 * padding[1]],
+            kernel_size
+        );
+    }
+
+    Tensor::new(B::avg_pool2d(
+        &x.value,
+        kernel_size,
+        stride,
+        padding,
+        count_include_pad,
+    ))
+}
+
+/// Applies a 2D adaptive average pooling over an input of shape
+/// `[batch_size, channels, height, width]`, giving an output of shape
+/// `[batch_size, channels, output_size[0], output_size[1]]`.
+///
+/// The window of the output position `i` spans the input positions from
+/// `floor(i * size / output_size)` to `ceil((i + 1) * size / output_size)`, so windows vary in
+/// size and overlap when the input size isn't divisible by the output size.
+pub fn adaptive_avg_pool2d<B>(x: &Tensor<B, 4>, output_size: [usize; 2]) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    if output_size.contains(&0) {
+        panic!("The output size must be at least 1, got {:?}", output_size);
+    }
+
+    Tensor::new(B::adaptive_avg_pool2d(&x.value, output_size))
+}
@@ -0,0 +1,300 @@
+use crate::tensor::{Data, Shape};
+use std::io::{Error, ErrorKind, Read, Write};
+use std::path::Path;
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// Element that can be stored in the NumPy `.npy` format.
+pub trait NpyElement: Sized + Copy {
+    /// The NumPy type descriptor, without the byte order character.
+    const DESCR: &'static str;
+
+    fn write_le<W: Write>(&self, writer: &mut W) -> Result<(), Error>;
+    fn read_le<R: Read>(reader: &mut R) -> Result<Self, Error>;
+}
+
+macro_rules! npy_element {
+    ($ty:ty, $descr:expr, $size:expr) => {
+        impl NpyElement for $ty {
+            const DESCR: &'static str = $descr;
+
+            fn write_le<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+                writer.write_all(&self.to_le_bytes())
+            }
+
+            fn read_le<R: Read>(reader: &mut R) -> Result<Self, Error> {
+                let mut bytes = [0; $size];
+                reader.read_exact(&mut bytes)?;
+                Ok(<$ty>::from_le_bytes(bytes))
+            }
+        }
+    };
+}
+
+npy_element!(f32, "f4", 4);
+npy_element!(f64, "f8", 8);
+npy_element!(i64, "i8", 8);
+
+impl<P: NpyElement, const D: usize> Data<P, D> {
+    /// Load the data from a NumPy `.npy` file.
+    ///
+    /// Only little endian arrays stored in C order are supported, and the dtype must match the
+    /// element type `P`.
+    pub fn from_npy<A: AsRef<Path>>(path: A) -> Result<Self, Error> {
+        let file = std::fs::File::open(path)?;
+        let len = file.metadata()?.len();
+        Self::read_npy(&mut std::io::BufReader::new(file), len)
+    }
+
+    /// Save the data to a NumPy `.npy` file.
+    pub fn to_npy<A: AsRef<Path>>(&self, path: A) -> Result<(), Error> {
+        let file = std::fs::File::create(path)?;
+        let mut writer = std::io::BufWriter::new(file);
+
+        self.write_npy(&mut writer)?;
+        writer.flush()
+    }
+
+    fn write_npy<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        let shape = match D {
+            1 => format!("({},)", self.shape.dims[0]),
+            _ => {
+                let dims: Vec<String> = self.shape.dims.iter().map(|d| d.to_string()).collect();
+                format!("({})", dims.join(", "))
+            }
+        };
+        let mut header = format!(
+            "{{'descr': '<{}', 'fortran_order': False, 'shape': {}, }}",
+            P::DESCR,
+            shape
+        );
+
+        // The header is padded with spaces so that the data is aligned on 64 bytes.
+        let preamble_size = MAGIC.len() + 2 + 2;
+        let padding = 64 - (preamble_size + header.len() + 1) % 64;
+        header.push_str(&" ".repeat(padding % 64));
+        header.push('\n');
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[1, 0])?;
+        writer.write_all(&(header.len() as u16).to_le_bytes())?;
+        writer.write_all(header.as_bytes())?;
+
+        for value in self.value.iter() {
+            value.write_le(writer)?;
+        }
+
+        Ok(())
+    }
+
+    /// Read the data from a reader containing `len` bytes, which bounds the sizes found in the
+    /// header before anything is allocated.
+    fn read_npy<R: Read>(reader: &mut R, len: u64) -> Result<Self, Error> {
+        let mut magic = [0; 6];
+        reader.read_exact(&mut magic)?;
+        if magic != MAGIC {
+            return Err(invalid_data("Not a npy file".to_string()));
+        }
+
+        let mut version = [0; 2];
+        reader.read_exact(&mut version)?;
+        let (preamble_len, header_len) = match version[0] {
+            1 => {
+                let mut bytes = [0; 2];
+                reader.read_exact(&mut bytes)?;
+                (10, u16::from_le_bytes(bytes) as u64)
+            }
+            2 | 3 => {
+                let mut bytes = [0; 4];
+                reader.read_exact(&mut bytes)?;
+                (12, u32::from_le_bytes(bytes) as u64)
+            }
+            major => {
+                return Err(invalid_data(format!(
+                    "Unsupported npy format version {}",
+                    major
+                )))
+            }
+        };
+
+        let data_len = match len.checked_sub(preamble_len + header_len) {
+            Some(data_len) => data_len,
+            None => {
+                return Err(invalid_data(format!(
+                    "The npy header of {} bytes is larger than the file",
+                    header_len
+                )))
+            }
+        };
+
+        let mut header = vec![0; header_len as usize];
+        reader.read_exact(&mut header)?;
+        let header = String::from_utf8(header).map_err(|err| invalid_data(format!("{}", err)))?;
+
+        let descr = header_value(&header, "descr")?;
+        let descr = descr.trim_matches('\'');
+        let expected = [format!("<{}", P::DESCR), format!("|{}", P::DESCR)];
+        if !expected.iter().any(|expected| expected == descr) {
+            return Err(invalid_data(format!(
+                "Unsupported dtype {}, expected <{}",
+                descr,
+                P::DESCR
+            )));
+        }
+
+        if header_value(&header, "fortran_order")? != "False" {
+            return Err(invalid_data(
+                "Only arrays stored in C order are supported".to_string(),
+            ));
+        }
+
+        let shape = header_value(&header, "shape")?;
+        let dims = shape
+            .trim_matches(|c| c == '(' || c == ')')
+            .split(',')
+            .map(|dim| dim.trim())
+            .filter(|dim| !dim.is_empty())
+            .map(|dim| dim.parse::<usize>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| invalid_data(format!("Invalid shape {}: {}", shape, err)))?;
+        if dims.len() != D {
+            return Err(invalid_data(format!(
+                "Expected an array with {} dimensions, got shape {}",
+                D, shape
+            )));
+        }
+
+        let mut shape = [0; D];
+        shape.copy_from_slice(&dims);
+        let shape = Shape::new(shape);
+
+        let num_elements = dims
+            .iter()
+            .try_fold(1usize, |num, dim| num.checked_mul(*dim));
+        let num_bytes =
+            num_elements.and_then(|num| (num as u64).checked_mul(std::mem::size_of::<P>() as u64));
+        let num_elements = match (num_elements, num_bytes) {
+            (Some(num_elements), Some(num_bytes)) if num_bytes <= data_len => num_elements,
+            _ => {
+                return Err(invalid_data(format!(
+                    "The npy data of shape {:?} is larger than the file",
+                    dims
+                )))
+            }
+        };
+        let mut value = Vec::with_capacity(num_elements);
+        for _ in 0..num_elements {
+            value.push(P::read_le(reader)?);
+        }
+
+        Ok(Data::new(value, shape))
+    }
+}
+
+/// Extract the raw value of the given key from the header dictionary.
+fn header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, Error> {
+    let pattern = format!("'{}':", key);
+    let start = match header.find(&pattern) {
+        Some(index) => index + pattern.len(),
+        None => return Err(invalid_data(format!("Missing {} in npy header", key))),
+    };
+    let value = header[start..].trim_start();
+
+    let end = match value.starts_with('(') {
+        true => value.find(')').map(|index| index + 1),
+        false => value.find([',', '}']),
+    };
+
+    match end {
+        Some(end) => Ok(value[..end].trim()),
+        None => Err(invalid_data(format!("Invalid {} in npy header", key))),
+    }
+}
+
+fn invalid_data(message: String) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_round_trip_npy_file() {
+        let data = Data::<f32, 2>::from([[1.0, -2.5, 3.0], [4.25, 5.0, -6.0]]);
+        let directory =
+            std::env::temp_dir().join(format!("burn-tensor-npy-{}", std::process::id()));
+        let path = directory.join("round-trip.npy");
+        std::fs::create_dir_all(&directory).unwrap();
+
+        data.to_npy(&path).unwrap();
+        let data_loaded = Data::<f32, 2>::from_npy(&path).unwrap();
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(data, data_loaded);
+    }
+
+    #[test]
+    fn should_write_aligned_header() {
+        let data = Data::<i64, 1>::from([1, 2, 3]);
+        let mut bytes = Vec::new();
+
+        data.write_npy(&mut bytes).unwrap();
+
+        let header_len = u16::from_le_bytes([bytes[8], bytes[9]]) as usize;
+        let header = std::str::from_utf8(&bytes[10..10 + header_len]).unwrap();
+        assert_eq!((10 + header_len) % 64, 0);
+        assert!(header.starts_with("{'descr': '<i8', 'fortran_order': False, 'shape': (3,), }"));
+        assert_eq!(bytes.len(), 10 + header_len + 3 * 8);
+    }
+
+    #[test]
+    fn should_fail_on_unsupported_dtype() {
+        let data = Data::<f64, 2>::from([[1.0, 2.0]]);
+        let mut bytes = Vec::new();
+        data.write_npy(&mut bytes).unwrap();
+
+        let result = Data::<f32, 2>::read_npy(&mut bytes.as_slice(), bytes.len() as u64);
+
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn should_fail_on_fortran_order() {
+        let header = "{'descr': '<f4', 'fortran_order': True, 'shape': (1, 1), }\n";
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&[1, 0]);
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(&1.0f32.to_le_bytes());
+
+        let result = Data::<f32, 2>::read_npy(&mut bytes.as_slice(), bytes.len() as u64);
+
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn should_fail_on_shape_larger_than_the_file() {
+        let header = "{'descr': '<f4', 'fortran_order': False, 'shape': (100000, 100000), }\n";
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&[1, 0]);
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(&1.0f32.to_le_bytes());
+
+        let result = Data::<f32, 2>::read_npy(&mut bytes.as_slice(), bytes.len() as u64);
+
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn should_fail_on_header_larger_than_the_file() {
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&[2, 0]);
+        bytes.extend_from_slice(&u32::MAX.to_le_bytes());
+
+        let result = Data::<f32, 2>::read_npy(&mut bytes.as_slice(), bytes.len() as u64);
+
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidData);
+    }
+}
@@ -11,20 +11,85 @@ pub trait ModuleOps<B: Backend> {
         output: &B::TensorPrimitive<3>,
         indexes: &<B::IntegerBackend as Backend>::TensorPrimitive<2>,
     ) -> B::TensorPrimitive<2>;
+    fn conv1d(
+        x: &B::TensorPrimitive<3>,
+        weight: &B::TensorPrimitive<3>,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+    ) -> B::TensorPrimitive<3>;
+    fn conv1d_x_backward(
+        x: &B::TensorPrimitive<3>,
+        weight: &B::TensorPrimitive<3>,
+        output_grad: &B::TensorPrimitive<3>,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+    ) -> B::TensorPrimitive<3>;
+    fn conv1d_weight_backward(
+        x: &B::TensorPrimitive<3>,
+        weight: &B::TensorPrimitive<3>,
+        output_grad: &B::TensorPrimitive<3>,
+        stride: usize,
+        padding: usize,
+        dilation: usize,
+    ) -> B::TensorPrimitive<3>;
+    fn avg_pool2d(
+        x: &B::TensorPrimitive<4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> B::TensorPrimitive<4>;
+    fn avg_pool2d_backward(
+        x: &B::TensorPrimitive<4>,
+        output_grad: &B::TensorPrimitive<4>,
+        kernel_size: [usize; 2],
+        stride: [usize; 2],
+        padding: [usize; 2],
+        count_include_pad: bool,
+    ) -> B::TensorPrimitive<4>;
+    fn adaptive_avg_pool2d(
+        x: &B::TensorPrimitive<4>,
+        output_size: [usize; 2],
+    ) -> B::TensorPrimitive<4>;
+    fn adaptive_avg_pool2d_backward(
+        x: &B::TensorPrimitive<4>,
+        output_grad: &B::TensorPrimitive<4>,
+    ) -> B::TensorPrimitive<4>;
 }
 
 pub trait TensorOps<B: Backend> {
     fn shape<const D: usize>(tensor: &B::TensorPrimitive<D>) -> &Shape<D>;
     fn to_data<const D: usize>(tensor: &B::TensorPrimitive<D>) -> Data<B::Elem, D>;
     fn into_data<const D: usize>(tensor: B::TensorPrimitive<D>) -> Data<B::Elem, D>;
+    fn count_nonzero<const D: usize>(tensor: &B::TensorPrimitive<D>) -> usize;
     fn bool_shape<const D: usize>(tensor: &B::BoolTensorPrimitive<D>) -> &Shape<D>;
     fn bool_to_data<const D: usize>(tensor: &B::BoolTensorPrimitive<D>) -> Data<bool, D>;
     fn bool_into_data<const D: usize>(tensor: B::BoolTensorPrimitive<D>) -> Data<bool, D>;
+    fn bool_all<const D: usize>(tensor: &B::BoolTensorPrimitive<D>) -> bool;
+    fn bool_any<const D: usize>(tensor: &B::BoolTensorPrimitive<D>) -> bool;
+    fn bool_all_dim<const D: usize>(
+        tensor: &B::BoolTensorPrimitive<D>,
+        dim: usize,
+    ) -> B::BoolTensorPrimitive<D>;
+    fn bool_any_dim<const D: usize>(
+        tensor: &B::BoolTensorPrimitive<D>,
+        dim: usize,
+    ) -> B::BoolTensorPrimitive<D>;
+    fn bool_to_float<const D: usize>(tensor: &B::BoolTensorPrimitive<D>) -> B::TensorPrimitive<D>;
     fn device<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::Device;
+    fn set_require_grad<const D: usize>(
+        tensor: B::TensorPrimitive<D>,
+        _require_grad: bool,
+    ) -> B::TensorPrimitive<D> {
+        tensor
+    }
     fn to_device<const D: usize>(
         tensor: &B::TensorPrimitive<D>,
         device: B::Device,
     ) -> B::TensorPrimitive<D>;
+    fn deep_clone<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
     fn arange(
         range: Range<usize>,
         device: B::Device,
@@ -44,10 +109,8 @@ pub trait TensorOps<B: Backend> {
         times: usize,
     ) -> B::TensorPrimitive<D> {
         let mut shape = *B::shape(tensor);
-        if shape.dims[dim] != 1 {
-            panic!("Can only repeat dimension with dim=1");
-        }
-        shape.dims[dim] = times;
+        let size = shape.dims[dim];
+        shape.dims[dim] = size * times;
 
         let mut i = 0;
         let indexes_select_all = [0; D].map(|_| {
@@ -60,7 +123,7 @@ pub trait TensorOps<B: Backend> {
         let mut tensor_output = B::empty(shape, B::device(tensor));
         for i in 0..times {
             let mut indexes = indexes_select_all.clone();
-            indexes[dim] = i..i + 1;
+            indexes[dim] = i * size..(i + 1) * size;
             tensor_output = B::index_assign(&tensor_output, indexes, tensor);
         }
 
@@ -74,6 +137,9 @@ pub trait TensorOps<B: Backend> {
         lhs: &B::TensorPrimitive<D>,
         rhs: &B::Elem,
     ) -> B::TensorPrimitive<D>;
+    fn add_assign<const D: usize>(lhs: &mut B::TensorPrimitive<D>, rhs: &B::TensorPrimitive<D>) {
+        *lhs = B::add(lhs, rhs);
+    }
     fn sub<const D: usize>(
         lhs: &B::TensorPrimitive<D>,
         rhs: &B::TensorPrimitive<D>,
@@ -82,6 +148,9 @@ pub trait TensorOps<B: Backend> {
         lhs: &B::TensorPrimitive<D>,
         rhs: &B::Elem,
     ) -> B::TensorPrimitive<D>;
+    fn sub_assign<const D: usize>(lhs: &mut B::TensorPrimitive<D>, rhs: &B::TensorPrimitive<D>) {
+        *lhs = B::sub(lhs, rhs);
+    }
     fn mul<const D: usize>(
         lhs: &B::TensorPrimitive<D>,
         rhs: &B::TensorPrimitive<D>,
@@ -90,6 +159,9 @@ pub trait TensorOps<B: Backend> {
         lhs: &B::TensorPrimitive<D>,
         rhs: &B::Elem,
     ) -> B::TensorPrimitive<D>;
+    fn mul_assign<const D: usize>(lhs: &mut B::TensorPrimitive<D>, rhs: &B::TensorPrimitive<D>) {
+        *lhs = B::mul(lhs, rhs);
+    }
     fn div<const D: usize>(
         lhs: &B::TensorPrimitive<D>,
         rhs: &B::TensorPrimitive<D>,
@@ -103,6 +175,18 @@ pub trait TensorOps<B: Backend> {
         rhs: &B::TensorPrimitive<D>,
     ) -> B::TensorPrimitive<D>;
     fn neg<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+    fn abs<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+    fn sin<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+    fn cos<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+    fn tan<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+    fn sign<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+    fn round<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+    fn floor<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+    fn ceil<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+    fn trunc<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+    fn reciprocal<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+    fn rsqrt<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
+    fn sigmoid<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::TensorPrimitive<D>;
     fn transpose<const D: usize>(tensor: &B::TensorPrimitive<D>) -> B::TensorPrimitive<D> {
         Self::swap_dims(tensor, D - 2, D - 1)
     }
@@ -111,10 +195,22 @@ pub trait TensorOps<B: Backend> {
         dim1: usize,
         dim2: usize,
     ) -> B::TensorPrimitive<D>;
+    fn permute<const D: usize>(
+        tensor: &B::TensorPrimitive<D>,
+        axes: [usize; D],
+    ) -> B::TensorPrimitive<D>;
+    fn flip<const D: usize>(
+        tensor: &B::TensorPrimitive<D>,
+        dims: &[usize],
+    ) -> B::TensorPrimitive<D>;
     fn reshape<const D1: usize, const D2: usize>(
         tensor: &B::TensorPrimitive<D1>,
         shape: Shape<D2>,
     ) -> B::TensorPrimitive<D2>;
+    fn expand<const D: usize>(
+        tensor: &B::TensorPrimitive<D>,
+        shape: Shape<D>,
+    ) -> B::TensorPrimitive<D>;
     fn index<const D1: usize, const D2: usize>(
         tensor: &B::TensorPrimitive<D1>,
         indexes: [Range<usize>; D2],
@@ -124,11 +220,38 @@ pub trait TensorOps<B: Backend> {
         indexes: [Range<usize>; D2],
         value: &B::TensorPrimitive<D1>,
     ) -> B::TensorPrimitive<D1>;
+    fn index_select<const D: usize>(
+        tensor: &B::TensorPrimitive<D>,
+        dim: usize,
+        indexes: &<B::IntegerBackend as Backend>::TensorPrimitive<1>,
+    ) -> B::TensorPrimitive<D>;
+    fn index_select_add<const D: usize>(
+        tensor: &B::TensorPrimitive<D>,
+        dim: usize,
+        indexes: &<B::IntegerBackend as Backend>::TensorPrimitive<1>,
+        value: &B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D>;
     fn mask_fill<const D: usize>(
         tensor: &B::TensorPrimitive<D>,
         mask: &B::BoolTensorPrimitive<D>,
         value: B::Elem,
     ) -> B::TensorPrimitive<D>;
+    fn maximum<const D: usize>(
+        lhs: &B::TensorPrimitive<D>,
+        rhs: &B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D>;
+    fn maximum_scalar<const D: usize>(
+        lhs: &B::TensorPrimitive<D>,
+        rhs: &B::Elem,
+    ) -> B::TensorPrimitive<D>;
+    fn minimum<const D: usize>(
+        lhs: &B::TensorPrimitive<D>,
+        rhs: &B::TensorPrimitive<D>,
+    ) -> B::TensorPrimitive<D>;
+    fn minimum_scalar<const D: usize>(
+        lhs: &B::TensorPrimitive<D>,
+        rhs: &B::Elem,
+    ) -> B::TensorPrimitive<D>;
 }
 
 pub trait TensorOpsMapComparison<B: Backend, const D: usize> {
@@ -149,6 +272,10 @@ pub trait TensorOpsAggregation<B: Backend, const D: usize> {
     fn sum(&self) -> B::TensorPrimitive<1>;
     fn mean_dim(&self, dim: usize) -> B::TensorPrimitive<D>;
     fn sum_dim(&self, dim: usize) -> B::TensorPrimitive<D>;
+    fn mean_dims(&self, dims: &[usize]) -> B::TensorPrimitive<D>;
+    fn sum_dims(&self, dims: &[usize]) -> B::TensorPrimitive<D>;
+    fn prod(&self) -> B::TensorPrimitive<1>;
+    fn prod_dim(&self, dim: usize) -> B::TensorPrimitive<D>;
 }
 
 pub trait TensorOpsPrecision<B: Backend, const D: usize> {
@@ -173,10 +300,12 @@ pub trait TensorOpsCat<E, const D: usize> {
 
 pub trait TensorOpsPow<E, const D: usize> {
     fn powf(&self, value: f32) -> Self;
+    fn powi(&self, value: i32) -> Self;
 }
 
 pub trait TensorOpsLog<E, const D: usize> {
     fn log(&self) -> Self;
+    fn log1p(&self) -> Self;
 }
 
 pub trait TensorOpsDetach<E, const D: usize> {
@@ -6,13 +6,23 @@ pub struct Shape<const D: usize> {
 }
 
 impl<const D: usize> Shape<D> {
+    /// Get the number of elements of a tensor with the current shape.
     pub fn num_elements(&self) -> usize {
-        let mut num_elements = 1;
-        for i in 0..D {
-            num_elements *= self.dims[i];
-        }
+        self.dims.iter().product()
+    }
+
+    /// Get the size of each dimension.
+    pub fn dims(&self) -> &[usize] {
+        &self.dims
+    }
 
-        num_elements
+    /// Check if a tensor with the current shape can be broadcasted to the other shape, which is
+    /// the case when each dimension is either equal to the other one or of size 1.
+    pub fn is_broadcastable_to(&self, other: &Self) -> bool {
+        self.dims
+            .iter()
+            .zip(other.dims.iter())
+            .all(|(dim, dim_other)| dim == dim_other || *dim == 1)
     }
 }
 
@@ -68,3 +78,40 @@ impl<const D1: usize> Shape<D1> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn num_elements_should_be_the_product_of_the_dims() {
+        assert_eq!(Shape::new([]).num_elements(), 1);
+        assert_eq!(Shape::new([5]).num_elements(), 5);
+        assert_eq!(Shape::new([2, 3]).num_elements(), 6);
+        assert_eq!(Shape::new([2, 3, 4, 5]).num_elements(), 120);
+        assert_eq!(Shape::new([2, 0, 4]).num_elements(), 0);
+    }
+
+    #[test]
+    fn dims_should_return_the_size_of_each_dim() {
+        assert_eq!(Shape::new([2, 3, 4]).dims(), &[2, 3, 4]);
+    }
+
+    #[test]
+    fn should_be_broadcastable_to_compatible_shapes() {
+        let shape = Shape::new([2, 3, 4]);
+
+        assert!(shape.is_broadcastable_to(&shape));
+        assert!(Shape::new([1, 3, 1]).is_broadcastable_to(&shape));
+        assert!(Shape::new([1, 1, 1]).is_broadcastable_to(&shape));
+    }
+
+    #[test]
+    fn should_not_be_broadcastable_to_incompatible_shapes() {
+        let shape = Shape::new([2, 3, 4]);
+
+        assert!(!Shape::new([2, 2, 4]).is_broadcastable_to(&shape));
+        assert!(!Shape::new([4, 3, 2]).is_broadcastable_to(&shape));
+        assert!(!shape.is_broadcastable_to(&Shape::new([1, 3, 4])));
+    }
+}
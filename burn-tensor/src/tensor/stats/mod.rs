@@ -1,4 +1,5 @@
-use crate::{backend::Backend, Tensor};
+use crate::{backend::Backend, Data, ElementConversion, Tensor};
+use num_traits::ToPrimitive;
 
 pub fn var<B: Backend, const D: usize>(tensor: &Tensor<B, D>, dim: usize) -> Tensor<B, D> {
     let mean = tensor.mean_dim(dim);
@@ -34,3 +35,47 @@ pub fn var_with_mean_n<B: Backend, const D: usize>(
 ) -> Tensor<B, D> {
     tensor.sub(mean).powf(2.0).sum_dim(dim).div_scalar(n as f32)
 }
+
+pub fn quantile<B: Backend, const D: usize>(
+    tensor: &Tensor<B, D>,
+    q: f64,
+    dim: usize,
+) -> Tensor<B, D> {
+    let shape = *tensor.shape();
+    let size_dim = shape.dims[dim];
+    if size_dim == 0 {
+        panic!("Can't compute a quantile of the empty dimension {}", dim);
+    }
+
+    let size_inner: usize = shape.dims[dim + 1..].iter().product();
+    let size_outer: usize = shape.dims[..dim].iter().product();
+    let values: Vec<f64> = tensor
+        .to_data()
+        .value
+        .iter()
+        .map(|value| value.to_f64().unwrap())
+        .collect();
+
+    let position = q * (size_dim - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    let fraction = position - lower as f64;
+
+    // The quantile is the weighted sum of the elements with the ranks around its position.
+    let mut weights = vec![0.0; shape.num_elements()];
+    for outer in 0..size_outer {
+        for inner in 0..size_inner {
+            let index = |i: usize| outer * size_dim * size_inner + i * size_inner + inner;
+            let mut ranks: Vec<usize> = (0..size_dim).collect();
+            ranks.sort_by(|a, b| values[index(*a)].total_cmp(&values[index(*b)]));
+
+            weights[index(ranks[lower])] += 1.0 - fraction;
+            weights[index(ranks[upper])] += fraction;
+        }
+    }
+
+    let weights: Vec<B::Elem> = weights.into_iter().map(|weight| weight.to_elem()).collect();
+    let weights = Tensor::<B, D>::from_data_device(Data::new(weights, shape), tensor.device());
+
+    tensor.mul(&weights).sum_dim(dim)
+}
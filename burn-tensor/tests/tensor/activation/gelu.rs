@@ -16,3 +16,13 @@ fn test_gelu() {
     ]]);
     data_expected.assert_approx_eq(&data_actual, 3);
 }
+
+#[test]
+fn test_gelu_known_values() {
+    let tensor = Tensor::<TestBackend, 1>::from_data(Data::from([-1.0, 0.0, 1.0]));
+
+    let data_actual = activation::gelu(&tensor).into_data();
+
+    let data_expected = Data::from([-0.1587, 0.0, 0.8413]);
+    data_expected.assert_approx_eq(&data_actual, 3);
+}
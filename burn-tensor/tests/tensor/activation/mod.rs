@@ -1,3 +1,5 @@
 mod gelu;
 mod relu;
+mod sigmoid;
 mod softmax;
+mod tanh;
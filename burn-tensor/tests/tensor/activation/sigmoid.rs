@@ -0,0 +1,23 @@
+use super::super::TestBackend;
+use burn_tensor::activation;
+use burn_tensor::{Data, Tensor};
+
+#[test]
+fn test_sigmoid() {
+    let data = Data::from([[-2.0, -0.5, 0.0], [0.5, 2.0, 10.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let data_actual = activation::sigmoid(&tensor).to_data();
+
+    let data_expected = Data::from([[0.11920, 0.37754, 0.5], [0.62246, 0.88080, 1.0]]);
+    data_expected.assert_approx_eq(&data_actual, 3);
+}
+
+#[test]
+fn test_sigmoid_should_not_overflow() {
+    let tensor = Tensor::<TestBackend, 1>::from_data(Data::from([-1000.0, 1000.0]));
+
+    let data_actual = activation::sigmoid(&tensor).into_data();
+
+    assert_eq!(data_actual, Data::from([0.0, 1.0]));
+}
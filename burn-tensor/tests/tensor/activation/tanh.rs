@@ -0,0 +1,14 @@
+use super::super::TestBackend;
+use burn_tensor::activation;
+use burn_tensor::{Data, Tensor};
+
+#[test]
+fn test_tanh() {
+    let data = Data::from([[-2.0, -0.5, 0.0], [0.5, 2.0, 10.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let data_actual = activation::tanh(&tensor).to_data();
+
+    let data_expected = Data::from([[-0.9640, -0.4621, 0.0], [0.4621, 0.9640, 1.0]]);
+    data_expected.assert_approx_eq(&data_actual, 3);
+}
@@ -24,3 +24,133 @@ fn test_sum_dim_grad() {
         .to_data()
         .assert_approx_eq(&Data::from([[690.0, 690.0], [958.0, 958.0]]), 3);
 }
+
+#[test]
+fn test_sum_dims_grad() {
+    let tensor = Tensor::<TestADBackend, 3>::from_data(data_2x3x4());
+
+    let tensor_out = tensor.sum_dims(&[0, 2]);
+    let grads = tensor_out.powf(2.0).sum().backward();
+    let grad = tensor.grad(&grads).unwrap();
+
+    assert_eq!(tensor_out.shape().dims, [1, 3, 1]);
+    grad.to_data().assert_approx_eq(
+        &Data::from([
+            [[120.0; 4], [184.0; 4], [248.0; 4]],
+            [[120.0; 4], [184.0; 4], [248.0; 4]],
+        ]),
+        3,
+    );
+}
+
+#[test]
+fn test_mean_dims_grad() {
+    let tensor = Tensor::<TestADBackend, 3>::from_data(data_2x3x4());
+
+    let tensor_out = tensor.mean_dims(&[0, 2]);
+    let grads = tensor_out.powf(2.0).sum().backward();
+    let grad = tensor.grad(&grads).unwrap();
+
+    assert_eq!(tensor_out.shape().dims, [1, 3, 1]);
+    grad.to_data().assert_approx_eq(
+        &Data::from([
+            [[1.875; 4], [2.875; 4], [3.875; 4]],
+            [[1.875; 4], [2.875; 4], [3.875; 4]],
+        ]),
+        3,
+    );
+}
+
+#[test]
+fn test_sum_dim_squeeze_grad() {
+    let tensor_1 = Tensor::<TestADBackend, 3>::from_data(data_2x3x4());
+    let tensor_2 = Tensor::<TestADBackend, 2>::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+
+    let tensor_3 = tensor_1.sum_dim_squeeze::<2>(2);
+    let tensor_4 = tensor_2.matmul(&tensor_3);
+    let grads = tensor_4.sum().backward();
+    let grad_1 = tensor_1.grad(&grads).unwrap();
+    let grad_2 = tensor_2.grad(&grads).unwrap();
+
+    grad_1.to_data().assert_approx_eq(
+        &Data::from([
+            [[4.0; 4], [4.0; 4], [4.0; 4]],
+            [[6.0; 4], [6.0; 4], [6.0; 4]],
+        ]),
+        3,
+    );
+    grad_2
+        .to_data()
+        .assert_approx_eq(&Data::from([[66.0, 210.0], [66.0, 210.0]]), 3);
+}
+
+#[test]
+fn test_mean_dim_squeeze_grad() {
+    let tensor = Tensor::<TestADBackend, 3>::from_data(data_2x3x4());
+
+    let tensor_out = tensor.mean_dim_squeeze::<2>(0);
+    let grads = tensor_out.sum().backward();
+    let grad = tensor.grad(&grads).unwrap();
+
+    assert_eq!(tensor_out.shape().dims, [3, 4]);
+    grad.to_data().assert_approx_eq(
+        &Data::from([
+            [[0.5; 4], [0.5; 4], [0.5; 4]],
+            [[0.5; 4], [0.5; 4], [0.5; 4]],
+        ]),
+        3,
+    );
+}
+
+fn data_2x3x4() -> Data<f32, 3> {
+    Data::from([
+        [
+            [0.0, 1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0, 7.0],
+            [8.0, 9.0, 10.0, 11.0],
+        ],
+        [
+            [12.0, 13.0, 14.0, 15.0],
+            [16.0, 17.0, 18.0, 19.0],
+            [20.0, 21.0, 22.0, 23.0],
+        ],
+    ])
+}
+
+#[test]
+fn test_prod_grad() {
+    let data = Data::from([[1.0, 2.0], [3.0, 4.0]]);
+    let tensor = Tensor::<TestADBackend, 2>::from_data(data);
+
+    let grads = tensor.prod().backward();
+    let grad = tensor.grad(&grads).unwrap();
+
+    assert_eq!(grad.to_data(), Data::from([[24.0, 12.0], [8.0, 6.0]]));
+}
+
+#[test]
+fn test_prod_grad_with_a_zero() {
+    let data = Data::from([[1.0, 0.0], [3.0, 4.0]]);
+    let tensor = Tensor::<TestADBackend, 2>::from_data(data);
+
+    let grads = tensor.prod().backward();
+    let grad = tensor.grad(&grads).unwrap();
+
+    assert_eq!(grad.to_data(), Data::from([[0.0, 12.0], [0.0, 0.0]]));
+}
+
+#[test]
+fn test_prod_dim_grad_with_zeros_in_the_reduced_dim() {
+    let data = Data::from([[2.0, 0.0, 3.0], [1.0, 4.0, 5.0], [0.0, 2.0, 0.0]]);
+    let tensor = Tensor::<TestADBackend, 2>::from_data(data);
+
+    let tensor_out = tensor.prod_dim(1);
+    let grads = tensor_out.sum().backward();
+    let grad = tensor.grad(&grads).unwrap();
+
+    assert_eq!(tensor_out.to_data(), Data::from([[0.0], [20.0], [0.0]]));
+    assert_eq!(
+        grad.to_data(),
+        Data::from([[0.0, 6.0, 0.0], [20.0, 5.0, 4.0], [0.0, 0.0, 0.0]])
+    );
+}
@@ -0,0 +1,30 @@
+use crate::tensor::TestADTensor;
+use burn_tensor::{detect_anomaly, Data};
+
+#[test]
+#[should_panic(expected = "ADTensorLogOps")]
+fn should_panic_with_the_op_producing_nan() {
+    let tensor = TestADTensor::from_data(Data::from([[1.0, -1.0], [2.0, 3.0]]));
+
+    detect_anomaly(|| tensor.log().mul_scalar(2.0).sum().backward());
+}
+
+#[test]
+fn should_not_panic_without_anomaly_detection() {
+    let tensor = TestADTensor::from_data(Data::from([[1.0, -1.0], [2.0, 3.0]]));
+
+    let grads = tensor.log().mul_scalar(2.0).sum().backward();
+
+    let grad = tensor.grad(&grads).unwrap();
+    grad.to_data()
+        .assert_approx_eq(&Data::from([[2.0, -2.0], [1.0, 0.6667]]), 3);
+}
+
+#[test]
+fn should_not_panic_when_values_are_finite() {
+    let tensor = TestADTensor::from_data(Data::from([[1.0, 0.5], [2.0, 3.0]]));
+
+    let grads = detect_anomaly(|| tensor.log().mul(&tensor).sum().backward());
+
+    assert!(tensor.grad(&grads).is_some());
+}
@@ -0,0 +1,102 @@
+use crate::tensor::TestADTensor;
+use burn_tensor::{checkpoint, Data};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[test]
+fn should_diff_requested_tensors_like_full_backward() {
+    let [tensor_1, tensor_2, tensor_3] = branchy_graph_leaves();
+    let output = branchy_graph(&tensor_1, &tensor_2, &tensor_3);
+
+    let grads = output.backward();
+    let grads_subset = output.backward_for(&[tensor_1.node_id(), tensor_2.node_id()]);
+
+    assert_eq!(
+        tensor_1.grad(&grads_subset).unwrap().to_data(),
+        tensor_1.grad(&grads).unwrap().to_data()
+    );
+    assert_eq!(
+        tensor_2.grad(&grads_subset).unwrap().to_data(),
+        tensor_2.grad(&grads).unwrap().to_data()
+    );
+    assert!(tensor_3.grad(&grads).is_some());
+    assert!(tensor_3.grad(&grads_subset).is_none());
+}
+
+#[test]
+fn should_skip_branches_not_leading_to_requested_tensors() {
+    let [tensor_1, tensor_2, tensor_3] = branchy_graph_leaves();
+    let output = branchy_graph(&tensor_1, &tensor_2, &tensor_3);
+
+    let grads = output.backward();
+    let grads_subset = output.backward_for(&[tensor_3.node_id()]);
+
+    assert_eq!(
+        tensor_3.grad(&grads_subset).unwrap().to_data(),
+        tensor_3.grad(&grads).unwrap().to_data()
+    );
+    assert!(tensor_1.grad(&grads_subset).is_none());
+    assert!(tensor_2.grad(&grads_subset).is_none());
+}
+
+#[test]
+fn should_diff_requested_tensors_of_different_ranks() {
+    let input = TestADTensor::from_data(Data::from([[1.0, 2.0], [3.0, -1.0]]));
+    let weight = TestADTensor::from_data(Data::from([[0.5, -1.0], [2.0, 0.1]]));
+    let bias = TestADTensor::from_data(Data::from([1.0, -2.0]));
+    let output = input.matmul(&weight).add(&bias.unsqueeze()).mul(&input);
+
+    let grads = output.backward();
+    let grads_subset = output.backward_for(&[weight.node_id(), bias.node_id()]);
+
+    assert_eq!(
+        weight.grad(&grads_subset).unwrap().to_data(),
+        weight.grad(&grads).unwrap().to_data()
+    );
+    assert_eq!(
+        bias.grad(&grads_subset).unwrap().to_data(),
+        bias.grad(&grads).unwrap().to_data()
+    );
+    assert!(input.grad(&grads_subset).is_none());
+}
+
+#[test]
+fn should_not_execute_branches_not_leading_to_requested_tensors() {
+    let [tensor_1, tensor_2, tensor_3] = branchy_graph_leaves();
+    let executions = Arc::new(AtomicUsize::new(0));
+    let executions_captured = executions.clone();
+    // The checkpointed function is executed again only when the gradients flow through it.
+    let right = checkpoint(
+        move |x: TestADTensor<2>| {
+            executions_captured.fetch_add(1, Ordering::Relaxed);
+            x.exp().mul(&x)
+        },
+        &tensor_3,
+    );
+    let output = tensor_1.matmul(&tensor_2).add(&right);
+
+    output.backward_for(&[tensor_1.node_id()]);
+    assert_eq!(executions.load(Ordering::Relaxed), 1);
+
+    output.backward_for(&[tensor_3.node_id()]);
+    assert_eq!(executions.load(Ordering::Relaxed), 2);
+}
+
+fn branchy_graph_leaves() -> [TestADTensor<2>; 3] {
+    [
+        TestADTensor::from_data(Data::from([[1.0, 7.0], [2.0, 3.0]])),
+        TestADTensor::from_data(Data::from([[4.0, 7.0], [2.0, 3.0]])),
+        TestADTensor::from_data(Data::from([[0.5, -1.0], [2.0, 0.1]])),
+    ]
+}
+
+fn branchy_graph(
+    tensor_1: &TestADTensor<2>,
+    tensor_2: &TestADTensor<2>,
+    tensor_3: &TestADTensor<2>,
+) -> TestADTensor<2> {
+    let left = tensor_1.matmul(tensor_2).mul(tensor_1);
+    let right = tensor_3.exp().mul(tensor_3);
+
+    left.add(&right).add(&tensor_1.mul(tensor_3))
+}
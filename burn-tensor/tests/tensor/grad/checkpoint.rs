@@ -0,0 +1,67 @@
+use crate::tensor::TestADTensor;
+use burn_tensor::{activation, checkpoint, Data};
+
+#[test]
+fn should_diff_checkpoint_like_without_checkpoint() {
+    let data_1: Data<f32, 2> = Data::from([[1.0, -2.0], [3.0, 0.5]]);
+    let data_2: Data<f32, 2> = Data::from([[0.5, 1.5], [-1.0, 2.0]]);
+
+    let input = TestADTensor::from_data(data_1.clone());
+    let weights = TestADTensor::from_data(data_2.clone());
+    let output = block(&input.mul_scalar(2.0), &weights);
+    let grads = output.mul(&output).sum().backward();
+
+    let input_checkpoint = TestADTensor::from_data(data_1);
+    let weights_checkpoint = TestADTensor::from_data(data_2);
+    let weights_captured = weights_checkpoint.clone();
+    let output_checkpoint = checkpoint(
+        move |x| block(&x, &weights_captured),
+        &input_checkpoint.mul_scalar(2.0),
+    );
+    let grads_checkpoint = output_checkpoint.mul(&output_checkpoint).sum().backward();
+
+    output
+        .to_data()
+        .assert_approx_eq(&output_checkpoint.to_data(), 3);
+    input.grad(&grads).unwrap().to_data().assert_approx_eq(
+        &input_checkpoint.grad(&grads_checkpoint).unwrap().to_data(),
+        3,
+    );
+    weights.grad(&grads).unwrap().to_data().assert_approx_eq(
+        &weights_checkpoint
+            .grad(&grads_checkpoint)
+            .unwrap()
+            .to_data(),
+        3,
+    );
+}
+
+#[test]
+fn should_diff_parameter_used_inside_and_outside_checkpoint() {
+    let data_1: Data<f32, 2> = Data::from([[1.0, -2.0], [3.0, 0.5]]);
+    let data_2: Data<f32, 2> = Data::from([[0.5, 1.5], [-1.0, 2.0]]);
+
+    let input = TestADTensor::from_data(data_1.clone());
+    let weights = TestADTensor::from_data(data_2.clone());
+    let output = block(&input, &weights).matmul(&weights);
+    let grads = output.sum().backward();
+
+    let input_checkpoint = TestADTensor::from_data(data_1);
+    let weights_checkpoint = TestADTensor::from_data(data_2);
+    let weights_captured = weights_checkpoint.clone();
+    let output_checkpoint = checkpoint(move |x| block(&x, &weights_captured), &input_checkpoint)
+        .matmul(&weights_checkpoint);
+    let grads_checkpoint = output_checkpoint.sum().backward();
+
+    weights.grad(&grads).unwrap().to_data().assert_approx_eq(
+        &weights_checkpoint
+            .grad(&grads_checkpoint)
+            .unwrap()
+            .to_data(),
+        3,
+    );
+}
+
+fn block(x: &TestADTensor<2>, weights: &TestADTensor<2>) -> TestADTensor<2> {
+    activation::tanh(&x.matmul(weights)).matmul(weights)
+}
@@ -0,0 +1,56 @@
+use crate::tensor::TestADTensor;
+use burn_tensor::{Data, Tensor};
+
+#[test]
+fn should_diff_chunk_and_reassemble() {
+    let data: Data<f32, 2> = Data::from([
+        [0.0, 1.0, 2.0, 3.0],
+        [4.0, 5.0, 6.0, 7.0],
+        [8.0, 9.0, 10.0, 11.0],
+        [12.0, 13.0, 14.0, 15.0],
+        [16.0, 17.0, 18.0, 19.0],
+        [20.0, 21.0, 22.0, 23.0],
+    ]);
+    let tensor = TestADTensor::from_data(data);
+
+    let chunks: Vec<_> = tensor
+        .chunk(3, 0)
+        .into_iter()
+        .enumerate()
+        .map(|(i, chunk)| chunk.mul_scalar((i + 1) as f32))
+        .collect();
+    let grads = Tensor::cat(chunks, 0).sum().backward();
+
+    let grad = tensor.grad(&grads).unwrap();
+    assert_eq!(
+        grad.to_data(),
+        Data::from([
+            [1.0, 1.0, 1.0, 1.0],
+            [1.0, 1.0, 1.0, 1.0],
+            [2.0, 2.0, 2.0, 2.0],
+            [2.0, 2.0, 2.0, 2.0],
+            [3.0, 3.0, 3.0, 3.0],
+            [3.0, 3.0, 3.0, 3.0],
+        ])
+    );
+}
+
+#[test]
+fn should_diff_iter_dim() {
+    let data: Data<f32, 2> = Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    let tensor = TestADTensor::from_data(data);
+
+    let grads = tensor
+        .iter_dim(1)
+        .enumerate()
+        .map(|(i, item)| item.mul_scalar(i as f32).sum())
+        .reduce(|acc, item| acc.add(&item))
+        .unwrap()
+        .backward();
+
+    let grad = tensor.grad(&grads).unwrap();
+    assert_eq!(
+        grad.to_data(),
+        Data::from([[0.0, 1.0, 2.0], [0.0, 1.0, 2.0]])
+    );
+}
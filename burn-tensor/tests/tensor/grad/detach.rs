@@ -0,0 +1,35 @@
+use crate::tensor::TestADTensor;
+use burn_tensor::Data;
+
+#[test]
+fn should_keep_the_value_of_the_source() {
+    let data = Data::<f32, 2>::from([[1.0, 7.0], [2.0, 3.0]]);
+    let tensor = TestADTensor::from_data(data).mul_scalar(2.0);
+
+    let detached = tensor.clone().detach();
+
+    assert_eq!(detached.to_data(), tensor.to_data());
+}
+
+#[test]
+fn should_not_diff_through_detached_tensor() {
+    let data = Data::<f32, 2>::from([[1.0, 7.0], [2.0, 3.0]]);
+    let tensor = TestADTensor::from_data(data);
+
+    let detached = tensor.mul_scalar(2.0).detach();
+    let grads = detached.mul_scalar(3.0).sum().backward();
+
+    assert!(tensor.grad(&grads).is_none());
+}
+
+#[test]
+fn should_only_diff_through_attached_operand() {
+    let data = Data::<f32, 2>::from([[1.0, 7.0], [2.0, 3.0]]);
+    let tensor = TestADTensor::from_data(data);
+
+    let detached = tensor.mul_scalar(2.0).detach();
+    let grads = tensor.mul(&detached).sum().backward();
+
+    let grad = tensor.grad(&grads).unwrap();
+    assert_eq!(grad.to_data(), Data::from([[2.0, 14.0], [4.0, 6.0]]));
+}
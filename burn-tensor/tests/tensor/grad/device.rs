@@ -0,0 +1,34 @@
+use crate::tensor::TestADTensor;
+use burn_tensor::Data;
+
+#[test]
+fn should_diff_to_device() {
+    let tensor_1 = TestADTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+    let tensor_2 = TestADTensor::from_data(Data::from([[4.0, 3.0], [2.0, 1.0]]));
+
+    let tensor_3 = tensor_1.to_device(tensor_1.device()).mul(&tensor_2);
+    let grads = tensor_3.sum().backward();
+
+    let grad_1 = tensor_1.grad(&grads).unwrap();
+    assert_eq!(grad_1.into_data(), Data::from([[4.0, 3.0], [2.0, 1.0]]));
+}
+
+#[cfg(feature = "cuda")]
+mod cuda {
+    use burn_tensor::backend::{TchADBackend, TchDevice};
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_diff_to_device_back_to_the_source_device() {
+        let data = Data::from([[1.0, 2.0], [3.0, 4.0]]);
+        let tensor_cpu = Tensor::<TchADBackend<f32>, 2>::from_data_device(data, TchDevice::cpu());
+
+        let tensor_cuda = tensor_cpu.to_device(TchDevice::cuda(0));
+        let tensor_out = tensor_cuda.mul(&tensor_cuda).to_device(TchDevice::cpu());
+        let grads = tensor_out.sum().backward();
+
+        let grad = tensor_cpu.grad(&grads).unwrap();
+        assert_eq!(grad.device(), TchDevice::cpu());
+        assert_eq!(grad.into_data(), Data::from([[2.0, 4.0], [6.0, 8.0]]));
+    }
+}
@@ -0,0 +1,33 @@
+use crate::tensor::TestADTensor;
+use burn_tensor::Data;
+
+#[test]
+fn should_diff_diagonal_with_zeros_outside_diagonal() {
+    let data: Data<f32, 2> = Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    let weights: Data<f32, 1> = Data::from([2.0, -3.0]);
+    let tensor = TestADTensor::from_data(data);
+    let weights = TestADTensor::from_data(weights);
+
+    let grads = tensor.diagonal::<1>().mul(&weights).sum().backward();
+
+    let grad = tensor.grad(&grads).unwrap();
+    let grad_weights = weights.grad(&grads).unwrap();
+    assert_eq!(
+        grad.to_data(),
+        Data::from([[2.0, 0.0, 0.0], [0.0, -3.0, 0.0]])
+    );
+    assert_eq!(grad_weights.to_data(), Data::from([1.0, 5.0]));
+}
+
+#[test]
+fn should_diff_diag_embed_from_diagonal_positions() {
+    let data: Data<f32, 1> = Data::from([1.0, 2.0]);
+    let weights: Data<f32, 2> = Data::from([[3.0, 4.0], [5.0, 6.0]]);
+    let tensor = TestADTensor::from_data(data);
+    let weights = TestADTensor::from_data(weights);
+
+    let grads = tensor.diag_embed::<2>().mul(&weights).sum().backward();
+
+    let grad = tensor.grad(&grads).unwrap();
+    assert_eq!(grad.to_data(), Data::from([3.0, 6.0]));
+}
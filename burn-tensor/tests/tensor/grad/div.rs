@@ -84,3 +84,47 @@ fn test_div_complex_2() {
         .to_data()
         .assert_approx_eq(&Data::from([[0.0833, 0.0959], [-0.0556, -0.0671]]), 3);
 }
+
+#[test]
+fn should_diff_div_safe_like_div_with_large_denominator() {
+    let data_1 = Data::from([1.0, 7.0]);
+    let data_2 = Data::from([400.0, 700.0]);
+
+    let tensor_1 = TestADTensor::from_data(data_1);
+    let tensor_2 = TestADTensor::from_data(data_2);
+
+    let grads_safe = tensor_1.div_safe(&tensor_2, 1e-5).backward();
+    let grads = tensor_1.div(&tensor_2).backward();
+
+    tensor_1
+        .grad(&grads_safe)
+        .unwrap()
+        .to_data()
+        .assert_approx_eq(&tensor_1.grad(&grads).unwrap().to_data(), 6);
+    tensor_2
+        .grad(&grads_safe)
+        .unwrap()
+        .to_data()
+        .assert_approx_eq(&tensor_2.grad(&grads).unwrap().to_data(), 6);
+}
+
+#[test]
+fn should_diff_div_safe_with_finite_gradients_near_zero() {
+    let data_1 = Data::from([1.0, -2.0]);
+    let data_2 = Data::from([0.0, 1e-9]);
+
+    let tensor_1 = TestADTensor::from_data(data_1);
+    let tensor_2 = TestADTensor::from_data(data_2);
+
+    let grads = tensor_1.div_safe(&tensor_2, 1e-2).backward();
+
+    let grad_1 = tensor_1.grad(&grads).unwrap();
+    let grad_2 = tensor_2.grad(&grads).unwrap();
+
+    grad_1
+        .to_data()
+        .assert_approx_eq(&Data::from([100.0, 100.0]), 3);
+    grad_2
+        .to_data()
+        .assert_approx_eq(&Data::from([-10000.0, 20000.0]), 0);
+}
@@ -0,0 +1,21 @@
+use crate::tensor::{check_grad, TestADTensor};
+use burn_tensor::Data;
+
+#[test]
+fn should_diff_erf_like_finite_differences() {
+    let tensor = TestADTensor::from_data(Data::from([-2.0, -0.7, -0.001, 0.0, 0.001, 0.3, 1.5]));
+
+    check_grad(|tensor| tensor.erf(), &tensor, 1e-3);
+}
+
+#[test]
+fn should_diff_erf_at_zero() {
+    let tensor = TestADTensor::from_data(Data::from([0.0]));
+
+    let grads = tensor.erf().mul_scalar(3.0).backward();
+
+    let grad = tensor.grad(&grads).unwrap();
+    let grad_expected = 3.0 * 2.0 / std::f32::consts::PI.sqrt();
+    grad.into_data()
+        .assert_approx_eq(&Data::from([grad_expected]), 4);
+}
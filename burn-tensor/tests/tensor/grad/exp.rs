@@ -0,0 +1,30 @@
+use crate::tensor::TestADTensor;
+use burn_tensor::Data;
+
+#[test]
+fn should_diff_exp_to_forward_output() {
+    let data = Data::from([[0.0, 1.0, -2.0], [3.0, -4.0, 5.5]]);
+    let tensor = TestADTensor::from_data(data);
+
+    let output = tensor.exp();
+    let grads = output.backward();
+
+    let grad = tensor.grad(&grads).unwrap();
+    grad.to_data().assert_approx_eq(&output.to_data(), 3);
+}
+
+#[test]
+fn should_diff_exp_of_large_values_consistently_with_forward() {
+    let data = Data::from([80.0, 100.0]);
+    let tensor = TestADTensor::from_data(data);
+
+    let output = tensor.exp();
+    let grads = output.backward();
+
+    let grad = tensor.grad(&grads).unwrap().into_data();
+    let output = output.into_data();
+    assert!(output.value[0].is_finite());
+    assert_eq!(grad.value[0], output.value[0]);
+    assert_eq!(output.value[1], f32::INFINITY);
+    assert_eq!(grad.value[1], f32::INFINITY);
+}
@@ -0,0 +1,36 @@
+use crate::tensor::TestADTensor;
+use burn_tensor::Data;
+
+#[test]
+fn should_diff_flip() {
+    let data: Data<f32, 2> = Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    let weights: Data<f32, 2> = Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+    let tensor = TestADTensor::from_data(data);
+    let weights = TestADTensor::from_data(weights);
+
+    let grads = tensor.flip(&[1]).mul(&weights).sum().backward();
+
+    let grad = tensor.grad(&grads).unwrap();
+    assert_eq!(
+        grad.to_data(),
+        Data::from([[3.0, 2.0, 1.0], [6.0, 5.0, 4.0]])
+    );
+}
+
+#[test]
+fn should_diff_flip_multiple_dims() {
+    let data: Data<f32, 2> = Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    let weights: Data<f32, 2> = Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+    let tensor = TestADTensor::from_data(data);
+    let weights = TestADTensor::from_data(weights);
+
+    let grads = tensor.flip(&[0, 1]).mul(&weights).sum().backward();
+
+    let grad = tensor.grad(&grads).unwrap();
+    assert_eq!(
+        grad.to_data(),
+        Data::from([[6.0, 5.0, 4.0], [3.0, 2.0, 1.0]])
+    );
+}
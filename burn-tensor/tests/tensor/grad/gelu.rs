@@ -0,0 +1,9 @@
+use crate::tensor::{check_grad, TestADTensor};
+use burn_tensor::{activation, Data};
+
+#[test]
+fn should_diff_gelu() {
+    let tensor = TestADTensor::from_data(Data::from([-2.0, -1.0, -0.3, 0.0, 0.5, 1.0, 2.5]));
+
+    check_grad(activation::gelu, &tensor, 1e-3);
+}
@@ -0,0 +1,77 @@
+use crate::tensor::TestADTensor;
+use burn_tensor::{Data, Shape, Tensor};
+
+#[test]
+fn should_sum_merged_gradients_of_two_backward_passes() {
+    let weight = TestADTensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+    let input_1 = TestADTensor::from_data(Data::from([[1.0, -1.0]]));
+    let input_2 = TestADTensor::from_data(Data::from([[2.0, 0.5]]));
+
+    let mut grads = input_1.matmul(&weight).sum().backward();
+    let grads_2 = input_2.matmul(&weight).sum().backward();
+    grads.merge(grads_2);
+
+    let grad = weight.grad(&grads).unwrap();
+    assert_eq!(grad.to_data(), Data::from([[3.0, 3.0], [-0.5, -0.5]]));
+
+    let mut weight_updated = weight.inner();
+    weight_updated.sub_assign(&grad.mul_scalar(0.5));
+    assert_eq!(
+        weight_updated.into_data(),
+        Data::from([[-0.5, 0.5], [3.25, 4.25]])
+    );
+}
+
+#[test]
+fn should_keep_gradients_only_present_in_one_side_when_merging() {
+    let tensor_1 = TestADTensor::from_data(Data::from([1.0, 2.0]));
+    let tensor_2 = TestADTensor::from_data(Data::from([3.0, 4.0]));
+
+    let mut grads = tensor_1.mul_scalar(2.0).backward();
+    grads.merge(tensor_2.mul_scalar(3.0).backward());
+
+    assert_eq!(
+        tensor_1.grad(&grads).unwrap().into_data(),
+        Data::from([2.0, 2.0])
+    );
+    assert_eq!(
+        tensor_2.grad(&grads).unwrap().into_data(),
+        Data::from([3.0, 3.0])
+    );
+}
+
+#[test]
+fn should_zero_all_gradients() {
+    let tensor_1 = TestADTensor::from_data(Data::from([[1.0, 7.0], [13.0, -3.0]]));
+    let tensor_2 = TestADTensor::from_data(Data::from([[4.0, 7.0], [2.0, 3.0]]));
+
+    let mut grads = tensor_1.matmul(&tensor_2).backward();
+    grads.zero();
+
+    assert_eq!(
+        tensor_1.grad(&grads).unwrap().into_data(),
+        Data::zeros(Shape::new([2, 2]))
+    );
+    assert_eq!(
+        tensor_2.grad(&grads).unwrap().into_data(),
+        Data::zeros(Shape::new([2, 2]))
+    );
+}
+
+#[test]
+fn should_replace_the_gradient_of_a_tensor() {
+    let tensor_1 = TestADTensor::from_data(Data::from([1.0, 2.0]));
+    let tensor_2 = TestADTensor::from_data(Data::from([3.0, 4.0]));
+    let mut grads = tensor_1.mul(&tensor_2).backward();
+
+    tensor_1.grad_replace(&mut grads, Tensor::from_data(Data::from([5.0, 6.0])));
+
+    assert_eq!(
+        tensor_1.grad(&grads).unwrap().into_data(),
+        Data::from([5.0, 6.0])
+    );
+    assert_eq!(
+        tensor_2.grad(&grads).unwrap().into_data(),
+        Data::from([1.0, 2.0])
+    );
+}
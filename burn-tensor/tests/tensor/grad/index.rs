@@ -72,3 +72,44 @@ fn should_diff_matmul_with_index_assign_complex() {
     assert_eq!(grad_1.to_data(), Data::from([[85.0, 65.0], [118.0, 82.0]]));
     assert_eq!(grad_2.to_data(), Data::from([[88.0, 15.0], [24.0, 50.0]]));
 }
+
+#[test]
+fn should_diff_index_assign_with_broadcasted_row() {
+    let data: Data<f32, 2> = Data::from([
+        [1.0, 2.0, 3.0],
+        [4.0, 5.0, 6.0],
+        [7.0, 8.0, 9.0],
+        [10.0, 11.0, 12.0],
+        [13.0, 14.0, 15.0],
+    ]);
+    let weights: Data<f32, 2> = Data::from([
+        [1.0, 2.0, 3.0],
+        [4.0, 5.0, 6.0],
+        [7.0, 8.0, 9.0],
+        [10.0, 11.0, 12.0],
+        [13.0, 14.0, 15.0],
+    ]);
+    let row: Data<f32, 2> = Data::from([[1.0, -1.0, 2.0]]);
+
+    let tensor = TestADTensor::from_data(data);
+    let weights = TestADTensor::from_data(weights);
+    let row = TestADTensor::from_data(row);
+
+    let output = tensor.index_assign([1..5, 0..3], &row).mul(&weights);
+    let grads = output.sum().backward();
+
+    let grad_tensor = tensor.grad(&grads).unwrap();
+    let grad_row = row.grad(&grads).unwrap();
+
+    assert_eq!(
+        grad_tensor.to_data(),
+        Data::from([
+            [1.0, 2.0, 3.0],
+            [0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0]
+        ])
+    );
+    assert_eq!(grad_row.to_data(), Data::from([[34.0, 38.0, 42.0]]));
+}
@@ -0,0 +1,28 @@
+use crate::tensor::{TestADBackend, TestADTensor};
+use burn_tensor::{backend::Backend, Data, Tensor};
+
+#[test]
+fn should_accumulate_grad_on_repeated_indexes() {
+    let data_1 = Data::<f32, 2>::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+    let data_2 = Data::<f32, 2>::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+    let indexes =
+        Tensor::<<TestADBackend as Backend>::IntegerBackend, 1>::from_data(Data::from([2, 0, 2]));
+
+    let tensor_1 = TestADTensor::from_data(data_1);
+    let tensor_2 = TestADTensor::from_data(data_2);
+
+    let tensor_3 = tensor_1.index_select(0, &indexes).mul(&tensor_2);
+    let grads = tensor_3.backward();
+
+    let grad_1 = tensor_1.grad(&grads).unwrap();
+    let grad_2 = tensor_2.grad(&grads).unwrap();
+
+    assert_eq!(
+        grad_1.to_data(),
+        Data::from([[3.0, 4.0], [0.0, 0.0], [6.0, 8.0]])
+    );
+    assert_eq!(
+        grad_2.to_data(),
+        Data::from([[5.0, 6.0], [1.0, 2.0], [5.0, 6.0]])
+    );
+}
@@ -0,0 +1,16 @@
+use crate::tensor::{check_grad, TestADTensor};
+use burn_tensor::Data;
+
+#[test]
+fn should_diff_log_like_finite_differences() {
+    let tensor = TestADTensor::from_data(Data::from([[0.1, 1.0], [3.0, 42.0]]));
+
+    check_grad(|tensor| tensor.log(), &tensor, 1e-3);
+}
+
+#[test]
+fn should_diff_log1p_like_finite_differences() {
+    let tensor = TestADTensor::from_data(Data::from([[1e-10, 0.5], [3.0, -0.5]]));
+
+    check_grad(|tensor| tensor.log1p(), &tensor, 1e-3);
+}
@@ -21,3 +21,22 @@ fn should_diff_mask() {
     assert_eq!(grad_1.to_data(), Data::from([[7.0, 3.0], [4.0, 2.0]]));
     assert_eq!(grad_2.to_data(), Data::from([[2.0, 1.0], [3.0, 7.0]]));
 }
+
+#[test]
+fn should_diff_mask_fill_with_zero_gradient_at_masked_positions() {
+    let data = Data::<f32, 2>::from([[1.0, -2.0, 3.0, -4.0], [5.0, -6.0, 7.0, -8.0]]);
+    let weights = Data::<f32, 2>::from([[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]]);
+    let mask = Data::<bool, 2>::from([[false, true, false, true], [true, false, true, false]]);
+
+    let tensor = TestADTensor::from_data(data);
+    let weights = TestADTensor::from_data(weights);
+    let mask = BoolTensor::from_data(mask);
+
+    let grads = tensor.mask_fill(&mask, 0.0).mul(&weights).sum().backward();
+
+    let grad = tensor.grad(&grads).unwrap();
+    assert_eq!(
+        grad.to_data(),
+        Data::from([[1.0, 0.0, 3.0, 0.0], [0.0, 6.0, 0.0, 8.0]])
+    );
+}
@@ -73,3 +73,48 @@ fn test_matmul_complex_2() {
         Data::from([[264., 264.0], [344.0, 344.0]])
     );
 }
+
+#[test]
+fn should_diff_matmul_batched() {
+    let data_1 = Data::from([[[1.0, 7.0], [2.0, 3.0]], [[0.0, 1.0], [-1.0, 2.0]]]);
+    let data_2 = Data::from([[[4.0, 7.0], [2.0, 3.0]], [[1.0, 0.0], [2.0, 1.0]]]);
+
+    let tensor_1 = TestADTensor::from_data(data_1);
+    let tensor_2 = TestADTensor::from_data(data_2);
+
+    let tensor_3 = tensor_1.matmul(&tensor_2);
+    let grads = tensor_3.backward();
+
+    let grad_1 = tensor_1.grad(&grads).unwrap();
+    let grad_2 = tensor_2.grad(&grads).unwrap();
+
+    assert_eq!(
+        grad_1.to_data(),
+        Data::from([[[11.0, 5.0], [11.0, 5.0]], [[1.0, 3.0], [1.0, 3.0]]])
+    );
+    assert_eq!(
+        grad_2.to_data(),
+        Data::from([[[3.0, 3.0], [10.0, 10.0]], [[-1.0, -1.0], [3.0, 3.0]]])
+    );
+}
+
+#[test]
+fn should_diff_matmul_broadcast_batch() {
+    let data_1 = Data::from([[[1.0, 7.0], [2.0, 3.0]], [[0.0, 1.0], [-1.0, 2.0]]]);
+    let data_2 = Data::from([[[4.0, 7.0], [2.0, 3.0]]]);
+
+    let tensor_1 = TestADTensor::from_data(data_1);
+    let tensor_2 = TestADTensor::from_data(data_2);
+
+    let tensor_3 = tensor_1.matmul(&tensor_2);
+    let grads = tensor_3.backward();
+
+    let grad_1 = tensor_1.grad(&grads).unwrap();
+    let grad_2 = tensor_2.grad(&grads).unwrap();
+
+    assert_eq!(
+        grad_1.to_data(),
+        Data::from([[[11.0, 5.0], [11.0, 5.0]], [[11.0, 5.0], [11.0, 5.0]]])
+    );
+    assert_eq!(grad_2.to_data(), Data::from([[[2.0, 2.0], [13.0, 13.0]]]));
+}
@@ -0,0 +1,62 @@
+use crate::tensor::TestADTensor;
+use burn_tensor::Data;
+
+#[test]
+fn should_diff_maximum() {
+    let data_1 = Data::<f32, 2>::from([[1.0, 5.0], [3.0, -2.0]]);
+    let data_2 = Data::<f32, 2>::from([[4.0, 2.0], [3.0, 0.0]]);
+    let weights = Data::<f32, 2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+    let tensor_1 = TestADTensor::from_data(data_1);
+    let tensor_2 = TestADTensor::from_data(data_2);
+    let weights = TestADTensor::from_data(weights);
+
+    let grads = tensor_1.maximum(&tensor_2).mul(&weights).sum().backward();
+
+    let grad_1 = tensor_1.grad(&grads).unwrap();
+    let grad_2 = tensor_2.grad(&grads).unwrap();
+
+    assert_eq!(grad_1.to_data(), Data::from([[0.0, 2.0], [1.5, 0.0]]));
+    assert_eq!(grad_2.to_data(), Data::from([[1.0, 0.0], [1.5, 4.0]]));
+}
+
+#[test]
+fn should_diff_minimum() {
+    let data_1 = Data::<f32, 2>::from([[1.0, 5.0], [3.0, -2.0]]);
+    let data_2 = Data::<f32, 2>::from([[4.0, 2.0], [3.0, 0.0]]);
+    let weights = Data::<f32, 2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+    let tensor_1 = TestADTensor::from_data(data_1);
+    let tensor_2 = TestADTensor::from_data(data_2);
+    let weights = TestADTensor::from_data(weights);
+
+    let grads = tensor_1.minimum(&tensor_2).mul(&weights).sum().backward();
+
+    let grad_1 = tensor_1.grad(&grads).unwrap();
+    let grad_2 = tensor_2.grad(&grads).unwrap();
+
+    assert_eq!(grad_1.to_data(), Data::from([[1.0, 0.0], [1.5, 4.0]]));
+    assert_eq!(grad_2.to_data(), Data::from([[0.0, 2.0], [1.5, 0.0]]));
+}
+
+#[test]
+fn should_diff_maximum_scalar() {
+    let data = Data::<f32, 2>::from([[1.0, 2.0], [3.0, 4.0]]);
+    let tensor = TestADTensor::from_data(data);
+
+    let grads = tensor.maximum_scalar(2.0).sum().backward();
+
+    let grad = tensor.grad(&grads).unwrap();
+    assert_eq!(grad.to_data(), Data::from([[0.0, 0.5], [1.0, 1.0]]));
+}
+
+#[test]
+fn should_diff_minimum_scalar() {
+    let data = Data::<f32, 2>::from([[1.0, 2.0], [3.0, 4.0]]);
+    let tensor = TestADTensor::from_data(data);
+
+    let grads = tensor.minimum_scalar(2.0).sum().backward();
+
+    let grad = tensor.grad(&grads).unwrap();
+    assert_eq!(grad.to_data(), Data::from([[1.0, 0.5], [0.0, 0.0]]));
+}
@@ -1,13 +1,44 @@
+mod abs;
 mod add;
 mod aggregation;
+mod anomaly;
+mod backward_for;
+mod checkpoint;
+mod chunk;
 mod cross_entropy;
+mod detach;
+mod device;
+mod diagonal;
 mod div;
+mod erf;
+mod exp;
+mod expand;
+mod flip;
+mod gelu;
+mod gradients;
 mod index;
+mod index_select;
+mod log;
 mod mask;
 mod matmul;
+mod min_max;
 mod mul;
+mod narrow;
 mod neg;
+mod no_grad;
+mod pad;
+mod permute;
+mod pow;
+mod quantile;
+mod reciprocal;
+mod repeat;
+mod require_grad;
 mod reshape;
+mod roll;
+mod rounding;
+mod select_max;
+mod sigmoid;
 mod softmax;
 mod sub;
 mod transpose;
+mod trigonometry;
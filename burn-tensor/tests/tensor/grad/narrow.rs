@@ -0,0 +1,16 @@
+use crate::tensor::TestADTensor;
+use burn_tensor::Data;
+
+#[test]
+fn should_diff_narrow_with_zero_padded_gradient() {
+    let data: Data<f32, 2> = Data::from([[0.0, 1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0, 9.0]]);
+    let tensor = TestADTensor::from_data(data);
+
+    let grads = tensor.narrow(1, 2, 2).mul_scalar(3.0).sum().backward();
+
+    let grad = tensor.grad(&grads).unwrap();
+    assert_eq!(
+        grad.to_data(),
+        Data::from([[0.0, 0.0, 3.0, 3.0, 0.0], [0.0, 0.0, 3.0, 3.0, 0.0]])
+    );
+}
@@ -1,4 +1,4 @@
-use crate::tensor::TestADTensor;
+use crate::tensor::{check_grad, TestADTensor};
 use burn_tensor::Data;
 
 #[test]
@@ -19,3 +19,10 @@ fn should_diff_neg() {
     assert_eq!(grad_1.to_data(), Data::from([[11.0, 5.0], [11.0, 5.0]]));
     assert_eq!(grad_2.to_data(), Data::from([[3.0, 3.0], [10.0, 10.0]]));
 }
+
+#[test]
+fn should_diff_neg_like_finite_differences() {
+    let tensor = TestADTensor::from_data(Data::from([[1.0, 7.0], [2.0, 3.0]]));
+
+    check_grad(|tensor| tensor.neg().mul(tensor), &tensor, 1e-2);
+}
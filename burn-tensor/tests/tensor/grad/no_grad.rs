@@ -0,0 +1,31 @@
+use crate::tensor::TestADTensor;
+use burn_tensor::{no_grad, Data};
+
+#[test]
+fn should_not_compute_grads_inside_no_grad() {
+    let tensor_1 = TestADTensor::from_data(Data::from([[1.0, 7.0], [2.0, 3.0]]));
+    let tensor_2 = TestADTensor::from_data(Data::from([[4.0, 7.0], [2.0, 3.0]]));
+
+    let tensor_3 = no_grad(|| tensor_1.matmul(&tensor_2));
+    let grads = tensor_3.backward();
+
+    assert!(tensor_1.grad(&grads).is_none());
+    assert!(tensor_2.grad(&grads).is_none());
+    assert_eq!(
+        tensor_3.into_data(),
+        Data::from([[18.0, 28.0], [14.0, 23.0]])
+    );
+}
+
+#[test]
+fn should_compute_grads_after_no_grad() {
+    let tensor_1 = TestADTensor::from_data(Data::from([[1.0, 7.0], [2.0, 3.0]]));
+    let tensor_2 = TestADTensor::from_data(Data::from([[4.0, 7.0], [2.0, 3.0]]));
+
+    no_grad(|| no_grad(|| tensor_1.matmul(&tensor_2)));
+    let tensor_3 = tensor_1.matmul(&tensor_2);
+    let grads = tensor_3.backward();
+
+    assert!(tensor_1.grad(&grads).is_some());
+    assert!(tensor_2.grad(&grads).is_some());
+}
@@ -0,0 +1,25 @@
+use crate::tensor::TestADTensor;
+use burn_tensor::Data;
+
+#[test]
+fn should_diff_pad_from_interior_only() {
+    let data: Data<f32, 2> = Data::from([[1.0, 2.0], [3.0, 4.0]]);
+    let weights: Data<f32, 2> = Data::from([
+        [1.0, 2.0, 3.0, 4.0],
+        [5.0, 6.0, 7.0, 8.0],
+        [9.0, 10.0, 11.0, 12.0],
+        [13.0, 14.0, 15.0, 16.0],
+    ]);
+
+    let tensor = TestADTensor::from_data(data);
+    let weights = TestADTensor::from_data(weights);
+
+    let grads = tensor
+        .pad([(1, 1), (1, 1)], 5.0)
+        .mul(&weights)
+        .sum()
+        .backward();
+
+    let grad = tensor.grad(&grads).unwrap();
+    assert_eq!(grad.to_data(), Data::from([[6.0, 7.0], [10.0, 11.0]]));
+}
@@ -0,0 +1,27 @@
+use crate::tensor::TestADTensor;
+use burn_tensor::Data;
+
+#[test]
+fn should_diff_permute() {
+    let data = Data::<f32, 3>::from([
+        [
+            [0.0, 1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0, 7.0],
+            [8.0, 9.0, 10.0, 11.0],
+        ],
+        [
+            [12.0, 13.0, 14.0, 15.0],
+            [16.0, 17.0, 18.0, 19.0],
+            [20.0, 21.0, 22.0, 23.0],
+        ],
+    ]);
+    let tensor = TestADTensor::from_data(data.clone());
+    let weights = TestADTensor::from_data(tensor.permute([2, 0, 1]).to_data());
+
+    let output = tensor.permute([2, 0, 1]).mul(&weights);
+    let grads = output.backward();
+
+    // The gradient is the weights with the inverse permutation applied, which is the input.
+    let grad = tensor.grad(&grads).unwrap();
+    assert_eq!(grad.to_data(), data);
+}
@@ -0,0 +1,26 @@
+use crate::tensor::TestADTensor;
+use burn_tensor::Data;
+
+#[test]
+fn should_diff_powf_square() {
+    let data = Data::from([[0.0, 1.0, -2.0], [3.0, -4.0, 5.5]]);
+    let tensor = TestADTensor::from_data(data);
+
+    let grads = tensor.powf(2.0).backward();
+
+    let grad = tensor.grad(&grads).unwrap();
+    let data_expected = Data::from([[0.0, 2.0, -4.0], [6.0, -8.0, 11.0]]);
+    grad.to_data().assert_approx_eq(&data_expected, 3);
+}
+
+#[test]
+fn should_diff_powi_with_negative_values() {
+    let data = Data::from([[-1.0, 2.0], [-3.0, 0.5]]);
+    let tensor = TestADTensor::from_data(data);
+
+    let grads = tensor.powi(3).backward();
+
+    let grad = tensor.grad(&grads).unwrap();
+    let data_expected = Data::from([[3.0, 12.0], [27.0, 0.75]]);
+    grad.to_data().assert_approx_eq(&data_expected, 3);
+}
@@ -0,0 +1,27 @@
+use crate::tensor::TestADTensor;
+use burn_tensor::Data;
+
+#[test]
+fn should_diff_median_to_middle_elements() {
+    let data: Data<f32, 1> = Data::from([3.0, 1.0, 4.0, 2.0]);
+    let tensor = TestADTensor::from_data(data);
+
+    let grads = tensor.median().mul_scalar(2.0).backward();
+
+    let grad = tensor.grad(&grads).unwrap();
+    assert_eq!(grad.to_data(), Data::from([1.0, 0.0, 0.0, 1.0]));
+}
+
+#[test]
+fn should_diff_quantile_with_interpolation_weights() {
+    let data: Data<f32, 2> = Data::from([[0.0, 4.0, 2.0, 6.0], [7.0, -1.0, 3.0, 1.0]]);
+    let tensor = TestADTensor::from_data(data);
+
+    let grads = tensor.quantile(0.25, 1).sum().backward();
+
+    let grad = tensor.grad(&grads).unwrap();
+    assert_eq!(
+        grad.to_data(),
+        Data::from([[0.25, 0.0, 0.75, 0.0], [0.0, 0.25, 0.0, 0.75]])
+    );
+}
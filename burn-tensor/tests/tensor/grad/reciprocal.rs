@@ -0,0 +1,16 @@
+use crate::tensor::{check_grad, TestADTensor};
+use burn_tensor::Data;
+
+#[test]
+fn should_diff_reciprocal() {
+    let tensor = TestADTensor::from_data(Data::from([-2.0, -0.5, 0.3, 1.2]));
+
+    check_grad(|tensor| tensor.reciprocal(), &tensor, 1e-3);
+}
+
+#[test]
+fn should_diff_rsqrt() {
+    let tensor = TestADTensor::from_data(Data::from([0.3, 0.5, 1.2, 4.0]));
+
+    check_grad(|tensor| tensor.rsqrt(), &tensor, 1e-3);
+}
@@ -0,0 +1,34 @@
+use crate::tensor::TestADTensor;
+use burn_tensor::Data;
+
+#[test]
+fn should_diff_repeat_by_summing_the_copies() {
+    let data_1: Data<f32, 2> = Data::from([[0.0, 1.0], [2.0, 3.0]]);
+    let data_2: Data<f32, 2> = Data::from([
+        [1.0, 2.0],
+        [3.0, 4.0],
+        [5.0, 6.0],
+        [7.0, 8.0],
+        [9.0, 10.0],
+        [11.0, 12.0],
+    ]);
+    let tensor_1 = TestADTensor::from_data(data_1);
+    let tensor_2 = TestADTensor::from_data(data_2);
+
+    let grads = tensor_1.repeat(0, 3).mul(&tensor_2).sum().backward();
+
+    let grad_1 = tensor_1.grad(&grads).unwrap();
+    let grad_2 = tensor_2.grad(&grads).unwrap();
+    assert_eq!(grad_1.to_data(), Data::from([[15.0, 18.0], [21.0, 24.0]]));
+    assert_eq!(
+        grad_2.to_data(),
+        Data::from([
+            [0.0, 1.0],
+            [2.0, 3.0],
+            [0.0, 1.0],
+            [2.0, 3.0],
+            [0.0, 1.0],
+            [2.0, 3.0],
+        ])
+    );
+}
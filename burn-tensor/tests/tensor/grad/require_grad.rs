@@ -0,0 +1,19 @@
+use crate::tensor::TestADTensor;
+use burn_tensor::Data;
+
+#[test]
+fn should_not_register_grad_when_not_required() {
+    let data_1 = Data::<f32, 2>::from([[1.0, 7.0], [2.0, 3.0]]);
+    let data_2 = Data::<f32, 2>::from([[4.0, 7.0], [2.0, 3.0]]);
+
+    let tensor_1 = TestADTensor::from_data(data_1).set_require_grad(false);
+    let tensor_2 = TestADTensor::from_data(data_2);
+
+    let tensor_3 = tensor_1.matmul(&tensor_2);
+    let grads = tensor_3.backward();
+
+    let grad_2 = tensor_2.grad(&grads).unwrap();
+
+    assert!(tensor_1.grad(&grads).is_none());
+    assert_eq!(grad_2.to_data(), Data::from([[3.0, 3.0], [10.0, 10.0]]));
+}
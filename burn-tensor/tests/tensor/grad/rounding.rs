@@ -0,0 +1,65 @@
+use crate::tensor::TestADTensor;
+use burn_tensor::Data;
+
+#[test]
+fn should_diff_rounding_with_zero_gradients() {
+    let data_1 = Data::<f32, 2>::from([[-1.5, 0.5], [2.3, -0.7]]);
+    let data_2 = Data::<f32, 2>::from([[6.0, 7.0], [9.0, -10.0]]);
+
+    let tensor_1 = TestADTensor::from_data(data_1);
+    let tensor_2 = TestADTensor::from_data(data_2);
+
+    let tensor_3 = tensor_1
+        .sign()
+        .add(&tensor_1.round())
+        .add(&tensor_1.floor())
+        .add(&tensor_1.ceil())
+        .add(&tensor_1.trunc())
+        .mul(&tensor_2);
+    let grads = tensor_3.backward();
+
+    let grad_1 = tensor_1.grad(&grads).unwrap();
+    let grad_2 = tensor_2.grad(&grads).unwrap();
+
+    assert_eq!(grad_1.to_data(), Data::from([[0.0, 0.0], [0.0, 0.0]]));
+    assert_eq!(grad_2.to_data(), Data::from([[-7.0, 2.0], [10.0, -3.0]]));
+}
+
+#[test]
+fn should_diff_straight_through_rounding_with_identity_gradients() {
+    let data_1 = Data::<f32, 2>::from([[-1.5, 0.5], [2.3, -0.7]]);
+    let data_2 = Data::<f32, 2>::from([[6.0, 7.0], [9.0, -10.0]]);
+
+    let tensor_1 = TestADTensor::from_data(data_1.clone());
+    let tensor_2 = TestADTensor::from_data(data_2);
+    let tensor_3 = TestADTensor::from_data(data_1);
+
+    let output_ste = tensor_1.round_ste().mul(&tensor_2);
+    let output = tensor_3.round().mul(&tensor_2);
+    let grads_ste = output_ste.backward();
+    let grads = output.backward();
+
+    let grad_ste = tensor_1.grad(&grads_ste).unwrap();
+    let grad = tensor_3.grad(&grads).unwrap();
+
+    assert_eq!(grad_ste.to_data(), Data::from([[6.0, 7.0], [9.0, -10.0]]));
+    assert_eq!(grad.to_data(), Data::from([[0.0, 0.0], [0.0, 0.0]]));
+}
+
+#[test]
+fn should_diff_straight_through_sign_with_identity_gradients() {
+    let data_1 = Data::<f32, 2>::from([[-1.5, 0.5], [2.3, -0.7]]);
+    let data_2 = Data::<f32, 2>::from([[6.0, 7.0], [9.0, -10.0]]);
+
+    let tensor_1 = TestADTensor::from_data(data_1);
+    let tensor_2 = TestADTensor::from_data(data_2);
+
+    let tensor_3 = tensor_1.sign_ste().mul(&tensor_2);
+    let grads = tensor_3.backward();
+
+    let grad_1 = tensor_1.grad(&grads).unwrap();
+    let grad_2 = tensor_2.grad(&grads).unwrap();
+
+    assert_eq!(grad_1.to_data(), Data::from([[6.0, 7.0], [9.0, -10.0]]));
+    assert_eq!(grad_2.to_data(), Data::from([[-1.0, 1.0], [1.0, -1.0]]));
+}
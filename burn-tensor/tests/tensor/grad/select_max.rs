@@ -0,0 +1,24 @@
+use crate::tensor::TestADTensor;
+use burn_tensor::Data;
+
+#[test]
+fn should_diff_select_max() {
+    let data_1 = Data::<f32, 2>::from([[1.0, 7.0, 3.0], [8.0, 2.0, 5.0]]);
+    let data_2 = Data::<f32, 2>::from([[2.0], [3.0]]);
+
+    let tensor_1 = TestADTensor::from_data(data_1);
+    let tensor_2 = TestADTensor::from_data(data_2);
+
+    let tensor_3 = tensor_1.select_max(1).mul(&tensor_2);
+    let grads = tensor_3.backward();
+
+    let grad_1 = tensor_1.grad(&grads).unwrap();
+    let grad_2 = tensor_2.grad(&grads).unwrap();
+
+    assert_eq!(tensor_3.into_data(), Data::from([[14.0], [24.0]]));
+    assert_eq!(
+        grad_1.to_data(),
+        Data::from([[0.0, 2.0, 0.0], [3.0, 0.0, 0.0]])
+    );
+    assert_eq!(grad_2.to_data(), Data::from([[7.0], [8.0]]));
+}
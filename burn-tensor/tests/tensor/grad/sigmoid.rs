@@ -0,0 +1,26 @@
+use crate::tensor::TestADTensor;
+use burn_tensor::{activation, Data};
+
+#[test]
+fn should_diff_sigmoid() {
+    let tensor = TestADTensor::from_data(Data::from([-2.0, 0.0, 0.5, -1000.0, 1000.0]));
+
+    let grads = activation::sigmoid(&tensor).backward();
+
+    // The derivative is sigmoid(x) * (1 - sigmoid(x)).
+    let grad = tensor.grad(&grads).unwrap();
+    grad.to_data()
+        .assert_approx_eq(&Data::from([0.1050, 0.25, 0.2350, 0.0, 0.0]), 3);
+}
+
+#[test]
+fn should_diff_tanh() {
+    let tensor = TestADTensor::from_data(Data::from([-2.0, 0.0, 0.5]));
+
+    let grads = activation::tanh(&tensor).backward();
+
+    // The derivative is 1 - tanh(x)^2.
+    let grad = tensor.grad(&grads).unwrap();
+    grad.to_data()
+        .assert_approx_eq(&Data::from([0.07065, 1.0, 0.78645]), 3);
+}
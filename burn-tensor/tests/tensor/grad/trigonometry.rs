@@ -0,0 +1,39 @@
+use crate::tensor::{check_grad, TestADTensor};
+use burn_tensor::Data;
+
+const POINTS: [f32; 4] = [-2.0, -0.5, 0.3, 1.2];
+
+#[test]
+fn should_diff_sin() {
+    let tensor = TestADTensor::from_data(Data::from(POINTS));
+
+    check_grad(|tensor| tensor.sin(), &tensor, 1e-3);
+}
+
+#[test]
+fn should_diff_cos() {
+    let tensor = TestADTensor::from_data(Data::from(POINTS));
+
+    check_grad(|tensor| tensor.cos(), &tensor, 1e-3);
+}
+
+#[test]
+fn should_diff_tan() {
+    let tensor = TestADTensor::from_data(Data::from(POINTS));
+
+    check_grad(|tensor| tensor.tan(), &tensor, 1e-3);
+}
+
+#[test]
+fn should_diff_tan_near_half_pi_consistently() {
+    let tensor = TestADTensor::from_data(Data::from([std::f32::consts::FRAC_PI_2]));
+
+    let output = tensor.tan();
+    let grads = output.backward();
+
+    let output = output.into_data().value[0];
+    let grad = tensor.grad(&grads).unwrap().into_data().value[0];
+    assert_eq!(output.is_finite(), grad.is_finite());
+    assert!(f32::abs(output) > 1e6);
+    assert_eq!(grad, 1.0 + output * output);
+}
@@ -0,0 +1,35 @@
+use super::TestADTensor;
+use burn_tensor::Data;
+
+/// Step used to estimate the gradient with central differences.
+const EPSILON: f32 = 1e-3;
+
+/// Check the gradient computed by the backward pass of the sum of `func` with respect to
+/// `input` against its estimate with central differences.
+///
+/// Each element of the input is perturbed by `±1e-3`, so the tolerance should account for the
+/// truncation error of the estimate.
+pub fn check_grad<F, const D: usize, const D2: usize>(
+    func: F,
+    input: &TestADTensor<D>,
+    tolerance: f64,
+) where
+    F: Fn(&TestADTensor<D>) -> TestADTensor<D2>,
+{
+    let data = input.to_data();
+    let tensor = TestADTensor::from_data(data.clone());
+
+    let grads = func(&tensor).sum().backward();
+    let grad = tensor.grad(&grads).unwrap().into_data();
+
+    let eval = |index: usize, delta: f32| {
+        let mut data = data.clone();
+        data.value[index] += delta;
+        func(&TestADTensor::from_data(data)).sum().into_data().value[0]
+    };
+    let grad_expected = (0..data.value.len())
+        .map(|index| (eval(index, EPSILON) - eval(index, -EPSILON)) / (2.0 * EPSILON))
+        .collect();
+
+    grad.assert_within_tolerance(&Data::new(grad_expected, data.shape), tolerance);
+}
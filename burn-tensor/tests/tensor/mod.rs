@@ -12,8 +12,21 @@ pub type TestADBackend = burn_tensor::backend::TchADBackend<f32>;
 
 pub type TestADTensor<const D: usize> = burn_tensor::Tensor<TestADBackend, D>;
 
+pub use grad_check::check_grad;
+
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+static RNG: Mutex<()> = Mutex::new(());
+
+/// Lock the random number generator of the backend, which is global, so that the tests seeding
+/// it don't run in parallel with the tests drawing random numbers.
+pub fn lock_rng() -> MutexGuard<'static, ()> {
+    RNG.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
 mod activation;
 mod grad;
+mod grad_check;
 mod module;
 mod ops;
 mod stats;
@@ -22,3 +22,86 @@ fn test_embedding_backward() {
         Data::<<TestADBackend as Backend>::Elem, 2>::from([[3., 9., 7.], [21., 35., 27.]]);
     assert_eq!(grad.to_data(), expected);
 }
+
+#[test]
+fn test_conv1d_backward() {
+    let x = Data::from([[[1.0, 2.0, 3.0, 4.0, 5.0]], [[2.0, 0.0, 1.0, 3.0, 1.0]]]);
+    let weight = Data::from([[[1.0, 2.0]], [[-1.0, 1.0]]]);
+    let output_grad = Data::from([
+        [[1.0, 2.0, 0.0], [3.0, 4.0, 1.0]],
+        [[2.0, 1.0, 1.0], [0.0, 1.0, 2.0]],
+    ]);
+    let x = Tensor::<TestADBackend, 3>::from_data(x);
+    let weight = Tensor::<TestADBackend, 3>::from_data(weight);
+    let output_grad = Tensor::<TestADBackend, 3>::from_data(output_grad);
+
+    let output = module::conv1d(&x, &weight, None, 2, 1, 2);
+    let grads = output.mul(&output_grad).sum().backward();
+
+    let x_grad = x.grad(&grads).unwrap();
+    let weight_grad = weight.grad(&grads).unwrap();
+    assert_eq!(
+        x_grad.to_data(),
+        Data::from([[[0.0, 3.0, 0.0, 7.0, 0.0]], [[0.0, 4.0, 0.0, 2.0, 0.0]]])
+    );
+    assert_eq!(
+        weight_grad.to_data(),
+        Data::from([[[7.0, 13.0]], [[18.0, 25.0]]])
+    );
+}
+
+#[test]
+fn test_avg_pool2d_backward() {
+    let x = Tensor::<TestADBackend, 4>::from_data(Data::from([[[
+        [1.0, 2.0, 3.0, 4.0],
+        [5.0, 6.0, 7.0, 8.0],
+        [9.0, 10.0, 11.0, 12.0],
+        [13.0, 14.0, 15.0, 16.0],
+    ]]]));
+    let output_grad =
+        Tensor::<TestADBackend, 4>::from_data(Data::from([[[[1.0, 2.0], [3.0, 4.0]]]]));
+
+    let output = module::avg_pool2d(&x, [2, 2], [2, 2], [0, 0], true);
+    let grads = output.mul(&output_grad).sum().backward();
+
+    let x_grad = x.grad(&grads).unwrap();
+    let expected = Data::from([[[
+        [0.25, 0.25, 0.5, 0.5],
+        [0.25, 0.25, 0.5, 0.5],
+        [0.75, 0.75, 1.0, 1.0],
+        [0.75, 0.75, 1.0, 1.0],
+    ]]]);
+    assert_eq!(x_grad.to_data(), expected);
+}
+
+#[test]
+fn test_avg_pool2d_backward_exclude_pad() {
+    let x = Tensor::<TestADBackend, 4>::ones([1, 1, 4, 4]);
+
+    let output = module::avg_pool2d(&x, [2, 2], [2, 2], [1, 1], false);
+    let grads = output.sum().backward();
+
+    let x_grad = x.grad(&grads).unwrap();
+    let expected = Data::from([[[
+        [1.0, 0.5, 0.5, 1.0],
+        [0.5, 0.25, 0.25, 0.5],
+        [0.5, 0.25, 0.25, 0.5],
+        [1.0, 0.5, 0.5, 1.0],
+    ]]]);
+    assert_eq!(x_grad.to_data(), expected);
+}
+
+#[test]
+fn test_adaptive_avg_pool2d_backward_global() {
+    let x = Tensor::<TestADBackend, 4>::ones([1, 1, 7, 7]);
+
+    let output = module::adaptive_avg_pool2d(&x, [1, 1]);
+    let grads = output.sum().backward();
+
+    let x_grad = x.grad(&grads).unwrap();
+    let expected = Data::from([1.0 / 49.0; 49]);
+    x_grad
+        .reshape([49])
+        .to_data()
+        .assert_approx_eq(&expected, 5);
+}
@@ -1,5 +1,5 @@
-use super::super::TestBackend;
-use burn_tensor::{backend::Backend, module, Data, Tensor};
+use super::super::{lock_rng, TestBackend};
+use burn_tensor::{backend::Backend, module, Data, Distribution, Tensor};
 
 #[test]
 fn test_embedding_forward() {
@@ -15,3 +15,87 @@ fn test_embedding_forward() {
     ]);
     assert_eq!(output.to_data(), expected);
 }
+
+#[test]
+fn test_conv1d_forward() {
+    let x = Data::from([[[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]]]);
+    let weight = Data::from([[[1.0, 2.0], [0.0, -1.0]], [[1.0, 1.0], [1.0, 1.0]]]);
+    let bias = Data::from([1.0, -1.0]);
+    let x = Tensor::<TestBackend, 3>::from_data(x);
+    let weight = Tensor::<TestBackend, 3>::from_data(weight);
+    let bias = Tensor::<TestBackend, 1>::from_data(bias);
+
+    let output = module::conv1d(&x, &weight, Some(&bias), 1, 1, 1);
+    let expected = Data::from([[[-2.0, 0.0, 2.0, 4.0, 5.0], [5.0, 13.0, 17.0, 21.0, 11.0]]]);
+    assert_eq!(output.to_data(), expected);
+}
+
+#[test]
+fn test_conv1d_forward_dilation() {
+    let x = Data::from([[[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]]]);
+    let weight = Data::from([[[1.0, 2.0], [0.0, -1.0]], [[1.0, 1.0], [1.0, 1.0]]]);
+    let x = Tensor::<TestBackend, 3>::from_data(x);
+    let weight = Tensor::<TestBackend, 3>::from_data(weight);
+
+    let output = module::conv1d(&x, &weight, None, 1, 1, 2);
+    let expected = Data::from([[[-2.0, 0.0, 2.0, 3.0], [8.0, 16.0, 20.0, 10.0]]]);
+    assert_eq!(output.to_data(), expected);
+}
+
+#[test]
+fn test_avg_pool2d_forward() {
+    let x = Data::from([[[
+        [1.0, 2.0, 3.0, 4.0],
+        [5.0, 6.0, 7.0, 8.0],
+        [9.0, 10.0, 11.0, 12.0],
+        [13.0, 14.0, 15.0, 16.0],
+    ]]]);
+    let x = Tensor::<TestBackend, 4>::from_data(x);
+
+    let output = module::avg_pool2d(&x, [2, 2], [2, 2], [0, 0], true);
+    let expected = Data::from([[[[3.5, 5.5], [11.5, 13.5]]]]);
+    assert_eq!(output.to_data(), expected);
+}
+
+#[test]
+fn test_avg_pool2d_forward_padding() {
+    let x = Data::from([[[
+        [1.0, 2.0, 3.0, 4.0],
+        [5.0, 6.0, 7.0, 8.0],
+        [9.0, 10.0, 11.0, 12.0],
+        [13.0, 14.0, 15.0, 16.0],
+    ]]]);
+    let x = Tensor::<TestBackend, 4>::from_data(x);
+
+    let output = module::avg_pool2d(&x, [2, 2], [2, 2], [1, 1], true);
+    let expected = Data::from([[[[0.25, 1.25, 1.0], [3.5, 8.5, 5.0], [3.25, 7.25, 4.0]]]]);
+    assert_eq!(output.to_data(), expected);
+
+    let output = module::avg_pool2d(&x, [2, 2], [2, 2], [1, 1], false);
+    let expected = Data::from([[[[1.0, 2.5, 4.0], [7.0, 8.5, 10.0], [13.0, 14.5, 16.0]]]]);
+    assert_eq!(output.to_data(), expected);
+}
+
+#[test]
+fn test_adaptive_avg_pool2d_forward_global() {
+    let _rng = lock_rng();
+    let x = Tensor::<TestBackend, 4>::random([1, 1, 7, 7], Distribution::Standard);
+
+    let output = module::adaptive_avg_pool2d(&x, [1, 1]);
+
+    assert_eq!(output.shape().dims, [1, 1, 1, 1]);
+    output
+        .reshape([1])
+        .into_data()
+        .assert_within_tolerance(&x.mean().into_data(), 1e-5);
+}
+
+#[test]
+fn test_adaptive_avg_pool2d_forward_overlapping_windows() {
+    let x = Data::from([[[[1.0, 2.0, 3.0, 4.0, 5.0], [6.0, 7.0, 8.0, 9.0, 10.0]]]]);
+    let x = Tensor::<TestBackend, 4>::from_data(x);
+
+    let output = module::adaptive_avg_pool2d(&x, [1, 3]);
+    let expected = Data::from([[[[4.0, 5.5, 7.0]]]]);
+    assert_eq!(output.to_data(), expected);
+}
@@ -21,6 +21,38 @@ fn test_should_sum() {
     assert_eq!(data_actual, Data::from([15.0]));
 }
 
+#[test]
+fn test_should_sum_and_mean_precise_like_full_precision() {
+    let data = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    assert_eq!(tensor.sum_precise().to_data(), tensor.sum().to_data());
+    assert_eq!(tensor.mean_precise().to_data(), tensor.mean().to_data());
+}
+
+#[cfg(feature = "tch")]
+#[test]
+fn test_should_sum_precise_half_precision_without_accumulated_rounding() {
+    use burn_tensor::{backend::TchBackend, f16, Shape};
+
+    let value = f16::from_f32(0.01);
+    let data = Data::new(vec![value; 4096], Shape::new([4096]));
+    let tensor = Tensor::<TchBackend<f16>, 1>::from_data(data.clone());
+    let expected = 4096.0 * value.to_f32();
+
+    // Past 32, adding 0.01 is less than half the spacing between half precision values.
+    let sum_naive = data
+        .value
+        .iter()
+        .fold(f16::from_f32(0.0), |sum, value| sum + *value);
+    let sum_precise = tensor.sum_precise().into_data().value[0];
+    let mean_precise = tensor.mean_precise().into_data().value[0];
+
+    assert_eq!(sum_naive.to_f32(), 32.0);
+    assert!((sum_precise.to_f32() - expected).abs() < 0.05);
+    assert!((mean_precise.to_f32() - value.to_f32()).abs() < 1e-4);
+}
+
 #[test]
 fn test_should_mean_dim() {
     let data = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
@@ -40,3 +72,110 @@ fn test_should_sum_dim() {
 
     assert_eq!(data_actual, Data::from([[3.0], [12.0]]));
 }
+
+#[test]
+fn test_should_prod() {
+    let data = Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let data_actual = tensor.prod().to_data();
+
+    assert_eq!(data_actual, Data::from([720.0]));
+}
+
+#[test]
+fn test_should_prod_dim() {
+    let data = Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    assert_eq!(tensor.prod_dim(1).to_data(), Data::from([[6.0], [120.0]]));
+    assert_eq!(
+        tensor.prod_dim(0).to_data(),
+        Data::from([[4.0, 10.0, 18.0]])
+    );
+}
+
+#[test]
+fn test_should_sum_dims() {
+    let tensor = tensor_2x3x4();
+
+    let data_actual = tensor.sum_dims(&[0, 2]).into_data();
+
+    assert_eq!(data_actual, Data::from([[[60.0], [92.0], [124.0]]]));
+}
+
+#[test]
+fn test_should_mean_dims() {
+    let tensor = tensor_2x3x4();
+
+    let data_actual = tensor.mean_dims(&[0, 2]).into_data();
+
+    assert_eq!(data_actual, Data::from([[[7.5], [11.5], [15.5]]]));
+}
+
+#[test]
+fn test_sum_dims_should_match_chained_sum_dim() {
+    let tensor = tensor_2x3x4();
+
+    let data_actual = tensor.sum_dims(&[2, 0]).into_data();
+
+    assert_eq!(data_actual, tensor.sum_dim(0).sum_dim(2).into_data());
+}
+
+fn tensor_2x3x4() -> Tensor<TestBackend, 3> {
+    Tensor::from_data(Data::from([
+        [
+            [0.0, 1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0, 7.0],
+            [8.0, 9.0, 10.0, 11.0],
+        ],
+        [
+            [12.0, 13.0, 14.0, 15.0],
+            [16.0, 17.0, 18.0, 19.0],
+            [20.0, 21.0, 22.0, 23.0],
+        ],
+    ]))
+}
+
+#[test]
+fn test_should_keep_the_reduced_dim() {
+    let tensor = tensor_2x3x4();
+
+    let tensor_sum = tensor.sum_dim(1);
+    let tensor_mean = tensor.mean_dim(1);
+
+    assert_eq!(tensor_sum.shape().dims, [2, 1, 4]);
+    assert_eq!(tensor_mean.shape().dims, [2, 1, 4]);
+}
+
+#[test]
+fn test_should_sum_dim_squeeze() {
+    let tensor = tensor_2x3x4();
+
+    let data_actual = tensor.sum_dim_squeeze::<2>(1).into_data();
+
+    assert_eq!(
+        data_actual,
+        Data::from([[12.0, 15.0, 18.0, 21.0], [48.0, 51.0, 54.0, 57.0]])
+    );
+}
+
+#[test]
+fn test_should_mean_dim_squeeze() {
+    let tensor = tensor_2x3x4();
+
+    let data_actual = tensor.mean_dim_squeeze::<2>(2).into_data();
+
+    assert_eq!(
+        data_actual,
+        Data::from([[1.5, 5.5, 9.5], [13.5, 17.5, 21.5]])
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_sum_dim_squeeze_should_panic_with_wrong_rank() {
+    let tensor = tensor_2x3x4();
+
+    tensor.sum_dim_squeeze::<3>(1);
+}
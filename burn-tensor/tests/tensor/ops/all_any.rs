@@ -0,0 +1,88 @@
+use super::super::TestBackend;
+use burn_tensor::{BoolTensor, Data};
+
+#[test]
+fn should_reduce_all_true() {
+    let tensor = BoolTensor::<TestBackend, 2>::from_data(Data::from([[true, true], [true, true]]));
+
+    assert!(tensor.all());
+    assert!(tensor.any());
+}
+
+#[test]
+fn should_reduce_all_false() {
+    let tensor =
+        BoolTensor::<TestBackend, 2>::from_data(Data::from([[false, false], [false, false]]));
+
+    assert!(!tensor.all());
+    assert!(!tensor.any());
+}
+
+#[test]
+fn should_reduce_mixed() {
+    let tensor = BoolTensor::<TestBackend, 2>::from_data(Data::from([[true, false], [true, true]]));
+
+    assert!(!tensor.all());
+    assert!(tensor.any());
+}
+
+#[test]
+fn should_reduce_all_dim() {
+    let tensor = BoolTensor::<TestBackend, 2>::from_data(Data::from([
+        [true, true, false],
+        [true, false, false],
+    ]));
+
+    assert_eq!(
+        tensor.all_dim(0).into_data(),
+        Data::from([[true, false, false]])
+    );
+    assert_eq!(
+        tensor.all_dim(1).into_data(),
+        Data::from([[false], [false]])
+    );
+}
+
+#[test]
+fn should_reduce_any_dim() {
+    let tensor = BoolTensor::<TestBackend, 2>::from_data(Data::from([
+        [true, true, false],
+        [true, false, false],
+    ]));
+
+    assert_eq!(
+        tensor.any_dim(0).into_data(),
+        Data::from([[true, true, false]])
+    );
+    assert_eq!(tensor.any_dim(1).into_data(), Data::from([[true], [true]]));
+}
+
+#[test]
+fn should_reduce_dims_of_uniform_tensors() {
+    let all_true =
+        BoolTensor::<TestBackend, 2>::from_data(Data::from([[true, true], [true, true]]));
+    let all_false =
+        BoolTensor::<TestBackend, 2>::from_data(Data::from([[false, false], [false, false]]));
+
+    assert_eq!(
+        all_true.all_dim(1).into_data(),
+        Data::from([[true], [true]])
+    );
+    assert_eq!(all_true.any_dim(0).into_data(), Data::from([[true, true]]));
+    assert_eq!(
+        all_false.all_dim(1).into_data(),
+        Data::from([[false], [false]])
+    );
+    assert_eq!(
+        all_false.any_dim(0).into_data(),
+        Data::from([[false, false]])
+    );
+}
+
+#[test]
+#[should_panic]
+fn should_panic_when_reducing_an_out_of_bounds_dim() {
+    let tensor = BoolTensor::<TestBackend, 1>::from_data(Data::from([true, false]));
+
+    tensor.all_dim(1);
+}
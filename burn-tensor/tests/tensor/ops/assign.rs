@@ -0,0 +1,57 @@
+use super::super::{TestADBackend, TestBackend};
+use burn_tensor::{Data, Tensor};
+
+#[test]
+fn should_support_add_assign_ops() {
+    let mut tensor_1 = Tensor::<TestBackend, 2>::from_data(Data::from([[0.0, 1.0], [2.0, 3.0]]));
+    let tensor_2 = Tensor::<TestBackend, 2>::from_data(Data::from([[4.0, 5.0], [6.0, 7.0]]));
+
+    tensor_1.add_assign(&tensor_2);
+
+    let data_expected = Data::from([[4.0, 6.0], [8.0, 10.0]]);
+    assert_eq!(data_expected, tensor_1.into_data());
+}
+
+#[test]
+fn should_support_sub_assign_ops_broadcast() {
+    let mut tensor_1 = Tensor::<TestBackend, 2>::from_data(Data::from([[0.0, 1.0], [2.0, 3.0]]));
+    let tensor_2 = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0]]));
+
+    tensor_1.sub_assign(&tensor_2);
+
+    let data_expected = Data::from([[-1.0, -1.0], [1.0, 1.0]]);
+    assert_eq!(data_expected, tensor_1.into_data());
+}
+
+#[test]
+fn should_support_mul_assign_ops() {
+    let mut tensor_1 = Tensor::<TestBackend, 2>::from_data(Data::from([[0.0, 1.0], [2.0, 3.0]]));
+    let tensor_2 = Tensor::<TestBackend, 2>::from_data(Data::from([[4.0, 5.0], [6.0, 7.0]]));
+
+    tensor_1.mul_assign(&tensor_2);
+
+    let data_expected = Data::from([[0.0, 5.0], [12.0, 21.0]]);
+    assert_eq!(data_expected, tensor_1.into_data());
+}
+
+#[test]
+fn should_support_assign_ops_on_untracked_ad_tensor() {
+    let mut tensor_1 = Tensor::<TestADBackend, 2>::from_data(Data::from([[0.0, 1.0], [2.0, 3.0]]));
+    let tensor_2 = Tensor::<TestADBackend, 2>::from_data(Data::from([[4.0, 5.0], [6.0, 7.0]]));
+
+    tensor_1.add_assign(&tensor_2);
+
+    let data_expected = Data::from([[4.0, 6.0], [8.0, 10.0]]);
+    assert_eq!(data_expected, tensor_1.into_data());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn should_reject_assign_ops_on_tracked_ad_tensor() {
+    let tensor_1 = Tensor::<TestADBackend, 2>::from_data(Data::from([[0.0, 1.0], [2.0, 3.0]]));
+    let tensor_2 = Tensor::<TestADBackend, 2>::from_data(Data::from([[4.0, 5.0], [6.0, 7.0]]));
+
+    let mut tensor_3 = tensor_1.mul(&tensor_2);
+    tensor_3.add_assign(&tensor_2);
+}
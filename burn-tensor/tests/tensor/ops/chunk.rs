@@ -0,0 +1,80 @@
+use super::super::TestBackend;
+use burn_tensor::{Data, Shape, Tensor};
+
+#[test]
+fn should_chunk_evenly() {
+    let data = Data::from([
+        [0.0, 1.0, 2.0, 3.0],
+        [4.0, 5.0, 6.0, 7.0],
+        [8.0, 9.0, 10.0, 11.0],
+        [12.0, 13.0, 14.0, 15.0],
+        [16.0, 17.0, 18.0, 19.0],
+        [20.0, 21.0, 22.0, 23.0],
+    ]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let chunks = tensor.chunk(3, 0);
+
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(
+        chunks[0].to_data(),
+        Data::from([[0.0, 1.0, 2.0, 3.0], [4.0, 5.0, 6.0, 7.0]])
+    );
+    assert_eq!(
+        chunks[1].to_data(),
+        Data::from([[8.0, 9.0, 10.0, 11.0], [12.0, 13.0, 14.0, 15.0]])
+    );
+    assert_eq!(
+        chunks[2].to_data(),
+        Data::from([[16.0, 17.0, 18.0, 19.0], [20.0, 21.0, 22.0, 23.0]])
+    );
+}
+
+#[test]
+fn should_give_the_remainder_to_the_first_chunks() {
+    let tensor = Tensor::<TestBackend, 2>::ones(Shape::new([2, 7]));
+
+    let sizes: Vec<_> = tensor
+        .chunk(3, 1)
+        .iter()
+        .map(|chunk| chunk.shape().dims[1])
+        .collect();
+
+    assert_eq!(sizes, vec![3, 2, 2]);
+}
+
+#[test]
+fn should_not_return_empty_chunks() {
+    let tensor = Tensor::<TestBackend, 1>::from_data(Data::from([1.0, 2.0]));
+
+    let chunks = tensor.chunk(4, 0);
+
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks[0].to_data(), Data::from([1.0]));
+    assert_eq!(chunks[1].to_data(), Data::from([2.0]));
+}
+
+#[test]
+#[should_panic]
+fn should_panic_when_chunking_into_zero_chunks() {
+    let tensor = Tensor::<TestBackend, 1>::from_data(Data::from([1.0, 2.0]));
+
+    tensor.chunk(0, 0);
+}
+
+#[test]
+fn should_iter_dim() {
+    let data = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let items: Vec<_> = tensor.iter_dim(1).map(|item| item.into_data()).collect();
+
+    assert_eq!(
+        items,
+        vec![
+            Data::from([[0.0], [3.0]]),
+            Data::from([[1.0], [4.0]]),
+            Data::from([[2.0], [5.0]]),
+        ]
+    );
+}
@@ -0,0 +1,17 @@
+use super::super::TestBackend;
+use burn_tensor::{Data, Tensor};
+
+#[test]
+fn should_count_nonzero_elements() {
+    let tensor =
+        Tensor::<TestBackend, 2>::from_data(Data::from([[0.0, 1.5, -2.0], [0.0, 0.0, 3.0]]));
+
+    assert_eq!(tensor.count_nonzero(), 3);
+}
+
+#[test]
+fn should_count_no_nonzero_elements_in_zeros() {
+    let tensor = Tensor::<TestBackend, 2>::zeros([2, 3]);
+
+    assert_eq!(tensor.count_nonzero(), 0);
+}
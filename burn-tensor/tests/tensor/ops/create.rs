@@ -0,0 +1,106 @@
+use super::super::{lock_rng, TestBackend};
+use burn_tensor::backend::Backend;
+use burn_tensor::{Data, Distribution, Shape, Tensor};
+
+#[test]
+fn should_infer_shape_from_floats_1d() {
+    let tensor = Tensor::<TestBackend, 1>::from_floats([1.0, 2.0, 3.0]);
+
+    assert_eq!(tensor.shape(), &Shape::new([3]));
+    assert_eq!(tensor.into_data(), Data::from([1.0, 2.0, 3.0]));
+}
+
+#[test]
+fn should_infer_shape_from_floats_2d() {
+    let tensor = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+    assert_eq!(tensor.shape(), &Shape::new([3, 2]));
+    assert_eq!(
+        tensor.into_data(),
+        Data::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]])
+    );
+}
+
+#[test]
+fn should_infer_shape_from_floats_3d() {
+    let tensor = Tensor::<TestBackend, 3>::from_floats([[[1.0, 2.0, 3.0]], [[4.0, 5.0, 6.0]]]);
+
+    assert_eq!(tensor.shape(), &Shape::new([2, 1, 3]));
+    assert_eq!(
+        tensor.into_data(),
+        Data::from([[[1.0, 2.0, 3.0]], [[4.0, 5.0, 6.0]]])
+    );
+}
+
+#[test]
+fn should_convert_ints_to_backend_elements() {
+    let tensor = Tensor::<TestBackend, 2>::from_ints([[1, 2], [3, 4]]);
+
+    assert_eq!(tensor.shape(), &Shape::new([2, 2]));
+    assert_eq!(tensor.into_data(), Data::from([[1.0, 2.0], [3.0, 4.0]]));
+}
+
+#[test]
+fn should_create_zeros_and_ones() {
+    let zeros = Tensor::<TestBackend, 2>::zeros([2, 3]);
+    let ones = Tensor::<TestBackend, 2>::ones([2, 3]);
+
+    assert_eq!(
+        zeros.into_data(),
+        Data::from([[0.0, 0.0, 0.0], [0.0, 0.0, 0.0]])
+    );
+    assert_eq!(
+        ones.into_data(),
+        Data::from([[1.0, 1.0, 1.0], [1.0, 1.0, 1.0]])
+    );
+}
+
+#[test]
+fn should_create_tensors_on_the_given_device() {
+    let _rng = lock_rng();
+    let device = <TestBackend as Backend>::Device::default();
+
+    let from_data = Tensor::<TestBackend, 1>::from_data_device(Data::from([1.0, 2.0]), device);
+    let random = Tensor::<TestBackend, 2>::random_device([2, 3], Distribution::Standard, device);
+    let zeros = Tensor::<TestBackend, 2>::zeros_device([2, 3], device);
+    let ones = Tensor::<TestBackend, 2>::ones_device([2, 3], device);
+
+    assert_eq!(from_data.device(), device);
+    assert_eq!(random.device(), device);
+    assert_eq!(random.shape(), &Shape::new([2, 3]));
+    assert_eq!(zeros.device(), device);
+    assert_eq!(ones.device(), device);
+    assert_eq!(
+        ones.into_data(),
+        Data::from([[1.0, 1.0, 1.0], [1.0, 1.0, 1.0]])
+    );
+}
+
+#[test]
+fn should_create_identity_matrix() {
+    let device = <TestBackend as Backend>::Device::default();
+
+    let eye = Tensor::<TestBackend, 2>::eye(5, device);
+
+    assert_eq!(eye.device(), device);
+    assert_eq!(eye.shape(), &Shape::new([5, 5]));
+    let data = eye.into_data();
+    for row in 0..5 {
+        for col in 0..5 {
+            let expected = if row == col { 1.0 } else { 0.0 };
+            assert_eq!(data.value[row * 5 + col], expected);
+        }
+    }
+}
+
+#[test]
+fn should_create_batch_of_identity_matrices() {
+    let device = <TestBackend as Backend>::Device::default();
+
+    let eye = Tensor::<TestBackend, 3>::eye_batch(2, 2, device);
+
+    assert_eq!(
+        eye.into_data(),
+        Data::from([[[1.0, 0.0], [0.0, 1.0]], [[1.0, 0.0], [0.0, 1.0]]])
+    );
+}
@@ -0,0 +1,38 @@
+use super::super::{TestADBackend, TestBackend};
+use burn_tensor::{Data, Tensor};
+
+#[test]
+fn should_not_modify_deep_clone_when_the_tensor_is_modified() {
+    let mut tensor = Tensor::<TestBackend, 2>::from_data(Data::from([[0.0, 1.0], [2.0, 3.0]]));
+    let tensor_other = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 1.0], [1.0, 1.0]]));
+
+    let snapshot = tensor.deep_clone();
+    tensor.sub_assign(&tensor_other);
+
+    assert_eq!(tensor.into_data(), Data::from([[-1.0, 0.0], [1.0, 2.0]]));
+    assert_eq!(snapshot.into_data(), Data::from([[0.0, 1.0], [2.0, 3.0]]));
+}
+
+#[test]
+fn should_not_modify_the_tensor_when_deep_clone_is_modified() {
+    let tensor = Tensor::<TestBackend, 2>::from_data(Data::from([[0.0, 1.0], [2.0, 3.0]]));
+    let tensor_other = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 1.0], [1.0, 1.0]]));
+
+    let mut copy = tensor.deep_clone();
+    copy.add_assign(&tensor_other);
+    let copy = copy.index_assign([0..1, 0..2], &Tensor::zeros([1, 2]));
+
+    assert_eq!(tensor.into_data(), Data::from([[0.0, 1.0], [2.0, 3.0]]));
+    assert_eq!(copy.into_data(), Data::from([[0.0, 0.0], [3.0, 4.0]]));
+}
+
+#[test]
+fn should_diff_through_deep_clone() {
+    let tensor = Tensor::<TestADBackend, 2>::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+
+    let tensor_copy = tensor.deep_clone();
+    let grads = tensor_copy.mul(&tensor).sum().backward();
+
+    let grad = tensor.grad(&grads).unwrap();
+    assert_eq!(grad.into_data(), Data::from([[2.0, 4.0], [6.0, 8.0]]));
+}
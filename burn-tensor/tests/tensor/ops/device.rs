@@ -0,0 +1,17 @@
+use burn_tensor::backend::{TchBackend, TchDevice};
+use burn_tensor::{Data, Tensor};
+
+#[test]
+fn should_move_tensor_to_cuda_and_back() {
+    let data = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+    let tensor = Tensor::<TchBackend<f32>, 2>::from_data_device(data, TchDevice::cpu());
+
+    let tensor_cuda = tensor.to_device(TchDevice::cuda(0));
+    assert_eq!(tensor_cuda.device(), TchDevice::cuda(0));
+
+    let output = tensor_cuda.add_scalar(1.0).to_device(TchDevice::cpu());
+    assert_eq!(output.device(), TchDevice::cpu());
+
+    let data_expected = Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    assert_eq!(output.into_data(), data_expected);
+}
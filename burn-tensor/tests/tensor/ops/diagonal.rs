@@ -0,0 +1,55 @@
+use super::super::TestBackend;
+use burn_tensor::{Data, Tensor};
+
+#[test]
+fn should_support_diagonal_of_square_matrix() {
+    let tensor = Tensor::<TestBackend, 2>::from_data(Data::from([
+        [1.0, 2.0, 3.0],
+        [4.0, 5.0, 6.0],
+        [7.0, 8.0, 9.0],
+    ]));
+
+    let data_actual = tensor.diagonal::<1>().into_data();
+
+    assert_eq!(data_actual, Data::from([1.0, 5.0, 9.0]));
+}
+
+#[test]
+fn should_support_diagonal_of_rectangular_matrices() {
+    let tensor = Tensor::<TestBackend, 3>::from_data(Data::from([
+        [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+        [[-1.0, -2.0, -3.0], [-4.0, -5.0, -6.0]],
+    ]));
+    let tensor_tall = tensor.transpose();
+
+    let data_actual = tensor.diagonal::<2>().into_data();
+    let data_actual_tall = tensor_tall.diagonal::<2>().into_data();
+
+    let data_expected = Data::from([[1.0, 5.0], [-1.0, -5.0]]);
+    assert_eq!(data_actual, data_expected);
+    assert_eq!(data_actual_tall, data_expected);
+}
+
+#[test]
+fn should_support_diag_embed() {
+    let tensor =
+        Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0, 3.0], [-4.0, 5.0, -6.0]]));
+
+    let data_actual = tensor.diag_embed::<3>().into_data();
+
+    let data_expected = Data::from([
+        [[1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 3.0]],
+        [[-4.0, 0.0, 0.0], [0.0, 5.0, 0.0], [0.0, 0.0, -6.0]],
+    ]);
+    assert_eq!(data_actual, data_expected);
+}
+
+#[test]
+fn diagonal_should_invert_diag_embed() {
+    let data = Data::from([3.0, -1.0, 2.0, 0.5]);
+    let tensor = Tensor::<TestBackend, 1>::from_data(data.clone());
+
+    let data_actual = tensor.diag_embed::<2>().diagonal::<1>().into_data();
+
+    assert_eq!(data_actual, data);
+}
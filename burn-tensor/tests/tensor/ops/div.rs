@@ -27,3 +27,17 @@ fn should_support_div_scalar_ops() {
     let data_expected = Data::from([[0.0, 0.5, 1.0], [1.5, 2.0, 2.5]]);
     assert_eq!(data_expected, data_actual);
 }
+
+#[test]
+fn should_support_div_safe_ops() {
+    let data_1 = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+    let data_2 = Data::from([[0.0, 0.0, 1.0], [2.0, 4.0, 8.0]]);
+    let tensor_1 = Tensor::<TestBackend, 2>::from_data(data_1);
+    let tensor_2 = Tensor::<TestBackend, 2>::from_data(data_2);
+
+    let output = tensor_1.div_safe(&tensor_2, 1e-2);
+
+    let data_actual = output.into_data();
+    let data_expected = Data::from([[0.0, 100.0, 1.9802], [1.4925, 0.9975, 0.6242]]);
+    data_expected.assert_within_tolerance(&data_actual, 1e-4);
+}
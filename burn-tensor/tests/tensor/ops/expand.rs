@@ -0,0 +1,42 @@
+use super::super::TestBackend;
+use burn_tensor::{Data, Tensor};
+
+#[test]
+fn should_expand_dimension_of_size_one() {
+    let tensor = Tensor::<TestBackend, 2>::from_data(Data::from([[0.0, 1.0, 2.0]]));
+
+    let output = tensor.expand([4, 3]);
+
+    assert_eq!(
+        output.into_data(),
+        Data::from([
+            [0.0, 1.0, 2.0],
+            [0.0, 1.0, 2.0],
+            [0.0, 1.0, 2.0],
+            [0.0, 1.0, 2.0],
+        ])
+    );
+}
+
+#[test]
+fn should_expand_multiple_dimensions() {
+    let tensor = Tensor::<TestBackend, 3>::from_data(Data::from([[[1.0], [2.0]]]));
+
+    let output = tensor.expand([2, 2, 3]);
+
+    assert_eq!(
+        output.into_data(),
+        Data::from([
+            [[1.0, 1.0, 1.0], [2.0, 2.0, 2.0]],
+            [[1.0, 1.0, 1.0], [2.0, 2.0, 2.0]],
+        ])
+    );
+}
+
+#[test]
+#[should_panic]
+fn should_panic_when_expanding_dimension_larger_than_one() {
+    let tensor = Tensor::<TestBackend, 2>::zeros([2, 3]);
+
+    tensor.expand([4, 3]);
+}
@@ -0,0 +1,33 @@
+use super::super::TestBackend;
+use burn_tensor::{Data, Tensor};
+
+#[test]
+fn should_extract_the_scalar_of_a_reduction() {
+    let data = Data::from([[1.0, 2.0], [3.0, 4.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    assert_eq!(tensor.sum().into_scalar(), 10.0);
+}
+
+#[test]
+fn should_extract_the_scalar_of_a_single_element_tensor() {
+    let tensor = Tensor::<TestBackend, 3>::from_data(Data::from([[[5.0]]]));
+
+    assert_eq!(tensor.into_scalar(), 5.0);
+}
+
+#[test]
+#[should_panic(expected = "Can't convert a tensor of shape [2, 2] with 4 elements into a scalar")]
+fn should_panic_when_extracting_the_scalar_of_many_elements() {
+    let data = Data::from([[1.0, 2.0], [3.0, 4.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    tensor.into_scalar();
+}
+
+#[test]
+fn should_extract_a_vec() {
+    let tensor = Tensor::<TestBackend, 1>::from_data(Data::from([1.0, 2.0, 3.0]));
+
+    assert_eq!(tensor.to_vec(), vec![1.0, 2.0, 3.0]);
+}
@@ -0,0 +1,36 @@
+use super::super::TestBackend;
+use burn_tensor::{Data, Tensor};
+
+#[test]
+fn should_detect_nan() {
+    let tensor = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, f32::NAN], [3.0, 4.0]]));
+
+    assert!(tensor.any_nan());
+    assert!(!tensor.any_inf());
+}
+
+#[test]
+fn should_detect_inf() {
+    let data = Data::from([[1.0, 2.0], [f32::NEG_INFINITY, 4.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    assert!(tensor.any_inf());
+    assert!(!tensor.any_nan());
+}
+
+#[test]
+fn should_not_detect_anything_in_finite_tensor() {
+    let data = Data::from([[1.0, -2.0], [f32::MAX, f32::MIN]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    assert!(!tensor.any_nan());
+    assert!(!tensor.any_inf());
+}
+
+#[test]
+fn should_detect_nan_produced_by_an_operation() {
+    let tensor = Tensor::<TestBackend, 1>::from_data(Data::from([-1.0, 1.0]));
+
+    assert!(tensor.log().any_nan());
+    assert!(tensor.sub(&tensor).log().any_inf());
+}
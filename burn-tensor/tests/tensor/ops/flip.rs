@@ -0,0 +1,43 @@
+use super::super::TestBackend;
+use burn_tensor::{Data, Tensor};
+
+#[test]
+fn should_flip_dim_1() {
+    let data = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let data_actual = tensor.flip(&[1]).into_data();
+
+    let data_expected = Data::from([[2.0, 1.0, 0.0], [5.0, 4.0, 3.0]]);
+    assert_eq!(data_expected, data_actual);
+}
+
+#[test]
+fn should_flip_multiple_dims() {
+    let data = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let data_actual = tensor.flip(&[0, 1]).into_data();
+
+    let data_expected = Data::from([[5.0, 4.0, 3.0], [2.0, 1.0, 0.0]]);
+    assert_eq!(data_expected, data_actual);
+}
+
+#[test]
+fn should_support_ops_after_flip() {
+    let data = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let data_actual = tensor.flip(&[0]).reshape([6]).add_scalar(1.0).into_data();
+
+    let data_expected = Data::from([4.0, 5.0, 6.0, 1.0, 2.0, 3.0]);
+    assert_eq!(data_expected, data_actual);
+}
+
+#[test]
+#[should_panic]
+fn should_panic_when_flipping_invalid_dim() {
+    let tensor = Tensor::<TestBackend, 2>::zeros([2, 3]);
+
+    tensor.flip(&[2]);
+}
@@ -74,3 +74,41 @@ fn should_support_indexe_assign_2d() {
     let data_expected = Data::from([[0.0, 1.0, 2.0], [10.0, 5.0, 5.0]]);
     assert_eq!(data_expected, data_actual);
 }
+
+#[test]
+fn should_broadcast_row_when_index_assign() {
+    let tensor = Tensor::<TestBackend, 2>::zeros([5, 3]);
+    let row = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 2.0, 3.0]]));
+
+    let data_actual = tensor.index_assign([1..5, 0..3], &row).into_data();
+
+    let data_expected = Data::from([
+        [0.0, 0.0, 0.0],
+        [1.0, 2.0, 3.0],
+        [1.0, 2.0, 3.0],
+        [1.0, 2.0, 3.0],
+        [1.0, 2.0, 3.0],
+    ]);
+    assert_eq!(data_expected, data_actual);
+}
+
+#[test]
+fn should_broadcast_scalar_when_index_assign() {
+    let data = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+    let value = Tensor::<TestBackend, 2>::from_data(Data::from([[9.0]]));
+
+    let data_actual = tensor.index_assign([0..2, 1..3], &value).into_data();
+
+    let data_expected = Data::from([[0.0, 9.0, 9.0], [3.0, 9.0, 9.0]]);
+    assert_eq!(data_expected, data_actual);
+}
+
+#[test]
+#[should_panic]
+fn should_panic_when_index_assign_values_can_not_be_broadcasted() {
+    let tensor = Tensor::<TestBackend, 2>::zeros([4, 3]);
+    let values = Tensor::<TestBackend, 2>::zeros([2, 3]);
+
+    tensor.index_assign([0..4, 0..3], &values);
+}
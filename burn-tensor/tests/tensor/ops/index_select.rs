@@ -0,0 +1,35 @@
+use super::super::TestBackend;
+use burn_tensor::{backend::Backend, Data, Tensor};
+
+#[test]
+fn should_select_rows_with_repeated_indexes() {
+    let tensor = Tensor::<TestBackend, 2>::from_data(Data::from([
+        [0.0, 1.0, 2.0, 3.0],
+        [4.0, 5.0, 6.0, 7.0],
+        [8.0, 9.0, 10.0, 11.0],
+    ]));
+    let indexes =
+        Tensor::<<TestBackend as Backend>::IntegerBackend, 1>::from_data(Data::from([2, 0, 2]));
+
+    let data_actual = tensor.index_select(0, &indexes).into_data();
+
+    let data_expected = Data::from([
+        [8.0, 9.0, 10.0, 11.0],
+        [0.0, 1.0, 2.0, 3.0],
+        [8.0, 9.0, 10.0, 11.0],
+    ]);
+    assert_eq!(data_expected, data_actual);
+}
+
+#[test]
+fn should_select_columns() {
+    let tensor =
+        Tensor::<TestBackend, 2>::from_data(Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]));
+    let indexes =
+        Tensor::<<TestBackend as Backend>::IntegerBackend, 1>::from_data(Data::from([1, 2]));
+
+    let data_actual = tensor.index_select(1, &indexes).into_data();
+
+    let data_expected = Data::from([[1.0, 2.0], [4.0, 5.0]]);
+    assert_eq!(data_expected, data_actual);
+}
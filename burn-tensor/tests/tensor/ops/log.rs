@@ -0,0 +1,45 @@
+use super::super::TestBackend;
+use burn_tensor::{Data, Tensor};
+use std::f32::consts::LN_2;
+
+#[test]
+fn should_support_log_ops() {
+    let data = Data::from([[1.0, 2.0, 3.0], [4.0, 5.0, 0.5]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let data_actual = tensor.log().into_data();
+
+    let data_expected = Data::from([[0.0, LN_2, 1.0986], [1.3863, 1.6094, -LN_2]]);
+    data_expected.assert_approx_eq(&data_actual, 3);
+}
+
+#[test]
+fn should_support_log1p_ops() {
+    let data = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let data_actual = tensor.log1p().into_data();
+
+    let data_expected = Data::from([[0.0, LN_2, 1.0986], [1.3863, 1.6094, 1.7918]]);
+    data_expected.assert_approx_eq(&data_actual, 3);
+}
+
+#[test]
+fn should_support_log1p_ops_with_small_values() {
+    let data = Data::from([1e-7]);
+    let tensor = Tensor::<TestBackend, 1>::from_data(data);
+
+    let data_actual = tensor.log1p().into_data();
+
+    assert!((data_actual.value[0] - 1e-7).abs() < 1e-12);
+}
+
+#[test]
+fn should_return_neg_inf_for_log_of_zero() {
+    let data = Data::from([0.0]);
+    let tensor = Tensor::<TestBackend, 1>::from_data(data);
+
+    let data_actual = tensor.log().into_data();
+
+    assert_eq!(data_actual.value[0], f32::NEG_INFINITY);
+}
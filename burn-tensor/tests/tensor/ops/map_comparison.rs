@@ -96,3 +96,16 @@ fn test_lower_equal() {
     let data_expected = Data::from([[true, true, false], [true, false, true]]);
     assert_eq!(data_expected, data_actual.to_data());
 }
+
+#[test]
+fn test_equal_approx() {
+    let data_1 = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+    let data_2 = Data::from([[0.009, 1.011, 2.0], [2.995, 4.02, -5.0]]);
+    let tensor_1 = Tensor::<TestBackend, 2>::from_data(data_1);
+    let tensor_2 = Tensor::<TestBackend, 2>::from_data(data_2);
+
+    let data_actual = tensor_1.equal_approx(&tensor_2, 0.01);
+
+    let data_expected = Data::from([[true, false, true], [true, false, false]]);
+    assert_eq!(data_expected, data_actual.to_data());
+}
@@ -1,5 +1,5 @@
 use super::super::TestBackend;
-use burn_tensor::{Data, Tensor};
+use burn_tensor::{Data, Shape, Tensor};
 
 #[test]
 fn test_matmul_d2() {
@@ -30,3 +30,65 @@ fn test_matmul_d3() {
         Data::from([[[18.0, 28.0], [14.0, 23.0]]])
     );
 }
+
+#[test]
+fn test_matmul_batched() {
+    let data_1 = Data::from([[[1.0, 7.0], [2.0, 3.0]], [[0.0, 1.0], [-1.0, 2.0]]]);
+    let data_2 = Data::from([[[4.0, 7.0], [2.0, 3.0]], [[1.0, 0.0], [2.0, 1.0]]]);
+    let tensor_1 = Tensor::<TestBackend, 3>::from_data(data_1);
+    let tensor_2 = Tensor::<TestBackend, 3>::from_data(data_2);
+
+    let tensor_3 = tensor_1.matmul(&tensor_2);
+
+    assert_eq!(
+        tensor_3.into_data(),
+        Data::from([[[18.0, 28.0], [14.0, 23.0]], [[2.0, 1.0], [3.0, 2.0]]])
+    );
+}
+
+#[test]
+fn test_matmul_broadcast_batch() {
+    let data_1 = Data::from([[[1.0, 7.0], [2.0, 3.0]], [[0.0, 1.0], [-1.0, 2.0]]]);
+    let data_2 = Data::from([[[4.0, 7.0, 1.0], [2.0, 3.0, 0.0]]]);
+    let tensor_1 = Tensor::<TestBackend, 3>::from_data(data_1);
+    let tensor_2 = Tensor::<TestBackend, 3>::from_data(data_2);
+
+    let tensor_3 = tensor_1.matmul(&tensor_2);
+
+    assert_eq!(
+        tensor_3.into_data(),
+        Data::from([
+            [[18.0, 28.0, 1.0], [14.0, 23.0, 2.0]],
+            [[2.0, 3.0, 0.0], [0.0, -1.0, -1.0]]
+        ])
+    );
+}
+
+#[test]
+fn test_matmul_broadcast_batch_both_sides() {
+    let tensor_1 = Tensor::<TestBackend, 4>::ones(Shape::new([2, 1, 3, 4]));
+    let tensor_2 = Tensor::<TestBackend, 4>::ones(Shape::new([1, 5, 4, 2]));
+
+    let tensor_3 = tensor_1.matmul(&tensor_2);
+
+    assert_eq!(tensor_3.shape(), &Shape::new([2, 5, 3, 2]));
+    assert_eq!(tensor_3.into_data(), Data::from([[[[4.0; 2]; 3]; 5]; 2]));
+}
+
+#[test]
+#[should_panic(expected = "Incompatible inner dimensions")]
+fn test_matmul_incompatible_inner_dims_should_panic() {
+    let tensor_1 = Tensor::<TestBackend, 3>::ones(Shape::new([2, 3, 4]));
+    let tensor_2 = Tensor::<TestBackend, 3>::ones(Shape::new([2, 3, 5]));
+
+    tensor_1.matmul(&tensor_2);
+}
+
+#[test]
+#[should_panic(expected = "Can't broadcast batch dimension")]
+fn test_matmul_incompatible_batch_dims_should_panic() {
+    let tensor_1 = Tensor::<TestBackend, 3>::ones(Shape::new([2, 3, 4]));
+    let tensor_2 = Tensor::<TestBackend, 3>::ones(Shape::new([3, 4, 5]));
+
+    tensor_1.matmul(&tensor_2);
+}
@@ -0,0 +1,50 @@
+use super::super::TestBackend;
+use burn_tensor::{Data, Tensor};
+
+#[test]
+fn should_support_maximum_ops() {
+    let data_1 = Data::from([[0.0, 4.0, 2.0], [3.0, -4.0, 5.0]]);
+    let data_2 = Data::from([[1.0, 1.0, 2.0], [3.0, 4.0, -5.0]]);
+    let tensor_1 = Tensor::<TestBackend, 2>::from_data(data_1);
+    let tensor_2 = Tensor::<TestBackend, 2>::from_data(data_2);
+
+    let data_actual = tensor_1.maximum(&tensor_2).into_data();
+
+    let data_expected = Data::from([[1.0, 4.0, 2.0], [3.0, 4.0, 5.0]]);
+    assert_eq!(data_expected, data_actual);
+}
+
+#[test]
+fn should_support_minimum_ops() {
+    let data_1 = Data::from([[0.0, 4.0, 2.0], [3.0, -4.0, 5.0]]);
+    let data_2 = Data::from([[1.0, 1.0, 2.0], [3.0, 4.0, -5.0]]);
+    let tensor_1 = Tensor::<TestBackend, 2>::from_data(data_1);
+    let tensor_2 = Tensor::<TestBackend, 2>::from_data(data_2);
+
+    let data_actual = tensor_1.minimum(&tensor_2).into_data();
+
+    let data_expected = Data::from([[0.0, 1.0, 2.0], [3.0, -4.0, -5.0]]);
+    assert_eq!(data_expected, data_actual);
+}
+
+#[test]
+fn should_support_maximum_scalar_ops() {
+    let data = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let data_actual = tensor.maximum_scalar(2.5).into_data();
+
+    let data_expected = Data::from([[2.5, 2.5, 2.5], [3.0, 4.0, 5.0]]);
+    assert_eq!(data_expected, data_actual);
+}
+
+#[test]
+fn should_support_minimum_scalar_ops() {
+    let data = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let data_actual = tensor.minimum_scalar(2.5).into_data();
+
+    let data_expected = Data::from([[0.0, 1.0, 2.0], [2.5, 2.5, 2.5]]);
+    assert_eq!(data_expected, data_actual);
+}
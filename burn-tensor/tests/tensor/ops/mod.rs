@@ -1,17 +1,46 @@
+mod abs;
 mod add;
 mod aggregation;
+mod all_any;
 mod arg;
+mod assign;
+mod chunk;
+mod cos;
+mod count_nonzero;
+mod create;
+mod deep_clone;
+#[cfg(feature = "cuda")]
+mod device;
+mod diagonal;
 mod div;
 mod erf;
 mod exp;
+mod expand;
+mod extract;
+mod finite;
+mod flip;
 mod index;
+mod index_select;
+mod log;
 mod map_comparison;
 mod mask;
 mod matmul;
+mod min_max;
 mod mul;
+mod narrow;
 mod neg;
+mod pad;
+mod permute;
 mod powf;
+mod random;
+mod reciprocal;
 mod repeat;
 mod reshape;
+mod roll;
+mod rounding;
+mod sin;
 mod sub;
+mod tan;
+mod to_float;
 mod transpose;
+mod triangular;
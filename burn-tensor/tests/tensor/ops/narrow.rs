@@ -0,0 +1,30 @@
+use super::super::TestBackend;
+use burn_tensor::{Data, Tensor};
+
+#[test]
+fn should_narrow_dim_1() {
+    let data = Data::from([[0.0, 1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0, 9.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let output = tensor.narrow(1, 2, 2);
+
+    assert_eq!(output.into_data(), Data::from([[2.0, 3.0], [7.0, 8.0]]));
+}
+
+#[test]
+fn should_narrow_dim_0() {
+    let data = Data::from([[0.0, 1.0], [2.0, 3.0], [4.0, 5.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let output = tensor.narrow(0, 1, 2);
+
+    assert_eq!(output.into_data(), Data::from([[2.0, 3.0], [4.0, 5.0]]));
+}
+
+#[test]
+#[should_panic]
+fn should_panic_when_narrowing_past_the_end() {
+    let tensor = Tensor::<TestBackend, 2>::zeros([2, 5]);
+
+    tensor.narrow(1, 4, 2);
+}
@@ -0,0 +1,33 @@
+use super::super::TestBackend;
+use burn_tensor::{Data, Tensor};
+
+#[test]
+fn should_pad_with_zeros() {
+    let data = Data::from([[1.0, 2.0], [3.0, 4.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let data_actual = tensor.pad([(1, 1), (1, 1)], 0.0).into_data();
+
+    let data_expected = Data::from([
+        [0.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 2.0, 0.0],
+        [0.0, 3.0, 4.0, 0.0],
+        [0.0, 0.0, 0.0, 0.0],
+    ]);
+    assert_eq!(data_expected, data_actual);
+}
+
+#[test]
+fn should_pad_asymmetrically_with_value() {
+    let data = Data::from([[1.0, 2.0], [3.0, 4.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let data_actual = tensor.pad([(0, 1), (2, 0)], -1.0).into_data();
+
+    let data_expected = Data::from([
+        [-1.0, -1.0, 1.0, 2.0],
+        [-1.0, -1.0, 3.0, 4.0],
+        [-1.0, -1.0, -1.0, -1.0],
+    ]);
+    assert_eq!(data_expected, data_actual);
+}
@@ -0,0 +1,46 @@
+use super::super::TestBackend;
+use burn_tensor::{Data, Shape, Tensor};
+
+#[test]
+fn should_support_permute_ops() {
+    let data = Data::from([
+        [
+            [0.0, 1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0, 7.0],
+            [8.0, 9.0, 10.0, 11.0],
+        ],
+        [
+            [12.0, 13.0, 14.0, 15.0],
+            [16.0, 17.0, 18.0, 19.0],
+            [20.0, 21.0, 22.0, 23.0],
+        ],
+    ]);
+    let tensor = Tensor::<TestBackend, 3>::from_data(data);
+
+    let tensor = tensor.permute([2, 0, 1]);
+
+    assert_eq!(tensor.shape(), &Shape::new([4, 2, 3]));
+    let data_expected = Data::from([
+        [[0.0, 4.0, 8.0], [12.0, 16.0, 20.0]],
+        [[1.0, 5.0, 9.0], [13.0, 17.0, 21.0]],
+        [[2.0, 6.0, 10.0], [14.0, 18.0, 22.0]],
+        [[3.0, 7.0, 11.0], [15.0, 19.0, 23.0]],
+    ]);
+    assert_eq!(tensor.into_data(), data_expected);
+}
+
+#[test]
+#[should_panic]
+fn should_panic_when_axes_are_not_a_permutation() {
+    let tensor = Tensor::<TestBackend, 3>::ones(Shape::new([2, 3, 4]));
+
+    tensor.permute([0, 1, 1]);
+}
+
+#[test]
+#[should_panic]
+fn should_panic_when_swapping_invalid_dims() {
+    let tensor = Tensor::<TestBackend, 3>::ones(Shape::new([2, 3, 4]));
+
+    tensor.swap_dims(0, 3);
+}
@@ -11,3 +11,24 @@ fn should_support_powf_ops() {
     let data_expected = Data::from([[0.0, 1.0, 1.6358], [2.182, 2.6759, 3.1352]]);
     data_expected.assert_approx_eq(&data_actual, 3);
 }
+
+#[test]
+fn should_return_nan_for_fractional_powf_of_negative_values() {
+    let data = Data::from([-1.0, -2.5]);
+    let tensor = Tensor::<TestBackend, 1>::from_data(data);
+
+    let data_actual = tensor.powf(0.5).into_data();
+
+    assert!(data_actual.value.iter().all(|value| value.is_nan()));
+}
+
+#[test]
+fn should_support_powi_ops_with_negative_values() {
+    let data = Data::from([[-2.0, -1.0, 0.0], [1.5, 2.0, -3.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let data_actual = tensor.powi(3).into_data();
+
+    let data_expected = Data::from([[-8.0, -1.0, 0.0], [3.375, 8.0, -27.0]]);
+    data_expected.assert_approx_eq(&data_actual, 3);
+}
@@ -0,0 +1,57 @@
+use super::super::{lock_rng, TestBackend};
+use burn_tensor::backend::Backend;
+use burn_tensor::{Distribution, Tensor};
+
+#[test]
+fn should_generate_same_data_with_same_seed() {
+    let _rng = lock_rng();
+    TestBackend::seed(42);
+    let tensor_1 = Tensor::<TestBackend, 2>::random([32, 32], Distribution::Standard);
+    TestBackend::seed(42);
+    let tensor_2 = Tensor::<TestBackend, 2>::random([32, 32], Distribution::Standard);
+
+    assert_eq!(tensor_1.into_data(), tensor_2.into_data());
+}
+
+#[test]
+fn should_generate_different_data_with_different_seeds() {
+    let _rng = lock_rng();
+    TestBackend::seed(42);
+    let tensor_1 = Tensor::<TestBackend, 2>::random([32, 32], Distribution::Standard);
+    TestBackend::seed(43);
+    let tensor_2 = Tensor::<TestBackend, 2>::random([32, 32], Distribution::Standard);
+
+    assert_ne!(tensor_1.into_data(), tensor_2.into_data());
+}
+
+#[test]
+fn should_sample_normal_distribution_with_mean_and_std() {
+    let _rng = lock_rng();
+    let tensor = Tensor::<TestBackend, 2>::random([200, 200], Distribution::Normal(2.0, 3.0));
+
+    let values = tensor.into_data().value;
+    let num_values = values.len() as f64;
+    let mean = values.iter().map(|value| *value as f64).sum::<f64>() / num_values;
+    let var = values
+        .iter()
+        .map(|value| (*value as f64 - mean).powi(2))
+        .sum::<f64>()
+        / num_values;
+
+    assert!((mean - 2.0).abs() < 0.1, "Mean was {}", mean);
+    assert!((var - 9.0).abs() < 0.3, "Variance was {}", var);
+}
+
+#[test]
+fn should_sample_bernoulli_distribution_with_prob() {
+    let _rng = lock_rng();
+    let tensor = Tensor::<TestBackend, 2>::random([200, 200], Distribution::Bernoulli(0.3));
+
+    let values = tensor.into_data().value;
+    let num_ones = values.iter().filter(|value| **value == 1.0).count();
+    let num_zeros = values.iter().filter(|value| **value == 0.0).count();
+    let ratio = num_ones as f64 / values.len() as f64;
+
+    assert_eq!(num_ones + num_zeros, values.len());
+    assert!((ratio - 0.3).abs() < 0.01, "Ratio was {}", ratio);
+}
@@ -0,0 +1,24 @@
+use super::super::TestBackend;
+use burn_tensor::{Data, Tensor};
+
+#[test]
+fn should_support_reciprocal_ops() {
+    let data = Data::from([[0.0, -1.0, 2.0], [-4.0, 0.5, -0.25]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let data_actual = tensor.reciprocal().into_data();
+
+    let data_expected = Data::from([[f32::INFINITY, -1.0, 0.5], [-0.25, 2.0, -4.0]]);
+    assert_eq!(data_expected, data_actual);
+}
+
+#[test]
+fn should_support_rsqrt_ops() {
+    let data = Data::from([[0.0, 1.0, 4.0], [16.0, 0.25, 100.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let data_actual = tensor.rsqrt().into_data();
+
+    let data_expected = Data::from([[f32::INFINITY, 1.0, 0.5], [0.25, 2.0, 0.1]]);
+    data_expected.assert_approx_eq(&data_actual, 5);
+}
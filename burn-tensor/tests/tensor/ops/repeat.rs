@@ -16,3 +16,32 @@ fn should_support_repeat_ops() {
     ]);
     assert_eq!(data_expected, data_actual);
 }
+
+#[test]
+fn should_tile_dimension_with_size_greater_than_one() {
+    let data = Data::from([[0.0, 1.0], [2.0, 3.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let data_actual = tensor.repeat(0, 3).into_data();
+
+    let data_expected = Data::from([
+        [0.0, 1.0],
+        [2.0, 3.0],
+        [0.0, 1.0],
+        [2.0, 3.0],
+        [0.0, 1.0],
+        [2.0, 3.0],
+    ]);
+    assert_eq!(data_expected, data_actual);
+}
+
+#[test]
+fn should_tile_last_dimension() {
+    let data = Data::from([[0.0, 1.0], [2.0, 3.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let data_actual = tensor.repeat(1, 2).into_data();
+
+    let data_expected = Data::from([[0.0, 1.0, 0.0, 1.0], [2.0, 3.0, 2.0, 3.0]]);
+    assert_eq!(data_expected, data_actual);
+}
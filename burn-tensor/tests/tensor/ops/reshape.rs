@@ -22,3 +22,42 @@ fn should_support_reshape_2d() {
     let data_expected = Data::from([0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
     assert_eq!(data_expected, data_actual);
 }
+
+#[test]
+fn should_support_reshape_infer() {
+    let tensor = Tensor::<TestBackend, 3>::zeros([2, 3, 4]);
+
+    assert_eq!(tensor.reshape_infer([2, -1]).shape(), &Shape::new([2, 12]));
+    assert_eq!(
+        tensor.reshape_infer([-1, 4, 2]).shape(),
+        &Shape::new([3, 4, 2])
+    );
+    assert_eq!(tensor.reshape_infer([6, 4]).shape(), &Shape::new([6, 4]));
+}
+
+#[test]
+fn should_keep_values_when_inferring_reshape() {
+    let data = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let data_actual = tensor.reshape_infer([-1]).into_data();
+
+    let data_expected = Data::from([0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+    assert_eq!(data_expected, data_actual);
+}
+
+#[test]
+#[should_panic]
+fn should_panic_when_inferred_dimension_does_not_divide() {
+    let tensor = Tensor::<TestBackend, 2>::zeros([2, 3]);
+
+    tensor.reshape_infer([4, -1]);
+}
+
+#[test]
+#[should_panic]
+fn should_panic_when_inferring_more_than_one_dimension() {
+    let tensor = Tensor::<TestBackend, 2>::zeros([2, 3]);
+
+    tensor.reshape_infer([-1, -1]);
+}
@@ -0,0 +1,46 @@
+use super::super::TestBackend;
+use burn_tensor::{Data, Tensor};
+
+#[test]
+fn should_roll_vector() {
+    let data = Data::from([0.0, 1.0, 2.0, 3.0, 4.0]);
+    let tensor = Tensor::<TestBackend, 1>::from_data(data);
+
+    let data_actual = tensor.roll(&[2], &[0]).into_data();
+
+    let data_expected = Data::from([3.0, 4.0, 0.0, 1.0, 2.0]);
+    assert_eq!(data_expected, data_actual);
+}
+
+#[test]
+fn should_roll_with_negative_shift() {
+    let data = Data::from([0.0, 1.0, 2.0, 3.0, 4.0]);
+    let tensor = Tensor::<TestBackend, 1>::from_data(data);
+
+    let data_actual = tensor.roll(&[-1], &[0]).into_data();
+
+    let data_expected = Data::from([1.0, 2.0, 3.0, 4.0, 0.0]);
+    assert_eq!(data_expected, data_actual);
+}
+
+#[test]
+fn should_wrap_shift_larger_than_dimension() {
+    let data = Data::from([0.0, 1.0, 2.0, 3.0, 4.0]);
+    let tensor = Tensor::<TestBackend, 1>::from_data(data);
+
+    let data_actual = tensor.roll(&[12], &[0]).into_data();
+
+    let data_expected = Data::from([3.0, 4.0, 0.0, 1.0, 2.0]);
+    assert_eq!(data_expected, data_actual);
+}
+
+#[test]
+fn should_roll_multiple_dims() {
+    let data = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let data_actual = tensor.roll(&[1, 1], &[0, 1]).into_data();
+
+    let data_expected = Data::from([[5.0, 3.0, 4.0], [2.0, 0.0, 1.0]]);
+    assert_eq!(data_expected, data_actual);
+}
@@ -0,0 +1,54 @@
+use super::super::TestBackend;
+use burn_tensor::{Data, Tensor};
+
+fn tensor() -> Tensor<TestBackend, 1> {
+    Tensor::from_data(Data::from([-2.5, -1.5, -0.5, -0.3, 0.0, 0.5, 1.5, 2.7]))
+}
+
+#[test]
+fn should_support_sign_ops() {
+    let data_actual = tensor().sign().into_data();
+
+    let data_expected = Data::from([-1.0, -1.0, -1.0, -1.0, 0.0, 1.0, 1.0, 1.0]);
+    assert_eq!(data_expected, data_actual);
+}
+
+#[test]
+fn should_support_round_ops_with_halfway_values_to_even() {
+    let data_actual = tensor().round().into_data();
+
+    let data_expected = Data::from([-2.0, -2.0, 0.0, 0.0, 0.0, 0.0, 2.0, 3.0]);
+    assert_eq!(data_expected, data_actual);
+}
+
+#[test]
+fn should_support_floor_ops() {
+    let data_actual = tensor().floor().into_data();
+
+    let data_expected = Data::from([-3.0, -2.0, -1.0, -1.0, 0.0, 0.0, 1.0, 2.0]);
+    assert_eq!(data_expected, data_actual);
+}
+
+#[test]
+fn should_support_ceil_ops() {
+    let data_actual = tensor().ceil().into_data();
+
+    let data_expected = Data::from([-2.0, -1.0, 0.0, 0.0, 0.0, 1.0, 2.0, 3.0]);
+    assert_eq!(data_expected, data_actual);
+}
+
+#[test]
+fn should_support_trunc_ops() {
+    let data_actual = tensor().trunc().into_data();
+
+    let data_expected = Data::from([-2.0, -1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 2.0]);
+    assert_eq!(data_expected, data_actual);
+}
+
+#[test]
+fn should_support_straight_through_rounding_ops() {
+    let tensor = tensor();
+
+    assert_eq!(tensor.round_ste().into_data(), tensor.round().into_data());
+    assert_eq!(tensor.sign_ste().into_data(), tensor.sign().into_data());
+}
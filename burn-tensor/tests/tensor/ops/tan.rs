@@ -0,0 +1,13 @@
+use super::super::TestBackend;
+use burn_tensor::{Data, Tensor};
+
+#[test]
+fn should_support_tan_ops() {
+    let data = Data::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let data_actual = tensor.tan().into_data();
+
+    let data_expected = Data::from([[0.0, 1.5574, -2.1850], [-0.1425, 1.1578, -3.3805]]);
+    data_expected.assert_within_tolerance(&data_actual, 1e-4);
+}
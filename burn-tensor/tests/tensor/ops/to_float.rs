@@ -0,0 +1,28 @@
+use super::super::TestBackend;
+use burn_tensor::{BoolTensor, Data, Tensor};
+
+#[test]
+fn should_cast_bool_mask_to_float() {
+    let mask = BoolTensor::<TestBackend, 2>::from_data(Data::from([
+        [true, false, true],
+        [false, false, true],
+    ]));
+
+    let tensor = mask.to_float();
+
+    assert_eq!(tensor.shape(), mask.shape());
+    assert_eq!(
+        tensor.into_data(),
+        Data::from([[1.0, 0.0, 1.0], [0.0, 0.0, 1.0]])
+    );
+}
+
+#[test]
+fn should_support_arithmetic_on_casted_mask() {
+    let tensor = Tensor::<TestBackend, 1>::from_data(Data::from([2.0, -1.0, 4.0]));
+    let mask = tensor.greater_scalar(0.0).to_float();
+
+    let masked = tensor.mul(&mask);
+
+    assert_eq!(masked.into_data(), Data::from([2.0, 0.0, 4.0]));
+}
@@ -0,0 +1,88 @@
+use super::super::TestBackend;
+use burn_tensor::{Data, Tensor};
+
+fn matrix() -> Tensor<TestBackend, 2> {
+    Tensor::from_data(Data::from([
+        [1.0, -2.0, 3.0, -4.0],
+        [-5.0, 6.0, -7.0, 8.0],
+        [9.0, -10.0, 11.0, -12.0],
+        [-13.0, 14.0, -15.0, 16.0],
+    ]))
+}
+
+#[test]
+fn should_support_tril_main_diagonal() {
+    let data_actual = matrix().tril(0).into_data();
+
+    let data_expected = Data::from([
+        [1.0, 0.0, 0.0, 0.0],
+        [-5.0, 6.0, 0.0, 0.0],
+        [9.0, -10.0, 11.0, 0.0],
+        [-13.0, 14.0, -15.0, 16.0],
+    ]);
+    assert_eq!(data_expected, data_actual);
+    assert_masked_are_positive_zeros(&data_actual, |row, col| col > row);
+}
+
+#[test]
+fn should_support_tril_below_diagonal() {
+    let data_actual = matrix().tril(-1).into_data();
+
+    let data_expected = Data::from([
+        [0.0, 0.0, 0.0, 0.0],
+        [-5.0, 0.0, 0.0, 0.0],
+        [9.0, -10.0, 0.0, 0.0],
+        [-13.0, 14.0, -15.0, 0.0],
+    ]);
+    assert_eq!(data_expected, data_actual);
+    assert_masked_are_positive_zeros(&data_actual, |row, col| col + 1 > row);
+}
+
+#[test]
+fn should_support_triu_main_diagonal() {
+    let data_actual = matrix().triu(0).into_data();
+
+    let data_expected = Data::from([
+        [1.0, -2.0, 3.0, -4.0],
+        [0.0, 6.0, -7.0, 8.0],
+        [0.0, 0.0, 11.0, -12.0],
+        [0.0, 0.0, 0.0, 16.0],
+    ]);
+    assert_eq!(data_expected, data_actual);
+    assert_masked_are_positive_zeros(&data_actual, |row, col| col < row);
+}
+
+#[test]
+fn should_support_triu_below_diagonal() {
+    let data_actual = matrix().triu(-1).into_data();
+
+    let data_expected = Data::from([
+        [1.0, -2.0, 3.0, -4.0],
+        [-5.0, 6.0, -7.0, 8.0],
+        [0.0, -10.0, 11.0, -12.0],
+        [0.0, 0.0, -15.0, 16.0],
+    ]);
+    assert_eq!(data_expected, data_actual);
+    assert_masked_are_positive_zeros(&data_actual, |row, col| col + 1 < row);
+}
+
+#[test]
+fn should_support_tril_on_batched_matrices() {
+    let tensor = Tensor::<TestBackend, 3>::from_data(Data::from([
+        [[1.0, 2.0], [3.0, 4.0]],
+        [[5.0, 6.0], [7.0, 8.0]],
+    ]));
+
+    let data_actual = tensor.tril(0).into_data();
+
+    let data_expected = Data::from([[[1.0, 0.0], [3.0, 4.0]], [[5.0, 0.0], [7.0, 8.0]]]);
+    assert_eq!(data_expected, data_actual);
+}
+
+fn assert_masked_are_positive_zeros<F: Fn(usize, usize) -> bool>(data: &Data<f32, 2>, masked: F) {
+    for (i, value) in data.value.iter().enumerate() {
+        if masked(i / 4, i % 4) {
+            assert_eq!(value.to_bits(), 0.0f32.to_bits());
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use super::super::TestBackend;
+use burn_tensor::{Data, Tensor};
+
+#[test]
+fn test_median_of_even_number_of_elements() {
+    let data = Data::from([3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0]);
+    let tensor = Tensor::<TestBackend, 1>::from_data(data);
+
+    let data_actual = tensor.median().into_data();
+
+    data_actual.assert_approx_eq(&Data::from([3.5]), 3);
+}
+
+#[test]
+fn test_median_of_odd_number_of_elements() {
+    let data = Data::from([[3.0, 1.0, 4.0], [1.0, 5.0, 9.0], [2.0, 6.0, 5.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let data_actual = tensor.median().into_data();
+
+    data_actual.assert_approx_eq(&Data::from([4.0]), 3);
+}
+
+#[test]
+fn test_quantiles_with_linear_interpolation() {
+    let data = Data::from([3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0]);
+    let tensor = Tensor::<TestBackend, 1>::from_data(data);
+
+    let first_quartile = tensor.quantile(0.25, 0).into_data();
+    let third_quartile = tensor.quantile(0.75, 0).into_data();
+
+    first_quartile.assert_approx_eq(&Data::from([1.75]), 3);
+    third_quartile.assert_approx_eq(&Data::from([5.25]), 3);
+}
+
+#[test]
+fn test_quantile_dim() {
+    let data = Data::from([[0.0, 4.0, 2.0, 6.0], [7.0, -1.0, 3.0, 1.0]]);
+    let tensor = Tensor::<TestBackend, 2>::from_data(data);
+
+    let data_actual = tensor.quantile(0.25, 1).into_data();
+
+    data_actual.assert_approx_eq(&Data::from([[1.5], [0.5]]), 3);
+}
+
+#[test]
+#[should_panic]
+fn test_quantile_out_of_range_should_panic() {
+    let tensor = Tensor::<TestBackend, 1>::from_data(Data::from([1.0, 2.0]));
+
+    tensor.quantile(1.5, 0);
+}
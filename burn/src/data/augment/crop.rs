@@ -0,0 +1,127 @@
+use crate as burn;
+use crate::config::Config;
+use crate::module::Forward;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Distribution, ElementConversion, Tensor};
+use num_traits::ToPrimitive;
+
+/// Configuration to create a [RandomCrop](RandomCrop) transform.
+#[derive(Config)]
+pub struct RandomCropConfig {
+    /// The size `[height, width]` of the cropped images.
+    pub size: [usize; 2],
+}
+
+/// Crop images at a random position during training.
+///
+/// The input tensor is expected to have the shape `[batch_size, channels, height, width]`,
+/// each image of the batch being cropped at its own random position.
+///
+/// Without autodiff, the images are cropped at their center so the output shape stays the same.
+#[derive(Clone, Debug)]
+pub struct RandomCrop {
+    size: [usize; 2],
+}
+
+impl RandomCrop {
+    pub fn new(config: &RandomCropConfig) -> Self {
+        Self { size: config.size }
+    }
+}
+
+impl<B: Backend> Forward<Tensor<B, 4>, Tensor<B, 4>> for RandomCrop {
+    fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let [batch_size, channels, height, width] = *input.dims();
+        let [crop_height, crop_width] = self.size;
+
+        if crop_height > height || crop_width > width {
+            panic!(
+                "Can't crop images of size {:?} to size {:?}",
+                [height, width],
+                self.size
+            );
+        }
+
+        let max_top = height - crop_height;
+        let max_left = width - crop_width;
+
+        if !B::ad_enabled() {
+            let top = max_top / 2;
+            let left = max_left / 2;
+
+            return input.index([
+                0..batch_size,
+                0..channels,
+                top..top + crop_height,
+                left..left + crop_width,
+            ]);
+        }
+
+        let tops = random_offsets::<B>(batch_size, max_top);
+        let lefts = random_offsets::<B>(batch_size, max_left);
+
+        let images = tops
+            .into_iter()
+            .zip(lefts)
+            .enumerate()
+            .map(|(index, (top, left))| {
+                input.index([
+                    index..index + 1,
+                    0..channels,
+                    top..top + crop_height,
+                    left..left + crop_width,
+                ])
+            })
+            .collect();
+
+        Tensor::cat(images, 0)
+    }
+}
+
+/// Sample `num` offsets uniformly in `[0, max]`.
+fn random_offsets<B: Backend>(num: usize, max: usize) -> Vec<usize> {
+    let distribution = Distribution::Uniform(B::Elem::from_elem(0.0), B::Elem::from_elem(1.0));
+    let samples = Tensor::<B, 1>::random([num], distribution).into_data();
+
+    samples
+        .value
+        .iter()
+        .map(|sample| {
+            let offset = (sample.to_f64().unwrap() * (max + 1) as f64) as usize;
+            usize::min(offset, max)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lock_rng;
+    use crate::tensor::Data;
+    use crate::{TestADBackend, TestBackend};
+
+    #[test]
+    fn with_ad_backend_should_crop_to_size() {
+        let _rng = lock_rng();
+        let tensor = Tensor::<TestADBackend, 4>::random([4, 3, 8, 6], Distribution::Standard);
+        let crop = RandomCrop::new(&RandomCropConfig::new([5, 4]));
+
+        let output = crop.forward(tensor);
+
+        assert_eq!(output.dims(), &[4, 3, 5, 4]);
+    }
+
+    #[test]
+    fn without_ad_backend_should_crop_center() {
+        let tensor = Tensor::<TestBackend, 4>::from_data(Data::from([[[
+            [0.0, 1.0, 2.0],
+            [3.0, 4.0, 5.0],
+            [6.0, 7.0, 8.0],
+        ]]]));
+        let crop = RandomCrop::new(&RandomCropConfig::new([1, 1]));
+
+        let output = crop.forward(tensor);
+
+        assert_eq!(output.into_data(), Data::from([[[[4.0]]]]));
+    }
+}
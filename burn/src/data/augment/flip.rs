@@ -0,0 +1,121 @@
+use crate as burn;
+use crate::config::Config;
+use crate::module::Forward;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Distribution, Tensor};
+use num_traits::ToPrimitive;
+
+/// Configuration to create a [RandomHorizontalFlip](RandomHorizontalFlip) transform.
+#[derive(Config)]
+pub struct RandomHorizontalFlipConfig {
+    /// The probability of flipping each image of the batch. Default: 0.5
+    #[config(default = 0.5)]
+    pub prob: f64,
+}
+
+/// Randomly flip images along their width dimension during training.
+///
+/// The input tensor is expected to have the shape `[batch_size, channels, height, width]`,
+/// each image of the batch being flipped independently with probability `p`.
+///
+/// The random draws come from the backend random generator, so they are reproducible
+/// when the backend is seeded.
+#[derive(Clone, Debug)]
+pub struct RandomHorizontalFlip {
+    prob: f64,
+}
+
+impl RandomHorizontalFlip {
+    pub fn new(config: &RandomHorizontalFlipConfig) -> Self {
+        Self { prob: config.prob }
+    }
+}
+
+impl<B: Backend> Forward<Tensor<B, 4>, Tensor<B, 4>> for RandomHorizontalFlip {
+    fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        if !B::ad_enabled() || self.prob == 0.0 {
+            return input;
+        }
+
+        let [batch_size, channels, height, width] = *input.dims();
+        let flips = Tensor::<B, 1>::random([batch_size], Distribution::Bernoulli(self.prob));
+        let flips = flips.into_data();
+
+        let images = flips
+            .value
+            .iter()
+            .enumerate()
+            .map(|(index, flip)| {
+                let image = input.index([index..index + 1, 0..channels, 0..height, 0..width]);
+
+                match flip.to_f64().unwrap() == 1.0 {
+                    true => flip_width(&image),
+                    false => image,
+                }
+            })
+            .collect();
+
+        Tensor::cat(images, 0)
+    }
+}
+
+fn flip_width<B: Backend>(tensor: &Tensor<B, 4>) -> Tensor<B, 4> {
+    let [batch_size, channels, height, width] = *tensor.dims();
+    let columns = (0..width)
+        .rev()
+        .map(|i| tensor.index([0..batch_size, 0..channels, 0..height, i..i + 1]))
+        .collect();
+
+    Tensor::cat(columns, 3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lock_rng;
+    use crate::tensor::Data;
+    use crate::{TestADBackend, TestBackend};
+
+    #[test]
+    fn flip_width_should_reverse_columns() {
+        let tensor =
+            Tensor::<TestBackend, 4>::from_data(Data::from([[[[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]]]));
+
+        let output = flip_width(&tensor);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[[[2.0, 1.0, 0.0], [5.0, 4.0, 3.0]]]])
+        );
+    }
+
+    #[test]
+    fn with_ad_backend_should_flip_with_prob() {
+        let _rng = lock_rng();
+        let num_images = 2000;
+        let tensor = Tensor::<TestADBackend, 4>::from_data(Data::from([[[[0.0, 1.0]]]]))
+            .repeat(0, num_images);
+        let flip = RandomHorizontalFlip::new(&RandomHorizontalFlipConfig::new().with_prob(0.3));
+
+        let output = flip.forward(tensor);
+
+        let num_flipped = output
+            .index([0..num_images, 0..1, 0..1, 0..1])
+            .sum()
+            .into_data()
+            .value[0];
+        let ratio = num_flipped as f64 / num_images as f64;
+        assert!((ratio - 0.3).abs() < 0.05, "Flip ratio was {}", ratio);
+    }
+
+    #[test]
+    fn without_ad_backend_should_not_change_input() {
+        let _rng = lock_rng();
+        let tensor = Tensor::<TestBackend, 4>::random([4, 3, 5, 5], Distribution::Standard);
+        let flip = RandomHorizontalFlip::new(&RandomHorizontalFlipConfig::new().with_prob(1.0));
+
+        let output = flip.forward(tensor.clone());
+
+        assert_eq!(tensor.into_data(), output.into_data());
+    }
+}
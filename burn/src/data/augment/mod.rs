@@ -0,0 +1,7 @@
+mod crop;
+mod flip;
+mod normalize;
+
+pub use crop::*;
+pub use flip::*;
+pub use normalize::*;
@@ -0,0 +1,109 @@
+use crate as burn;
+use crate::config::Config;
+use crate::module::Forward;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Data, ElementConversion, Shape, Tensor};
+
+/// Configuration to create a [Normalize](Normalize) transform.
+#[derive(Config)]
+pub struct NormalizeConfig {
+    /// The mean of each channel.
+    pub mean: Vec<f64>,
+    /// The standard deviation of each channel.
+    pub std: Vec<f64>,
+}
+
+/// Normalize each channel of images with its mean and standard deviation.
+///
+/// `Y = (X - mean) / std`
+///
+/// The input tensor is expected to have the shape `[batch_size, channels, height, width]`.
+/// Contrary to the random transforms, it is applied during training and evaluation.
+#[derive(Clone, Debug)]
+pub struct Normalize {
+    mean: Vec<f64>,
+    std: Vec<f64>,
+}
+
+impl Normalize {
+    pub fn new(config: &NormalizeConfig) -> Self {
+        if config.mean.len() != config.std.len() {
+            panic!(
+                "Mean and std must have the same number of channels, got {} and {}",
+                config.mean.len(),
+                config.std.len()
+            );
+        }
+
+        Self {
+            mean: config.mean.clone(),
+            std: config.std.clone(),
+        }
+    }
+
+    /// Revert the normalization, `X = Y * std + mean`.
+    pub fn inverse<B: Backend>(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let (mean, std) = self.channel_tensors(&input);
+        input.mul(&std).add(&mean)
+    }
+
+    fn channel_tensors<B: Backend>(&self, input: &Tensor<B, 4>) -> (Tensor<B, 4>, Tensor<B, 4>) {
+        let channels = input.dims()[1];
+        if channels != self.mean.len() {
+            panic!("Expected {} channels, got {}", self.mean.len(), channels);
+        }
+
+        let shape = Shape::new([1, channels, 1, 1]);
+        let to_tensor = |values: &[f64]| {
+            let values = values.iter().map(|value| B::Elem::from_elem(*value));
+            Tensor::from_data_device(Data::new(values.collect(), shape), input.device())
+        };
+
+        (to_tensor(&self.mean), to_tensor(&self.std))
+    }
+}
+
+impl<B: Backend> Forward<Tensor<B, 4>, Tensor<B, 4>> for Normalize {
+    fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let (mean, std) = self.channel_tensors(&input);
+        input.sub(&mean).div(&std)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lock_rng;
+    use crate::tensor::Distribution;
+    use crate::TestBackend;
+
+    #[test]
+    fn should_normalize_each_channel() {
+        let tensor =
+            Tensor::<TestBackend, 4>::from_data(Data::from([[[[1.0, 3.0]], [[2.0, 6.0]]]]));
+        let normalize = Normalize::new(&NormalizeConfig::new(vec![2.0, 4.0], vec![1.0, 2.0]));
+
+        let output = normalize.forward(tensor);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[[[-1.0, 1.0]], [[-1.0, 1.0]]]])
+        );
+    }
+
+    #[test]
+    fn inverse_should_round_trip() {
+        let _rng = lock_rng();
+        let tensor = Tensor::<TestBackend, 4>::random([2, 3, 4, 4], Distribution::Standard);
+        let normalize = Normalize::new(&NormalizeConfig::new(
+            vec![0.485, 0.456, 0.406],
+            vec![0.229, 0.224, 0.225],
+        ));
+
+        let output = normalize.inverse(normalize.forward(tensor.clone()));
+
+        output
+            .into_data()
+            .assert_within_tolerance(&tensor.into_data(), 1e-4);
+    }
+}
@@ -1,4 +1,7 @@
-use super::{batcher::Batcher, BatchDataLoader, BatchStrategy, DataLoader, FixBatchStrategy};
+use super::{
+    batcher::Batcher, BatchDataLoader, BatchStrategy, DataLoader, FixBatchStrategy,
+    PrefetchDataLoader,
+};
 use burn_dataset::{transform::ShuffledDataset, Dataset};
 use std::sync::Arc;
 
@@ -7,6 +10,7 @@ pub struct DataLoaderBuilder<I, O> {
     batcher: Arc<dyn Batcher<I, O>>,
     num_threads: Option<usize>,
     shuffle: Option<u64>,
+    prefetch: Option<usize>,
 }
 
 impl<I, O> DataLoaderBuilder<I, O>
@@ -20,6 +24,7 @@ where
             strategy: None,
             num_threads: None,
             shuffle: None,
+            prefetch: None,
         }
     }
 
@@ -38,6 +43,17 @@ where
         self
     }
 
+    /// Prepare batches on background workers, keeping at most `depth` batches ready ahead of
+    /// the training loop.
+    ///
+    /// Unlike the default multi-threaded loading, batches are yielded in the same order as with
+    /// a single thread. The number of workers is set with [num_workers](Self::num_workers) and
+    /// defaults to one.
+    pub fn prefetch(mut self, depth: usize) -> Self {
+        self.prefetch = Some(depth);
+        self
+    }
+
     pub fn build(self, dataset: Arc<dyn Dataset<I>>) -> Arc<dyn DataLoader<O>> {
         let dataset = match self.shuffle {
             Some(seed) => Arc::new(ShuffledDataset::with_seed(dataset, seed)),
@@ -47,6 +63,15 @@ where
             Some(strategy) => strategy,
             None => Box::new(FixBatchStrategy::new(1)),
         };
+        if let Some(depth) = self.prefetch {
+            return Arc::new(PrefetchDataLoader::new(
+                strategy,
+                dataset,
+                self.batcher,
+                self.num_threads.unwrap_or(1),
+                depth,
+            ));
+        }
         if let Some(num_threads) = self.num_threads {
             return Arc::new(BatchDataLoader::multi_thread(
                 strategy,
@@ -0,0 +1,341 @@
+use super::{batcher::Batcher, BatchStrategy, DataLoader, DataLoaderIterator, Progress};
+use burn_dataset::Dataset;
+use std::collections::HashMap;
+use std::sync::{mpsc, Arc, Condvar, Mutex};
+use std::thread;
+
+/// Data loader preparing batches on worker threads while the previous ones are being consumed.
+///
+/// Batches are yielded in the same order as with the [BatchDataLoader](super::BatchDataLoader),
+/// and at most `prefetch` batches are queued ahead of the consumer.
+pub struct PrefetchDataLoader<I, O> {
+    strategy: Box<dyn BatchStrategy<I>>,
+    dataset: Arc<dyn Dataset<I>>,
+    batcher: Arc<dyn Batcher<I, O>>,
+    num_workers: usize,
+    prefetch: usize,
+}
+
+struct Message<O> {
+    index: usize,
+    batch: O,
+    progress: Progress,
+}
+
+/// The items are grouped sequentially so that the batch content doesn't depend on the worker
+/// scheduling, only the batching itself is done in parallel.
+struct BatchProducer<I> {
+    strategy: Box<dyn BatchStrategy<I>>,
+    dataset: Arc<dyn Dataset<I>>,
+    current_index: usize,
+    batch_index: usize,
+}
+
+/// The range of batch indexes the workers are allowed to prepare, which follows the consumer so
+/// that the batches received out of order can't pile up.
+struct Window {
+    state: Mutex<WindowState>,
+    condvar: Condvar,
+}
+
+struct WindowState {
+    end: usize,
+    closed: bool,
+}
+
+struct PrefetchDataloaderIterator<O> {
+    next_index: usize,
+    prefetch: usize,
+    window: Arc<Window>,
+    workers: Vec<thread::JoinHandle<()>>,
+    receiver: mpsc::Receiver<Message<O>>,
+    pending: HashMap<usize, (O, Progress)>,
+    progress: Progress,
+}
+
+impl<I, O> PrefetchDataLoader<I, O> {
+    pub fn new(
+        strategy: Box<dyn BatchStrategy<I>>,
+        dataset: Arc<dyn Dataset<I>>,
+        batcher: Arc<dyn Batcher<I, O>>,
+        num_workers: usize,
+        prefetch: usize,
+    ) -> Self {
+        assert!(
+            num_workers > 0,
+            "At least one worker is required to prefetch"
+        );
+        assert!(
+            prefetch > 0,
+            "The prefetch depth must be at least one batch"
+        );
+
+        Self {
+            strategy,
+            dataset,
+            batcher,
+            num_workers,
+            prefetch,
+        }
+    }
+}
+
+impl<I, O> DataLoader<O> for PrefetchDataLoader<I, O>
+where
+    I: Send + 'static,
+    O: Send + 'static,
+{
+    fn iter<'a>(&'a self) -> Box<dyn DataLoaderIterator<O> + 'a> {
+        let (sender, receiver) = mpsc::sync_channel::<Message<O>>(self.prefetch);
+        let producer = Arc::new(Mutex::new(BatchProducer::new(
+            self.strategy.new_like(),
+            self.dataset.clone(),
+        )));
+        let window = Arc::new(Window::new(self.prefetch));
+
+        let workers = (0..self.num_workers)
+            .map(|_| {
+                let producer = producer.clone();
+                let window = window.clone();
+                let batcher = self.batcher.clone();
+                let sender = sender.clone();
+
+                thread::spawn(move || loop {
+                    let next = producer.lock().unwrap().next();
+                    let (index, items, progress) = match next {
+                        Some(next) => next,
+                        None => break,
+                    };
+                    if !window.wait(index) {
+                        break;
+                    }
+                    let batch = batcher.batch(items);
+
+                    // The receiver is dropped when the iterator is, so workers simply stop.
+                    if sender
+                        .send(Message {
+                            index,
+                            batch,
+                            progress,
+                        })
+                        .is_err()
+                    {
+                        break;
+                    }
+                })
+            })
+            .collect();
+
+        Box::new(PrefetchDataloaderIterator {
+            next_index: 0,
+            prefetch: self.prefetch,
+            window,
+            workers,
+            receiver,
+            pending: HashMap::new(),
+            progress: Progress {
+                items_processed: 0,
+                items_total: self.dataset.len(),
+            },
+        })
+    }
+}
+
+impl<I> BatchProducer<I> {
+    fn new(strategy: Box<dyn BatchStrategy<I>>, dataset: Arc<dyn Dataset<I>>) -> Self {
+        Self {
+            strategy,
+            dataset,
+            current_index: 0,
+            batch_index: 0,
+        }
+    }
+
+    fn next(&mut self) -> Option<(usize, Vec<I>, Progress)> {
+        let items = self.next_items()?;
+        let index = self.batch_index;
+        self.batch_index += 1;
+
+        let progress = Progress {
+            items_processed: self.current_index,
+            items_total: self.dataset.len(),
+        };
+
+        Some((index, items, progress))
+    }
+
+    fn next_items(&mut self) -> Option<Vec<I>> {
+        while let Some(item) = self.dataset.get(self.current_index) {
+            self.current_index += 1;
+            self.strategy.add(item);
+
+            if let Some(items) = self.strategy.batch(false) {
+                return Some(items);
+            }
+        }
+
+        self.strategy.batch(true)
+    }
+}
+
+impl Window {
+    fn new(end: usize) -> Self {
+        Self {
+            state: Mutex::new(WindowState { end, closed: false }),
+            condvar: Condvar::new(),
+        }
+    }
+
+    /// Block until the batch with the given index can be prepared, returns false if the
+    /// iterator has been dropped in the meantime.
+    fn wait(&self, index: usize) -> bool {
+        let mut state = self.state.lock().unwrap();
+        while index >= state.end && !state.closed {
+            state = self.condvar.wait(state).unwrap();
+        }
+
+        !state.closed
+    }
+
+    fn advance(&self, end: usize) {
+        self.state.lock().unwrap().end = end;
+        self.condvar.notify_all();
+    }
+
+    fn close(&self) {
+        self.state.lock().unwrap().closed = true;
+        self.condvar.notify_all();
+    }
+}
+
+impl<O> Iterator for PrefetchDataloaderIterator<O> {
+    type Item = O;
+
+    fn next(&mut self) -> Option<O> {
+        loop {
+            if let Some((batch, progress)) = self.pending.remove(&self.next_index) {
+                self.next_index += 1;
+                self.window.advance(self.next_index + self.prefetch);
+                self.progress = progress;
+                return Some(batch);
+            }
+
+            match self.receiver.recv() {
+                Ok(message) => {
+                    self.pending
+                        .insert(message.index, (message.batch, message.progress));
+                }
+                // All workers are done and every batch has been received.
+                Err(_) => {
+                    while let Some(worker) = self.workers.pop() {
+                        worker.join().unwrap();
+                    }
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+impl<O> DataLoaderIterator<O> for PrefetchDataloaderIterator<O> {
+    fn progress(&self) -> Progress {
+        self.progress.clone()
+    }
+}
+
+impl<O> Drop for PrefetchDataloaderIterator<O> {
+    fn drop(&mut self) {
+        // Release the workers waiting for the window to move.
+        self.window.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::dataloader::batcher::TestBatcher;
+    use crate::data::dataloader::{BatchDataLoader, FixBatchStrategy};
+    use crate::data::dataset::FakeDataset;
+    use burn_dataset::transform::ShuffledDataset;
+    use burn_dataset::InMemDataset;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    /// Batcher slow on the first batch, recording how many other batches were made meanwhile.
+    struct SlowFirstBatcher {
+        num_batches: AtomicUsize,
+        num_batches_during_first: AtomicUsize,
+    }
+
+    impl Batcher<usize, usize> for SlowFirstBatcher {
+        fn batch(&self, items: Vec<usize>) -> usize {
+            if items[0] == 0 {
+                thread::sleep(Duration::from_millis(100));
+                self.num_batches_during_first
+                    .store(self.num_batches.load(Ordering::SeqCst), Ordering::SeqCst);
+            }
+            self.num_batches.fetch_add(1, Ordering::SeqCst);
+            items[0]
+        }
+    }
+
+    #[test]
+    fn test_prefetch_dataloader_same_batches_as_sync_dataloader() {
+        let batcher = Arc::new(TestBatcher::new());
+        let dataset: Arc<dyn Dataset<String>> = Arc::new(ShuffledDataset::with_seed(
+            Arc::new(FakeDataset::<String>::new(27)),
+            42,
+        ));
+        let dataloader_sync = BatchDataLoader::new(
+            Box::new(FixBatchStrategy::new(5)),
+            dataset.clone(),
+            batcher.clone(),
+        );
+        let dataloader_prefetch =
+            PrefetchDataLoader::new(Box::new(FixBatchStrategy::new(5)), dataset, batcher, 3, 2);
+
+        let batches_sync: Vec<Vec<String>> = dataloader_sync.iter().collect();
+        let batches_prefetch: Vec<Vec<String>> = dataloader_prefetch.iter().collect();
+
+        assert_eq!(batches_sync.len(), 6);
+        assert_eq!(batches_sync, batches_prefetch);
+    }
+
+    #[test]
+    fn test_prefetch_dataloader_progress() {
+        let batcher = Arc::new(TestBatcher::new());
+        let dataset = Arc::new(FakeDataset::<String>::new(12));
+        let dataloader =
+            PrefetchDataLoader::new(Box::new(FixBatchStrategy::new(5)), dataset, batcher, 2, 1);
+        let mut iterator = dataloader.iter();
+
+        let mut items_processed = Vec::new();
+        while iterator.next().is_some() {
+            items_processed.push(iterator.progress().items_processed);
+        }
+
+        assert_eq!(items_processed, vec![5, 10, 12]);
+        assert_eq!(iterator.progress().items_total, 12);
+    }
+
+    #[test]
+    fn test_prefetch_dataloader_doesnt_get_ahead_of_a_slow_batch() {
+        let batcher = Arc::new(SlowFirstBatcher {
+            num_batches: AtomicUsize::new(0),
+            num_batches_during_first: AtomicUsize::new(0),
+        });
+        let dataset = Arc::new(InMemDataset::new((0..20).collect()));
+        let dataloader = PrefetchDataLoader::new(
+            Box::new(FixBatchStrategy::new(1)),
+            dataset,
+            batcher.clone(),
+            4,
+            2,
+        );
+
+        let batches: Vec<usize> = dataloader.iter().collect();
+
+        assert_eq!(batches, (0..20).collect::<Vec<_>>());
+        assert!(batcher.num_batches_during_first.load(Ordering::SeqCst) < 2);
+    }
+}
@@ -0,0 +1,265 @@
+use crate as burn;
+use crate::config::Config;
+use crate::data::dataset::Dataset;
+use crate::tensor::{Data, Shape};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use thiserror::Error;
+
+/// Configuration to create a [CsvDataset](CsvDataset).
+#[derive(Config)]
+pub struct CsvDatasetConfig {
+    /// The columns of the labels.
+    pub label_columns: Vec<usize>,
+    /// The columns of the features, all the columns that aren't labels when not set.
+    pub feature_columns: Option<Vec<usize>>,
+    /// If the first row contains the names of the columns and is skipped.
+    #[config(default = true)]
+    pub has_header: bool,
+    /// The character separating the values of a row.
+    #[config(default = ',')]
+    pub delimiter: char,
+}
+
+#[derive(Error, Debug)]
+pub enum CsvDatasetError {
+    #[error("fail to read the csv: `{0}`")]
+    IOError(#[from] std::io::Error),
+    #[error("row {row} has {found} columns, column {column} is missing")]
+    MissingColumn {
+        row: usize,
+        column: usize,
+        found: usize,
+    },
+    #[error("row {row} has {found} columns instead of {expected}")]
+    UnexpectedColumns {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("invalid number `{value}` at row {row}, column {column}")]
+    InvalidNumber {
+        row: usize,
+        column: usize,
+        value: String,
+    },
+}
+
+/// A row of a [CsvDataset](CsvDataset).
+#[derive(Clone, Debug)]
+pub struct CsvItem {
+    pub features: Data<f32, 1>,
+    pub label: Data<f32, 1>,
+}
+
+/// Dataset of numeric rows read from a csv, split into features and labels.
+///
+/// The rows are numbered from zero, the header excluded, and empty lines are skipped.
+pub struct CsvDataset {
+    items: Vec<CsvItem>,
+}
+
+impl CsvDataset {
+    /// Read the dataset from a csv file.
+    pub fn from_file(file: &str, config: &CsvDatasetConfig) -> Result<Self, CsvDatasetError> {
+        Self::from_reader(File::open(file)?, config)
+    }
+
+    /// Read the dataset from a csv reader.
+    ///
+    /// When the feature columns aren't set, they are derived from the width of the header, or
+    /// of the first row without header, and every row must have that width.
+    pub fn from_reader<R: Read>(
+        reader: R,
+        config: &CsvDatasetConfig,
+    ) -> Result<Self, CsvDatasetError> {
+        let mut lines = BufReader::new(reader).lines();
+        let mut num_columns = None;
+        if config.has_header {
+            if let Some(header) = lines.next().transpose()? {
+                num_columns = Some(header.split(config.delimiter).count());
+            }
+        }
+
+        let mut items = Vec::new();
+        let mut feature_columns = config.feature_columns.clone();
+
+        for line in lines {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let values: Vec<&str> = line.split(config.delimiter).collect();
+            let row = items.len();
+
+            if config.feature_columns.is_none() {
+                let expected = *num_columns.get_or_insert(values.len());
+
+                if values.len() < expected {
+                    return Err(CsvDatasetError::MissingColumn {
+                        row,
+                        column: values.len(),
+                        found: values.len(),
+                    });
+                }
+                if values.len() > expected {
+                    return Err(CsvDatasetError::UnexpectedColumns {
+                        row,
+                        expected,
+                        found: values.len(),
+                    });
+                }
+            }
+
+            let feature_columns = feature_columns.get_or_insert_with(|| {
+                (0..values.len())
+                    .filter(|column| !config.label_columns.contains(column))
+                    .collect()
+            });
+
+            let features = parse_columns(&values, feature_columns, row)?;
+            let label = parse_columns(&values, &config.label_columns, row)?;
+
+            items.push(CsvItem { features, label });
+        }
+
+        Ok(Self { items })
+    }
+}
+
+fn parse_columns(
+    values: &[&str],
+    columns: &[usize],
+    row: usize,
+) -> Result<Data<f32, 1>, CsvDatasetError> {
+    let mut parsed = Vec::with_capacity(columns.len());
+
+    for column in columns {
+        let value = values
+            .get(*column)
+            .ok_or(CsvDatasetError::MissingColumn {
+                row,
+                column: *column,
+                found: values.len(),
+            })?
+            .trim();
+        let value = value
+            .parse::<f32>()
+            .map_err(|_| CsvDatasetError::InvalidNumber {
+                row,
+                column: *column,
+                value: value.to_string(),
+            })?;
+
+        parsed.push(value);
+    }
+
+    Ok(Data::new(parsed, Shape::new([columns.len()])))
+}
+
+impl Dataset<CsvItem> for CsvDataset {
+    fn get(&self, index: usize) -> Option<CsvItem> {
+        self.items.get(index).cloned()
+    }
+
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Tensor;
+    use crate::TestBackend;
+
+    const CSV: &str = "x,label,y\n1.0,0,2.5\n-3,1,4\n\n0.5,2,1e1\n";
+
+    #[test]
+    fn should_parse_features_and_labels_into_tensors() {
+        let config = CsvDatasetConfig::new(vec![1]);
+        let dataset = CsvDataset::from_reader(CSV.as_bytes(), &config).unwrap();
+
+        let items: Vec<CsvItem> = dataset.iter().collect();
+        let features = items
+            .iter()
+            .map(|item| Tensor::<TestBackend, 1>::from_data(item.features.clone()))
+            .map(|tensor| tensor.reshape([1, 2]))
+            .collect();
+        let labels = items
+            .iter()
+            .map(|item| Tensor::<TestBackend, 1>::from_data(item.label.clone()))
+            .map(|tensor| tensor.reshape([1, 1]))
+            .collect();
+        let features = Tensor::cat(features, 0);
+        let labels = Tensor::cat(labels, 0);
+
+        assert_eq!(dataset.len(), 3);
+        features
+            .to_data()
+            .assert_approx_eq(&Data::from([[1.0, 2.5], [-3.0, 4.0], [0.5, 10.0]]), 3);
+        labels
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.0], [1.0], [2.0]]), 3);
+    }
+
+    #[test]
+    fn should_select_feature_columns() {
+        let config = CsvDatasetConfig::new(vec![0])
+            .with_feature_columns(Some(vec![2]))
+            .with_has_header(false)
+            .with_delimiter(';');
+        let dataset = CsvDataset::from_reader("1;2;3\n4;5;6".as_bytes(), &config).unwrap();
+
+        let item = dataset.get(1).unwrap();
+
+        assert_eq!(item.features, Data::from([6.0]));
+        assert_eq!(item.label, Data::from([4.0]));
+    }
+
+    #[test]
+    fn should_fail_on_rows_narrower_than_the_header() {
+        let config = CsvDatasetConfig::new(vec![0]);
+        let result = CsvDataset::from_reader("a,b,c\n1,2,3\n4,5\n".as_bytes(), &config);
+
+        assert!(matches!(
+            result,
+            Err(CsvDatasetError::MissingColumn {
+                row: 1,
+                column: 2,
+                found: 2,
+            })
+        ));
+    }
+
+    #[test]
+    fn should_fail_on_rows_wider_than_the_first_one() {
+        let config = CsvDatasetConfig::new(vec![0]).with_has_header(false);
+        let result = CsvDataset::from_reader("1,2\n3,4,5\n".as_bytes(), &config);
+
+        assert!(matches!(
+            result,
+            Err(CsvDatasetError::UnexpectedColumns {
+                row: 1,
+                expected: 2,
+                found: 3,
+            })
+        ));
+    }
+
+    #[test]
+    fn should_fail_on_invalid_number() {
+        let config = CsvDatasetConfig::new(vec![0]);
+        let result = CsvDataset::from_reader("a,b\n1,x\n".as_bytes(), &config);
+
+        assert!(matches!(
+            result,
+            Err(CsvDatasetError::InvalidNumber {
+                row: 0,
+                column: 1,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,170 @@
+use crate::data::dataset::Dataset;
+use crate::tensor::{Data, Shape};
+use std::path::{Path, PathBuf};
+
+/// An image of an [ImageFolderDataset](ImageFolderDataset) with the index of its class.
+#[derive(Clone, Debug)]
+pub struct ImageFolderItem {
+    /// The RGB values of the image between 0 and 1 with the shape `[channels, height, width]`,
+    /// so that a batch of images has the shape `[batch_size, channels, height, width]`.
+    pub image: Data<f32, 3>,
+    pub label: usize,
+}
+
+/// Dataset of the images found in the sub directories of a root directory, where each sub
+/// directory is a class.
+///
+/// The classes are sorted by name, so the label of an image is the index of the name of its
+/// directory in [classes](ImageFolderDataset::classes). The images are decoded when they are
+/// accessed, which only panics when a file with a valid header is truncated or modified after
+/// the dataset is created.
+pub struct ImageFolderDataset {
+    classes: Vec<String>,
+    items: Vec<(PathBuf, usize)>,
+    skipped: Vec<PathBuf>,
+}
+
+impl ImageFolderDataset {
+    /// Find the images in the sub directories of the root directory.
+    ///
+    /// Only the files with a `png`, `jpg` or `jpeg` extension are kept. Their header is read to
+    /// validate them, the files that aren't valid images are [skipped](Self::skipped).
+    pub fn new<P: AsRef<Path>>(root: P) -> Result<Self, std::io::Error> {
+        let mut directories = Vec::new();
+        for entry in std::fs::read_dir(root)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                directories.push(path);
+            }
+        }
+        directories.sort();
+
+        let mut classes = Vec::with_capacity(directories.len());
+        let mut items = Vec::new();
+        let mut skipped = Vec::new();
+
+        for (label, directory) in directories.iter().enumerate() {
+            let mut files = Vec::new();
+            for entry in std::fs::read_dir(directory)? {
+                let path = entry?.path();
+                if path.is_file() && is_image(&path) {
+                    files.push(path);
+                }
+            }
+            files.sort();
+
+            classes.push(directory.file_name().unwrap().to_string_lossy().to_string());
+            for file in files {
+                match image::image_dimensions(&file) {
+                    Ok(_) => items.push((file, label)),
+                    Err(_) => skipped.push(file),
+                }
+            }
+        }
+
+        Ok(Self {
+            classes,
+            items,
+            skipped,
+        })
+    }
+
+    /// The name of each class, ordered by label.
+    pub fn classes(&self) -> &[String] {
+        &self.classes
+    }
+
+    /// The image files that can't be read and aren't part of the dataset.
+    pub fn skipped(&self) -> &[PathBuf] {
+        &self.skipped
+    }
+}
+
+fn is_image(path: &Path) -> bool {
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some(extension) => matches!(extension.to_lowercase().as_str(), "png" | "jpg" | "jpeg"),
+        None => false,
+    }
+}
+
+fn load_image(path: &Path) -> Data<f32, 3> {
+    let image = match image::open(path) {
+        Ok(image) => image.to_rgb8(),
+        Err(err) => panic!("Can't load the image {}: {}", path.display(), err),
+    };
+    let (width, height) = image.dimensions();
+    let (width, height) = (width as usize, height as usize);
+
+    let mut value = vec![0.0; 3 * height * width];
+    for (x, y, pixel) in image.enumerate_pixels() {
+        let (x, y) = (x as usize, y as usize);
+        for (channel, color) in pixel.0.iter().enumerate() {
+            value[channel * height * width + y * width + x] = *color as f32 / 255.0;
+        }
+    }
+
+    Data::new(value, Shape::new([3, height, width]))
+}
+
+impl Dataset<ImageFolderItem> for ImageFolderDataset {
+    fn get(&self, index: usize) -> Option<ImageFolderItem> {
+        self.items.get(index).map(|(path, label)| ImageFolderItem {
+            image: load_image(path),
+            label: *label,
+        })
+    }
+
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Tensor;
+    use crate::TestBackend;
+    use image::{Rgb, RgbImage};
+
+    #[test]
+    fn should_load_images_with_the_label_of_their_directory() {
+        let root = std::env::temp_dir().join(format!(
+            "burn-image-folder-dataset-test-{}",
+            std::process::id()
+        ));
+        std::fs::remove_dir_all(&root).ok();
+        for class in ["dog", "cat"] {
+            std::fs::create_dir_all(root.join(class)).unwrap();
+        }
+        RgbImage::from_pixel(3, 2, Rgb([255, 0, 51]))
+            .save(root.join("cat").join("0.png"))
+            .unwrap();
+        RgbImage::from_pixel(3, 2, Rgb([0, 255, 0]))
+            .save(root.join("dog").join("0.png"))
+            .unwrap();
+        std::fs::write(root.join("dog").join("notes.txt"), "not an image").unwrap();
+        std::fs::write(root.join("dog").join("1.png"), "not an image").unwrap();
+
+        let dataset = ImageFolderDataset::new(&root).unwrap();
+        let items: Vec<ImageFolderItem> = dataset.iter().collect();
+        let images = items
+            .iter()
+            .map(|item| Tensor::<TestBackend, 3>::from_data(item.image.clone()))
+            .map(|tensor| tensor.reshape([1, 3, 2, 3]))
+            .collect();
+        let images: Tensor<TestBackend, 4> = Tensor::cat(images, 0);
+
+        assert_eq!(dataset.classes(), ["cat", "dog"]);
+        assert_eq!(dataset.len(), 2);
+        assert_eq!(dataset.skipped(), [root.join("dog").join("1.png")]);
+        assert_eq!(items[0].label, 0);
+        assert_eq!(items[1].label, 1);
+        assert_eq!(images.dims(), &[2, 3, 2, 3]);
+        images
+            .mean_dim(3)
+            .mean_dim(2)
+            .reshape([2, 3])
+            .to_data()
+            .assert_approx_eq(&Data::from([[1.0, 0.0, 0.2], [0.0, 1.0, 0.0]]), 3);
+    }
+}
@@ -0,0 +1,262 @@
+use crate::data::dataset::Dataset;
+use crate::tensor::{Data, Shape};
+use flate2::read::GzDecoder;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+const URL: &str = "https://storage.googleapis.com/cvdf-datasets/mnist";
+const IMAGE_MAGIC_NUMBER: u32 = 2051;
+const LABEL_MAGIC_NUMBER: u32 = 2049;
+const HEIGHT: usize = 28;
+const WIDTH: usize = 28;
+
+#[derive(Error, Debug)]
+pub enum MnistError {
+    #[error("fail to download `{0}`: {1}")]
+    Download(String, String),
+    #[error("fail to read the mnist files: `{0}`")]
+    IOError(#[from] std::io::Error),
+    #[error("invalid mnist {0}: {1}")]
+    InvalidFile(String, String),
+}
+
+/// An image of the [MnistDataset](MnistDataset) with its digit.
+#[derive(Clone, Debug)]
+pub struct MnistItem {
+    /// The gray levels of the pixels between 0 and 255 with the shape `[28, 28]`.
+    pub image: Data<f32, 2>,
+    pub label: u8,
+}
+
+/// The [MNIST](http://yann.lecun.com/exdb/mnist/) dataset of handwritten digits.
+///
+/// The files are downloaded the first time a split is loaded and cached in the `burn/mnist`
+/// directory of the user data directory.
+pub struct MnistDataset {
+    images: Vec<u8>,
+    labels: Vec<u8>,
+}
+
+impl MnistDataset {
+    /// The train split, with 60000 items.
+    pub fn train() -> Result<Self, MnistError> {
+        Self::load("train", 60000)
+    }
+
+    /// The test split, with 10000 items.
+    pub fn test() -> Result<Self, MnistError> {
+        Self::load("t10k", 10000)
+    }
+
+    fn load(split: &str, num_items: usize) -> Result<Self, MnistError> {
+        let directory = cache_dir()?;
+        let images = download(&directory, &format!("{}-images-idx3-ubyte.gz", split))?;
+        let labels = download(&directory, &format!("{}-labels-idx1-ubyte.gz", split))?;
+
+        Ok(Self {
+            images: parse_images(&images, num_items)?,
+            labels: parse_labels(&labels, num_items)?,
+        })
+    }
+}
+
+impl Dataset<MnistItem> for MnistDataset {
+    fn get(&self, index: usize) -> Option<MnistItem> {
+        let label = *self.labels.get(index)?;
+        let start = index * HEIGHT * WIDTH;
+        let image = self.images[start..start + HEIGHT * WIDTH]
+            .iter()
+            .map(|pixel| *pixel as f32)
+            .collect();
+
+        Some(MnistItem {
+            image: Data::new(image, Shape::new([HEIGHT, WIDTH])),
+            label,
+        })
+    }
+
+    fn len(&self) -> usize {
+        self.labels.len()
+    }
+}
+
+fn cache_dir() -> Result<PathBuf, MnistError> {
+    let directory = dirs::data_dir()
+        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, "No user data directory"))?
+        .join("burn")
+        .join("mnist");
+    std::fs::create_dir_all(&directory)?;
+
+    Ok(directory)
+}
+
+/// Download the file if it isn't cached and return its decompressed content.
+fn download(directory: &Path, file_name: &str) -> Result<Vec<u8>, MnistError> {
+    let path = directory.join(file_name);
+
+    if !path.exists() {
+        let url = format!("{}/{}", URL, file_name);
+        let mut bytes = Vec::new();
+        ureq::get(&url)
+            .call()
+            .map_err(|err| MnistError::Download(url.clone(), err.to_string()))?
+            .into_reader()
+            .read_to_end(&mut bytes)?;
+
+        // Written under another name first so an interrupted download isn't cached.
+        let path_tmp = directory.join(format!("{}.tmp", file_name));
+        std::fs::write(&path_tmp, bytes)?;
+        std::fs::rename(&path_tmp, &path)?;
+    }
+
+    let mut bytes = Vec::new();
+    GzDecoder::new(std::fs::File::open(&path)?)
+        .read_to_end(&mut bytes)
+        .map_err(|err| {
+            MnistError::InvalidFile(format!("file `{}`", path.display()), err.to_string())
+        })?;
+
+    Ok(bytes)
+}
+
+fn parse_images(bytes: &[u8], num_items: usize) -> Result<Vec<u8>, MnistError> {
+    let header = parse_header(bytes, "images", IMAGE_MAGIC_NUMBER, 4)?;
+    let expected = [num_items, HEIGHT, WIDTH];
+
+    if header[1..] != expected {
+        return Err(invalid(
+            "images",
+            format!("expected dimensions {:?}, got {:?}", expected, &header[1..]),
+        ));
+    }
+    check_size(bytes, "images", 16 + num_items * HEIGHT * WIDTH)?;
+
+    Ok(bytes[16..].to_vec())
+}
+
+fn parse_labels(bytes: &[u8], num_items: usize) -> Result<Vec<u8>, MnistError> {
+    let header = parse_header(bytes, "labels", LABEL_MAGIC_NUMBER, 2)?;
+
+    if header[1] != num_items {
+        return Err(invalid(
+            "labels",
+            format!("expected {} items, got {}", num_items, header[1]),
+        ));
+    }
+    check_size(bytes, "labels", 8 + num_items)?;
+
+    Ok(bytes[8..].to_vec())
+}
+
+/// Read the big endian integers of the header, starting with the magic number.
+fn parse_header(
+    bytes: &[u8],
+    kind: &str,
+    magic_number: u32,
+    size: usize,
+) -> Result<Vec<usize>, MnistError> {
+    if bytes.len() < 4 * size {
+        return Err(invalid(
+            kind,
+            format!(
+                "expected a header of {} bytes, got {}",
+                4 * size,
+                bytes.len()
+            ),
+        ));
+    }
+
+    let header: Vec<usize> = bytes[..4 * size]
+        .chunks(4)
+        .map(|chunk| u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]) as usize)
+        .collect();
+
+    if header[0] != magic_number as usize {
+        return Err(invalid(
+            kind,
+            format!("expected magic number {}, got {}", magic_number, header[0]),
+        ));
+    }
+
+    Ok(header)
+}
+
+fn check_size(bytes: &[u8], kind: &str, size: usize) -> Result<(), MnistError> {
+    if bytes.len() != size {
+        return Err(invalid(
+            kind,
+            format!("expected {} bytes, got {}", size, bytes.len()),
+        ));
+    }
+
+    Ok(())
+}
+
+fn invalid(kind: &str, reason: String) -> MnistError {
+    MnistError::InvalidFile(kind.to_string(), reason)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(values: &[u32]) -> Vec<u8> {
+        values
+            .iter()
+            .flat_map(|value| value.to_be_bytes())
+            .collect()
+    }
+
+    #[test]
+    fn should_parse_idx_files() {
+        let mut images = header(&[IMAGE_MAGIC_NUMBER, 2, 28, 28]);
+        images.extend((0..2 * 28 * 28).map(|i| (i % 256) as u8));
+        let mut labels = header(&[LABEL_MAGIC_NUMBER, 2]);
+        labels.extend([7, 3]);
+
+        let dataset = MnistDataset {
+            images: parse_images(&images, 2).unwrap(),
+            labels: parse_labels(&labels, 2).unwrap(),
+        };
+        let item = dataset.get(1).unwrap();
+
+        assert_eq!(dataset.len(), 2);
+        assert_eq!(item.label, 3);
+        assert_eq!(item.image.shape, Shape::new([28, 28]));
+        assert_eq!(item.image.value[0], (28 * 28 % 256) as f32);
+    }
+
+    #[test]
+    fn should_fail_on_invalid_magic_number() {
+        let mut labels = header(&[IMAGE_MAGIC_NUMBER, 2]);
+        labels.extend([7, 3]);
+
+        assert!(matches!(
+            parse_labels(&labels, 2),
+            Err(MnistError::InvalidFile(..))
+        ));
+    }
+
+    #[test]
+    fn should_fail_on_truncated_file() {
+        let mut images = header(&[IMAGE_MAGIC_NUMBER, 2, 28, 28]);
+        images.extend([0; 28 * 28]);
+
+        assert!(matches!(
+            parse_images(&images, 2),
+            Err(MnistError::InvalidFile(..))
+        ));
+    }
+
+    #[test]
+    #[ignore = "downloads the mnist dataset"]
+    fn should_load_train_split() {
+        let dataset = MnistDataset::train().unwrap();
+        let item = dataset.get(0).unwrap();
+
+        assert_eq!(dataset.len(), 60000);
+        assert_eq!(item.image.shape, Shape::new([28, 28]));
+        assert!(item.label < 10);
+    }
+}
@@ -0,0 +1,12 @@
+mod csv;
+#[cfg(feature = "image")]
+mod image_folder;
+#[cfg(feature = "mnist")]
+mod mnist;
+
+pub use burn_dataset::*;
+pub use csv::*;
+#[cfg(feature = "image")]
+pub use image_folder::*;
+#[cfg(feature = "mnist")]
+pub use mnist::*;
@@ -1,4 +1,3 @@
+pub mod augment;
 pub mod dataloader;
-pub mod dataset {
-    pub use burn_dataset::*;
-}
+pub mod dataset;
@@ -13,3 +13,14 @@ pub mod train;
 pub type TestBackend = crate::tensor::backend::TchBackend<f32>;
 #[cfg(test)]
 pub type TestADBackend = crate::tensor::backend::TchADBackend<f32>;
+
+#[cfg(test)]
+static RNG: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// Lock the random number generator of the backend, which is global, so that the tests seeding
+/// it don't run in parallel with the tests drawing random numbers.
+#[cfg(test)]
+pub(crate) fn lock_rng() -> std::sync::MutexGuard<'static, ()> {
+    RNG.lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
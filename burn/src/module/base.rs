@@ -1,4 +1,4 @@
-use super::{State, StateNamed};
+use super::{ModuleSummary, ParamMapper, ParamVisitor, State, StateNamed};
 use crate::optim::Optimizer;
 use crate::tensor::{
     backend::{ADBackend, Backend},
@@ -60,10 +60,47 @@ pub trait Module: Send + Sync + std::fmt::Debug + std::fmt::Display {
         -> Result<(), LoadingError>;
     /// Get the module state.
     fn state(&self) -> State<<Self::Backend as Backend>::Elem>;
+    /// Load the parameters of the module found in the given state, e.g. to load a pre-trained
+    /// backbone into a larger model.
+    ///
+    /// The parameters are matched by their path in the state, made of the field names of the
+    /// modules or of the [names](crate::module::Param::with_name) of the parameters. The
+    /// parameters missing from the state or having a different shape keep their current value.
+    ///
+    /// Nothing is logged: the sorted paths of the parameters that weren't loaded are returned
+    /// instead, so that the caller can warn about them.
+    fn load_partial(
+        &mut self,
+        state: &State<<Self::Backend as Backend>::Elem>,
+    ) -> Result<Vec<String>, LoadingError> {
+        let mut skipped = Vec::new();
+        let state = self.state().merge_partial(state, "", &mut skipped);
+        skipped.sort();
+        skipped.dedup();
+
+        self.load(&state)?;
+        Ok(skipped)
+    }
     /// Detach the module from the graph.
+    ///
+    /// Every parameter is replaced by a [detached](crate::tensor::Tensor::detach) tensor with
+    /// the same value, so that no gradient flows back to the operations that created it, such
+    /// as the updates of a previous training step. The parameters stay leaves of the graph and
+    /// keep their gradient requirement.
     fn detach(&mut self);
     /// Get the number of parameters the module has, including all of its sub-modules.
     fn num_params(&self) -> usize;
+    /// Get the name and shape of every parameter of the module, including all of its sub-modules.
+    fn summary(&self) -> ModuleSummary;
+    /// Visit every tensor parameter of the module, including all of its sub-modules, to read
+    /// its value. [Buffers](crate::module::Buffer) aren't visited.
+    fn visit_params<V: ParamVisitor<Self::Backend>>(&self, visitor: &mut V);
+    /// Replace the value of every tensor parameter of the module, including all of its
+    /// sub-modules, e.g. for a custom initialization.
+    ///
+    /// The new value becomes a leaf of the graph with the gradient requirement of the
+    /// parameter. [Buffers](crate::module::Buffer) aren't mapped.
+    fn map_params<M: ParamMapper<Self::Backend>>(&mut self, mapper: &mut M);
     /// Update the module parameters with the given [gradients](Gradients) and [optimizer](Optimizer).
     fn update_params<O: Optimizer<Backend = Self::Backend>>(
         &mut self,
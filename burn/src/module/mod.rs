@@ -1,7 +1,15 @@
 mod base;
 mod param;
+#[cfg(feature = "pytorch")]
+mod pytorch;
 mod state;
+mod summary;
+mod visitor;
 
 pub use base::*;
 pub use param::*;
+#[cfg(feature = "pytorch")]
+pub use pytorch::*;
 pub use state::*;
+pub use summary::*;
+pub use visitor::*;
@@ -6,6 +6,8 @@ use crate::tensor::Element;
 pub struct Param<T> {
     pub(super) id: ParamId,
     pub(super) value: T,
+    pub(super) require_grad: bool,
+    pub(super) name: Option<String>,
 }
 
 impl<T> std::fmt::Display for Param<T> {
@@ -19,8 +21,30 @@ impl<T> Param<T> {
         Self {
             id: ParamId::new(),
             value,
+            require_grad: true,
+            name: None,
         }
     }
+
+    /// Set the name of the parameter, which replaces the field name as its key in the
+    /// [state](crate::module::Module::state) of its parent module.
+    ///
+    /// This keeps the state compatible when the field is renamed, or makes it match the state
+    /// of another module for [partial loading](crate::module::Module::load_partial).
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// The name of the parameter, see [with_name](Param::with_name).
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// If the gradients of the parameter are computed and if it is updated by optimizers.
+    pub fn is_require_grad(&self) -> bool {
+        self.require_grad
+    }
 }
 
 impl<T> std::ops::Deref for Param<T> {
@@ -0,0 +1,122 @@
+use super::{load_with_id, state_with_id, Param};
+use crate::module::{
+    LoadingError, Module, ModuleSummary, ParamMapper, ParamVisitor, State, StateNamed,
+};
+use crate::optim::Optimizer;
+use crate::tensor::{
+    backend::{ADBackend, Backend},
+    Data, Gradients, Tensor,
+};
+use std::sync::{Arc, RwLock};
+
+/// A tensor that isn't trained, but that is saved in the [module](Module) state, like the
+/// running statistics of a batch normalization layer.
+///
+/// The value can be updated during the forward pass, it never receives gradients and isn't
+/// updated by optimizers.
+#[derive(Debug)]
+pub struct Buffer<T> {
+    value: Arc<RwLock<T>>,
+}
+
+impl<const D: usize, B: Backend> Buffer<Tensor<B, D>> {
+    pub fn new(value: Tensor<B, D>) -> Self {
+        Self {
+            value: Arc::new(RwLock::new(value.detach())),
+        }
+    }
+
+    /// Get the current value of the buffer.
+    pub fn value(&self) -> Tensor<B, D> {
+        self.value.read().unwrap().clone()
+    }
+
+    /// Update the value of the buffer, the value is detached from the graph.
+    pub fn update(&self, value: Tensor<B, D>) {
+        *self.value.write().unwrap() = value.detach();
+    }
+}
+
+impl<const D: usize, B: Backend> Module for Param<Buffer<Tensor<B, D>>> {
+    type Backend = B;
+
+    fn num_params(&self) -> usize {
+        0
+    }
+
+    fn summary(&self) -> ModuleSummary {
+        ModuleSummary::new()
+    }
+
+    fn visit_params<V: ParamVisitor<B>>(&self, _visitor: &mut V) {}
+
+    fn map_params<M: ParamMapper<B>>(&mut self, _mapper: &mut M) {}
+
+    fn update_params<O: Optimizer<Backend = B>>(&mut self, _grads: &Gradients, _optim: &mut O)
+    where
+        B: ADBackend,
+    {
+    }
+
+    fn load_optim_state<O: Optimizer<Backend = B>>(
+        &self,
+        _optim: &mut O,
+        _state_optim: &StateNamed<B::Elem>,
+    ) where
+        B: ADBackend,
+    {
+    }
+
+    fn register_optim_state<O: Optimizer<Backend = B>>(
+        &self,
+        _optim: &O,
+        _state_optim: &mut StateNamed<B::Elem>,
+    ) where
+        B: ADBackend,
+    {
+    }
+
+    fn devices(&self) -> Vec<B::Device> {
+        vec![self.value.value().device()]
+    }
+
+    fn to_device(&mut self, device: B::Device) {
+        self.value.update(self.value.value().to_device(device));
+    }
+
+    fn state(&self) -> State<B::Elem> {
+        let state = State::Data(self.value.value().to_data().serialize());
+
+        state_with_id(self.id.clone(), state)
+    }
+
+    fn load(&mut self, state: &State<B::Elem>) -> Result<(), LoadingError> {
+        let (id, state) = load_with_id(state)?;
+        self.id = id.clone();
+
+        match state {
+            State::Data(data) => {
+                let device = self.value.value().device();
+                self.value
+                    .update(Tensor::from_data_device(Data::from(data), device));
+            }
+            _ => return Err(LoadingError::new("Can't load buffer".to_string())),
+        };
+
+        Ok(())
+    }
+
+    fn detach(&mut self) {}
+}
+
+impl<const D: usize, B: Backend> Param<Buffer<Tensor<B, D>>> {
+    pub fn inner(&self) -> Param<Buffer<Tensor<B::InnerBackend, D>>>
+    where
+        B: ADBackend,
+    {
+        let mut param = Param::new(Buffer::new(self.value.value().inner()));
+        param.id = self.id.clone();
+        param.name = self.name.clone();
+        param
+    }
+}
@@ -1,9 +1,11 @@
 mod base;
+mod buffer;
 mod id;
 mod module;
 mod tensor;
 
 pub use base::*;
+pub use buffer::*;
 pub use id::*;
 pub use module::*;
 pub use tensor::*;
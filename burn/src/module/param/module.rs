@@ -1,5 +1,7 @@
 use super::{load_with_id, state_with_id, Param};
-use crate::module::{ADModule, LoadingError, Module, State, StateNamed};
+use crate::module::{
+    ADModule, LoadingError, Module, ModuleSummary, ParamMapper, ParamVisitor, State, StateNamed,
+};
 use crate::optim::Optimizer;
 use crate::tensor::{
     backend::{ADBackend, Backend},
@@ -13,6 +15,18 @@ impl<M: Module> Module for Param<M> {
         self.value.num_params()
     }
 
+    fn summary(&self) -> ModuleSummary {
+        self.value.summary()
+    }
+
+    fn visit_params<V: ParamVisitor<M::Backend>>(&self, visitor: &mut V) {
+        self.value.visit_params(visitor);
+    }
+
+    fn map_params<P: ParamMapper<M::Backend>>(&mut self, mapper: &mut P) {
+        self.value.map_params(mapper);
+    }
+
     fn update_params<O: Optimizer<Backend = M::Backend>>(
         &mut self,
         grads: &Gradients,
@@ -81,6 +95,28 @@ impl<M: Module> Module for Param<Vec<M>> {
         num_params
     }
 
+    fn summary(&self) -> ModuleSummary {
+        let mut summary = ModuleSummary::new();
+
+        for (i, module) in self.value.iter().enumerate() {
+            summary.register(format!("mod-{}", i).as_str(), module.summary());
+        }
+
+        summary
+    }
+
+    fn visit_params<V: ParamVisitor<M::Backend>>(&self, visitor: &mut V) {
+        for module in self.value.iter() {
+            module.visit_params(visitor);
+        }
+    }
+
+    fn map_params<P: ParamMapper<M::Backend>>(&mut self, mapper: &mut P) {
+        for module in self.value.iter_mut() {
+            module.map_params(mapper);
+        }
+    }
+
     fn update_params<O: Optimizer<Backend = M::Backend>>(
         &mut self,
         grads: &Gradients,
@@ -176,7 +212,9 @@ impl<M: Module> Param<Vec<M>> {
         M: ADModule,
         M::Backend: ADBackend,
     {
-        Param::new(self.value.iter().map(|v| v.inner()).collect())
+        let mut param = Param::new(self.value.iter().map(|v| v.inner()).collect());
+        param.name = self.name.clone();
+        param
     }
 }
 
@@ -186,6 +224,8 @@ impl<M: Module> Param<M> {
         M: ADModule,
         M::Backend: ADBackend,
     {
-        Param::new(self.value.inner())
+        let mut param = Param::new(self.value.inner());
+        param.name = self.name.clone();
+        param
     }
 }
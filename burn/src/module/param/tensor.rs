@@ -1,5 +1,7 @@
 use super::{load_with_id, state_with_id, Param};
-use crate::module::{LoadingError, Module, State, StateNamed};
+use crate::module::{
+    LoadingError, Module, ModuleSummary, ParamMapper, ParamVisitor, State, StateNamed,
+};
 use crate::optim::Optimizer;
 use crate::tensor::{
     backend::{ADBackend, Backend},
@@ -13,11 +15,28 @@ impl<const D: usize, B: Backend> Module for Param<Tensor<B, D>> {
         self.value.shape().num_elements()
     }
 
+    fn summary(&self) -> ModuleSummary {
+        ModuleSummary::param(self.value.shape().dims.to_vec())
+    }
+
+    fn visit_params<V: ParamVisitor<B>>(&self, visitor: &mut V) {
+        visitor.visit(&self.id, &self.value);
+    }
+
+    fn map_params<M: ParamMapper<B>>(&mut self, mapper: &mut M) {
+        self.value = mapper
+            .map(&self.id, self.value.clone())
+            .detach()
+            .set_require_grad(self.require_grad);
+    }
+
     fn update_params<O: Optimizer<Backend = B>>(&mut self, grads: &Gradients, optim: &mut O)
     where
         B: ADBackend,
     {
-        optim.update(&self.id, &mut self.value, grads);
+        if self.require_grad {
+            optim.update(&self.id, &mut self.value, grads);
+        }
     }
 
     fn load_optim_state<O: Optimizer<Backend = B>>(
@@ -45,7 +64,10 @@ impl<const D: usize, B: Backend> Module for Param<Tensor<B, D>> {
     }
 
     fn to_device(&mut self, device: B::Device) {
-        self.value = self.value.to_device(device);
+        self.value = self
+            .value
+            .to_device(device)
+            .set_require_grad(self.require_grad);
     }
 
     fn state(&self) -> State<B::Elem> {
@@ -60,7 +82,8 @@ impl<const D: usize, B: Backend> Module for Param<Tensor<B, D>> {
 
         match state {
             State::Data(data) => {
-                self.value = Tensor::from_data_device(Data::from(data), self.value.device());
+                self.value = Tensor::from_data_device(Data::from(data), self.value.device())
+                    .set_require_grad(self.require_grad);
             }
             _ => return Err(LoadingError::new("Can't load tensor".to_string())),
         };
@@ -69,7 +92,11 @@ impl<const D: usize, B: Backend> Module for Param<Tensor<B, D>> {
     }
 
     fn detach(&mut self) {
-        self.value = self.value.clone().detach()
+        self.value = self
+            .value
+            .clone()
+            .detach()
+            .set_require_grad(self.require_grad)
     }
 }
 
@@ -84,10 +111,38 @@ impl<const D: usize, B: Backend> Module for Param<Option<Tensor<B, D>>> {
         0
     }
 
+    fn summary(&self) -> ModuleSummary {
+        match &self.value {
+            Some(value) => ModuleSummary::param(value.shape().dims.to_vec()),
+            None => ModuleSummary::new(),
+        }
+    }
+
+    fn visit_params<V: ParamVisitor<B>>(&self, visitor: &mut V) {
+        if let Some(value) = &self.value {
+            visitor.visit(&self.id, value);
+        }
+    }
+
+    fn map_params<M: ParamMapper<B>>(&mut self, mapper: &mut M) {
+        if let Some(value) = &self.value {
+            self.value = Some(
+                mapper
+                    .map(&self.id, value.clone())
+                    .detach()
+                    .set_require_grad(self.require_grad),
+            );
+        }
+    }
+
     fn update_params<O: Optimizer<Backend = B>>(&mut self, grads: &Gradients, optim: &mut O)
     where
         B: ADBackend,
     {
+        if !self.require_grad {
+            return;
+        }
+
         if let Some(value) = &mut self.value {
             optim.update(&self.id, value, grads);
         }
@@ -127,7 +182,7 @@ impl<const D: usize, B: Backend> Module for Param<Option<Tensor<B, D>>> {
 
     fn to_device(&mut self, device: B::Device) {
         if let Some(value) = &self.value {
-            self.value = Some(value.to_device(device));
+            self.value = Some(value.to_device(device).set_require_grad(self.require_grad));
         }
     }
 
@@ -154,14 +209,18 @@ impl<const D: usize, B: Backend> Module for Param<Option<Tensor<B, D>>> {
         };
 
         if let Some(value) = &self.value {
-            self.value = Some(Tensor::from_data_device(Data::from(data), value.device()));
+            let tensor = Tensor::from_data_device(Data::from(data), value.device());
+            self.value = Some(tensor.set_require_grad(self.require_grad));
         }
 
         Ok(())
     }
 
     fn detach(&mut self) {
-        self.value = self.value.clone().map(|tensor| tensor.detach());
+        self.value = self
+            .value
+            .clone()
+            .map(|tensor| tensor.detach().set_require_grad(self.require_grad));
     }
 }
 
@@ -170,7 +229,47 @@ impl<const D: usize, B: Backend> Param<Tensor<B, D>> {
     where
         B: ADBackend,
     {
-        Param::new(self.value.inner())
+        let mut param = Param::new(self.value.inner());
+        param.require_grad = self.require_grad;
+        param.name = self.name.clone();
+        param
+    }
+
+    /// Set if the gradients of the parameter are computed and if it is updated by optimizers.
+    pub fn set_require_grad(&mut self, require_grad: bool) {
+        self.require_grad = require_grad;
+        self.value = self.value.clone().set_require_grad(require_grad);
+    }
+
+    /// Freeze the parameter, its gradients aren't computed and optimizers don't update it.
+    pub fn freeze(&mut self) {
+        self.set_require_grad(false);
+    }
+
+    /// Unfreeze the parameter, see [freeze](Param::freeze).
+    pub fn unfreeze(&mut self) {
+        self.set_require_grad(true);
+    }
+}
+
+impl<const D: usize, B: Backend> Param<Option<Tensor<B, D>>> {
+    /// Set if the gradients of the parameter are computed and if it is updated by optimizers.
+    pub fn set_require_grad(&mut self, require_grad: bool) {
+        self.require_grad = require_grad;
+        self.value = self
+            .value
+            .clone()
+            .map(|tensor| tensor.set_require_grad(require_grad));
+    }
+
+    /// Freeze the parameter, its gradients aren't computed and optimizers don't update it.
+    pub fn freeze(&mut self) {
+        self.set_require_grad(false);
+    }
+
+    /// Unfreeze the parameter, see [freeze](Param::freeze).
+    pub fn unfreeze(&mut self) {
+        self.set_require_grad(true);
     }
 }
 
@@ -179,9 +278,11 @@ impl<const D: usize, B: Backend> Param<Option<Tensor<B, D>>> {
     where
         B: ADBackend,
     {
-        match &self.value {
+        let mut param = match &self.value {
             Some(tensor) => Param::new(Some(tensor.inner())),
             None => Param::new(None),
-        }
+        };
+        param.name = self.name.clone();
+        param
     }
 }
@@ -0,0 +1,4 @@
+mod pickle;
+mod state_dict;
+
+pub use state_dict::*;
@@ -0,0 +1,337 @@
+use crate::module::LoadingError;
+use std::collections::HashMap;
+
+/// A python object, restricted to what is found in the pickle of a PyTorch `state_dict`.
+#[derive(Debug, Clone)]
+pub(super) enum Value {
+    Int(i64),
+    String(String),
+    Tuple(Vec<Value>),
+    List(Vec<Value>),
+    Dict(Vec<(Value, Value)>),
+    Global(String, String),
+    Storage(StorageRef),
+    Tensor(TensorRef),
+    /// An object that isn't needed to rebuild the tensors, like `None`, booleans, floats or
+    /// instances of other classes.
+    Other,
+}
+
+/// A storage saved in its own file of the archive.
+#[derive(Debug, Clone)]
+pub(super) struct StorageRef {
+    pub key: String,
+    pub dtype: String,
+}
+
+/// A view of a storage.
+#[derive(Debug, Clone)]
+pub(super) struct TensorRef {
+    pub storage: StorageRef,
+    pub offset: usize,
+    pub shape: Vec<usize>,
+    pub stride: Vec<usize>,
+}
+
+/// Execute the pickle program, returning the unpickled object.
+///
+/// Only the opcodes used by `torch.save` are supported, the instances of the classes other than
+/// the ordered dictionaries and the tensors aren't rebuilt.
+pub(super) fn unpickle(bytes: &[u8]) -> Result<Value, LoadingError> {
+    let mut reader = Reader { bytes, position: 0 };
+    let mut stack: Vec<Value> = Vec::new();
+    let mut marks: Vec<usize> = Vec::new();
+    let mut memo: HashMap<usize, Value> = HashMap::new();
+
+    loop {
+        let opcode = reader.read(1)?[0];
+
+        match opcode {
+            // PROTO
+            0x80 => {
+                reader.read(1)?;
+            }
+            // FRAME
+            0x95 => {
+                reader.read(8)?;
+            }
+            // STOP
+            b'.' => return pop(&mut stack),
+            // MARK
+            b'(' => marks.push(stack.len()),
+            // NONE, NEWTRUE, NEWFALSE
+            b'N' | 0x88 | 0x89 => stack.push(Value::Other),
+            // BININT, BININT1, BININT2, LONG1
+            b'J' => stack.push(Value::Int(i32::from_le_bytes(reader.read_array()?) as i64)),
+            b'K' => stack.push(Value::Int(reader.read(1)?[0] as i64)),
+            b'M' => stack.push(Value::Int(u16::from_le_bytes(reader.read_array()?) as i64)),
+            0x8a => {
+                let size = reader.read(1)?[0] as usize;
+                stack.push(Value::Int(read_long(reader.read(size)?)?));
+            }
+            // BINFLOAT
+            b'G' => {
+                reader.read(8)?;
+                stack.push(Value::Other);
+            }
+            // BINUNICODE, SHORT_BINUNICODE, BINSTRING, SHORT_BINSTRING
+            b'X' | b'T' => {
+                let size = u32::from_le_bytes(reader.read_array()?) as usize;
+                stack.push(Value::String(reader.read_string(size)?));
+            }
+            0x8c | b'U' => {
+                let size = reader.read(1)?[0] as usize;
+                stack.push(Value::String(reader.read_string(size)?));
+            }
+            // EMPTY_TUPLE, EMPTY_LIST, EMPTY_DICT
+            b')' => stack.push(Value::Tuple(Vec::new())),
+            b']' => stack.push(Value::List(Vec::new())),
+            b'}' => stack.push(Value::Dict(Vec::new())),
+            // TUPLE, TUPLE1, TUPLE2, TUPLE3
+            b't' => {
+                let items = pop_mark(&mut stack, &mut marks)?;
+                stack.push(Value::Tuple(items));
+            }
+            0x85..=0x87 => {
+                let size = (opcode - 0x84) as usize;
+                if stack.len() < size {
+                    return Err(invalid("stack underflow"));
+                }
+                let items = stack.split_off(stack.len() - size);
+                stack.push(Value::Tuple(items));
+            }
+            // APPEND, APPENDS
+            b'a' => {
+                let item = pop(&mut stack)?;
+                list_mut(&mut stack)?.push(item);
+            }
+            b'e' => {
+                let items = pop_mark(&mut stack, &mut marks)?;
+                list_mut(&mut stack)?.extend(items);
+            }
+            // SETITEM, SETITEMS
+            b's' => {
+                let value = pop(&mut stack)?;
+                let key = pop(&mut stack)?;
+                dict_mut(&mut stack)?.push((key, value));
+            }
+            b'u' => {
+                let items = pop_mark(&mut stack, &mut marks)?;
+                if items.len() % 2 != 0 {
+                    return Err(invalid("odd number of dictionary items"));
+                }
+                let mut items = items.into_iter();
+                let dict = dict_mut(&mut stack)?;
+                while let (Some(key), Some(value)) = (items.next(), items.next()) {
+                    dict.push((key, value));
+                }
+            }
+            // BINPUT, LONG_BINPUT, MEMOIZE
+            b'q' => {
+                let index = reader.read(1)?[0] as usize;
+                memo.insert(index, top(&stack)?.clone());
+            }
+            b'r' => {
+                let index = u32::from_le_bytes(reader.read_array()?) as usize;
+                memo.insert(index, top(&stack)?.clone());
+            }
+            0x94 => {
+                memo.insert(memo.len(), top(&stack)?.clone());
+            }
+            // BINGET, LONG_BINGET
+            b'h' | b'j' => {
+                let index = match opcode {
+                    b'h' => reader.read(1)?[0] as usize,
+                    _ => u32::from_le_bytes(reader.read_array()?) as usize,
+                };
+                let value = memo
+                    .get(&index)
+                    .ok_or_else(|| invalid(&format!("missing memo entry {}", index)))?;
+                stack.push(value.clone());
+            }
+            // GLOBAL, STACK_GLOBAL
+            b'c' => {
+                let module = reader.read_line()?;
+                let name = reader.read_line()?;
+                stack.push(Value::Global(module, name));
+            }
+            0x93 => {
+                let name = pop(&mut stack)?;
+                let module = pop(&mut stack)?;
+                match (module, name) {
+                    (Value::String(module), Value::String(name)) => {
+                        stack.push(Value::Global(module, name))
+                    }
+                    _ => return Err(invalid("the global isn't named by strings")),
+                }
+            }
+            // REDUCE, NEWOBJ
+            b'R' | 0x81 => {
+                let args = pop(&mut stack)?;
+                let callable = pop(&mut stack)?;
+                stack.push(reduce(callable, args)?);
+            }
+            // BUILD, the state of the rebuilt objects isn't needed.
+            b'b' => {
+                pop(&mut stack)?;
+            }
+            // BINPERSID
+            b'Q' => {
+                let id = pop(&mut stack)?;
+                stack.push(persistent_load(id)?);
+            }
+            _ => {
+                return Err(LoadingError::new(format!(
+                    "Unsupported pickle opcode {:#04x}",
+                    opcode
+                )))
+            }
+        }
+    }
+}
+
+fn reduce(callable: Value, args: Value) -> Result<Value, LoadingError> {
+    let (module, name) = match &callable {
+        Value::Global(module, name) => (module.as_str(), name.as_str()),
+        _ => return Ok(Value::Other),
+    };
+
+    match (module, name, args) {
+        ("collections", "OrderedDict", _) => Ok(Value::Dict(Vec::new())),
+        ("torch._utils", "_rebuild_tensor_v2", Value::Tuple(args)) => {
+            match (args.first(), args.get(1), args.get(2), args.get(3)) {
+                (
+                    Some(Value::Storage(storage)),
+                    Some(Value::Int(offset)),
+                    Some(Value::Tuple(shape)),
+                    Some(Value::Tuple(stride)),
+                ) => Ok(Value::Tensor(TensorRef {
+                    storage: storage.clone(),
+                    offset: *offset as usize,
+                    shape: to_usizes(shape)?,
+                    stride: to_usizes(stride)?,
+                })),
+                _ => Err(invalid("unexpected arguments to rebuild a tensor")),
+            }
+        }
+        ("torch._utils", "_rebuild_parameter", Value::Tuple(mut args)) if !args.is_empty() => {
+            Ok(args.remove(0))
+        }
+        _ => Ok(Value::Other),
+    }
+}
+
+/// The persistent ids of the storages are `('storage', type, key, location, size)`.
+fn persistent_load(id: Value) -> Result<Value, LoadingError> {
+    match id {
+        Value::Tuple(id) => match (id.first(), id.get(1), id.get(2)) {
+            (
+                Some(Value::String(kind)),
+                Some(Value::Global(_, dtype)),
+                Some(Value::String(key)),
+            ) if kind == "storage" => Ok(Value::Storage(StorageRef {
+                key: key.clone(),
+                dtype: dtype.clone(),
+            })),
+            _ => Err(invalid("unsupported persistent id")),
+        },
+        _ => Err(invalid("unsupported persistent id")),
+    }
+}
+
+fn to_usizes(values: &[Value]) -> Result<Vec<usize>, LoadingError> {
+    values
+        .iter()
+        .map(|value| match value {
+            Value::Int(value) if *value >= 0 => Ok(*value as usize),
+            _ => Err(invalid("expected a tuple of positive integers")),
+        })
+        .collect()
+}
+
+fn read_long(bytes: &[u8]) -> Result<i64, LoadingError> {
+    if bytes.len() > 8 {
+        return Err(invalid("integer too large"));
+    }
+
+    let fill = match bytes.last() {
+        Some(byte) if byte & 0x80 != 0 => 0xff,
+        _ => 0x00,
+    };
+    let mut value = [fill; 8];
+    value[..bytes.len()].copy_from_slice(bytes);
+
+    Ok(i64::from_le_bytes(value))
+}
+
+fn pop(stack: &mut Vec<Value>) -> Result<Value, LoadingError> {
+    stack.pop().ok_or_else(|| invalid("stack underflow"))
+}
+
+fn top(stack: &[Value]) -> Result<&Value, LoadingError> {
+    stack.last().ok_or_else(|| invalid("stack underflow"))
+}
+
+fn pop_mark(stack: &mut Vec<Value>, marks: &mut Vec<usize>) -> Result<Vec<Value>, LoadingError> {
+    match marks.pop() {
+        Some(mark) if mark <= stack.len() => Ok(stack.split_off(mark)),
+        _ => Err(invalid("missing mark")),
+    }
+}
+
+fn list_mut(stack: &mut [Value]) -> Result<&mut Vec<Value>, LoadingError> {
+    match stack.last_mut() {
+        Some(Value::List(items)) => Ok(items),
+        _ => Err(invalid("expected a list")),
+    }
+}
+
+fn dict_mut(stack: &mut [Value]) -> Result<&mut Vec<(Value, Value)>, LoadingError> {
+    match stack.last_mut() {
+        Some(Value::Dict(items)) => Ok(items),
+        _ => Err(invalid("expected a dictionary")),
+    }
+}
+
+fn invalid(reason: &str) -> LoadingError {
+    LoadingError::new(format!("Invalid pickle: {}", reason))
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn read(&mut self, size: usize) -> Result<&'a [u8], LoadingError> {
+        let end = self.position + size;
+        if end > self.bytes.len() {
+            return Err(invalid("unexpected end of data"));
+        }
+
+        let bytes = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], LoadingError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.read(N)?);
+        Ok(array)
+    }
+
+    fn read_string(&mut self, size: usize) -> Result<String, LoadingError> {
+        String::from_utf8(self.read(size)?.to_vec()).map_err(|_| invalid("invalid utf-8 string"))
+    }
+
+    fn read_line(&mut self) -> Result<String, LoadingError> {
+        let size = self.bytes[self.position..]
+            .iter()
+            .position(|byte| *byte == b'\n')
+            .ok_or_else(|| invalid("unexpected end of data"))?;
+        let line = self.read_string(size)?;
+        self.read(1)?;
+
+        Ok(line)
+    }
+}
@@ -0,0 +1,433 @@
+use super::pickle::{unpickle, TensorRef, Value};
+use crate::module::{LoadingError, Module, State, StateNamed};
+use crate::tensor::{DataSerialize, Element};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+
+/// The tensors of a PyTorch `state_dict` saved with `torch.save`, to port pre-trained weights to
+/// a [module](Module).
+///
+/// Only the `f32` tensors are read, the other entries, like the number of batches tracked by
+/// the batch norm layers, are skipped.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let mut model = Model::new(&config);
+/// let state_dict = PyTorchStateDict::load("model.pt")?
+///     .remap(|name| name.strip_prefix("encoder.").map(String::from))
+///     .transpose(|name| name.ends_with("linear.weight"));
+///
+/// let skipped = state_dict.load_into(&mut model)?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PyTorchStateDict {
+    tensors: HashMap<String, DataSerialize<f32>>,
+}
+
+impl PyTorchStateDict {
+    /// Read the state dict from a file saved with the zip format of `torch.save`, the default
+    /// since PyTorch 1.6.
+    pub fn load(file: &str) -> Result<Self, LoadingError> {
+        let file = File::open(file)
+            .map_err(|err| LoadingError::new(format!("Can't open {}: {}", file, err)))?;
+        let mut archive = zip::ZipArchive::new(file).map_err(|err| {
+            LoadingError::new(format!("Expected a zip archive saved by torch: {}", err))
+        })?;
+
+        let pickle_file = archive
+            .file_names()
+            .find(|name| name.ends_with("data.pkl"))
+            .map(String::from)
+            .ok_or_else(|| LoadingError::new("No data.pkl in the archive".to_string()))?;
+        let prefix = pickle_file.trim_end_matches("data.pkl").to_string();
+
+        let entries = match unpickle(&read_file(&mut archive, &pickle_file)?)? {
+            Value::Dict(entries) => entries,
+            _ => {
+                return Err(LoadingError::new(
+                    "Expected the state dict to be a dictionary".to_string(),
+                ))
+            }
+        };
+
+        let mut tensors = HashMap::new();
+        let mut storages: HashMap<String, Vec<f32>> = HashMap::new();
+
+        for (name, value) in entries {
+            let (name, tensor) = match (name, value) {
+                (Value::String(name), Value::Tensor(tensor)) => (name, tensor),
+                _ => continue,
+            };
+            if tensor.storage.dtype != "FloatStorage" {
+                continue;
+            }
+
+            if !storages.contains_key(&tensor.storage.key) {
+                let path = format!("{}data/{}", prefix, tensor.storage.key);
+                let values = read_file(&mut archive, &path)?
+                    .chunks_exact(4)
+                    .map(|bytes| f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+                    .collect();
+                storages.insert(tensor.storage.key.clone(), values);
+            }
+
+            let data = strided_data(&tensor, &storages[&tensor.storage.key], &name)?;
+            tensors.insert(name, data);
+        }
+
+        Ok(Self { tensors })
+    }
+
+    /// Rename the tensors, those mapped to `None` being removed.
+    ///
+    /// The names must match the paths of the parameters in the module, made of the field names
+    /// of the modules or of the [names](crate::module::Param::with_name) of the parameters
+    /// separated by dots.
+    pub fn remap<F: Fn(&str) -> Option<String>>(self, func: F) -> Self {
+        let tensors = self
+            .tensors
+            .into_iter()
+            .filter_map(|(name, tensor)| func(&name).map(|name| (name, tensor)))
+            .collect();
+
+        Self { tensors }
+    }
+
+    /// Transpose the matrices selected by name, e.g. the weights of the linear layers which are
+    /// stored as `[d_output, d_input]` by PyTorch and as `[d_input, d_output]` by
+    /// [Linear](crate::nn::Linear).
+    pub fn transpose<F: Fn(&str) -> bool>(mut self, func: F) -> Self {
+        for (name, tensor) in self.tensors.iter_mut() {
+            if tensor.shape.len() != 2 || !func(name) {
+                continue;
+            }
+
+            let [rows, cols] = [tensor.shape[0], tensor.shape[1]];
+            let value = (0..rows * cols)
+                .map(|index| tensor.value[(index % rows) * cols + index / rows])
+                .collect();
+            *tensor = DataSerialize {
+                value,
+                shape: vec![cols, rows],
+            };
+        }
+
+        self
+    }
+
+    /// Get the tensor with the given name.
+    pub fn get(&self, name: &str) -> Option<&DataSerialize<f32>> {
+        self.tensors.get(name)
+    }
+
+    /// The names of the tensors, sorted.
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.tensors.keys().map(String::as_str).collect();
+        names.sort();
+        names
+    }
+
+    /// Load the tensors into the parameters of the module having the same path and shape.
+    ///
+    /// Like [load_partial](Module::load_partial), the other parameters keep their current value
+    /// and the tensors not matching any parameter are ignored.
+    ///
+    /// Returns the sorted paths of the parameters that weren't loaded.
+    pub fn load_into<M: Module>(&self, module: &mut M) -> Result<Vec<String>, LoadingError> {
+        let state = self
+            .state(&module.state(), "")
+            .unwrap_or_else(|| State::StateNamed(StateNamed::new()));
+
+        module.load_partial(&state)
+    }
+
+    /// The state with the tensors named after the paths of the given module state, the values
+    /// without any tensor being left out.
+    fn state<E: Element>(&self, module: &State<E>, path: &str) -> Option<State<E>> {
+        match module {
+            State::StateNamed(current) => {
+                let mut state = StateNamed::new();
+
+                for (key, value) in current.values.iter() {
+                    let path = match key.as_str() {
+                        "data" | "id" => path.to_string(),
+                        _ if path.is_empty() => key.clone(),
+                        _ => format!("{}.{}", path, key),
+                    };
+                    if let Some(value) = self.state(value, &path) {
+                        state.register_state(key, value);
+                    }
+                }
+
+                Some(State::StateNamed(state))
+            }
+            State::Data(_) => self
+                .tensors
+                .get(path)
+                .map(|tensor| State::Data(tensor.clone().convert())),
+            State::ParamId(_) => None,
+        }
+    }
+}
+
+/// Read the values of the tensor view from its storage.
+fn strided_data(
+    tensor: &TensorRef,
+    storage: &[f32],
+    name: &str,
+) -> Result<DataSerialize<f32>, LoadingError> {
+    let out_of_bounds = || {
+        LoadingError::new(format!(
+            "Tensor '{}' is out of the bounds of its storage",
+            name
+        ))
+    };
+    let num_elements = tensor
+        .shape
+        .iter()
+        .try_fold(1usize, |num_elements, size| num_elements.checked_mul(*size))
+        .ok_or_else(out_of_bounds)?;
+
+    // Views with null strides, like expanded tensors, repeat the elements of their storage, so
+    // their number isn't bounded by the size of the storage.
+    if num_elements > storage.len() {
+        return Err(LoadingError::new(format!(
+            "Tensor '{}' has more elements than its storage, views with null strides aren't \
+             supported",
+            name
+        )));
+    }
+
+    if num_elements > 0 {
+        let last_position = tensor.shape.iter().zip(tensor.stride.iter()).try_fold(
+            tensor.offset,
+            |position, (size, stride)| {
+                (size - 1)
+                    .checked_mul(*stride)
+                    .and_then(|step| position.checked_add(step))
+            },
+        );
+        match last_position {
+            Some(position) if position < storage.len() => {}
+            _ => return Err(out_of_bounds()),
+        }
+    }
+
+    let mut value = Vec::with_capacity(num_elements);
+
+    for index in 0..num_elements {
+        let mut remainder = index;
+        let mut position = tensor.offset;
+
+        for (size, stride) in tensor.shape.iter().zip(tensor.stride.iter()).rev() {
+            position += (remainder % size) * stride;
+            remainder /= size;
+        }
+
+        value.push(storage[position]);
+    }
+
+    Ok(DataSerialize {
+        value,
+        shape: tensor.shape.clone(),
+    })
+}
+
+fn read_file(archive: &mut zip::ZipArchive<File>, name: &str) -> Result<Vec<u8>, LoadingError> {
+    let mut bytes = Vec::new();
+    archive
+        .by_name(name)
+        .map_err(|err| LoadingError::new(format!("Can't find {}: {}", name, err)))?
+        .read_to_end(&mut bytes)
+        .map_err(|err| LoadingError::new(format!("Can't read {}: {}", name, err)))?;
+
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::lock_rng;
+    use crate::module::pytorch::pickle::StorageRef;
+    use crate::module::{Forward, Param};
+    use crate::nn::{Linear, LinearConfig};
+    use crate::tensor::backend::Backend;
+    use crate::tensor::{Data, Shape, Tensor};
+    use crate::TestBackend;
+    use std::io::Write;
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        fc: Param<Linear<B>>,
+        scale: Param<Tensor<B, 1>>,
+    }
+
+    struct Pickle {
+        bytes: Vec<u8>,
+    }
+
+    impl Pickle {
+        fn op(&mut self, bytes: &[u8]) -> &mut Self {
+            self.bytes.extend_from_slice(bytes);
+            self
+        }
+
+        fn string(&mut self, value: &str) -> &mut Self {
+            self.op(b"X")
+                .op(&(value.len() as u32).to_le_bytes())
+                .op(value.as_bytes())
+        }
+
+        fn int(&mut self, value: u8) -> &mut Self {
+            self.op(&[b'K', value])
+        }
+
+        fn tuple(&mut self, values: &[u8]) -> &mut Self {
+            self.op(b"(");
+            for value in values {
+                self.int(*value);
+            }
+            self.op(b"t")
+        }
+
+        /// The pickle of a tensor as written by `torch.save`.
+        fn tensor(&mut self, name: &str, storage: &str, offset: u8, shape: &[u8]) -> &mut Self {
+            let mut stride = vec![1; shape.len()];
+            for i in (0..shape.len() - 1).rev() {
+                stride[i] = stride[i + 1] * shape[i + 1];
+            }
+
+            self.string(name)
+                .op(b"ctorch._utils\n_rebuild_tensor_v2\nq\x02(")
+                .op(b"(")
+                .string("storage")
+                .op(b"ctorch\nFloatStorage\n")
+                .string(storage)
+                .string("cpu")
+                .int(6)
+                .op(b"tQ")
+                .int(offset)
+                .tuple(shape)
+                .tuple(&stride)
+                .op(b"\x89h\x00)RtR")
+        }
+    }
+
+    fn write_state_dict(file: &str) {
+        let mut pickle = Pickle { bytes: Vec::new() };
+        pickle
+            .op(b"\x80\x02ccollections\nOrderedDict\nq\x00)Rq\x01(")
+            .tensor("model.fc.weight", "0", 0, &[2, 3])
+            .tensor("model.fc.bias", "1", 1, &[2])
+            .tensor("model.scale", "1", 3, &[3])
+            .tensor("model.unused", "1", 0, &[1])
+            .op(b"u}b.");
+
+        let options =
+            zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Stored);
+        let mut zip = zip::ZipWriter::new(File::create(file).unwrap());
+        zip.start_file("archive/data.pkl", options).unwrap();
+        zip.write_all(&pickle.bytes).unwrap();
+
+        for (key, values) in [
+            ("0", [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0]),
+            ("1", [0.0, 0.1, 0.2, 0.3, 0.4, 0.5]),
+        ] {
+            zip.start_file(format!("archive/data/{}", key), options)
+                .unwrap();
+            for value in values {
+                zip.write_all(&value.to_le_bytes()).unwrap();
+            }
+        }
+        zip.finish().unwrap();
+    }
+
+    #[test]
+    fn should_load_state_dict_into_module() {
+        let _rng = lock_rng();
+        let file = std::env::temp_dir().join(format!(
+            "burn-pytorch-state-dict-test-{}.pt",
+            std::process::id()
+        ));
+        let file = file.to_str().unwrap();
+        write_state_dict(file);
+        let mut model = Model::<TestBackend> {
+            fc: Param::new(Linear::new(&LinearConfig::new(3, 2))),
+            scale: Param::new(Tensor::zeros([4])),
+        };
+
+        let state_dict = PyTorchStateDict::load(file)
+            .unwrap()
+            .remap(|name| name.strip_prefix("model.").map(String::from))
+            .transpose(|name| name == "fc.weight");
+        let skipped = state_dict.load_into(&mut model).unwrap();
+
+        assert_eq!(
+            state_dict.names(),
+            vec!["fc.bias", "fc.weight", "scale", "unused"]
+        );
+        assert_eq!(skipped, vec!["scale".to_string()]);
+        let output = model
+            .fc
+            .forward(Tensor::<TestBackend, 2>::from_data(Data::from([[
+                1.0, 0.0, 0.0,
+            ]])));
+        output
+            .to_data()
+            .assert_approx_eq(&Data::from([[1.1, 4.2]]), 5);
+        assert_eq!(model.scale.to_data(), Data::zeros(Shape::new([4])));
+    }
+
+    #[test]
+    fn should_read_tensor_view_of_storage() {
+        let file = std::env::temp_dir().join(format!(
+            "burn-pytorch-state-dict-view-test-{}.pt",
+            std::process::id()
+        ));
+        let file = file.to_str().unwrap();
+        write_state_dict(file);
+
+        let state_dict = PyTorchStateDict::load(file).unwrap();
+
+        let scale = state_dict.get("model.scale").unwrap();
+        assert_eq!(scale.shape, vec![3]);
+        assert_eq!(scale.value, vec![0.3, 0.4, 0.5]);
+    }
+
+    #[test]
+    fn should_fail_when_tensor_is_larger_than_its_storage() {
+        let tensor = TensorRef {
+            storage: StorageRef {
+                key: "0".to_string(),
+                dtype: "FloatStorage".to_string(),
+            },
+            offset: 0,
+            shape: vec![usize::MAX / 2, 4],
+            stride: vec![4, 1],
+        };
+
+        let result = strided_data(&tensor, &[0.0; 8], "weight");
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_fail_when_tensor_has_null_strides() {
+        let tensor = TensorRef {
+            storage: StorageRef {
+                key: "0".to_string(),
+                dtype: "FloatStorage".to_string(),
+            },
+            offset: 0,
+            shape: vec![1 << 20, 1 << 20],
+            stride: vec![0, 0],
+        };
+
+        let result = strided_data(&tensor, &[0.0; 1], "weight");
+
+        assert!(result.is_err());
+    }
+}
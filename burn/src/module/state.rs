@@ -6,18 +6,38 @@ use flate2::Compression;
 use std::collections::HashMap;
 use std::io::{Read, Write};
 
-#[derive(Debug, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct StateNamed<E> {
     pub values: HashMap<String, State<E>>,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum State<E> {
     StateNamed(StateNamed<E>),
     Data(DataSerialize<E>),
     ParamId(ParamId),
 }
 
+/// The format in which a [state](State) is written to files, which are always compressed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StateFormat {
+    /// Human-readable JSON, useful for debugging.
+    #[default]
+    Json,
+    /// Compact binary [MessagePack](https://msgpack.org).
+    MessagePack,
+}
+
+impl StateFormat {
+    /// The extension of the files in this format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Json => "json.gz",
+            Self::MessagePack => "mpk.gz",
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum StateError {
     InvalidFormat(String),
@@ -146,6 +166,14 @@ impl<E: Element> StateNamed<E> {
         self.values.is_empty()
     }
 
+    /// Whether the state is the wrapper of a [param](crate::module::Param), made of its `data`
+    /// and its `id`.
+    fn is_param(&self) -> bool {
+        self.values.len() == 2
+            && self.values.contains_key("data")
+            && matches!(self.values.get("id"), Some(State::ParamId(_)))
+    }
+
     pub fn convert<O: Element>(self) -> StateNamed<O> {
         let mut values = HashMap::with_capacity(self.values.len());
 
@@ -180,6 +208,53 @@ impl<E: Element> State<E> {
             State::ParamId(id) => State::ParamId(id),
         }
     }
+
+    /// Replace the values of the state with the values of the other state having the same path
+    /// and shape, the paths of the values that aren't replaced being added to `skipped`.
+    ///
+    /// The path of a value is made of the names of its parents separated by dots, without the
+    /// `data` and `id` entries of the [param](crate::module::Param) wrappers. The ids missing
+    /// from the other state are kept as is, since they aren't values.
+    pub(crate) fn merge_partial(
+        &self,
+        other: &Self,
+        path: &str,
+        skipped: &mut Vec<String>,
+    ) -> Self {
+        match (self, other) {
+            (State::StateNamed(current), State::StateNamed(other)) => {
+                let mut merged = StateNamed::new();
+                let is_param = current.is_param();
+
+                for (key, value) in current.values.iter() {
+                    let path = match key.as_str() {
+                        _ if is_param => path.to_string(),
+                        _ if path.is_empty() => key.clone(),
+                        _ => format!("{}.{}", path, key),
+                    };
+                    let value = match other.get(key) {
+                        Some(other) => value.merge_partial(other, &path, skipped),
+                        None if is_param && key == "id" => value.clone(),
+                        None => {
+                            skipped.push(path);
+                            value.clone()
+                        }
+                    };
+                    merged.register_state(key, value);
+                }
+
+                State::StateNamed(merged)
+            }
+            (State::Data(current), State::Data(data)) if current.shape == data.shape => {
+                other.clone()
+            }
+            (State::ParamId(_), State::ParamId(_)) => other.clone(),
+            _ => {
+                skipped.push(path.to_string());
+                self.clone()
+            }
+        }
+    }
 }
 
 impl<E: Element> State<E>
@@ -187,27 +262,45 @@ where
     E: serde::de::DeserializeOwned,
     E: serde::Serialize,
 {
+    /// Save the state in the [JSON](StateFormat::Json) format.
     pub fn save(self, file: &str) -> std::io::Result<()> {
+        self.save_format(file, StateFormat::Json)
+    }
+
+    /// Save the state in the given format.
+    pub fn save_format(self, file: &str, format: StateFormat) -> std::io::Result<()> {
         let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
         let value: serde_json::Value = self.into();
 
-        let content = value.to_string();
-        encoder.write_all(content.as_bytes()).unwrap();
+        let content = match format {
+            StateFormat::Json => value.to_string().into_bytes(),
+            StateFormat::MessagePack => rmp_serde::to_vec(&value).unwrap(),
+        };
+        encoder.write_all(&content).unwrap();
         let content_compressed = encoder.finish().unwrap();
 
         std::fs::write(file, content_compressed)
     }
 
+    /// Load a state saved in any [format](StateFormat), which is detected from the content of
+    /// the file.
     pub fn load(file: &str) -> Result<Self, StateError> {
         let content_compressed =
             std::fs::read(file).map_err(|err| StateError::FileNotFound(format!("{:?}", err)))?;
 
         let mut decoder = GzDecoder::new(content_compressed.as_slice());
-        let mut content = String::new();
-        decoder.read_to_string(&mut content).unwrap();
-
-        let value: serde_json::Value = serde_json::from_str(&content)
+        let mut content = Vec::new();
+        decoder
+            .read_to_end(&mut content)
             .map_err(|err| StateError::InvalidFormat(format!("{:?}", err)))?;
+
+        // A JSON state is an object while a MessagePack state starts with a map marker.
+        let value: serde_json::Value = match content.first() {
+            Some(b'{') => serde_json::from_slice(&content)
+                .map_err(|err| StateError::InvalidFormat(format!("{:?}", err)))?,
+            _ => rmp_serde::from_slice(&content)
+                .map_err(|err| StateError::InvalidFormat(format!("{:?}", err)))?,
+        };
         Self::try_from(value)
     }
 }
@@ -215,16 +308,19 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::lock_rng;
     use crate::module::Module;
     use crate::nn;
     use crate::tensor::backend::Backend;
 
     #[test]
     fn test_state_to_from_value() {
+        let _rng = lock_rng();
         let linear = nn::Linear::<crate::TestBackend>::new(&nn::LinearConfig {
             d_input: 32,
             d_output: 32,
             bias: true,
+            initializer: None,
         });
 
         let state = linear.state();
@@ -239,14 +335,35 @@ mod tests {
 
     #[test]
     fn test_can_save_and_load_from_file() {
+        let _rng = lock_rng();
         let mut linear = nn::Linear::<crate::TestBackend>::new(&nn::LinearConfig {
             d_input: 32,
             d_output: 32,
             bias: true,
+            initializer: None,
         });
         linear.state().save("/tmp/test.json").unwrap();
         linear
             .load(&State::load("/tmp/test.json").unwrap())
             .unwrap();
     }
+
+    #[test]
+    fn test_can_save_and_load_message_pack_from_file() {
+        let _rng = lock_rng();
+        let linear = nn::Linear::<crate::TestBackend>::new(&nn::LinearConfig {
+            d_input: 8,
+            d_output: 8,
+            bias: true,
+            initializer: None,
+        });
+        let state = linear.state();
+
+        state
+            .clone()
+            .save_format("/tmp/test.mpk.gz", StateFormat::MessagePack)
+            .unwrap();
+
+        assert_eq!(State::load("/tmp/test.mpk.gz").unwrap(), state);
+    }
 }
@@ -0,0 +1,132 @@
+/// Summary of the parameters of a [module](crate::module::Module), see
+/// [summary](crate::module::Module::summary).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModuleSummary {
+    pub params: Vec<ParamSummary>,
+}
+
+/// Name and shape of a parameter tensor.
+///
+/// The name is the path of the parameter in the module, with each level separated by a dot.
+#[derive(new, Debug, Clone, PartialEq, Eq)]
+pub struct ParamSummary {
+    pub name: String,
+    pub shape: Vec<usize>,
+}
+
+impl ParamSummary {
+    /// Get the number of scalar values of the parameter.
+    pub fn num_params(&self) -> usize {
+        self.shape.iter().product()
+    }
+}
+
+impl ModuleSummary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create the summary of a single parameter with the given shape.
+    ///
+    /// The parameter is unnamed until the summary is [registered](ModuleSummary::register) in
+    /// the summary of its parent module.
+    pub fn param(shape: Vec<usize>) -> Self {
+        Self {
+            params: vec![ParamSummary::new(String::new(), shape)],
+        }
+    }
+
+    /// Register the summary of a sub-module, prefixing the names of its parameters.
+    pub fn register(&mut self, name: &str, summary: ModuleSummary) {
+        for param in summary.params {
+            let name = match param.name.is_empty() {
+                true => name.to_string(),
+                false => format!("{}.{}", name, param.name),
+            };
+            self.params.push(ParamSummary::new(name, param.shape));
+        }
+    }
+
+    /// Get the total number of scalar parameters.
+    pub fn num_params(&self) -> usize {
+        self.params.iter().map(ParamSummary::num_params).sum()
+    }
+}
+
+impl std::fmt::Display for ModuleSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let shapes: Vec<String> = self
+            .params
+            .iter()
+            .map(|param| format!("{:?}", param.shape))
+            .collect();
+        let name_width = self
+            .params
+            .iter()
+            .map(|param| param.name.len())
+            .chain(std::iter::once("Name".len()))
+            .max()
+            .unwrap_or_default();
+        let shape_width = shapes
+            .iter()
+            .map(String::len)
+            .chain(std::iter::once("Shape".len()))
+            .max()
+            .unwrap_or_default();
+
+        writeln!(
+            f,
+            "{:name_width$}  {:shape_width$}  Params",
+            "Name", "Shape"
+        )?;
+        for (param, shape) in self.params.iter().zip(shapes.iter()) {
+            writeln!(
+                f,
+                "{:name_width$}  {:shape_width$}  {}",
+                param.name,
+                shape,
+                param.num_params()
+            )?;
+        }
+        write!(f, "Total params: {}", self.num_params())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_prefix_the_names_of_registered_params() {
+        let mut linear = ModuleSummary::new();
+        linear.register("weight", ModuleSummary::param(vec![4, 8]));
+        linear.register("bias", ModuleSummary::param(vec![8]));
+        let mut model = ModuleSummary::new();
+
+        model.register("linear", linear);
+
+        assert_eq!(
+            model.params,
+            vec![
+                ParamSummary::new("linear.weight".to_string(), vec![4, 8]),
+                ParamSummary::new("linear.bias".to_string(), vec![8]),
+            ]
+        );
+        assert_eq!(model.num_params(), 40);
+    }
+
+    #[test]
+    fn should_display_a_table_of_params() {
+        let mut summary = ModuleSummary::new();
+        summary.register("weight", ModuleSummary::param(vec![4, 8]));
+        summary.register("bias", ModuleSummary::param(vec![8]));
+
+        assert_eq!(
+            summary.to_string(),
+            "Name    Shape   Params\n\
+             weight  [4, 8]  32\n\
+             bias    [8]     8\n\
+             Total params: 40"
+        );
+    }
+}
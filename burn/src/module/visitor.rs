@@ -0,0 +1,51 @@
+use super::ParamId;
+use crate::tensor::{backend::Backend, Tensor};
+
+/// Visit the tensor parameters of a [module](crate::module::Module), see
+/// [visit_params](crate::module::Module::visit_params).
+///
+/// Since parameters have different dimensions, a visitor is a type with a generic method rather
+/// than a closure.
+///
+/// # Example
+///
+/// ```rust
+/// use burn::module::{ParamId, ParamVisitor};
+/// use burn::tensor::backend::Backend;
+/// use burn::tensor::Tensor;
+///
+/// struct NumElements(usize);
+///
+/// impl<B: Backend> ParamVisitor<B> for NumElements {
+///     fn visit<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D>) {
+///         self.0 += tensor.shape().num_elements();
+///     }
+/// }
+/// ```
+pub trait ParamVisitor<B: Backend> {
+    /// Visit a parameter.
+    fn visit<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>);
+}
+
+/// Replace the tensor parameters of a [module](crate::module::Module), see
+/// [map_params](crate::module::Module::map_params).
+///
+/// # Example
+///
+/// ```rust
+/// use burn::module::{ParamId, ParamMapper};
+/// use burn::tensor::backend::Backend;
+/// use burn::tensor::Tensor;
+///
+/// struct Scale(f32);
+///
+/// impl<B: Backend> ParamMapper<B> for Scale {
+///     fn map<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+///         tensor.mul_scalar(self.0)
+///     }
+/// }
+/// ```
+pub trait ParamMapper<B: Backend> {
+    /// Map a parameter to its new value, which must have the same shape.
+    fn map<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D>;
+}
@@ -0,0 +1,169 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::{Forward, Param};
+use crate::nn::{Linear, LinearConfig};
+use crate::tensor::activation;
+use crate::tensor::backend::Backend;
+use crate::tensor::{BoolTensor, Tensor};
+
+/// Value added to the attention scores of masked positions, small enough to get a weight of
+/// zero after the softmax while still being representable in half precision.
+const MASK_VALUE: f32 = -1.0e4;
+
+/// Configuration to create a [MultiHeadAttention](MultiHeadAttention) layer.
+#[derive(Config)]
+pub struct MultiHeadAttentionConfig {
+    /// The size of each input and output features.
+    pub d_model: usize,
+    /// The number of attention heads, must divide `d_model`.
+    pub n_heads: usize,
+}
+
+/// Applies the multi-head attention mechanism as described in the paper
+/// [Attention Is All You Need](https://arxiv.org/abs/1706.03762).
+///
+/// `Attention(Q, K, V) = softmax(QK^T / sqrt(d_k))V`
+#[derive(Module, Debug)]
+pub struct MultiHeadAttention<B: Backend> {
+    query: Param<Linear<B>>,
+    key: Param<Linear<B>>,
+    value: Param<Linear<B>>,
+    output: Param<Linear<B>>,
+    n_heads: usize,
+    d_k: usize,
+}
+
+/// [Multi-head attention](MultiHeadAttention) forward pass output.
+pub struct MultiHeadAttentionOutput<B: Backend> {
+    /// The context of each position with shape `[batch_size, seq_length, d_model]`.
+    pub context: Tensor<B, 3>,
+    /// The attention weights with shape `[batch_size, n_heads, seq_length, seq_length]`.
+    pub weights: Tensor<B, 4>,
+}
+
+impl<B: Backend> MultiHeadAttention<B> {
+    /// Create the module from the given configuration.
+    ///
+    /// # Panics
+    ///
+    /// If `d_model` isn't divisible by `n_heads`.
+    pub fn new(config: &MultiHeadAttentionConfig) -> Self {
+        if !config.d_model.is_multiple_of(config.n_heads) {
+            panic!(
+                "The size of the model {} must be divisible by the number of heads {}",
+                config.d_model, config.n_heads
+            );
+        }
+
+        let config_linear = LinearConfig::new(config.d_model, config.d_model);
+
+        Self {
+            query: Param::new(Linear::new(&config_linear)),
+            key: Param::new(Linear::new(&config_linear)),
+            value: Param::new(Linear::new(&config_linear)),
+            output: Param::new(Linear::new(&config_linear)),
+            n_heads: config.n_heads,
+            d_k: config.d_model / config.n_heads,
+        }
+    }
+
+    /// Applies the attention on the input with shape `[batch_size, seq_length, d_model]`.
+    ///
+    /// The positions where the mask with shape `[batch_size, seq_length, seq_length]` is true
+    /// aren't attended to.
+    pub fn forward_mask(
+        &self,
+        input: Tensor<B, 3>,
+        mask: Option<&BoolTensor<B, 3>>,
+    ) -> MultiHeadAttentionOutput<B> {
+        let [batch_size, seq_length, d_model] = input.shape().dims;
+
+        let query = self.split_heads(self.query.forward(input.clone()));
+        let key = self.split_heads(self.key.forward(input.clone()));
+        let value = self.split_heads(self.value.forward(input));
+
+        let scores = query
+            .matmul(&key.transpose())
+            .div_scalar((self.d_k as f32).sqrt());
+        let scores = match mask {
+            Some(mask) => {
+                let mask = mask
+                    .to_float()
+                    .reshape([batch_size, 1, seq_length, seq_length]);
+                scores.add(&mask.mul_scalar(MASK_VALUE))
+            }
+            None => scores,
+        };
+
+        let weights = activation::softmax(&scores, 3);
+        let context = weights
+            .matmul(&value)
+            .swap_dims(1, 2)
+            .reshape([batch_size, seq_length, d_model]);
+        let context = self.output.forward(context);
+
+        MultiHeadAttentionOutput { context, weights }
+    }
+
+    fn split_heads(&self, tensor: Tensor<B, 3>) -> Tensor<B, 4> {
+        let [batch_size, seq_length, _] = tensor.shape().dims;
+
+        tensor
+            .reshape([batch_size, seq_length, self.n_heads, self.d_k])
+            .swap_dims(1, 2)
+    }
+}
+
+impl<B: Backend> Forward<Tensor<B, 3>, Tensor<B, 3>> for MultiHeadAttention<B> {
+    fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        self.forward_mask(input, None).context
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lock_rng;
+    use crate::tensor::{Data, Distribution, Shape};
+    use crate::TestBackend;
+
+    #[test]
+    fn should_have_the_right_output_shape() {
+        let _rng = lock_rng();
+        let attention =
+            MultiHeadAttention::<TestBackend>::new(&MultiHeadAttentionConfig::new(8, 2));
+        let input = Tensor::<TestBackend, 3>::random([3, 5, 8], Distribution::Standard);
+
+        let output = attention.forward_mask(input, None);
+
+        assert_eq!(output.context.shape(), &Shape::new([3, 5, 8]));
+        assert_eq!(output.weights.shape(), &Shape::new([3, 2, 5, 5]));
+    }
+
+    #[test]
+    fn masked_positions_should_have_zero_attention_weight() {
+        let _rng = lock_rng();
+        let attention =
+            MultiHeadAttention::<TestBackend>::new(&MultiHeadAttentionConfig::new(4, 2));
+        let input = Tensor::<TestBackend, 3>::random([1, 3, 4], Distribution::Standard);
+        let mask = BoolTensor::from_data(Data::from([[
+            [false, false, true],
+            [false, false, true],
+            [false, false, true],
+        ]]));
+
+        let output = attention.forward_mask(input, Some(&mask));
+
+        let sums = output.weights.sum_dim(3).into_data();
+        let weights = output.weights.into_data();
+        for (i, weight) in weights.value.iter().enumerate() {
+            match i % 3 {
+                2 => assert_eq!(*weight, 0.0),
+                _ => assert!(*weight > 0.0),
+            }
+        }
+        sums.assert_approx_eq(&Data::ones(Shape::new([1, 2, 3, 1])), 3);
+    }
+}
@@ -0,0 +1,208 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::{Buffer, Forward, Param};
+use crate::tensor::backend::Backend;
+use crate::tensor::{Shape, Tensor};
+
+/// Configuration to create a [BatchNorm2d](BatchNorm2d) layer.
+#[derive(Config)]
+pub struct BatchNorm2dConfig {
+    /// The number of channels of the input.
+    pub num_features: usize,
+    /// A value required for numerical stability. Default: 1e-5
+    #[config(default = 1e-5)]
+    pub epsilon: f64,
+    /// Momentum used to update the running statistics. Default: 0.1
+    #[config(default = 0.1)]
+    pub momentum: f64,
+}
+
+/// Applies Batch Normalization over an input tensor with shape
+/// `[batch_size, channels, height, width]` as described in the paper
+/// [Batch Normalization](https://arxiv.org/abs/1502.03167).
+///
+/// `Y = norm(X) * γ + β`
+///
+/// During training, when the backend has auto-differentiation enabled, the input is normalized
+/// with the statistics of the batch for each channel and the running statistics are updated:
+///
+/// `running = (1 - momentum) * running + momentum * batch`
+///
+/// Otherwise, the running statistics are used.
+#[derive(Module, Debug)]
+pub struct BatchNorm2d<B: Backend> {
+    gamma: Param<Tensor<B, 1>>,
+    beta: Param<Tensor<B, 1>>,
+    running_mean: Param<Buffer<Tensor<B, 1>>>,
+    running_var: Param<Buffer<Tensor<B, 1>>>,
+    momentum: f64,
+    epsilon: f64,
+}
+
+impl<B: Backend> BatchNorm2d<B> {
+    pub fn new(config: &BatchNorm2dConfig) -> Self {
+        let shape = Shape::new([config.num_features]);
+
+        Self {
+            gamma: Param::new(Tensor::ones(shape)),
+            beta: Param::new(Tensor::zeros(shape)),
+            running_mean: Param::new(Buffer::new(Tensor::zeros(shape))),
+            running_var: Param::new(Buffer::new(Tensor::ones(shape))),
+            momentum: config.momentum,
+            epsilon: config.epsilon,
+        }
+    }
+
+    fn forward_train(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let [batch_size, channels, height, width] = input.shape().dims;
+
+        let input_channels = input
+            .swap_dims(0, 1)
+            .reshape([channels, batch_size * height * width]);
+        let (var, mean) = input_channels.var_mean_bias(1);
+
+        let num_elements = (batch_size * height * width) as f64;
+        let var_unbiased = var.mul_scalar(num_elements / f64::max(num_elements - 1.0, 1.0));
+        let mean = mean.reshape([channels]);
+        let var_unbiased = var_unbiased.reshape([channels]);
+
+        self.running_mean.update(
+            self.running_mean
+                .value()
+                .mul_scalar(1.0 - self.momentum)
+                .add(&mean.mul_scalar(self.momentum)),
+        );
+        self.running_var.update(
+            self.running_var
+                .value()
+                .mul_scalar(1.0 - self.momentum)
+                .add(&var_unbiased.mul_scalar(self.momentum)),
+        );
+
+        self.normalize(input, mean, var.reshape([channels]))
+    }
+
+    fn normalize(
+        &self,
+        input: Tensor<B, 4>,
+        mean: Tensor<B, 1>,
+        var: Tensor<B, 1>,
+    ) -> Tensor<B, 4> {
+        let channels = mean.shape().dims[0];
+        let shape = [1, channels, 1, 1];
+
+        let input_normalized = input
+            .sub(&mean.reshape(shape))
+            .div(&var.add_scalar(self.epsilon).powf(0.5).reshape(shape));
+
+        input_normalized
+            .mul(&self.gamma.reshape(shape))
+            .add(&self.beta.reshape(shape))
+    }
+}
+
+impl<B: Backend> Forward<Tensor<B, 4>, Tensor<B, 4>> for BatchNorm2d<B> {
+    fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        match B::ad_enabled() {
+            true => self.forward_train(input),
+            false => self.normalize(input, self.running_mean.value(), self.running_var.value()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Data;
+    use crate::{TestADBackend, TestBackend};
+
+    fn input<B: Backend<Elem = f32>>() -> Tensor<B, 4> {
+        Tensor::from_data(Data::from([
+            [[[1.0, 2.0], [3.0, 4.0]], [[0.0, 0.0], [2.0, 2.0]]],
+            [[[5.0, 6.0], [7.0, 8.0]], [[4.0, 4.0], [6.0, 6.0]]],
+        ]))
+    }
+
+    #[test]
+    fn running_stats_should_move_toward_batch_stats() {
+        let module = BatchNorm2d::<TestADBackend>::new(&BatchNorm2dConfig::new(2));
+        let mut distance_previous = f32::MAX;
+
+        for _ in 0..100 {
+            module.forward(input());
+
+            let running_mean = module.running_mean.value().into_data();
+            let distance =
+                f32::abs(running_mean.value[0] - 4.5) + f32::abs(running_mean.value[1] - 3.0);
+            assert!(distance < distance_previous);
+            distance_previous = distance;
+        }
+
+        module
+            .running_mean
+            .value()
+            .into_data()
+            .assert_approx_eq(&Data::from([4.5, 3.0]), 2);
+        module
+            .running_var
+            .value()
+            .into_data()
+            .assert_approx_eq(&Data::from([6.0, 5.7143]), 2);
+    }
+
+    #[test]
+    fn training_should_normalize_with_batch_stats() {
+        let module = BatchNorm2d::<TestADBackend>::new(&BatchNorm2dConfig::new(2));
+
+        let output = module.forward(input());
+
+        let (var, mean) = output.swap_dims(0, 1).reshape([2, 8]).var_mean_bias(1);
+        mean.into_data()
+            .assert_approx_eq(&Data::from([[0.0], [0.0]]), 3);
+        var.into_data()
+            .assert_approx_eq(&Data::from([[1.0], [1.0]]), 3);
+    }
+
+    #[test]
+    fn eval_should_use_frozen_running_stats() {
+        let module = BatchNorm2d::<TestBackend>::new(&BatchNorm2dConfig::new(2));
+        module
+            .running_mean
+            .update(Tensor::from_data(Data::from([1.0, 2.0])));
+        module
+            .running_var
+            .update(Tensor::from_data(Data::from([4.0, 16.0])));
+
+        let output_1 = module.forward(input());
+        let output_2 = module.forward(input());
+
+        module
+            .running_mean
+            .value()
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, 2.0]), 3);
+        assert_eq!(output_1.to_data(), output_2.to_data());
+        output_1
+            .index([0..1, 0..2, 0..1, 0..1])
+            .into_data()
+            .assert_approx_eq(&Data::from([[[[0.0]], [[-0.5]]]]), 3);
+    }
+
+    #[test]
+    fn running_stats_should_be_saved_but_not_trained() {
+        let module = BatchNorm2d::<TestADBackend>::new(&BatchNorm2dConfig::new(2));
+        let mut module_loaded = BatchNorm2d::<TestADBackend>::new(&BatchNorm2dConfig::new(2));
+
+        let grads = module.forward(input()).backward();
+        module_loaded.load(&module.state()).unwrap();
+
+        assert_eq!(module.num_params(), 4);
+        assert!(module.running_mean.value().grad(&grads).is_none());
+        assert_eq!(
+            module_loaded.running_mean.value().into_data(),
+            module.running_mean.value().into_data()
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::{Forward, Param};
+use crate::nn::Initializer;
+use crate::tensor::backend::Backend;
+use crate::tensor::{module, Distribution, ElementConversion, Shape, Tensor};
+use std::ops::Deref;
+
+/// Configuration to create a [1D convolution](Conv1d) layer.
+#[derive(Config)]
+pub struct Conv1dConfig {
+    /// The number of channels of the input.
+    pub channels_in: usize,
+    /// The number of channels of the output.
+    pub channels_out: usize,
+    /// The size of the kernel.
+    pub kernel_size: usize,
+    /// The step between two positions of the kernel.
+    #[config(default = 1)]
+    pub stride: usize,
+    /// The number of zeros added on both sides of the input.
+    #[config(default = 0)]
+    pub padding: usize,
+    /// The spacing between the elements of the kernel.
+    #[config(default = 1)]
+    pub dilation: usize,
+    /// If a bias should be added to each output channel.
+    #[config(default = true)]
+    pub bias: bool,
+    /// The [initializer](Initializer) of the weight, uniform between `-k` and `k` with
+    /// `k = 1 / sqrt(channels_in * kernel_size)` when not set.
+    pub initializer: Option<Initializer>,
+}
+
+/// Applies a 1D convolution over an input of shape `[batch_size, channels_in, length]`.
+///
+/// The output has a shape of `[batch_size, channels_out, length_out]`, where `length_out` is
+/// given by [conv1d_output_length](crate::tensor::module::conv1d_output_length).
+#[derive(Module, Debug)]
+pub struct Conv1d<B: Backend> {
+    weight: Param<Tensor<B, 3>>,
+    bias: Param<Option<Tensor<B, 1>>>,
+    stride: usize,
+    padding: usize,
+    dilation: usize,
+}
+
+impl<B: Backend> Conv1d<B> {
+    pub fn new(config: &Conv1dConfig) -> Self {
+        let weight = match &config.initializer {
+            // The initializer expects the input size first.
+            Some(initializer) => initializer
+                .init::<B, 3, _>([config.channels_in, config.channels_out, config.kernel_size])
+                .swap_dims(0, 1),
+            None => {
+                let fan_in = (config.channels_in * config.kernel_size) as f64;
+                let start = -1.0 / f64::sqrt(fan_in);
+                let end = 1.0 / f64::sqrt(fan_in);
+                let distribution = Distribution::Uniform(start.to_elem(), end.to_elem());
+                Tensor::random(
+                    Shape::new([config.channels_out, config.channels_in, config.kernel_size]),
+                    distribution,
+                )
+            }
+        };
+        let bias = match config.bias {
+            true => Some(Tensor::zeros(Shape::new([config.channels_out]))),
+            false => None,
+        };
+
+        Self {
+            weight: Param::new(weight),
+            bias: Param::new(bias),
+            stride: config.stride,
+            padding: config.padding,
+            dilation: config.dilation,
+        }
+    }
+}
+
+impl<B: Backend> Forward<Tensor<B, 3>, Tensor<B, 3>> for Conv1d<B> {
+    fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        module::conv1d(
+            &input,
+            &self.weight,
+            self.bias.deref().as_ref(),
+            self.stride,
+            self.padding,
+            self.dilation,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lock_rng;
+    use crate::tensor::module::conv1d_output_length;
+    use crate::TestBackend;
+
+    #[test]
+    fn output_length_should_match_across_strides_and_dilations() {
+        let _rng = lock_rng();
+        for (stride, padding, dilation) in [(1, 0, 1), (2, 0, 1), (1, 2, 2), (3, 1, 2), (2, 3, 4)] {
+            let config = Conv1dConfig::new(3, 4, 3)
+                .with_stride(stride)
+                .with_padding(padding)
+                .with_dilation(dilation);
+            let conv = Conv1d::<TestBackend>::new(&config);
+            let input = Tensor::<TestBackend, 3>::zeros([2, 3, 17]);
+
+            let output = conv.forward(input);
+
+            let length_out = conv1d_output_length(17, 3, stride, padding, dilation);
+            assert_eq!(output.shape().dims, [2, 4, length_out]);
+        }
+        assert_eq!(conv1d_output_length(17, 3, 3, 1, 2), 5);
+        assert_eq!(conv1d_output_length(17, 3, 1, 2, 2), 17);
+    }
+
+    #[test]
+    fn initializer_should_give_the_weight_shape() {
+        let _rng = lock_rng();
+        let config = Conv1dConfig::new(3, 4, 5).with_initializer(Some(Initializer::XavierUniform));
+
+        let conv = Conv1d::<TestBackend>::new(&config);
+
+        assert_eq!(conv.weight.shape().dims, [4, 3, 5]);
+    }
+}
@@ -45,11 +45,13 @@ impl<B: Backend, const D: usize> Forward<Tensor<B, D>, Tensor<B, D>> for Dropout
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::lock_rng;
     use crate::tensor::Shape;
     use crate::{TestADBackend, TestBackend};
 
     #[test]
     fn with_ad_backend_should_mark_input() {
+        let _rng = lock_rng();
         let tensor = Tensor::<TestADBackend, 2>::ones(Shape::new([100, 100]));
         let dropout = Dropout::new(&DropoutConfig { prob: 0.5 });
 
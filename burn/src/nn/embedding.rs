@@ -3,6 +3,7 @@ use crate as burn;
 use crate::config::Config;
 use crate::module::Module;
 use crate::module::{Forward, Param};
+use crate::nn::Initializer;
 use crate::tensor::backend::Backend;
 use crate::tensor::{Distribution, ElementConversion, Tensor};
 
@@ -13,6 +14,9 @@ pub struct EmbeddingConfig {
     n_embedding: usize,
     /// The size of each vector.
     d_model: usize,
+    /// The [initializer](Initializer) of the embedding vectors, uniform between
+    /// `-1 / sqrt(d_model)` and `1 / sqrt(d_model)` when not set.
+    initializer: Option<Initializer>,
 }
 
 /// Lookup table to store a fix number of vectors.
@@ -23,10 +27,16 @@ pub struct Embedding<B: Backend> {
 
 impl<B: Backend> Embedding<B> {
     pub fn new(config: &EmbeddingConfig) -> Self {
-        let start = -1.0 / f64::sqrt(config.d_model as f64);
-        let end = 1.0 / f64::sqrt(config.d_model as f64);
-        let distribution = Distribution::Uniform(start.to_elem(), end.to_elem());
-        let weight = Tensor::random([config.n_embedding, config.d_model], distribution);
+        let shape = [config.n_embedding, config.d_model];
+        let weight = match &config.initializer {
+            Some(initializer) => initializer.init(shape),
+            None => {
+                let start = -1.0 / f64::sqrt(config.d_model as f64);
+                let end = 1.0 / f64::sqrt(config.d_model as f64);
+                let distribution = Distribution::Uniform(start.to_elem(), end.to_elem());
+                Tensor::random(shape, distribution)
+            }
+        };
 
         Self {
             weight: Param::new(weight),
@@ -0,0 +1,38 @@
+use crate as burn;
+
+use crate::module::Module;
+use crate::module::{Forward, Param};
+use crate::nn::{Linear, LinearConfig};
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// A gate of a recurrent layer such as [Lstm](crate::nn::Lstm) or [Gru](crate::nn::Gru),
+/// applying a linear transformation to the input and to the hidden state.
+#[derive(Module, Debug)]
+pub struct GateController<B: Backend> {
+    pub(crate) input_transform: Param<Linear<B>>,
+    pub(crate) hidden_transform: Param<Linear<B>>,
+}
+
+impl<B: Backend> GateController<B> {
+    pub(crate) fn new(d_input: usize, d_hidden: usize) -> Self {
+        Self {
+            input_transform: Param::new(Linear::new(&LinearConfig::new(d_input, d_hidden))),
+            hidden_transform: Param::new(Linear::new(
+                &LinearConfig::new(d_hidden, d_hidden).with_bias(false),
+            )),
+        }
+    }
+
+    pub(crate) fn gate_product(&self, input: &Tensor<B, 2>, hidden: &Tensor<B, 2>) -> Tensor<B, 2> {
+        self.input_product(input).add(&self.hidden_product(hidden))
+    }
+
+    pub(crate) fn input_product(&self, input: &Tensor<B, 2>) -> Tensor<B, 2> {
+        self.input_transform.forward(input.clone())
+    }
+
+    pub(crate) fn hidden_product(&self, hidden: &Tensor<B, 2>) -> Tensor<B, 2> {
+        self.hidden_transform.forward(hidden.clone())
+    }
+}
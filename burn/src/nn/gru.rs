@@ -0,0 +1,156 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::{Forward, Param};
+use crate::nn::GateController;
+use crate::tensor::activation;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Configuration to create a [Gru](Gru) layer.
+#[derive(Config)]
+pub struct GruConfig {
+    /// The size of the input features.
+    pub d_input: usize,
+    /// The size of the hidden state.
+    pub d_hidden: usize,
+}
+
+/// Applies a Gated Recurrent Unit layer over an input sequence as described in the paper
+/// [Learning Phrase Representations using RNN Encoder-Decoder for Statistical Machine Translation](https://arxiv.org/abs/1406.1078).
+///
+/// `r = sigmoid(W_ir x + b_ir + W_hr h)`
+///
+/// `z = sigmoid(W_iz x + b_iz + W_hz h)`
+///
+/// `n = tanh(W_in x + b_in + r * (W_hn h))`
+///
+/// `h' = (1 - z) * n + z * h`
+#[derive(Module, Debug)]
+pub struct Gru<B: Backend> {
+    reset_gate: Param<GateController<B>>,
+    update_gate: Param<GateController<B>>,
+    new_gate: Param<GateController<B>>,
+    d_hidden: usize,
+}
+
+impl<B: Backend> Gru<B> {
+    pub fn new(config: &GruConfig) -> Self {
+        let gate = || Param::new(GateController::new(config.d_input, config.d_hidden));
+
+        Self {
+            reset_gate: gate(),
+            update_gate: gate(),
+            new_gate: gate(),
+            d_hidden: config.d_hidden,
+        }
+    }
+
+    /// Applies the layer on the input with shape `[batch_size, seq_length, d_input]`, starting
+    /// from the given hidden state with shape `[batch_size, d_hidden]` or from zeros.
+    ///
+    /// Returns the hidden states of every position with shape
+    /// `[batch_size, seq_length, d_hidden]` and the final hidden state.
+    pub fn forward_state(
+        &self,
+        input: Tensor<B, 3>,
+        hidden: Option<Tensor<B, 2>>,
+    ) -> (Tensor<B, 3>, Tensor<B, 2>) {
+        let [batch_size, seq_length, d_input] = input.shape().dims;
+
+        let mut hidden = match hidden {
+            Some(hidden) => hidden,
+            None => Tensor::zeros_device([batch_size, self.d_hidden], input.device()),
+        };
+
+        let mut outputs = Vec::with_capacity(seq_length);
+        for t in 0..seq_length {
+            let input_t = input
+                .index([0..batch_size, t..t + 1, 0..d_input])
+                .reshape([batch_size, d_input]);
+
+            hidden = self.step(&input_t, &hidden);
+            outputs.push(hidden.reshape([batch_size, 1, self.d_hidden]));
+        }
+
+        (Tensor::cat(outputs, 1), hidden)
+    }
+
+    fn step(&self, input: &Tensor<B, 2>, hidden: &Tensor<B, 2>) -> Tensor<B, 2> {
+        let reset_values = activation::sigmoid(&self.reset_gate.gate_product(input, hidden));
+        let update_values = activation::sigmoid(&self.update_gate.gate_product(input, hidden));
+        let new_values = activation::tanh(
+            &self
+                .new_gate
+                .input_product(input)
+                .add(&reset_values.mul(&self.new_gate.hidden_product(hidden))),
+        );
+
+        new_values.add(&update_values.mul(&hidden.sub(&new_values)))
+    }
+}
+
+impl<B: Backend> Forward<Tensor<B, 3>, Tensor<B, 3>> for Gru<B> {
+    fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        self.forward_state(input, None).0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lock_rng;
+    use crate::module::State;
+    use crate::optim::{Sgd, SgdConfig};
+    use crate::tensor::{Distribution, Shape};
+    use crate::{TestADBackend, TestBackend};
+
+    #[test]
+    fn should_have_the_right_output_shape() {
+        let _rng = lock_rng();
+        let gru = Gru::<TestBackend>::new(&GruConfig::new(4, 6));
+        let input = Tensor::<TestBackend, 3>::random([2, 5, 4], Distribution::Standard);
+        let hidden = Tensor::<TestBackend, 2>::random([2, 6], Distribution::Standard);
+
+        let (output, hidden) = gru.forward_state(input, Some(hidden));
+
+        assert_eq!(output.shape(), &Shape::new([2, 5, 6]));
+        assert_eq!(hidden.shape(), &Shape::new([2, 6]));
+        assert_eq!(
+            output.index([0..2, 4..5, 0..6]).reshape([2, 6]).into_data(),
+            hidden.into_data()
+        );
+    }
+
+    #[test]
+    fn gradients_should_reach_all_gate_weights() {
+        let _rng = lock_rng();
+        let mut gru = Gru::<TestADBackend>::new(&GruConfig::new(3, 2));
+        let mut optim = Sgd::new(&SgdConfig::new().with_learning_rate(1.0));
+        let input = Tensor::<TestADBackend, 3>::random([1, 3, 3], Distribution::Standard);
+        let weights_before = gate_weights(&gru);
+
+        let (output, _) = gru.forward_state(input, None);
+        let grads = output.sum().backward();
+        gru.update_params(&grads, &mut optim);
+
+        for (before, after) in weights_before.iter().zip(gate_weights(&gru).iter()) {
+            assert_ne!(before, after);
+        }
+    }
+
+    fn gate_weights<B: Backend>(gru: &Gru<B>) -> Vec<State<B::Elem>> {
+        let gates = [&gru.reset_gate, &gru.update_gate, &gru.new_gate];
+
+        gates
+            .into_iter()
+            .flat_map(|gate| [&gate.input_transform, &gate.hidden_transform])
+            .map(|linear| {
+                let state = linear.state();
+                let weight = state.get("data").unwrap().get("weight").unwrap();
+                weight.get("data").unwrap().clone()
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,125 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Distribution, ElementConversion, Shape, Tensor};
+
+/// Initializer used to create the parameters of a layer.
+///
+/// The fans are computed assuming the `[d_input, d_output, ...]` layout used by the weights of
+/// [linear](crate::nn::Linear) layers, where the size of the remaining dimensions is the receptive
+/// field.
+#[derive(Config, Debug, PartialEq)]
+pub enum Initializer {
+    /// Uniform distribution with a variance of `2 / (fan_in + fan_out)`, as described in
+    /// [Understanding the difficulty of training deep feedforward neural networks](http://proceedings.mlr.press/v9/glorot10a).
+    XavierUniform,
+    /// Normal distribution with a variance of `2 / (fan_in + fan_out)`.
+    XavierNormal,
+    /// Uniform distribution with a variance of `2 / fan_in`, as described in
+    /// [Delving Deep into Rectifiers](https://arxiv.org/abs/1502.01852).
+    KaimingUniform,
+    /// Normal distribution with a variance of `2 / fan_in`.
+    KaimingNormal,
+    /// Every element is set to the given value.
+    Constant(f64),
+}
+
+impl Initializer {
+    /// Create a new tensor of the given shape initialized with the current method.
+    pub fn init<B: Backend, const D: usize, S: Into<Shape<D>>>(&self, shape: S) -> Tensor<B, D> {
+        let shape = shape.into();
+        let (fan_in, fan_out) = fans(&shape);
+
+        match self {
+            Self::XavierUniform => uniform(shape, f64::sqrt(6.0 / (fan_in + fan_out) as f64)),
+            Self::XavierNormal => normal(shape, f64::sqrt(2.0 / (fan_in + fan_out) as f64)),
+            Self::KaimingUniform => uniform(shape, f64::sqrt(6.0 / fan_in as f64)),
+            Self::KaimingNormal => normal(shape, f64::sqrt(2.0 / fan_in as f64)),
+            Self::Constant(value) => Tensor::zeros(shape).add_scalar(*value),
+        }
+    }
+}
+
+fn fans<const D: usize>(shape: &Shape<D>) -> (usize, usize) {
+    if D < 2 {
+        return (shape.dims[0], shape.dims[0]);
+    }
+
+    let receptive_field: usize = shape.dims[2..].iter().product();
+
+    (
+        shape.dims[0] * receptive_field,
+        shape.dims[1] * receptive_field,
+    )
+}
+
+fn uniform<B: Backend, const D: usize>(shape: Shape<D>, bound: f64) -> Tensor<B, D> {
+    Tensor::random(
+        shape,
+        Distribution::Uniform((-bound).to_elem(), bound.to_elem()),
+    )
+}
+
+fn normal<B: Backend, const D: usize>(shape: Shape<D>, std: f64) -> Tensor<B, D> {
+    Tensor::random(shape, Distribution::Normal(0.0, std))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lock_rng;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_xavier_uniform_variance() {
+        let _rng = lock_rng();
+        assert_variance(Initializer::XavierUniform, 2.0 / (400.0 + 600.0));
+    }
+
+    #[test]
+    fn test_xavier_normal_variance() {
+        let _rng = lock_rng();
+        assert_variance(Initializer::XavierNormal, 2.0 / (400.0 + 600.0));
+    }
+
+    #[test]
+    fn test_kaiming_uniform_variance() {
+        let _rng = lock_rng();
+        assert_variance(Initializer::KaimingUniform, 2.0 / 400.0);
+    }
+
+    #[test]
+    fn test_kaiming_normal_variance() {
+        let _rng = lock_rng();
+        assert_variance(Initializer::KaimingNormal, 2.0 / 400.0);
+    }
+
+    #[test]
+    fn test_constant() {
+        let tensor: Tensor<TestBackend, 2> = Initializer::Constant(0.5).init([3, 4]);
+
+        assert_eq!(tensor.into_data().value, vec![0.5; 12]);
+    }
+
+    fn assert_variance(initializer: Initializer, expected: f64) {
+        let tensor: Tensor<TestBackend, 2> = initializer.init([400, 600]);
+
+        let values = tensor.into_data().value;
+        let num_values = values.len() as f64;
+        let mean = values.iter().map(|value| *value as f64).sum::<f64>() / num_values;
+        let var = values
+            .iter()
+            .map(|value| (*value as f64 - mean).powi(2))
+            .sum::<f64>()
+            / num_values;
+
+        assert!(mean.abs() < 0.01, "Mean was {}", mean);
+        assert!(
+            (var - expected).abs() / expected < 0.05,
+            "Variance was {}, expected {}",
+            var,
+            expected
+        );
+    }
+}
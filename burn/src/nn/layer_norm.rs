@@ -43,9 +43,7 @@ impl<B: Backend, const D: usize> Forward<Tensor<B, D>, Tensor<B, D>> for LayerNo
     fn forward(&self, input: Tensor<B, D>) -> Tensor<B, D> {
         let (var, mean) = input.var_mean_bias(D - 1);
 
-        let input_normalized = input
-            .sub(&mean)
-            .div(&var.powf(0.5).add_scalar(self.epsilon));
+        let input_normalized = input.sub(&mean).mul(&var.add_scalar(self.epsilon).rsqrt());
 
         input_normalized
             .mul(&self.gamma.unsqueeze())
@@ -3,6 +3,7 @@ use crate as burn;
 use crate::config::Config;
 use crate::module::Module;
 use crate::module::{Forward, Param};
+use crate::nn::Initializer;
 use crate::tensor::backend::Backend;
 use crate::tensor::{Distribution, ElementConversion, Shape, Tensor};
 use std::ops::Deref;
@@ -17,6 +18,9 @@ pub struct LinearConfig {
     /// If a bias should be applied during the linear transformation.
     #[config(default = true)]
     pub bias: bool,
+    /// The [initializer](Initializer) of the weight, uniform between `-1 / sqrt(d_input)` and
+    /// `1 / sqrt(d_input)` when not set.
+    pub initializer: Option<Initializer>,
 }
 
 /// Applies a linear transformation to the input tensor:
@@ -30,12 +34,16 @@ pub struct Linear<B: Backend> {
 
 impl<B: Backend> Linear<B> {
     pub fn new(config: &LinearConfig) -> Self {
-        // Glorot init
-        let start = -1.0 / f64::sqrt(config.d_input as f64);
-        let end = 1.0 / f64::sqrt(config.d_input as f64);
-        let distribution = Distribution::Uniform(start.to_elem(), end.to_elem());
-
-        let weight = Tensor::random(Shape::new([config.d_input, config.d_output]), distribution);
+        let shape = Shape::new([config.d_input, config.d_output]);
+        let weight = match &config.initializer {
+            Some(initializer) => initializer.init(shape),
+            None => {
+                let start = -1.0 / f64::sqrt(config.d_input as f64);
+                let end = 1.0 / f64::sqrt(config.d_input as f64);
+                let distribution = Distribution::Uniform(start.to_elem(), end.to_elem());
+                Tensor::random(shape, distribution)
+            }
+        };
         let bias = match config.bias {
             true => Some(Tensor::zeros(Shape::new([config.d_output]))),
             false => None,
@@ -58,3 +66,33 @@ impl<B: Backend, const D: usize> Forward<Tensor<B, D>, Tensor<B, D>> for Linear<
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lock_rng;
+    use crate::optim::{Sgd, SgdConfig};
+    use crate::TestADBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn frozen_weight_should_not_have_grad_and_not_be_updated() {
+        let _rng = lock_rng();
+        let mut linear = Linear::<TestADBackend>::new(&LinearConfig::new(2, 2));
+        let mut optim = Sgd::new(&SgdConfig::new());
+        let input = Tensor::<TestADBackend, 2>::from_data(Data::from([[1.0, 2.0]]));
+        linear.weight.freeze();
+        let weight_before = linear.weight.to_data();
+
+        let grads = linear.forward(input).backward();
+        assert!(linear.weight.grad(&grads).is_none());
+        assert!(linear.bias.as_ref().unwrap().grad(&grads).is_some());
+        linear.update_params(&grads, &mut optim);
+
+        assert_eq!(linear.weight.to_data(), weight_before);
+        assert_ne!(
+            linear.bias.as_ref().unwrap().to_data(),
+            Data::zeros(Shape::new([2]))
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use crate::tensor::backend::Backend;
+use crate::tensor::{activation, Tensor};
+
+/// How the element wise losses are reduced.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reduction {
+    /// The mean of all losses, the output has a single element.
+    Mean,
+    /// The sum of all losses, the output has a single element.
+    Sum,
+    /// No reduction, the output has the same shape as the inputs.
+    None,
+}
+
+/// Computes the mean squared error between the predictions and the targets.
+///
+/// `loss = (pred - target)^2`
+pub fn mse_loss<B: Backend, const D: usize>(
+    pred: &Tensor<B, D>,
+    target: &Tensor<B, D>,
+    reduction: Reduction,
+) -> Tensor<B, D> {
+    let diff = pred.sub(target);
+
+    reduce(diff.mul(&diff), reduction)
+}
+
+/// Computes the mean absolute error between the predictions and the targets.
+///
+/// `loss = |pred - target|`
+pub fn mae_loss<B: Backend, const D: usize>(
+    pred: &Tensor<B, D>,
+    target: &Tensor<B, D>,
+    reduction: Reduction,
+) -> Tensor<B, D> {
+    reduce(pred.sub(target).abs(), reduction)
+}
+
+/// Computes the binary cross entropy between the logits and the targets, where the targets are
+/// probabilities in `[0, 1]`.
+///
+/// The sigmoid isn't applied explicitly, the loss is computed with the numerically stable
+/// formulation `max(x, 0) - x * z + log(1 + exp(-|x|))`, which doesn't overflow for large logits.
+pub fn binary_cross_entropy_with_logits<B: Backend, const D: usize>(
+    logits: &Tensor<B, D>,
+    targets: &Tensor<B, D>,
+    reduction: Reduction,
+) -> Tensor<B, D> {
+    let losses = activation::relu(logits)
+        .sub(&logits.mul(targets))
+        .add(&logits.abs().neg().exp().log1p());
+
+    reduce(losses, reduction)
+}
+
+fn reduce<B: Backend, const D: usize>(losses: Tensor<B, D>, reduction: Reduction) -> Tensor<B, D> {
+    match reduction {
+        Reduction::Mean => losses.mean().reshape([1; D]),
+        Reduction::Sum => losses.sum().reshape([1; D]),
+        Reduction::None => losses,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TestADBackend, TestBackend};
+    use burn_tensor::Data;
+
+    #[test]
+    fn mse_loss_forward() {
+        let (pred, target) = inputs::<TestBackend>();
+
+        let mean = mse_loss(&pred, &target, Reduction::Mean);
+        let sum = mse_loss(&pred, &target, Reduction::Sum);
+        let none = mse_loss(&pred, &target, Reduction::None);
+
+        assert_eq!(mean.into_data(), Data::from([[2.25]]));
+        assert_eq!(sum.into_data(), Data::from([[9.0]]));
+        assert_eq!(none.into_data(), Data::from([[1.0, 0.0], [4.0, 4.0]]));
+    }
+
+    #[test]
+    fn mae_loss_forward() {
+        let (pred, target) = inputs::<TestBackend>();
+
+        let mean = mae_loss(&pred, &target, Reduction::Mean);
+        let sum = mae_loss(&pred, &target, Reduction::Sum);
+        let none = mae_loss(&pred, &target, Reduction::None);
+
+        assert_eq!(mean.into_data(), Data::from([[1.25]]));
+        assert_eq!(sum.into_data(), Data::from([[5.0]]));
+        assert_eq!(none.into_data(), Data::from([[1.0, 0.0], [2.0, 2.0]]));
+    }
+
+    #[test]
+    fn mse_loss_backward() {
+        assert_grad(
+            mse_loss,
+            Reduction::Mean,
+            Data::from([[-0.5, 0.0], [1.0, -1.0]]),
+        );
+        assert_grad(
+            mse_loss,
+            Reduction::Sum,
+            Data::from([[-2.0, 0.0], [4.0, -4.0]]),
+        );
+        assert_grad(
+            mse_loss,
+            Reduction::None,
+            Data::from([[-2.0, 0.0], [4.0, -4.0]]),
+        );
+    }
+
+    #[test]
+    fn mae_loss_backward() {
+        assert_grad(
+            mae_loss,
+            Reduction::Mean,
+            Data::from([[-0.25, 0.0], [0.25, -0.25]]),
+        );
+        assert_grad(
+            mae_loss,
+            Reduction::Sum,
+            Data::from([[-1.0, 0.0], [1.0, -1.0]]),
+        );
+        assert_grad(
+            mae_loss,
+            Reduction::None,
+            Data::from([[-1.0, 0.0], [1.0, -1.0]]),
+        );
+    }
+
+    #[test]
+    fn binary_cross_entropy_with_logits_backward() {
+        let logits = [[0.5, -1.0], [2.0, -3.0]];
+        let targets = [[1.0, 0.0], [0.5, 1.0]];
+        let logits_tensor = Tensor::<TestADBackend, 2>::from_data(Data::from(logits));
+        let targets_tensor = Tensor::<TestADBackend, 2>::from_data(Data::from(targets));
+
+        let loss =
+            binary_cross_entropy_with_logits(&logits_tensor, &targets_tensor, Reduction::Mean);
+        let grads = loss.backward();
+        let grad = logits_tensor.grad(&grads).unwrap();
+
+        let sigmoid = |x: f32| 1.0 / (1.0 + f32::exp(-x));
+        let expected =
+            [0, 1].map(|i| [0, 1].map(|j| (sigmoid(logits[i][j]) - targets[i][j]) / 4.0));
+        grad.to_data().assert_approx_eq(&Data::from(expected), 5);
+    }
+
+    #[test]
+    fn binary_cross_entropy_with_logits_forward_extreme_logits() {
+        let logits =
+            Tensor::<TestADBackend, 2>::from_data(Data::from([[50.0, -50.0], [50.0, -50.0]]));
+        let targets = Tensor::<TestADBackend, 2>::from_data(Data::from([[0.0, 1.0], [1.0, 0.0]]));
+
+        let loss = binary_cross_entropy_with_logits(&logits, &targets, Reduction::None);
+        let grads = loss.backward();
+        let grad = logits.grad(&grads).unwrap();
+
+        loss.into_data()
+            .assert_approx_eq(&Data::from([[50.0, 50.0], [0.0, 0.0]]), 3);
+        grad.into_data()
+            .assert_approx_eq(&Data::from([[1.0, -1.0], [0.0, 0.0]]), 3);
+    }
+
+    type LossFn = fn(
+        &Tensor<TestADBackend, 2>,
+        &Tensor<TestADBackend, 2>,
+        Reduction,
+    ) -> Tensor<TestADBackend, 2>;
+
+    fn assert_grad(loss_fn: LossFn, reduction: Reduction, expected: Data<f32, 2>) {
+        let (pred, target) = inputs::<TestADBackend>();
+
+        let grads = loss_fn(&pred, &target, reduction).backward();
+        let grad = pred.grad(&grads).unwrap();
+
+        assert_eq!(grad.to_data(), expected);
+    }
+
+    fn inputs<B: Backend<Elem = f32>>() -> (Tensor<B, 2>, Tensor<B, 2>) {
+        let pred = Tensor::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+        let target = Tensor::from_data(Data::from([[2.0, 2.0], [1.0, 6.0]]));
+
+        (pred, target)
+    }
+}
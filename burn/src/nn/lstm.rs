@@ -0,0 +1,183 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::{Forward, Param};
+use crate::nn::GateController;
+use crate::tensor::activation;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Configuration to create a [Lstm](Lstm) layer.
+#[derive(Config)]
+pub struct LstmConfig {
+    /// The size of the input features.
+    pub d_input: usize,
+    /// The size of the hidden state.
+    pub d_hidden: usize,
+}
+
+/// The hidden and cell states of a [Lstm](Lstm) layer, both with shape `[batch_size, d_hidden]`.
+#[derive(Debug, Clone)]
+pub struct LstmState<B: Backend> {
+    pub hidden: Tensor<B, 2>,
+    pub cell: Tensor<B, 2>,
+}
+
+/// Applies a Long Short-Term Memory layer over an input sequence as described in the paper
+/// [Long Short-Term Memory](https://www.bioinf.jku.at/publications/older/2604.pdf).
+///
+/// `i = sigmoid(W_ii x + b_ii + W_hi h)`
+///
+/// `f = sigmoid(W_if x + b_if + W_hf h)`
+///
+/// `g = tanh(W_ig x + b_ig + W_hg h)`
+///
+/// `o = sigmoid(W_io x + b_io + W_ho h)`
+///
+/// `c' = f * c + i * g`
+///
+/// `h' = o * tanh(c')`
+#[derive(Module, Debug)]
+pub struct Lstm<B: Backend> {
+    input_gate: Param<GateController<B>>,
+    forget_gate: Param<GateController<B>>,
+    cell_gate: Param<GateController<B>>,
+    output_gate: Param<GateController<B>>,
+    d_hidden: usize,
+}
+
+impl<B: Backend> Lstm<B> {
+    pub fn new(config: &LstmConfig) -> Self {
+        let gate = || Param::new(GateController::new(config.d_input, config.d_hidden));
+
+        Self {
+            input_gate: gate(),
+            forget_gate: gate(),
+            cell_gate: gate(),
+            output_gate: gate(),
+            d_hidden: config.d_hidden,
+        }
+    }
+
+    /// Applies the layer on the input with shape `[batch_size, seq_length, d_input]`, starting
+    /// from the given state or from zeros.
+    ///
+    /// Returns the hidden states of every position with shape
+    /// `[batch_size, seq_length, d_hidden]` and the final state.
+    pub fn forward_state(
+        &self,
+        input: Tensor<B, 3>,
+        state: Option<LstmState<B>>,
+    ) -> (Tensor<B, 3>, LstmState<B>) {
+        let [batch_size, seq_length, d_input] = input.shape().dims;
+
+        let mut state = match state {
+            Some(state) => state,
+            None => {
+                let zeros = Tensor::zeros_device([batch_size, self.d_hidden], input.device());
+                LstmState {
+                    hidden: zeros.clone(),
+                    cell: zeros,
+                }
+            }
+        };
+
+        let mut outputs = Vec::with_capacity(seq_length);
+        for t in 0..seq_length {
+            let input_t = input
+                .index([0..batch_size, t..t + 1, 0..d_input])
+                .reshape([batch_size, d_input]);
+
+            state = self.step(&input_t, &state);
+            outputs.push(state.hidden.reshape([batch_size, 1, self.d_hidden]));
+        }
+
+        (Tensor::cat(outputs, 1), state)
+    }
+
+    fn step(&self, input: &Tensor<B, 2>, state: &LstmState<B>) -> LstmState<B> {
+        let hidden = &state.hidden;
+
+        let input_values = activation::sigmoid(&self.input_gate.gate_product(input, hidden));
+        let forget_values = activation::sigmoid(&self.forget_gate.gate_product(input, hidden));
+        let cell_candidate = activation::tanh(&self.cell_gate.gate_product(input, hidden));
+        let output_values = activation::sigmoid(&self.output_gate.gate_product(input, hidden));
+
+        let cell = forget_values
+            .mul(&state.cell)
+            .add(&input_values.mul(&cell_candidate));
+        let hidden = output_values.mul(&activation::tanh(&cell));
+
+        LstmState { hidden, cell }
+    }
+}
+
+impl<B: Backend> Forward<Tensor<B, 3>, Tensor<B, 3>> for Lstm<B> {
+    fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        self.forward_state(input, None).0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lock_rng;
+    use crate::module::State;
+    use crate::optim::{Sgd, SgdConfig};
+    use crate::tensor::{Distribution, Shape};
+    use crate::{TestADBackend, TestBackend};
+
+    #[test]
+    fn should_have_the_right_output_shape() {
+        let _rng = lock_rng();
+        let lstm = Lstm::<TestBackend>::new(&LstmConfig::new(4, 6));
+        let input = Tensor::<TestBackend, 3>::random([2, 5, 4], Distribution::Standard);
+
+        let (output, state) = lstm.forward_state(input, None);
+
+        assert_eq!(output.shape(), &Shape::new([2, 5, 6]));
+        assert_eq!(state.hidden.shape(), &Shape::new([2, 6]));
+        assert_eq!(state.cell.shape(), &Shape::new([2, 6]));
+        assert_eq!(
+            output.index([0..2, 4..5, 0..6]).reshape([2, 6]).into_data(),
+            state.hidden.into_data()
+        );
+    }
+
+    #[test]
+    fn gradients_should_reach_all_gate_weights() {
+        let _rng = lock_rng();
+        let mut lstm = Lstm::<TestADBackend>::new(&LstmConfig::new(3, 2));
+        let mut optim = Sgd::new(&SgdConfig::new().with_learning_rate(1.0));
+        let input = Tensor::<TestADBackend, 3>::random([1, 3, 3], Distribution::Standard);
+        let weights_before = gate_weights(&lstm);
+
+        let (output, _) = lstm.forward_state(input, None);
+        let grads = output.sum().backward();
+        lstm.update_params(&grads, &mut optim);
+
+        for (before, after) in weights_before.iter().zip(gate_weights(&lstm).iter()) {
+            assert_ne!(before, after);
+        }
+    }
+
+    fn gate_weights<B: Backend>(lstm: &Lstm<B>) -> Vec<State<B::Elem>> {
+        let gates = [
+            &lstm.input_gate,
+            &lstm.forget_gate,
+            &lstm.cell_gate,
+            &lstm.output_gate,
+        ];
+
+        gates
+            .into_iter()
+            .flat_map(|gate| [&gate.input_transform, &gate.hidden_transform])
+            .map(|linear| {
+                let state = linear.state();
+                let weight = state.get("data").unwrap().get("weight").unwrap();
+                weight.get("data").unwrap().clone()
+            })
+            .collect()
+    }
+}
@@ -1,13 +1,35 @@
+mod attention;
+mod batch_norm;
+mod conv1d;
 mod dropout;
 mod embedding;
+mod gate;
 mod gelu;
+mod gru;
+mod initializer;
 mod layer_norm;
 mod linear;
+mod lstm;
+mod pool;
+mod pos_encoding;
 mod relu;
+mod sequential;
 
+pub mod loss;
+
+pub use attention::*;
+pub use batch_norm::*;
+pub use conv1d::*;
 pub use dropout::*;
 pub use embedding::*;
+pub use gate::*;
 pub use gelu::*;
+pub use gru::*;
+pub use initializer::*;
 pub use layer_norm::*;
 pub use linear::*;
+pub use lstm::*;
+pub use pool::*;
+pub use pos_encoding::*;
 pub use relu::*;
+pub use sequential::*;
@@ -0,0 +1,112 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Forward;
+use crate::tensor::backend::Backend;
+use crate::tensor::{module, Tensor};
+
+/// Configuration to create a [2D average pooling](AvgPool2d) layer.
+#[derive(Config)]
+pub struct AvgPool2dConfig {
+    /// The size of the pooling windows.
+    pub kernel_size: [usize; 2],
+    /// The step between two pooling windows, equal to the kernel size when not set.
+    pub stride: Option<[usize; 2]>,
+    /// The number of zeros added on both sides of each spatial dimension, no padding when not
+    /// set.
+    pub padding: Option<[usize; 2]>,
+    /// If the padded zeros should be counted in the average of the windows overlapping them.
+    #[config(default = true)]
+    pub count_include_pad: bool,
+}
+
+/// Applies a 2D average pooling over an input of shape `[batch_size, channels, height, width]`.
+#[derive(Clone, Debug)]
+pub struct AvgPool2d {
+    kernel_size: [usize; 2],
+    stride: [usize; 2],
+    padding: [usize; 2],
+    count_include_pad: bool,
+}
+
+impl AvgPool2d {
+    pub fn new(config: &AvgPool2dConfig) -> Self {
+        Self {
+            kernel_size: config.kernel_size,
+            stride: config.stride.unwrap_or(config.kernel_size),
+            padding: config.padding.unwrap_or([0, 0]),
+            count_include_pad: config.count_include_pad,
+        }
+    }
+}
+
+impl<B: Backend> Forward<Tensor<B, 4>, Tensor<B, 4>> for AvgPool2d {
+    fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        module::avg_pool2d(
+            &input,
+            self.kernel_size,
+            self.stride,
+            self.padding,
+            self.count_include_pad,
+        )
+    }
+}
+
+/// Configuration to create a [2D adaptive average pooling](AdaptiveAvgPool2d) layer.
+#[derive(Config)]
+pub struct AdaptiveAvgPool2dConfig {
+    /// The spatial size of the output.
+    pub output_size: [usize; 2],
+}
+
+/// Applies a 2D adaptive average pooling over an input of shape
+/// `[batch_size, channels, height, width]`, giving an output of the same spatial size whatever
+/// the size of the input.
+#[derive(Clone, Debug)]
+pub struct AdaptiveAvgPool2d {
+    output_size: [usize; 2],
+}
+
+impl AdaptiveAvgPool2d {
+    pub fn new(config: &AdaptiveAvgPool2dConfig) -> Self {
+        Self {
+            output_size: config.output_size,
+        }
+    }
+}
+
+impl<B: Backend> Forward<Tensor<B, 4>, Tensor<B, 4>> for AdaptiveAvgPool2d {
+    fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        module::adaptive_avg_pool2d(&input, self.output_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn stride_should_default_to_the_kernel_size() {
+        let pool = AvgPool2d::new(&AvgPool2dConfig::new([2, 3]));
+        let input = Tensor::<TestBackend, 4>::ones([2, 3, 6, 9]);
+
+        let output = pool.forward(input);
+
+        assert_eq!(output.shape().dims, [2, 3, 3, 3]);
+        assert_eq!(output.into_data().value, vec![1.0; 54]);
+    }
+
+    #[test]
+    fn adaptive_should_give_the_output_size_for_any_input_size() {
+        let pool = AdaptiveAvgPool2d::new(&AdaptiveAvgPool2dConfig::new([2, 3]));
+
+        for [height, width] in [[2, 3], [7, 7], [13, 5]] {
+            let input = Tensor::<TestBackend, 4>::ones([2, 4, height, width]);
+
+            let output = pool.forward(input);
+
+            assert_eq!(output.shape().dims, [2, 4, 2, 3]);
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::module::{Buffer, Forward, Param};
+use crate::tensor::backend::Backend;
+use crate::tensor::{Data, Shape, Tensor};
+
+/// Configuration to create a [PositionalEncoding](PositionalEncoding) layer.
+#[derive(Config)]
+pub struct PositionalEncodingConfig {
+    /// The size of the input features, must be even.
+    pub d_model: usize,
+    /// The maximum sequence length supported.
+    #[config(default = 5000)]
+    pub max_len: usize,
+}
+
+/// Adds the sinusoidal positional encoding described in the paper
+/// [Attention Is All You Need](https://arxiv.org/abs/1706.03762) to the input.
+///
+/// `PE(pos, 2i) = sin(pos / 10000^(2i / d_model))`
+///
+/// `PE(pos, 2i + 1) = cos(pos / 10000^(2i / d_model))`
+///
+/// The table is computed once and stored in a [buffer](Buffer), so it isn't trained but is saved
+/// in the module state and moved with the module to other devices.
+#[derive(Module, Debug)]
+pub struct PositionalEncoding<B: Backend> {
+    table: Param<Buffer<Tensor<B, 2>>>,
+}
+
+impl<B: Backend> PositionalEncoding<B> {
+    /// Create the module from the given configuration.
+    ///
+    /// # Panics
+    ///
+    /// If `d_model` isn't even.
+    pub fn new(config: &PositionalEncodingConfig) -> Self {
+        if config.d_model % 2 == 1 {
+            panic!(
+                "The size of the model must be even for positional encoding, got {}",
+                config.d_model
+            );
+        }
+
+        let [max_len, d_model] = [config.max_len, config.d_model];
+        let positions = Tensor::<B, 2>::from_floats(Data::new(
+            (0..max_len).map(|pos| pos as f32).collect(),
+            Shape::new([max_len, 1]),
+        ));
+        let frequencies = Tensor::<B, 2>::from_floats(Data::new(
+            (0..d_model).step_by(2).map(|i| i as f32).collect(),
+            Shape::new([1, d_model / 2]),
+        ))
+        .mul_scalar(-f64::ln(10000.0) / d_model as f64)
+        .exp();
+
+        // The sines and cosines of each angle are interleaved along the last dimension.
+        let angles = positions
+            .matmul(&frequencies)
+            .reshape([max_len, d_model / 2, 1]);
+        let table = Tensor::cat(vec![angles.sin(), angles.cos()], 2).reshape([max_len, d_model]);
+
+        Self {
+            table: Param::new(Buffer::new(table)),
+        }
+    }
+}
+
+impl<B: Backend> Forward<Tensor<B, 3>, Tensor<B, 3>> for PositionalEncoding<B> {
+    /// Add the positional encoding to the input with shape `[batch_size, seq_length, d_model]`.
+    ///
+    /// # Panics
+    ///
+    /// If the sequence length is greater than the maximum length.
+    fn forward(&self, input: Tensor<B, 3>) -> Tensor<B, 3> {
+        let table = self.table.value();
+        let [max_len, d_model] = table.shape().dims;
+        let [_, seq_length, _] = input.shape().dims;
+
+        if seq_length > max_len {
+            panic!(
+                "Sequence length {} is greater than the maximum length {} of the positional encoding",
+                seq_length, max_len
+            );
+        }
+
+        let encoding = table.index([0..seq_length, 0..d_model]);
+
+        input.add(&encoding.unsqueeze())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn should_have_the_right_output_shape() {
+        let pos_encoding = PositionalEncoding::<TestBackend>::new(
+            &PositionalEncodingConfig::new(8).with_max_len(10),
+        );
+        let input = Tensor::<TestBackend, 3>::zeros(Shape::new([2, 5, 8]));
+
+        let output = pos_encoding.forward(input);
+
+        assert_eq!(output.shape(), &Shape::new([2, 5, 8]));
+    }
+
+    #[test]
+    fn position_zero_should_have_zero_sine_components() {
+        let pos_encoding =
+            PositionalEncoding::<TestBackend>::new(&PositionalEncodingConfig::new(6));
+        let input = Tensor::<TestBackend, 3>::zeros(Shape::new([1, 3, 6]));
+
+        let output = pos_encoding.forward(input);
+
+        let position_0 = output.index([0..1, 0..1, 0..6]).into_data();
+        assert_eq!(position_0.value, vec![0.0, 1.0, 0.0, 1.0, 0.0, 1.0]);
+        let position_1 = output.index([0..1, 1..2, 0..2]).into_data();
+        position_1.assert_approx_eq(&Data::from([[[f32::sin(1.0), f32::cos(1.0)]]]), 3);
+    }
+
+    #[test]
+    fn should_match_the_sinusoidal_formula() {
+        let pos_encoding = PositionalEncoding::<TestBackend>::new(
+            &PositionalEncodingConfig::new(4).with_max_len(3),
+        );
+        let input = Tensor::<TestBackend, 3>::zeros(Shape::new([1, 3, 4]));
+
+        let output = pos_encoding.forward(input);
+
+        let mut expected = Vec::new();
+        for pos in 0..3 {
+            for i in [0.0, 2.0] {
+                let angle = pos as f32 / f32::powf(10000.0, i / 4.0);
+                expected.push(angle.sin());
+                expected.push(angle.cos());
+            }
+        }
+        output
+            .into_data()
+            .assert_approx_eq(&Data::new(expected, Shape::new([1, 3, 4])), 3);
+    }
+
+    #[test]
+    fn table_should_be_saved_but_not_trained() {
+        let config = PositionalEncodingConfig::new(4).with_max_len(3);
+        let pos_encoding = PositionalEncoding::<TestBackend>::new(&config);
+        let mut pos_encoding_loaded = PositionalEncoding::<TestBackend>::new(&config);
+        pos_encoding_loaded.table.update(Tensor::zeros([3, 4]));
+
+        pos_encoding_loaded.load(&pos_encoding.state()).unwrap();
+
+        assert_eq!(pos_encoding.num_params(), 0);
+        assert_eq!(
+            pos_encoding_loaded.table.value().into_data(),
+            pos_encoding.table.value().into_data()
+        );
+    }
+}
@@ -0,0 +1,289 @@
+use crate::module::{
+    ADModule, Forward, LoadingError, Module, ModuleSummary, Param, ParamMapper, ParamVisitor,
+    State, StateNamed,
+};
+use crate::optim::Optimizer;
+use crate::tensor::backend::{ADBackend, Backend};
+use crate::tensor::{Gradients, Tensor};
+
+/// Chain two [modules](Module), the output of the first one being the input of the second one.
+///
+/// Containers of more than two modules are created by nesting sequential modules, which is
+/// what the [sequential](crate::sequential) macro does.
+///
+/// # Example
+///
+/// ```rust
+/// use burn::nn;
+/// use burn::sequential;
+/// use burn::tensor::backend::Backend;
+///
+/// fn mlp<B: Backend>() -> nn::Sequential<nn::Linear<B>, nn::Sequential<nn::Linear<B>, nn::Linear<B>>> {
+///     sequential![
+///         nn::Linear::new(&nn::LinearConfig::new(784, 256)),
+///         nn::Linear::new(&nn::LinearConfig::new(256, 64)),
+///         nn::Linear::new(&nn::LinearConfig::new(64, 10))
+///     ]
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Sequential<M1, M2> {
+    first: Param<M1>,
+    second: Param<M2>,
+}
+
+impl<M1, M2> Sequential<M1, M2> {
+    pub fn new(first: M1, second: M2) -> Self {
+        Self {
+            first: Param::new(first),
+            second: Param::new(second),
+        }
+    }
+}
+
+/// Create a [sequential](crate::nn::Sequential) module from a list of modules.
+#[macro_export]
+macro_rules! sequential {
+    ($module:expr $(,)?) => {
+        $module
+    };
+    ($first:expr, $($rest:expr),+ $(,)?) => {
+        $crate::nn::Sequential::new($first, $crate::sequential!($($rest),+))
+    };
+}
+
+impl<B, const D: usize, M1, M2> Forward<Tensor<B, D>, Tensor<B, D>> for Sequential<M1, M2>
+where
+    B: Backend,
+    M1: Forward<Tensor<B, D>, Tensor<B, D>>,
+    M2: Forward<Tensor<B, D>, Tensor<B, D>>,
+{
+    fn forward(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        let x = self.first.forward(input);
+        self.second.forward(x)
+    }
+}
+
+impl<M1, M2> Module for Sequential<M1, M2>
+where
+    M1: Module,
+    M2: Module<Backend = M1::Backend>,
+{
+    type Backend = M1::Backend;
+
+    fn devices(&self) -> Vec<<Self::Backend as Backend>::Device> {
+        let mut devices = self.first.devices();
+        devices.append(&mut self.second.devices());
+        devices
+    }
+
+    fn to_device(&mut self, device: <Self::Backend as Backend>::Device) {
+        self.first.to_device(device);
+        self.second.to_device(device);
+    }
+
+    fn load(
+        &mut self,
+        state: &State<<Self::Backend as Backend>::Elem>,
+    ) -> Result<(), LoadingError> {
+        let state_first = state
+            .get("first")
+            .ok_or_else(|| LoadingError::new("Missing module 'first' from state".to_string()))?;
+        let state_second = state
+            .get("second")
+            .ok_or_else(|| LoadingError::new("Missing module 'second' from state".to_string()))?;
+
+        self.first
+            .load(state_first)
+            .map_err(|err| LoadingError::new(format!("Can't load module first: {}", err)))?;
+        self.second
+            .load(state_second)
+            .map_err(|err| LoadingError::new(format!("Can't load module second: {}", err)))?;
+
+        Ok(())
+    }
+
+    fn state(&self) -> State<<Self::Backend as Backend>::Elem> {
+        let mut state = StateNamed::new();
+        state.register_state("first", self.first.state());
+        state.register_state("second", self.second.state());
+
+        State::StateNamed(state)
+    }
+
+    fn detach(&mut self) {
+        self.first.detach();
+        self.second.detach();
+    }
+
+    fn num_params(&self) -> usize {
+        self.first.num_params() + self.second.num_params()
+    }
+
+    fn summary(&self) -> ModuleSummary {
+        let mut summary = ModuleSummary::new();
+        summary.register("first", self.first.summary());
+        summary.register("second", self.second.summary());
+
+        summary
+    }
+
+    fn visit_params<V: ParamVisitor<Self::Backend>>(&self, visitor: &mut V) {
+        self.first.visit_params(visitor);
+        self.second.visit_params(visitor);
+    }
+
+    fn map_params<P: ParamMapper<Self::Backend>>(&mut self, mapper: &mut P) {
+        self.first.map_params(mapper);
+        self.second.map_params(mapper);
+    }
+
+    fn update_params<O: Optimizer<Backend = Self::Backend>>(
+        &mut self,
+        grads: &Gradients,
+        optim: &mut O,
+    ) where
+        Self::Backend: ADBackend,
+    {
+        self.first.update_params(grads, optim);
+        self.second.update_params(grads, optim);
+    }
+
+    fn load_optim_state<O: Optimizer<Backend = Self::Backend>>(
+        &self,
+        optim: &mut O,
+        state_optim: &StateNamed<<Self::Backend as Backend>::Elem>,
+    ) where
+        Self::Backend: ADBackend,
+    {
+        self.first.load_optim_state(optim, state_optim);
+        self.second.load_optim_state(optim, state_optim);
+    }
+
+    fn register_optim_state<O: Optimizer<Backend = Self::Backend>>(
+        &self,
+        optim: &O,
+        state_optim: &mut StateNamed<<Self::Backend as Backend>::Elem>,
+    ) where
+        Self::Backend: ADBackend,
+    {
+        self.first.register_optim_state(optim, state_optim);
+        self.second.register_optim_state(optim, state_optim);
+    }
+}
+
+impl<M1, M2> ADModule for Sequential<M1, M2>
+where
+    M1: ADModule,
+    M2: ADModule<ADBackend = M1::ADBackend>,
+    M2::InnerModule: Module<Backend = <M1::InnerModule as Module>::Backend>,
+{
+    type ADBackend = M1::ADBackend;
+    type InnerModule = Sequential<M1::InnerModule, M2::InnerModule>;
+
+    fn inner(&self) -> Self::InnerModule {
+        Sequential {
+            first: self.first.inner(),
+            second: self.second.inner(),
+        }
+    }
+}
+
+impl<M1: Module, M2: Module<Backend = M1::Backend>> std::fmt::Display for Sequential<M1, M2> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Sequential[num_params={}]", self.num_params())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lock_rng;
+    use crate::module::{ParamId, ParamSummary};
+    use crate::nn::{Linear, LinearConfig};
+    use crate::tensor::{Distribution, Shape};
+    use crate::TestADBackend;
+
+    #[test]
+    fn test_mlp_should_expose_the_state_of_both_layers() {
+        let _rng = lock_rng();
+        let mlp = mlp();
+
+        let state = mlp.state();
+
+        assert_eq!(mlp.num_params(), (4 * 8 + 8) + (8 * 2 + 2));
+        assert!(state.get("first").is_some());
+        assert!(state.get("second").is_some());
+    }
+
+    #[test]
+    fn test_mlp_summary_should_list_the_params_of_both_layers() {
+        let _rng = lock_rng();
+        let mlp = mlp();
+
+        let summary = mlp.summary();
+
+        assert_eq!(
+            summary.params,
+            vec![
+                ParamSummary::new("first.weight".to_string(), vec![4, 8]),
+                ParamSummary::new("first.bias".to_string(), vec![8]),
+                ParamSummary::new("second.weight".to_string(), vec![8, 2]),
+                ParamSummary::new("second.bias".to_string(), vec![2]),
+            ]
+        );
+        assert_eq!(summary.num_params(), (4 * 8 + 8) + (8 * 2 + 2));
+        assert_eq!(summary.num_params(), mlp.num_params());
+    }
+
+    #[test]
+    fn test_mlp_should_load_its_state() {
+        let _rng = lock_rng();
+        let mlp_1 = mlp();
+        let mut mlp_2 = mlp();
+        assert_ne!(mlp_1.state(), mlp_2.state());
+
+        mlp_2.load(&mlp_1.state()).unwrap();
+
+        assert_eq!(mlp_1.state(), mlp_2.state());
+    }
+
+    #[test]
+    fn test_mlp_parameters_should_receive_gradients() {
+        let _rng = lock_rng();
+        let mut mlp = mlp();
+        let input = Tensor::<TestADBackend, 2>::random(Shape::new([3, 4]), Distribution::Standard);
+
+        let grads = mlp.forward(input).backward();
+        let mut optim = GradRecorder { ids: Vec::new() };
+        mlp.update_params(&grads, &mut optim);
+
+        assert_eq!(optim.ids.len(), 4);
+    }
+
+    fn mlp() -> Sequential<Linear<TestADBackend>, Linear<TestADBackend>> {
+        crate::sequential![
+            Linear::new(&LinearConfig::new(4, 8)),
+            Linear::new(&LinearConfig::new(8, 2)),
+        ]
+    }
+
+    struct GradRecorder {
+        ids: Vec<ParamId>,
+    }
+
+    impl Optimizer for GradRecorder {
+        type Backend = TestADBackend;
+
+        fn update<const D: usize>(
+            &mut self,
+            id: &ParamId,
+            tensor: &mut Tensor<TestADBackend, D>,
+            grads: &Gradients,
+        ) {
+            if tensor.grad(grads).is_some() {
+                self.ids.push(id.clone());
+            }
+        }
+    }
+}
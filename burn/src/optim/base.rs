@@ -12,6 +12,34 @@ pub trait Optimizer: Send + Sync {
         grads: &Gradients,
     );
 
+    /// The learning rate currently used by the optimizer, if it has one.
+    ///
+    /// It is read by the [learner](crate::train::Learner) at every training step and given to the
+    /// [metrics](crate::train::metric::MetricMetadata), so changes to the learning rate, for
+    /// instance by a scheduler, are reflected in the metrics.
+    fn learning_rate(&self) -> Option<f64> {
+        None
+    }
+
+    /// The factor by which the loss should be multiplied before the backward pass, for the
+    /// optimizers scaling the loss like [MixedPrecision](crate::optim::MixedPrecision).
+    fn loss_scale(&self) -> Option<f64> {
+        None
+    }
+
+    /// Update the parameters of a module with their gradients.
+    ///
+    /// By default, each parameter is [updated](Optimizer::update) independently.
+    fn update_module<M: Module<Backend = Self::Backend>>(
+        &mut self,
+        module: &mut M,
+        grads: &Gradients,
+    ) where
+        Self: Sized,
+    {
+        module.update_params(grads, self);
+    }
+
     /// Register the optimizer state for a given parameter.
     ///
     /// # Note
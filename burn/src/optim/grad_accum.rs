@@ -0,0 +1,189 @@
+use crate::module::{Module, ParamId};
+use crate::optim::Optimizer;
+use crate::tensor::backend::ADBackend;
+use crate::tensor::{Gradients, Tensor};
+
+/// Accumulate the gradients of a [module](Module) over multiple backward passes, to train with
+/// an effective batch size larger than what fits in memory.
+///
+/// The parameters of the module must not be updated between two accumulations, since gradients
+/// are tracked per parameter tensor.
+pub struct GradientsAccumulator {
+    grads: Gradients,
+    count: usize,
+}
+
+impl Default for GradientsAccumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GradientsAccumulator {
+    pub fn new() -> Self {
+        Self {
+            grads: Gradients::empty(),
+            count: 0,
+        }
+    }
+
+    /// Add the gradients of the module parameters to the accumulated ones.
+    pub fn accumulate<M: Module>(&mut self, module: &mut M, grads: &Gradients)
+    where
+        M::Backend: ADBackend,
+    {
+        let mut visitor = Accumulate::<M::Backend>::new(&mut self.grads);
+        module.update_params(grads, &mut visitor);
+        self.count += 1;
+    }
+
+    /// The number of backward passes accumulated since the last [mean](Self::mean).
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Take the mean of the accumulated gradients and reset the accumulator.
+    ///
+    /// When the loss of each pass is a mean over its batch, the result is the gradients of the
+    /// mean loss over all the accumulated batches, provided they have the same size.
+    pub fn mean<M: Module>(&mut self, module: &mut M) -> Gradients
+    where
+        M::Backend: ADBackend,
+    {
+        let accumulated = std::mem::replace(&mut self.grads, Gradients::empty());
+        let mut grads = Gradients::empty();
+
+        if self.count > 0 {
+            let mut visitor = Scale::<M::Backend>::new(&mut grads, 1.0 / self.count as f64);
+            module.update_params(&accumulated, &mut visitor);
+        }
+        self.count = 0;
+
+        grads
+    }
+}
+
+/// Visit the module parameters through [update_params](Module::update_params) without modifying
+/// them, summing their gradients.
+struct Accumulate<'a, B> {
+    accumulated: &'a mut Gradients,
+    _b: std::marker::PhantomData<B>,
+}
+
+impl<'a, B> Accumulate<'a, B> {
+    fn new(accumulated: &'a mut Gradients) -> Self {
+        Self {
+            accumulated,
+            _b: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, B: ADBackend> Optimizer for Accumulate<'a, B> {
+    type Backend = B;
+
+    fn update<const D: usize>(
+        &mut self,
+        _id: &ParamId,
+        tensor: &mut Tensor<B, D>,
+        grads: &Gradients,
+    ) {
+        if let Some(grad) = tensor.grad(grads) {
+            let grad = match tensor.grad(self.accumulated) {
+                Some(accumulated) => accumulated.add(&grad),
+                None => grad,
+            };
+            tensor.grad_replace(self.accumulated, grad);
+        }
+    }
+}
+
+/// Visit the module parameters through [update_params](Module::update_params) without modifying
+/// them, scaling their gradients.
+struct Scale<'a, B> {
+    scaled: &'a mut Gradients,
+    factor: f64,
+    _b: std::marker::PhantomData<B>,
+}
+
+impl<'a, B> Scale<'a, B> {
+    fn new(scaled: &'a mut Gradients, factor: f64) -> Self {
+        Self {
+            scaled,
+            factor,
+            _b: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, B: ADBackend> Optimizer for Scale<'a, B> {
+    type Backend = B;
+
+    fn update<const D: usize>(
+        &mut self,
+        _id: &ParamId,
+        tensor: &mut Tensor<B, D>,
+        grads: &Gradients,
+    ) {
+        if let Some(grad) = tensor.grad(grads) {
+            tensor.grad_replace(self.scaled, grad.mul_scalar(self.factor));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lock_rng;
+    use crate::module::Forward;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::optim::{Sgd, SgdConfig};
+    use crate::tensor::Distribution;
+    use crate::TestADBackend;
+
+    #[test]
+    fn accumulating_two_half_batches_should_update_like_the_full_batch() {
+        let _rng = lock_rng();
+        let input = Tensor::<TestADBackend, 2>::random([4, 3], Distribution::Standard);
+        let mut linear_full = Linear::<TestADBackend>::new(&LinearConfig::new(3, 2));
+        let mut linear_accumulated = Linear::<TestADBackend>::new(&LinearConfig::new(3, 2));
+        linear_accumulated.load(&linear_full.state()).unwrap();
+        let mut optim = Sgd::new(&SgdConfig::new().with_learning_rate(0.5));
+
+        let grads = loss(&linear_full, input.clone()).backward();
+        linear_full.update_params(&grads, &mut optim);
+
+        let mut accumulator = GradientsAccumulator::new();
+        for batch in [0..2, 2..4] {
+            let grads = loss(&linear_accumulated, input.index([batch, 0..3])).backward();
+            accumulator.accumulate(&mut linear_accumulated, &grads);
+        }
+        assert_eq!(accumulator.count(), 2);
+        let grads = accumulator.mean(&mut linear_accumulated);
+        linear_accumulated.update_params(&grads, &mut optim);
+
+        assert_eq!(accumulator.count(), 0);
+        let output_full = linear_full.forward(input.clone()).into_data();
+        let output_accumulated = linear_accumulated.forward(input).into_data();
+        output_full.assert_within_tolerance(&output_accumulated, 1e-5);
+    }
+
+    #[test]
+    fn mean_without_accumulated_grads_should_not_update_the_module() {
+        let _rng = lock_rng();
+        let mut linear = Linear::<TestADBackend>::new(&LinearConfig::new(3, 2));
+        let state_before = linear.state();
+
+        let grads = GradientsAccumulator::new().mean(&mut linear);
+        linear.update_params(&grads, &mut Sgd::new(&SgdConfig::new()));
+
+        assert_eq!(linear.state(), state_before);
+    }
+
+    fn loss(
+        linear: &Linear<TestADBackend>,
+        input: Tensor<TestADBackend, 2>,
+    ) -> Tensor<TestADBackend, 1> {
+        linear.forward(input).powf(2.0).mean()
+    }
+}
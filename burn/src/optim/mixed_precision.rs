@@ -0,0 +1,281 @@
+use super::{load_state_gradients, register_state_gradients};
+use crate::module::{Module, ParamId, StateNamed};
+use crate::optim::Optimizer;
+use crate::tensor::backend::{ADBackend, Backend};
+use crate::tensor::{Gradients, Tensor};
+use num_traits::ToPrimitive;
+
+/// Number of consecutive steps without overflow after which the loss scale is doubled.
+const GROWTH_INTERVAL: usize = 2000;
+
+/// The inner backend of the full precision copies of the parameters.
+type FullPrecision<B> = <<B as ADBackend>::InnerBackend as Backend>::FullPrecisionBackend;
+
+/// Optimizer of a [module](Module) trained in reduced precision, with dynamic loss scaling and a
+/// full precision copy of its parameters.
+///
+/// The forward and backward passes run on the backend of the module, typically with
+/// [f16](crate::tensor::f16) elements, while a master copy of each parameter is kept on its
+/// [full precision backend](Backend::FullPrecisionBackend). The wrapped optimizer works on that
+/// backend: it updates the master copies with the unscaled gradients, which are then rounded back
+/// into the module, so that updates too small to change a reduced precision parameter aren't
+/// lost.
+///
+/// The loss should be multiplied by the [loss scale](Optimizer::loss_scale) before the backward
+/// pass to keep small gradients from underflowing. When the gradients overflow,
+/// [update_module](Optimizer::update_module) skips the step and halves the scale; it is doubled
+/// again after 2000 steps without overflow.
+pub struct MixedPrecision<B: ADBackend, O> {
+    optim: O,
+    loss_scale: f64,
+    steps_without_overflow: usize,
+    master: Gradients,
+    _b: std::marker::PhantomData<B>,
+}
+
+impl<B: ADBackend, O> MixedPrecision<B, O> {
+    pub fn new(optim: O, loss_scale: f64) -> Self {
+        Self {
+            optim,
+            loss_scale,
+            steps_without_overflow: 0,
+            master: Gradients::empty(),
+            _b: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<B, O> Optimizer for MixedPrecision<B, O>
+where
+    B: ADBackend,
+    O: Optimizer<Backend = B::FullPrecisionBackend>,
+    B::FullPrecisionBackend: ADBackend<InnerBackend = FullPrecision<B>>,
+{
+    type Backend = B;
+
+    /// Update a parameter, which isn't updated when its gradient isn't finite.
+    fn update<const D: usize>(
+        &mut self,
+        id: &ParamId,
+        tensor: &mut Tensor<B, D>,
+        grads: &Gradients,
+    ) {
+        let grad = match unscaled_grad(tensor, grads, self.loss_scale) {
+            Some(grad) if is_finite(&grad) => grad,
+            _ => return,
+        };
+        let key = id.to_string();
+        let master = match self.master.get::<Tensor<FullPrecision<B>, D>>(&key) {
+            Some(master) => master.clone(),
+            None => tensor.inner().to_full_precision(),
+        };
+
+        let mut master = Tensor::<B::FullPrecisionBackend, D>::from_inner(master);
+        let mut master_grads = Gradients::empty();
+        master.grad_replace(&mut master_grads, grad);
+        self.optim.update(id, &mut master, &master_grads);
+
+        let master = master.inner();
+        tensor.update(Tensor::from_full_precision(master.clone()));
+        self.master.register_any(key, master);
+    }
+
+    fn learning_rate(&self) -> Option<f64> {
+        self.optim.learning_rate()
+    }
+
+    fn loss_scale(&self) -> Option<f64> {
+        Some(self.loss_scale)
+    }
+
+    /// Update the module, or skip the step and halve the loss scale when any gradient isn't
+    /// finite.
+    fn update_module<M: Module<Backend = B>>(&mut self, module: &mut M, grads: &Gradients) {
+        let mut visitor = FiniteCheck::<B>::new(self.loss_scale);
+        module.update_params(grads, &mut visitor);
+
+        if !visitor.finite {
+            self.loss_scale /= 2.0;
+            self.steps_without_overflow = 0;
+            return;
+        }
+
+        module.update_params(grads, self);
+
+        self.steps_without_overflow += 1;
+        if self.steps_without_overflow >= GROWTH_INTERVAL {
+            self.loss_scale *= 2.0;
+            self.steps_without_overflow = 0;
+        }
+    }
+
+    fn register_param_state<const D: usize>(&self, id: &ParamId, state: &mut StateNamed<B::Elem>) {
+        let mut state_full = StateNamed::new();
+        self.optim.register_param_state::<D>(id, &mut state_full);
+        register_state_gradients::<D, B::FullPrecisionBackend, _>(
+            id,
+            &mut state_full,
+            &self.master,
+            Self::state_key,
+        );
+
+        state.values.extend(state_full.convert().values);
+    }
+
+    fn load_param_state<const D: usize>(
+        &mut self,
+        id: &ParamId,
+        state: &StateNamed<B::Elem>,
+        device: &B::Device,
+    ) {
+        let state_full = state.clone().convert();
+        self.optim.load_param_state::<D>(id, &state_full, device);
+        load_state_gradients::<D, B::FullPrecisionBackend, _>(
+            id,
+            &state_full,
+            &mut self.master,
+            Self::state_key,
+            device,
+        );
+    }
+}
+
+impl<B: ADBackend, O> MixedPrecision<B, O> {
+    fn state_key(id: &str) -> String {
+        format!("mixed-precision-master-{}", id)
+    }
+}
+
+fn unscaled_grad<B: ADBackend, const D: usize>(
+    tensor: &Tensor<B, D>,
+    grads: &Gradients,
+    loss_scale: f64,
+) -> Option<Tensor<FullPrecision<B>, D>> {
+    tensor
+        .grad(grads)
+        .map(|grad| grad.to_full_precision().div_scalar(loss_scale))
+}
+
+fn is_finite<B: Backend, const D: usize>(tensor: &Tensor<B, D>) -> bool {
+    tensor
+        .to_data()
+        .value
+        .iter()
+        .all(|value| value.to_f64().map(f64::is_finite).unwrap_or(false))
+}
+
+/// Visit the module parameters through [update_params](Module::update_params) without modifying
+/// them, checking that their unscaled gradients are finite.
+struct FiniteCheck<B> {
+    loss_scale: f64,
+    finite: bool,
+    _b: std::marker::PhantomData<B>,
+}
+
+impl<B> FiniteCheck<B> {
+    fn new(loss_scale: f64) -> Self {
+        Self {
+            loss_scale,
+            finite: true,
+            _b: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<B: ADBackend> Optimizer for FiniteCheck<B> {
+    type Backend = B;
+
+    fn update<const D: usize>(
+        &mut self,
+        _id: &ParamId,
+        tensor: &mut Tensor<B, D>,
+        grads: &Gradients,
+    ) {
+        if let Some(grad) = unscaled_grad(tensor, grads, self.loss_scale) {
+            self.finite = self.finite && is_finite(&grad);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lock_rng;
+    use crate::module::Forward;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::optim::decay::WeightDecayConfig;
+    use crate::optim::momentum::MomentumConfig;
+    use crate::optim::{Sgd, SgdConfig};
+    use crate::tensor::{Data, Distribution};
+    use crate::TestADBackend;
+
+    #[test]
+    fn loss_should_decrease_with_mixed_precision() {
+        let _rng = lock_rng();
+        let input = Tensor::<TestADBackend, 2>::random([8, 3], Distribution::Standard);
+        let mut linear = Linear::<TestADBackend>::new(&LinearConfig::new(3, 2));
+        let mut amp =
+            MixedPrecision::new(Sgd::new(&SgdConfig::new().with_learning_rate(0.1)), 1024.0);
+        let loss_initial = loss(&linear, input.clone()).into_data().value[0];
+
+        for _ in 0..10 {
+            let loss_scale = amp.loss_scale().unwrap();
+            let grads = loss(&linear, input.clone())
+                .mul_scalar(loss_scale)
+                .backward();
+            amp.update_module(&mut linear, &grads);
+        }
+
+        let loss_final = loss(&linear, input).into_data().value[0];
+        assert!(loss_final < loss_initial);
+        assert_eq!(amp.loss_scale(), Some(1024.0));
+    }
+
+    #[test]
+    fn should_update_like_the_optimizer_once_unscaled() {
+        let _rng = lock_rng();
+        let config = SgdConfig::new()
+            .with_learning_rate(0.5)
+            .with_weight_decay(Some(WeightDecayConfig::new(0.1)))
+            .with_momentum(Some(MomentumConfig::new()));
+        let input = Tensor::<TestADBackend, 2>::random([4, 3], Distribution::Standard);
+        let mut linear = Linear::<TestADBackend>::new(&LinearConfig::new(3, 2));
+        let mut linear_amp = Linear::<TestADBackend>::new(&LinearConfig::new(3, 2));
+        linear_amp.load(&linear.state()).unwrap();
+        let mut optim = Sgd::new(&config);
+        let mut amp = MixedPrecision::new(Sgd::new(&config), 8.0);
+
+        for _ in 0..3 {
+            let grads = loss(&linear, input.clone()).backward();
+            optim.update_module(&mut linear, &grads);
+            let grads = loss(&linear_amp, input.clone()).mul_scalar(8.0).backward();
+            amp.update_module(&mut linear_amp, &grads);
+        }
+
+        let output = linear.forward(input.clone()).into_data();
+        let output_amp = linear_amp.forward(input).into_data();
+        output.assert_within_tolerance(&output_amp, 1e-4);
+    }
+
+    #[test]
+    fn should_skip_the_step_and_back_off_on_inf_gradients() {
+        let _rng = lock_rng();
+        let input = Tensor::<TestADBackend, 2>::from_data(Data::from([[1.0, f32::INFINITY, 1.0]]));
+        let mut linear = Linear::<TestADBackend>::new(&LinearConfig::new(3, 2));
+        let state_before = linear.state();
+        let mut amp = MixedPrecision::new(Sgd::new(&SgdConfig::new()), 1024.0);
+
+        let grads = loss(&linear, input).mul_scalar(1024.0).backward();
+        amp.update_module(&mut linear, &grads);
+
+        assert_eq!(amp.loss_scale(), Some(512.0));
+        assert_eq!(linear.state(), state_before);
+    }
+
+    fn loss(
+        linear: &Linear<TestADBackend>,
+        input: Tensor<TestADBackend, 2>,
+    ) -> Tensor<TestADBackend, 1> {
+        linear.forward(input).powf(2.0).mean()
+    }
+}
@@ -3,8 +3,16 @@ pub mod momentum;
 
 mod adam;
 mod base;
+mod grad_accum;
+mod mixed_precision;
+mod rmsprop;
 mod sgd;
+#[cfg(test)]
+mod test_utils;
 
 pub use adam::*;
 pub use base::*;
+pub use grad_accum::*;
+pub use mixed_precision::*;
+pub use rmsprop::*;
 pub use sgd::*;
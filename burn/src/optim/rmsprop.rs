@@ -0,0 +1,211 @@
+use super::decay::{WeightDecay, WeightDecayConfig};
+use super::{load_state_gradients, register_state_gradients};
+use crate as burn;
+use crate::config::Config;
+use crate::module::{ParamId, StateNamed};
+use crate::optim::Optimizer;
+use crate::tensor::backend::ADBackend;
+use crate::tensor::{ElementConversion, Gradients, Tensor};
+
+/// Configuration to create the [RMSProp](RMSProp) optimizer.
+#[derive(Config)]
+pub struct RMSPropConfig {
+    /// Learning rate for the optimizer.
+    #[config(default = 0.01)]
+    pub learning_rate: f64,
+    /// Smoothing constant of the running average of the squared gradients.
+    #[config(default = 0.99)]
+    pub alpha: f64,
+    /// A value required for numerical stability.
+    #[config(default = 1e-8)]
+    pub epsilon: f64,
+    /// Momentum factor applied to the normalized gradients.
+    pub momentum: Option<f64>,
+    /// [Weight decay](WeightDecayConfig) config.
+    pub weight_decay: Option<WeightDecayConfig>,
+}
+
+/// Optimizer that implements RMSProp, dividing the gradients by the root of a running average of
+/// their squares.
+pub struct RMSProp<B: ADBackend> {
+    learning_rate: B::Elem,
+    alpha: f64,
+    epsilon: f64,
+    momentum: Option<f64>,
+    weight_decay: Option<WeightDecay<B>>,
+    square_avg: Gradients,
+    velocity: Gradients,
+}
+
+impl<B: ADBackend> RMSProp<B> {
+    pub fn new(config: &RMSPropConfig) -> Self {
+        Self {
+            learning_rate: config.learning_rate.to_elem(),
+            alpha: config.alpha,
+            epsilon: config.epsilon,
+            momentum: config.momentum,
+            weight_decay: config.weight_decay.as_ref().map(WeightDecay::new),
+            square_avg: Gradients::empty(),
+            velocity: Gradients::empty(),
+        }
+    }
+
+    fn square_avg_key(id: &str) -> String {
+        format!("rmsprop-square-avg-{}", id)
+    }
+
+    fn velocity_key(id: &str) -> String {
+        format!("rmsprop-velocity-{}", id)
+    }
+}
+
+impl<B: ADBackend> Optimizer for RMSProp<B> {
+    type Backend = B;
+
+    fn update<const D: usize>(
+        &mut self,
+        id: &ParamId,
+        tensor: &mut Tensor<B, D>,
+        grads: &Gradients,
+    ) {
+        if let Some(grad) = tensor.grad(grads) {
+            let id = id.to_string();
+            let grad = match &self.weight_decay {
+                Some(weight_decay) => weight_decay.transform(grad, &tensor.inner()),
+                None => grad,
+            };
+            let grad_squared = grad.powf(2.0).mul_scalar(1.0 - self.alpha);
+
+            let square_avg = match self.square_avg.get::<Tensor<B::InnerBackend, D>>(&id) {
+                Some(square_avg) => square_avg.mul_scalar(self.alpha).add(&grad_squared),
+                None => grad_squared,
+            };
+            self.square_avg.register_any(id.clone(), square_avg.clone());
+
+            let grad = grad.div(&square_avg.powf(0.5).add_scalar(self.epsilon));
+            let grad = match self.momentum {
+                Some(momentum) => {
+                    let velocity = match self.velocity.get::<Tensor<B::InnerBackend, D>>(&id) {
+                        Some(velocity) => velocity.mul_scalar(momentum).add(&grad),
+                        None => grad,
+                    };
+                    self.velocity.register_any(id, velocity.clone());
+                    velocity
+                }
+                None => grad,
+            };
+
+            let delta = grad.mul_scalar(self.learning_rate);
+            let mut inner = tensor.inner();
+            inner.sub_assign(&delta);
+            tensor.update(inner);
+        }
+    }
+
+    fn learning_rate(&self) -> Option<f64> {
+        Some(f64::from_elem(self.learning_rate))
+    }
+
+    fn register_param_state<const D: usize>(&self, id: &ParamId, state: &mut StateNamed<B::Elem>) {
+        register_state_gradients::<D, B, _>(id, state, &self.square_avg, Self::square_avg_key);
+        register_state_gradients::<D, B, _>(id, state, &self.velocity, Self::velocity_key);
+    }
+
+    fn load_param_state<const D: usize>(
+        &mut self,
+        id: &ParamId,
+        state: &StateNamed<B::Elem>,
+        device: &B::Device,
+    ) {
+        load_state_gradients::<D, B, _>(
+            id,
+            state,
+            &mut self.square_avg,
+            Self::square_avg_key,
+            device,
+        );
+        load_state_gradients::<D, B, _>(id, state, &mut self.velocity, Self::velocity_key, device);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lock_rng;
+    use crate::module::{Forward, Module};
+    use crate::nn::{Linear, LinearConfig};
+    use crate::optim::test_utils::{rmsprop_with_weight_decay, steps_without_loss_gradient};
+    use crate::tensor::{Data, Distribution};
+    use crate::TestADBackend;
+
+    #[test]
+    fn should_converge_on_a_convex_loss() {
+        let target = Tensor::<TestADBackend, 1>::from_data(Data::from([3.0, -2.0, 0.5]));
+        let mut tensor = Tensor::<TestADBackend, 1>::zeros([3]);
+        let mut optim = RMSProp::new(
+            &RMSPropConfig::new()
+                .with_learning_rate(0.02)
+                .with_momentum(Some(0.5)),
+        );
+        let id = ParamId::new();
+
+        for _ in 0..1000 {
+            let grads = tensor.sub(&target).powf(2.0).sum().backward();
+            optim.update(&id, &mut tensor, &grads);
+        }
+
+        tensor.into_data().assert_approx_eq(&target.into_data(), 1);
+    }
+
+    #[test]
+    fn should_load_the_squared_gradients_average() {
+        let _rng = lock_rng();
+        let mut layer = Linear::<TestADBackend>::new(&LinearConfig::new(4, 2));
+        let mut optim = RMSProp::new(&RMSPropConfig::new().with_momentum(Some(0.9)));
+        let input = Tensor::<TestADBackend, 2>::random([3, 4], Distribution::Standard);
+        let grads = layer.forward(input).backward();
+        layer.update_params(&grads, &mut optim);
+
+        let state = optim.state(&layer);
+        let mut optim_new = RMSProp::new(&RMSPropConfig::new().with_momentum(Some(0.9)));
+        let state_new = optim_new.state(&layer);
+        optim_new.load(&layer, &state).unwrap();
+        let state_restored = optim_new.state(&layer);
+
+        assert_ne!(state, state_new);
+        assert_eq!(state, state_restored);
+    }
+
+    #[test]
+    fn weight_decay_should_shrink_params_without_loss_gradient() {
+        let tensor = Tensor::<TestADBackend, 1>::from_data(Data::from([2.0, -1.0]));
+
+        let decayed = steps_without_loss_gradient(tensor.clone(), rmsprop_with_weight_decay(0.1));
+        let unchanged = steps_without_loss_gradient(tensor.clone(), rmsprop_with_weight_decay(0.0));
+
+        let decayed = decayed.into_data().value;
+        assert!(decayed[0] > 0.0 && decayed[0] < 2.0);
+        assert!(decayed[1] < 0.0 && decayed[1] > -1.0);
+        assert_eq!(unchanged.into_data(), tensor.into_data());
+    }
+
+    #[test]
+    fn without_momentum_should_only_have_the_squared_gradients_average() {
+        let _rng = lock_rng();
+        let mut tensor = Tensor::<TestADBackend, 2>::random([2, 3], Distribution::Standard);
+        let mut optim = RMSProp::new(&RMSPropConfig::new());
+        let id = ParamId::new();
+        let grads = tensor.powf(2.0).sum().backward();
+
+        optim.update(&id, &mut tensor, &grads);
+
+        let mut state = StateNamed::new();
+        optim.register_param_state::<2>(&id, &mut state);
+        assert!(state
+            .get(&RMSProp::<TestADBackend>::square_avg_key(&id.to_string()))
+            .is_some());
+        assert!(state
+            .get(&RMSProp::<TestADBackend>::velocity_key(&id.to_string()))
+            .is_none());
+    }
+}
@@ -55,8 +55,8 @@ impl<B: ADBackend> Optimizer for Sgd<B> {
         grads: &Gradients,
     ) {
         if let Some(grad) = tensor.grad(grads) {
-            let grad = match &mut self.weight_decay {
-                Some(weight_decay) => weight_decay.transform(id, grad),
+            let grad = match &self.weight_decay {
+                Some(weight_decay) => weight_decay.transform(grad, &tensor.inner()),
                 None => grad,
             };
             let grad = match &mut self.momentum {
@@ -64,19 +64,19 @@ impl<B: ADBackend> Optimizer for Sgd<B> {
                 None => grad,
             };
 
-            let delta = grad.mul_scalar(self.learning_rate);
-            tensor.update(tensor.inner() - delta);
+            let delta = Tensor::from_inner(grad.mul_scalar(self.learning_rate));
+            tensor.sub_assign(&delta);
         }
     }
 
+    fn learning_rate(&self) -> Option<f64> {
+        Some(f64::from_elem(self.learning_rate))
+    }
+
     fn register_param_state<const D: usize>(&self, id: &ParamId, state: &mut StateNamed<B::Elem>) {
         if let Some(momentum) = &self.momentum {
             momentum.register_state::<D>(id, state);
         }
-
-        if let Some(weight_decay) = &self.weight_decay {
-            weight_decay.register_state::<D>(id, state);
-        }
     }
 
     fn load_param_state<const D: usize>(
@@ -88,25 +88,24 @@ impl<B: ADBackend> Optimizer for Sgd<B> {
         if let Some(momentum) = &mut self.momentum {
             momentum.load_state::<D>(id, state, device);
         }
-
-        if let Some(weight_decay) = &mut self.weight_decay {
-            weight_decay.load_state::<D>(id, state, device);
-        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::lock_rng;
+    use crate::optim::test_utils::{sgd_with_weight_decay, steps_without_loss_gradient};
     use crate::{
         module::{Forward, Module},
         nn::{Linear, LinearConfig},
-        tensor::{Distribution, Shape},
+        tensor::{backend::Backend, Data, Distribution, Shape},
         TestADBackend,
     };
 
     #[test]
     fn with_updated_params_should_have_state() {
+        let _rng = lock_rng();
         let mut layer = layer();
         let mut optim = sgd_with_all();
         let loss = layer.forward(random_tensor());
@@ -120,6 +119,7 @@ mod tests {
 
     #[test]
     fn without_updated_params_should_not_have_state() {
+        let _rng = lock_rng();
         let layer = layer();
         let optim = sgd_with_all();
 
@@ -130,6 +130,7 @@ mod tests {
 
     #[test]
     fn without_momentum_and_weights_decay_should_not_have_state() {
+        let _rng = lock_rng();
         let mut layer = layer();
         let mut optim = sgd_with_nothing();
         let loss = layer.forward(random_tensor());
@@ -143,6 +144,7 @@ mod tests {
 
     #[test]
     fn should_load_state() {
+        let _rng = lock_rng();
         let mut layer = layer();
         let mut optim = sgd_with_all();
         let loss = layer.forward(random_tensor());
@@ -159,6 +161,68 @@ mod tests {
         assert_eq!(state, state_restored);
     }
 
+    #[test]
+    fn should_update_params_in_place() {
+        let mut tensor = Tensor::<TestADBackend, 1>::from_data(Data::from([2.0, -1.0]));
+        let mut optim = sgd_with_nothing();
+        let grads = tensor.mul(&tensor).sum().backward();
+
+        optim.update(&ParamId::new(), &mut tensor, &grads);
+
+        // The gradient is 2x, so x - 0.02 * 2x.
+        tensor
+            .into_data()
+            .assert_approx_eq(&Data::from([2.0 * 0.96, -0.96]), 3);
+    }
+
+    #[test]
+    fn weight_decay_should_shrink_params_without_loss_gradient() {
+        let tensor = Tensor::<TestADBackend, 1>::from_data(Data::from([2.0, -1.0]));
+
+        let decayed = steps_without_loss_gradient(tensor.clone(), sgd_with_weight_decay(0.1));
+        let unchanged = steps_without_loss_gradient(tensor.clone(), sgd_with_weight_decay(0.0));
+
+        decayed
+            .into_data()
+            .assert_approx_eq(&Data::from([2.0 * 0.99f32.powi(5), -0.99f32.powi(5)]), 3);
+        assert_eq!(unchanged.into_data(), tensor.into_data());
+    }
+
+    #[test]
+    fn should_report_its_learning_rate() {
+        let optim = sgd_with_nothing();
+
+        assert_eq!(optim.learning_rate(), Some(0.02f32 as f64));
+    }
+
+    #[test]
+    fn training_twice_with_the_same_seed_in_deterministic_mode_should_give_identical_params() {
+        let _rng = lock_rng();
+        TestADBackend::set_deterministic(true);
+
+        let output_1 = train_with_seed(42);
+        let output_2 = train_with_seed(42);
+
+        TestADBackend::set_deterministic(false);
+        assert_eq!(output_1, output_2);
+    }
+
+    /// Train a layer with the given seed, returning its output for a constant input, since the
+    /// parameter ids of its state aren't seeded.
+    fn train_with_seed(seed: u64) -> Data<f32, 2> {
+        TestADBackend::seed(seed);
+        let mut layer = layer();
+        let mut optim = sgd_with_all();
+
+        for _ in 0..5 {
+            let loss = layer.forward(random_tensor()).powf(2.0).mean();
+            let grads = loss.backward();
+            layer.update_params(&grads, &mut optim);
+        }
+
+        layer.forward(Tensor::ones([2, 20])).into_data()
+    }
+
     fn random_tensor() -> Tensor<TestADBackend, 2> {
         Tensor::<TestADBackend, 2>::random(Shape::new([2, 20]), Distribution::Standard)
     }
@@ -168,6 +232,7 @@ mod tests {
             d_input: 20,
             d_output: 20,
             bias: true,
+            initializer: None,
         })
     }
 
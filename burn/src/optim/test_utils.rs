@@ -0,0 +1,37 @@
+use super::decay::WeightDecayConfig;
+use super::{Optimizer, RMSProp, RMSPropConfig, Sgd, SgdConfig};
+use crate::module::ParamId;
+use crate::tensor::Tensor;
+use crate::TestADBackend;
+
+/// Apply a few steps of the optimizer to the tensor with a loss that doesn't depend on it, so
+/// that only the weight decay changes its value.
+pub fn steps_without_loss_gradient<O: Optimizer<Backend = TestADBackend>>(
+    mut tensor: Tensor<TestADBackend, 1>,
+    mut optim: O,
+) -> Tensor<TestADBackend, 1> {
+    let id = ParamId::new();
+
+    for _ in 0..5 {
+        let grads = tensor.mul_scalar(0.0).sum().backward();
+        optim.update(&id, &mut tensor, &grads);
+    }
+
+    tensor
+}
+
+pub fn sgd_with_weight_decay(penalty: f64) -> Sgd<TestADBackend> {
+    Sgd::new(&SgdConfig {
+        learning_rate: 0.1,
+        weight_decay: Some(WeightDecayConfig { penalty }),
+        momentum: None,
+    })
+}
+
+pub fn rmsprop_with_weight_decay(penalty: f64) -> RMSProp<TestADBackend> {
+    RMSProp::new(
+        &RMSPropConfig::new()
+            .with_learning_rate(0.01)
+            .with_weight_decay(Some(WeightDecayConfig::new(penalty))),
+    )
+}
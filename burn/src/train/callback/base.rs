@@ -14,4 +14,6 @@ pub struct LearnerItem<T> {
     pub epoch: usize,
     pub epoch_total: usize,
     pub iteration: usize,
+    /// The learning rate of the optimizer, only known for training items.
+    pub lr: Option<f64>,
 }
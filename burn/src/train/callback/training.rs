@@ -0,0 +1,18 @@
+use super::LearnerItem;
+
+/// Hooks called by the [learner](crate::train::Learner) at different points of the training loop,
+/// registered with [callback](crate::train::LearnerBuilder::callback).
+///
+/// Unlike the [learner callback](super::LearnerCallback) feeding the dashboard, the hooks are
+/// called synchronously in the training loop, so they should be fast to not slow down the
+/// training. They can be used for custom logging, profiling or conditional checkpointing.
+pub trait TrainingCallback<T>: Send {
+    /// Called before the training iterations of an epoch.
+    fn on_epoch_start(&mut self, _epoch: usize) {}
+    /// Called after each training iteration with its output.
+    fn on_batch_end(&mut self, _item: &LearnerItem<T>) {}
+    /// Called at the end of an epoch, after the validation and the checkpoint.
+    fn on_epoch_end(&mut self, _epoch: usize) {}
+    /// Called once when the training ends, including when it was stopped early.
+    fn on_training_end(&mut self) {}
+}
@@ -5,6 +5,7 @@ use std::sync::{mpsc, Arc};
 
 enum Message<E> {
     Save(usize, State<E>),
+    Flush(mpsc::Sender<Result<(), CheckpointerError>>),
     End,
 }
 
@@ -16,9 +17,23 @@ struct CheckpointerThread<T> {
 
 impl<T> CheckpointerThread<T> {
     fn run(self) {
+        let mut error = None;
+
         for item in self.receiver.iter() {
             match item {
-                Message::Save(epoch, state) => self.checkpointer.save(epoch, state).unwrap(),
+                Message::Save(epoch, state) => {
+                    if let Err(err) = self.checkpointer.save(epoch, state) {
+                        // Only keep the first error, the next ones are probably caused by it.
+                        error.get_or_insert(err);
+                    }
+                }
+                Message::Flush(callback) => {
+                    let result = match error.take() {
+                        Some(err) => Err(err),
+                        None => Ok(()),
+                    };
+                    callback.send(result).ok();
+                }
                 Message::End => {
                     return;
                 }
@@ -27,6 +42,11 @@ impl<T> CheckpointerThread<T> {
     }
 }
 
+/// Checkpointer saving the states in a background thread.
+///
+/// Errors happening during a save are returned by the next call to [flush](Checkpointer::flush).
+/// When the background thread has stopped, e.g. because a save panicked, the calls to
+/// [save](Checkpointer::save) and [flush](Checkpointer::flush) fail.
 pub struct AsyncCheckpointer<E> {
     checkpointer: Arc<dyn Checkpointer<E> + Send + Sync>,
     sender: mpsc::SyncSender<Message<E>>,
@@ -53,23 +73,108 @@ where
     E: Element + Sync + 'static,
 {
     fn save(&self, epoch: usize, state: State<E>) -> Result<(), CheckpointerError> {
-        self.sender.send(Message::Save(epoch, state)).unwrap();
-
-        Ok(())
+        self.sender
+            .send(Message::Save(epoch, state))
+            .map_err(|_| thread_stopped())
     }
 
     fn restore(&self, epoch: usize) -> Result<State<E>, CheckpointerError> {
         self.checkpointer.restore(epoch)
     }
+
+    fn flush(&self) -> Result<(), CheckpointerError> {
+        let (sender, receiver) = mpsc::channel();
+        self.sender
+            .send(Message::Flush(sender))
+            .map_err(|_| thread_stopped())?;
+
+        receiver.recv().map_err(|_| thread_stopped())?
+    }
+}
+
+fn thread_stopped() -> CheckpointerError {
+    CheckpointerError::IOError(std::io::Error::other("The checkpointer thread has stopped"))
 }
 
 impl<E> Drop for AsyncCheckpointer<E> {
     fn drop(&mut self) {
-        self.sender.send(Message::End).unwrap();
+        // The thread may have already stopped, its failure being reported by the last flush.
+        self.sender.send(Message::End).ok();
         let handler = std::mem::replace(&mut self.handler, None);
 
         if let Some(handler) = handler {
-            handler.join().unwrap();
+            handler.join().ok();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::DataSerialize;
+    use crate::train::checkpoint::FileCheckpointer;
+
+    fn state(value: Vec<f32>) -> State<f32> {
+        let shape = vec![value.len()];
+        State::Data(DataSerialize { value, shape })
+    }
+
+    #[test]
+    fn failing_save_should_be_returned_by_flush() {
+        let file = std::env::temp_dir().join(format!(
+            "burn-async-checkpoint-not-a-directory-{}",
+            std::process::id()
+        ));
+        std::fs::write(&file, "").unwrap();
+        let directory = file.join("checkpoint");
+        let checkpointer = FileCheckpointer::<f32>::new(directory.to_str().unwrap(), "model", 2);
+        let checkpointer = AsyncCheckpointer::<f32>::new(Arc::new(checkpointer));
+
+        checkpointer.save(1, state(vec![1.0])).unwrap();
+
+        assert!(matches!(
+            checkpointer.flush(),
+            Err(CheckpointerError::IOError(_))
+        ));
+        assert!(checkpointer.flush().is_ok());
+    }
+
+    #[test]
+    fn flush_should_wait_for_pending_saves() {
+        let directory = std::env::temp_dir().join(format!(
+            "burn-async-checkpoint-flush-{}",
+            std::process::id()
+        ));
+        let checkpointer = FileCheckpointer::<f32>::new(directory.to_str().unwrap(), "model", 2);
+        let checkpointer = AsyncCheckpointer::<f32>::new(Arc::new(checkpointer));
+        checkpointer.save(3, state(vec![1.0, 2.0])).unwrap();
+        checkpointer.flush().unwrap();
+
+        assert!(directory.join("model-3.json.gz").exists());
+    }
+
+    struct PanickingCheckpointer;
+
+    impl Checkpointer<f32> for PanickingCheckpointer {
+        fn save(&self, _epoch: usize, _state: State<f32>) -> Result<(), CheckpointerError> {
+            panic!("Failure in the checkpointer");
+        }
+
+        fn restore(&self, _epoch: usize) -> Result<State<f32>, CheckpointerError> {
+            panic!("Failure in the checkpointer");
         }
     }
+
+    #[test]
+    fn flush_should_fail_when_the_thread_has_stopped() {
+        let checkpointer = AsyncCheckpointer::<f32>::new(Arc::new(PanickingCheckpointer));
+
+        checkpointer.save(1, state(vec![1.0])).unwrap();
+
+        assert!(matches!(
+            checkpointer.flush(),
+            Err(CheckpointerError::IOError(_))
+        ));
+        assert!(checkpointer.save(2, state(vec![1.0])).is_err());
+    }
 }
@@ -4,9 +4,16 @@ use crate::module::{State, StateError};
 pub enum CheckpointerError {
     IOError(std::io::Error),
     StateError(StateError),
+    /// The checkpoint file doesn't match its checksum.
+    InvalidChecksum(String),
 }
 
 pub trait Checkpointer<E> {
     fn save(&self, epoch: usize, state: State<E>) -> Result<(), CheckpointerError>;
     fn restore(&self, epoch: usize) -> Result<State<E>, CheckpointerError>;
+    /// Wait until all pending checkpoints are saved, returning the first error that happened
+    /// since the last flush.
+    fn flush(&self) -> Result<(), CheckpointerError> {
+        Ok(())
+    }
 }
@@ -1,11 +1,19 @@
 use super::{Checkpointer, CheckpointerError};
-use crate::module::State;
+use crate::module::{State, StateFormat};
 use burn_tensor::Element;
 
+/// Checkpointer saving the states in compressed files.
+///
+/// A CRC32 checksum is written alongside each checkpoint, and a checkpoint that doesn't match its
+/// checksum when restored is reported as [invalid](CheckpointerError::InvalidChecksum). The
+/// [learner](crate::train::Learner) then resumes from the previous epoch, restoring the model,
+/// optimizer and rng checkpoints of that same epoch. Checkpoints saved without a checksum are
+/// restored unverified.
 pub struct FileCheckpointer<P> {
     directory: String,
     name: String,
     num_keep: usize,
+    format: StateFormat,
     _precision: P,
 }
 
@@ -17,14 +25,54 @@ impl<P: Element> FileCheckpointer<P> {
             directory: directory.to_string(),
             name: name.to_string(),
             num_keep,
+            format: StateFormat::Json,
             _precision: P::default(),
         }
     }
+
+    /// Set the [format](StateFormat) of the checkpoints, JSON by default.
+    ///
+    /// The checkpoints of a checkpointer must all be saved in the same format, since its file
+    /// extension is used to find them.
+    pub fn with_format(mut self, format: StateFormat) -> Self {
+        self.format = format;
+        self
+    }
+
     fn path_for_epoch(&self, epoch: usize) -> String {
-        format!("{}/{}-{}.json.gz", self.directory, self.name, epoch)
+        format!(
+            "{}/{}-{}.{}",
+            self.directory,
+            self.name,
+            epoch,
+            self.format.extension()
+        )
+    }
+    fn path_checksum(file_path: &str) -> String {
+        format!("{}.crc32", file_path)
+    }
+    fn verify_checksum(file_path: &str) -> Result<(), CheckpointerError> {
+        let checksum = match std::fs::read_to_string(Self::path_checksum(file_path)) {
+            Ok(checksum) => checksum,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(CheckpointerError::IOError(err)),
+        };
+        let content = std::fs::read(file_path).map_err(CheckpointerError::IOError)?;
+
+        if checksum.trim() != format!("{:08x}", crc32(&content)) {
+            return Err(CheckpointerError::InvalidChecksum(file_path.to_string()));
+        }
+
+        Ok(())
     }
 }
 
+fn crc32(content: &[u8]) -> u32 {
+    let mut crc = flate2::Crc::new();
+    crc.update(content);
+    crc.sum()
+}
+
 impl<E, P> Checkpointer<E> for FileCheckpointer<P>
 where
     P: serde::Serialize + serde::de::DeserializeOwned + Element,
@@ -34,14 +82,28 @@ where
         let file_path = self.path_for_epoch(epoch);
         state
             .convert::<P>()
-            .save(&file_path)
+            .save_format(&file_path, self.format)
             .map_err(CheckpointerError::IOError)?;
 
+        let content = std::fs::read(&file_path).map_err(CheckpointerError::IOError)?;
+        std::fs::write(
+            Self::path_checksum(&file_path),
+            format!("{:08x}", crc32(&content)),
+        )
+        .map_err(CheckpointerError::IOError)?;
+
         // Keep two versions because all checkpoints are not synced.
-        let file_path_old_checkpoint = self.path_for_epoch(epoch - self.num_keep);
+        if let Some(epoch_old) = epoch.checked_sub(self.num_keep) {
+            let file_path_old_checkpoint = self.path_for_epoch(epoch_old);
 
-        if std::path::Path::new(&file_path_old_checkpoint).exists() {
-            std::fs::remove_file(file_path_old_checkpoint).map_err(CheckpointerError::IOError)?;
+            for file_path in [
+                Self::path_checksum(&file_path_old_checkpoint),
+                file_path_old_checkpoint,
+            ] {
+                if std::path::Path::new(&file_path).exists() {
+                    std::fs::remove_file(file_path).map_err(CheckpointerError::IOError)?;
+                }
+            }
         }
 
         Ok(())
@@ -50,8 +112,132 @@ where
     fn restore(&self, epoch: usize) -> Result<State<E>, CheckpointerError> {
         let file_path = self.path_for_epoch(epoch);
 
+        Self::verify_checksum(&file_path)?;
         let state = State::<P>::load(&file_path).map_err(CheckpointerError::StateError)?;
 
         Ok(state.convert())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lock_rng;
+    use crate::module::Module;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::tensor::f16;
+    use crate::TestBackend;
+
+    #[test]
+    fn model_saved_in_half_precision_should_match_within_tolerance() {
+        let _rng = lock_rng();
+        let directory =
+            std::env::temp_dir().join(format!("burn-fp16-checkpoint-test-{}", std::process::id()));
+        let checkpointer = FileCheckpointer::<f16>::new(directory.to_str().unwrap(), "model", 2);
+        let model = Linear::<TestBackend>::new(&LinearConfig::new(32, 32));
+        let mut model_loaded = Linear::<TestBackend>::new(&LinearConfig::new(32, 32));
+
+        checkpointer.save(1, model.state()).unwrap();
+        let state: State<f32> = checkpointer.restore(1).unwrap();
+        model_loaded.load(&state).unwrap();
+
+        let expected = model.state();
+        let actual = model_loaded.state();
+        let weights =
+            |state: &State<f32>| match state.get("weight").and_then(|state| state.get("data")) {
+                Some(State::Data(data)) => data.value.clone(),
+                _ => panic!("Weight should be saved as data"),
+            };
+        assert_ne!(weights(&expected), weights(&actual));
+        for (a, b) in weights(&expected).iter().zip(weights(&actual).iter()) {
+            // f16 has 11 bits of precision, but the subnormal numbers have a fixed spacing of
+            // 2^-24, so their rounding error is absolute.
+            let tolerance = f32::max(f32::abs(*a) / 2048.0, 2f32.powi(-25));
+            assert!(f32::abs(a - b) <= tolerance, "{} != {}", a, b);
+        }
+    }
+
+    #[test]
+    fn full_precision_checkpointer_should_be_lossless() {
+        let _rng = lock_rng();
+        let directory =
+            std::env::temp_dir().join(format!("burn-fp32-checkpoint-test-{}", std::process::id()));
+        let checkpointer = FileCheckpointer::<f32>::new(directory.to_str().unwrap(), "model", 2);
+        let model = Linear::<TestBackend>::new(&LinearConfig::new(32, 32));
+
+        checkpointer.save(1, model.state()).unwrap();
+        let state: State<f32> = checkpointer.restore(1).unwrap();
+
+        assert_eq!(state, model.state());
+    }
+
+    #[test]
+    fn json_checkpoint_should_round_trip() {
+        let _rng = lock_rng();
+        round_trip_with_format(StateFormat::Json, "burn-json-checkpoint-test");
+    }
+
+    #[test]
+    fn message_pack_checkpoint_should_round_trip() {
+        let _rng = lock_rng();
+        round_trip_with_format(StateFormat::MessagePack, "burn-mpk-checkpoint-test");
+    }
+
+    fn round_trip_with_format(format: StateFormat, directory: &str) {
+        let directory = std::env::temp_dir().join(format!("{}-{}", directory, std::process::id()));
+        let checkpointer = FileCheckpointer::<f32>::new(directory.to_str().unwrap(), "model", 2)
+            .with_format(format);
+        let model = Linear::<TestBackend>::new(&LinearConfig::new(8, 8));
+        let mut model_loaded = Linear::<TestBackend>::new(&LinearConfig::new(8, 8));
+
+        checkpointer.save(1, model.state()).unwrap();
+        let state: State<f32> = checkpointer.restore(1).unwrap();
+        model_loaded.load(&state).unwrap();
+
+        assert!(directory
+            .join(format!("model-1.{}", format.extension()))
+            .exists());
+        assert_eq!(model_loaded.state(), model.state());
+    }
+
+    #[test]
+    fn corrupted_checkpoint_should_fail_without_falling_back_to_the_previous_one() {
+        let _rng = lock_rng();
+        let directory = std::env::temp_dir().join(format!(
+            "burn-corrupted-checkpoint-test-{}",
+            std::process::id()
+        ));
+        let checkpointer = FileCheckpointer::<f32>::new(directory.to_str().unwrap(), "model", 2);
+        let model_1 = Linear::<TestBackend>::new(&LinearConfig::new(4, 4));
+        let model_2 = Linear::<TestBackend>::new(&LinearConfig::new(4, 4));
+        checkpointer.save(1, model_1.state()).unwrap();
+        checkpointer.save(2, model_2.state()).unwrap();
+
+        let file_path = checkpointer.path_for_epoch(2);
+        let mut content = std::fs::read(&file_path).unwrap();
+        let index = content.len() / 2;
+        content[index] = !content[index];
+        std::fs::write(&file_path, content).unwrap();
+        let result: Result<State<f32>, _> = checkpointer.restore(2);
+
+        assert!(matches!(result, Err(CheckpointerError::InvalidChecksum(_))));
+    }
+
+    #[test]
+    fn checkpoint_without_checksum_should_be_restored() {
+        let _rng = lock_rng();
+        let directory = std::env::temp_dir().join(format!(
+            "burn-checkpoint-without-checksum-test-{}",
+            std::process::id()
+        ));
+        let checkpointer = FileCheckpointer::<f32>::new(directory.to_str().unwrap(), "model", 2);
+        let model = Linear::<TestBackend>::new(&LinearConfig::new(4, 4));
+        checkpointer.save(1, model.state()).unwrap();
+
+        let file_path = checkpointer.path_for_epoch(1);
+        std::fs::remove_file(FileCheckpointer::<f32>::path_checksum(&file_path)).unwrap();
+        let state: State<f32> = checkpointer.restore(1).unwrap();
+
+        assert_eq!(state, model.state());
+    }
+}
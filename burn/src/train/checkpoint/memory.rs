@@ -0,0 +1,83 @@
+use super::{Checkpointer, CheckpointerError};
+use crate::module::State;
+use burn_tensor::Element;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Checkpointer keeping the states in memory, mostly useful for testing.
+pub struct InMemoryCheckpointer<E> {
+    states: Mutex<HashMap<usize, State<E>>>,
+    num_keep: usize,
+}
+
+impl<E: Element> InMemoryCheckpointer<E> {
+    pub fn new(num_keep: usize) -> Self {
+        Self {
+            states: Mutex::new(HashMap::new()),
+            num_keep,
+        }
+    }
+}
+
+impl<E: Element> Checkpointer<E> for InMemoryCheckpointer<E> {
+    fn save(&self, epoch: usize, state: State<E>) -> Result<(), CheckpointerError> {
+        let mut states = self.states.lock().unwrap();
+        states.insert(epoch, state);
+
+        if let Some(epoch_old) = epoch.checked_sub(self.num_keep) {
+            states.remove(&epoch_old);
+        }
+
+        Ok(())
+    }
+
+    fn restore(&self, epoch: usize) -> Result<State<E>, CheckpointerError> {
+        let states = self.states.lock().unwrap();
+
+        match states.get(&epoch) {
+            Some(state) => Ok(state.clone()),
+            None => Err(CheckpointerError::IOError(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("No checkpoint saved for epoch {}", epoch),
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lock_rng;
+    use crate::module::Module;
+    use crate::nn::{Linear, LinearConfig};
+    use crate::TestBackend;
+
+    #[test]
+    fn should_checkpoint_and_restore_model() {
+        let _rng = lock_rng();
+        let checkpointer = InMemoryCheckpointer::<f32>::new(2);
+        let model = Linear::<TestBackend>::new(&LinearConfig::new(4, 4));
+        let mut model_restored = Linear::<TestBackend>::new(&LinearConfig::new(4, 4));
+
+        checkpointer.save(1, model.state()).unwrap();
+        let state = checkpointer.restore(1).unwrap();
+        model_restored.load(&state).unwrap();
+
+        assert_eq!(model_restored.state(), model.state());
+    }
+
+    #[test]
+    fn should_only_keep_the_last_checkpoints() {
+        let _rng = lock_rng();
+        let checkpointer = InMemoryCheckpointer::<f32>::new(2);
+        let model = Linear::<TestBackend>::new(&LinearConfig::new(4, 4));
+
+        for epoch in 1..4 {
+            checkpointer.save(epoch, model.state()).unwrap();
+        }
+
+        assert!(checkpointer.restore(1).is_err());
+        assert!(checkpointer.restore(2).is_ok());
+        assert!(checkpointer.restore(3).is_ok());
+    }
+}
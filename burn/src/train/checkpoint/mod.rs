@@ -1,7 +1,11 @@
 mod async_checkpoint;
 mod base;
 mod file;
+mod memory;
+mod rng;
 
 pub use async_checkpoint::*;
 pub use base::*;
 pub use file::*;
+pub use memory::*;
+pub use rng::*;
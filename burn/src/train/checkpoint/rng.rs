@@ -0,0 +1,136 @@
+use crate::module::{State, StateError};
+use crate::tensor::backend::Backend;
+use crate::tensor::{DataSerialize, Element};
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// The generator is saved as its 32 bytes seed, 8 bytes stream and 16 bytes position.
+const NUM_BYTES: usize = 32 + 8 + 16;
+const NUM_NIBBLES: usize = 2 * NUM_BYTES;
+
+/// State of the random number generator used during training.
+///
+/// The backend is reseeded at the beginning of each epoch with the next value of the generator,
+/// so restoring this state from a checkpoint makes the resumed training produce the same random
+/// tensors and dropout masks as an uninterrupted one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RngState {
+    rng: ChaCha8Rng,
+}
+
+impl RngState {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: ChaCha8Rng::seed_from_u64(seed),
+        }
+    }
+
+    /// Seed the backend for the next epoch.
+    pub fn reseed<B: Backend>(&mut self) {
+        B::seed(self.rng.next_u64());
+    }
+
+    /// Convert the generator into a [state](State).
+    ///
+    /// The bytes of the generator are split into 4 bits values so that they are exactly
+    /// represented by any element type.
+    pub fn to_state<E: Element>(&self) -> State<E> {
+        let mut bytes = Vec::with_capacity(NUM_BYTES);
+        bytes.extend(self.rng.get_seed());
+        bytes.extend(self.rng.get_stream().to_le_bytes());
+        bytes.extend(self.rng.get_word_pos().to_le_bytes());
+
+        let value = bytes
+            .iter()
+            .flat_map(|byte| [byte & 0xf, byte >> 4])
+            .map(E::from_elem)
+            .collect();
+
+        State::Data(DataSerialize {
+            value,
+            shape: vec![NUM_NIBBLES],
+        })
+    }
+
+    /// Load the generator from a [state](State) created with [to_state](Self::to_state).
+    pub fn from_state<E: Element>(state: &State<E>) -> Result<Self, StateError> {
+        let invalid = || StateError::InvalidFormat("Invalid rng state".to_string());
+        let data = match state {
+            State::Data(data) if data.value.len() == NUM_NIBBLES => data,
+            _ => return Err(invalid()),
+        };
+
+        let nibbles = data
+            .value
+            .iter()
+            .map(|nibble| match nibble.to_f64() {
+                Some(nibble) if (0.0..16.0).contains(&nibble) && nibble.fract() == 0.0 => {
+                    Ok(nibble as u8)
+                }
+                _ => Err(invalid()),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let bytes: Vec<u8> = nibbles
+            .chunks(2)
+            .map(|nibbles| nibbles[0] | (nibbles[1] << 4))
+            .collect();
+
+        let mut rng = ChaCha8Rng::from_seed(bytes[0..32].try_into().unwrap());
+        rng.set_stream(u64::from_le_bytes(bytes[32..40].try_into().unwrap()));
+        rng.set_word_pos(u128::from_le_bytes(bytes[40..56].try_into().unwrap()));
+
+        Ok(Self { rng })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lock_rng;
+    use crate::tensor::{f16, Distribution, Tensor};
+    use crate::train::checkpoint::{Checkpointer, FileCheckpointer};
+    use crate::TestBackend;
+
+    #[test]
+    fn test_rng_state_should_survive_half_precision() {
+        let _rng = lock_rng();
+        let mut rng = RngState::new(0xdead_beef_0123_4567);
+        rng.reseed::<TestBackend>();
+
+        let state = rng.to_state::<f32>().convert::<f16>();
+
+        assert_eq!(RngState::from_state(&state).unwrap(), rng);
+    }
+
+    #[test]
+    fn test_resumed_run_should_be_identical_to_uninterrupted_run() {
+        let _rng = lock_rng();
+        let directory =
+            std::env::temp_dir().join(format!("burn-rng-checkpoint-test-{}", std::process::id()));
+        let checkpointer = FileCheckpointer::<f32>::new(directory.to_str().unwrap(), "rng", 2);
+        let num_epochs = 4;
+        let checkpoint = 2;
+        let run_epoch = |rng: &mut RngState| {
+            rng.reseed::<TestBackend>();
+            Tensor::<TestBackend, 2>::random([8, 8], Distribution::Standard).into_data()
+        };
+
+        let mut rng = RngState::new(42);
+        let mut uninterrupted = Vec::new();
+        for epoch in 1..num_epochs + 1 {
+            uninterrupted.push(run_epoch(&mut rng));
+            if epoch == checkpoint {
+                checkpointer.save(epoch, rng.to_state::<f32>()).unwrap();
+            }
+        }
+
+        let state: State<f32> = checkpointer.restore(checkpoint).unwrap();
+        let mut rng = RngState::from_state(&state).unwrap();
+        let mut resumed = Vec::new();
+        for _ in checkpoint + 1..num_epochs + 1 {
+            resumed.push(run_epoch(&mut rng));
+        }
+
+        assert_eq!(uninterrupted[checkpoint..], resumed);
+    }
+}
@@ -0,0 +1,211 @@
+use crate::data::dataloader::DataLoader;
+use crate::tensor::no_grad;
+use crate::train::metric::{Metric, MetricMetadata, Numeric};
+use crate::train::ValidStep;
+use std::sync::Arc;
+
+/// Evaluate a model on a dataset without training it, e.g. to report the metrics of a trained
+/// model on a test set.
+///
+/// The result of each metric is the mean of its values over all iterations weighted by their
+/// [number of samples](Numeric::count), like the validation metric monitored by
+/// [early stopping](crate::train::LearnerBuilder::early_stopping).
+pub struct Evaluator<O> {
+    metrics: Vec<Box<dyn EvaluatorMetric<O>>>,
+}
+
+/// The results of an [evaluation](Evaluator::evaluate), in the registration order of the metrics.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricResults {
+    pub results: Vec<MetricResult>,
+}
+
+#[derive(new, Debug, Clone, PartialEq)]
+pub struct MetricResult {
+    pub name: String,
+    pub value: f64,
+}
+
+impl MetricResults {
+    /// The result of the metric with the given name.
+    pub fn get(&self, name: &str) -> Option<f64> {
+        self.results
+            .iter()
+            .find(|result| result.name == name)
+            .map(|result| result.value)
+    }
+}
+
+impl<O> Evaluator<O> {
+    pub fn new() -> Self {
+        Self {
+            metrics: Vec::new(),
+        }
+    }
+
+    /// Register a metric to compute during the evaluation.
+    pub fn metric<M: Metric<O> + Numeric + 'static>(mut self, metric: M) -> Self {
+        self.metrics.push(Box::new(metric));
+        self
+    }
+
+    /// Run the model on every item of the dataloader and compute the registered metrics.
+    ///
+    /// The model is run in a [no_grad](crate::tensor::no_grad) scope, so no gradients are
+    /// tracked even on a backend with autodiff. When the dataloader is empty, the results are NaN.
+    pub fn evaluate<M, I>(&mut self, model: &M, dataloader: Arc<dyn DataLoader<I>>) -> MetricResults
+    where
+        M: ValidStep<I, O>,
+    {
+        let mut sums = vec![0.0; self.metrics.len()];
+        let mut counts = vec![0; self.metrics.len()];
+        let mut iterator = dataloader.iter();
+        let mut iteration = 0;
+
+        while let Some(item) = iterator.next() {
+            let progress = iterator.progress();
+            iteration += 1;
+
+            let item = no_grad(|| model.step(item));
+            let metadata = MetricMetadata::new(progress, 1, 1, iteration, None);
+
+            for ((metric, sum), count) in self
+                .metrics
+                .iter_mut()
+                .zip(sums.iter_mut())
+                .zip(counts.iter_mut())
+            {
+                metric.update(&item, &metadata);
+                *sum += metric.value() * metric.count() as f64;
+                *count += metric.count();
+            }
+        }
+
+        let results = self
+            .metrics
+            .iter_mut()
+            .zip(sums.into_iter().zip(counts))
+            .map(|(metric, (sum, count))| {
+                metric.clear();
+                MetricResult::new(metric.name(), sum / count as f64)
+            })
+            .collect();
+
+        MetricResults { results }
+    }
+}
+
+impl<O> Default for Evaluator<O> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+trait EvaluatorMetric<O>: Metric<O> + Numeric {}
+
+impl<O, M: Metric<O> + Numeric> EvaluatorMetric<O> for M {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::dataloader::batcher::Batcher;
+    use crate::data::dataloader::{BatchDataLoader, FixBatchStrategy};
+    use crate::data::dataset::InMemDataset;
+    use crate::tensor::backend::Backend;
+    use crate::tensor::{is_grad_enabled, Data, Shape, Tensor};
+    use crate::train::metric::AccuracyMetric;
+    use crate::TestBackend;
+    use std::cell::Cell;
+
+    type IntTensor<const D: usize> = Tensor<<TestBackend as Backend>::IntegerBackend, D>;
+    type Batch = (Tensor<TestBackend, 2>, IntTensor<1>);
+
+    struct TestBatcher;
+
+    impl Batcher<([f32; 2], i64), Batch> for TestBatcher {
+        fn batch(&self, items: Vec<([f32; 2], i64)>) -> Batch {
+            let outputs = items
+                .iter()
+                .map(|(output, _)| Tensor::from_data(Data::from([*output])))
+                .collect();
+            let targets = items.iter().map(|(_, target)| *target).collect();
+
+            (
+                Tensor::cat(outputs, 0),
+                IntTensor::from_data(Data::new(targets, Shape::new([items.len()]))),
+            )
+        }
+    }
+
+    /// The outputs of the model are already in the dataset.
+    struct TestModel;
+
+    impl ValidStep<Batch, Batch> for TestModel {
+        fn step(&self, item: Batch) -> Batch {
+            item
+        }
+    }
+
+    /// Records whether the gradients were enabled during its steps.
+    struct GradRecordingModel {
+        grad_enabled: Cell<bool>,
+    }
+
+    impl ValidStep<Batch, Batch> for GradRecordingModel {
+        fn step(&self, item: Batch) -> Batch {
+            self.grad_enabled.set(is_grad_enabled());
+            item
+        }
+    }
+
+    fn dataloader() -> Arc<dyn DataLoader<Batch>> {
+        let dataset = InMemDataset::new(vec![
+            ([0.9, 0.1], 0),
+            ([0.2, 0.8], 1),
+            ([0.3, 0.7], 0),
+            ([0.6, 0.4], 0),
+            ([0.1, 0.9], 0),
+            ([0.8, 0.2], 1),
+            ([0.4, 0.6], 1),
+        ]);
+
+        Arc::new(BatchDataLoader::new(
+            Box::new(FixBatchStrategy::new(2)),
+            Arc::new(dataset),
+            Arc::new(TestBatcher),
+        ))
+    }
+
+    #[test]
+    fn should_compute_the_mean_accuracy_over_samples() {
+        let dataloader = dataloader();
+        let mut evaluator = Evaluator::<Batch>::new().metric(AccuracyMetric::new());
+
+        let results = evaluator.evaluate(&TestModel, dataloader.clone());
+
+        let mut accuracies = Vec::new();
+        for (outputs, targets) in dataloader.iter() {
+            let [batch_size, _] = *outputs.dims();
+            let predictions = outputs.argmax(1).reshape([batch_size]);
+            let correct = predictions.equal(&targets).to_int().sum().into_scalar();
+            accuracies.push(100.0 * correct as f64 / batch_size as f64);
+        }
+        // The last batch has a single sample, so the mean of the batch accuracies is 62.5 %.
+        assert_eq!(accuracies, vec![100.0, 50.0, 0.0, 100.0]);
+        assert_eq!(results.get("Accuracy"), Some(400.0 / 7.0));
+        assert_eq!(results.results.len(), 1);
+    }
+
+    #[test]
+    fn should_evaluate_without_tracking_gradients() {
+        let model = GradRecordingModel {
+            grad_enabled: Cell::new(true),
+        };
+        let mut evaluator = Evaluator::<Batch>::new().metric(AccuracyMetric::new());
+
+        evaluator.evaluate(&model, dataloader());
+
+        assert!(!model.grad_enabled.get());
+        assert!(is_grad_enabled());
+    }
+}
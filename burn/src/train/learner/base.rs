@@ -1,8 +1,8 @@
 use crate::module::ADModule;
 use crate::optim::Optimizer;
 use crate::tensor::backend::Backend;
-use crate::train::checkpoint::Checkpointer;
-use crate::train::LearnerCallback;
+use crate::train::checkpoint::{Checkpointer, CheckpointerError, RngState};
+use crate::train::{EarlyStopping, LearnerCallback, TrainingCallback};
 
 /// Learner struct encapsulating all components necessary to train a Neural Network model.
 ///
@@ -15,9 +15,15 @@ where
     pub(super) optim: O,
     pub(super) num_epochs: usize,
     pub(super) callback: Box<dyn LearnerCallback<TO, VO>>,
+    pub(super) callbacks: Vec<Box<dyn TrainingCallback<TO>>>,
     pub(super) checkpoint: Option<usize>,
     pub(super) checkpointer_model: Option<Box<dyn Checkpointer<<M::Backend as Backend>::Elem>>>,
     pub(super) checkpointer_optimizer: Option<Box<dyn Checkpointer<<M::Backend as Backend>::Elem>>>,
+    pub(super) checkpointer_rng: Option<Box<dyn Checkpointer<<M::Backend as Backend>::Elem>>>,
+    pub(super) rng: Option<RngState>,
+    pub(super) early_stopping: Option<EarlyStopping>,
+    pub(super) grad_accumulation: Option<usize>,
+    pub(super) detect_anomaly: bool,
 }
 
 impl<M, O, TO, VO> Learner<M, O, TO, VO>
@@ -36,17 +42,83 @@ where
                 .save(epoch, self.optim.state(&self.model))
                 .unwrap();
         }
+        if let (Some(checkpointer), Some(rng)) = (&self.checkpointer_rng, &self.rng) {
+            checkpointer.save(epoch, rng.to_state()).unwrap();
+        }
     }
 
-    pub(super) fn load_checkpoint(&mut self, epoch: usize) {
-        if let Some(checkpointer) = &self.checkpointer_model {
-            let state = checkpointer.restore(epoch).unwrap();
-            self.model.load(&state).unwrap();
+    /// Wait for all checkpoints to be saved, returning the first error of the checkpointers.
+    pub(super) fn flush_checkpoints(&self) -> Result<(), CheckpointerError> {
+        let checkpointers = [
+            &self.checkpointer_model,
+            &self.checkpointer_optimizer,
+            &self.checkpointer_rng,
+        ];
+
+        let mut result = Ok(());
+
+        // Every checkpointer is flushed even when one fails, so that no save is left pending.
+        for checkpointer in checkpointers.into_iter().flatten() {
+            if let Err(err) = checkpointer.flush() {
+                if result.is_ok() {
+                    result = Err(err);
+                }
+            }
         }
 
-        if let Some(checkpointer) = &self.checkpointer_optimizer {
-            let state = checkpointer.restore(epoch).unwrap();
+        result
+    }
+
+    /// Load the checkpoints of the given epoch, returning the epoch that was actually loaded.
+    ///
+    /// When a checkpoint doesn't match its [checksum](CheckpointerError::InvalidChecksum), the
+    /// checkpoints of the previous epoch are loaded instead, so that the model, optimizer and rng
+    /// states always come from the same epoch.
+    pub(super) fn load_checkpoint(&mut self, checkpoint: usize) -> usize {
+        let restore = |checkpointer: &Option<Box<dyn Checkpointer<_>>>, epoch| {
+            checkpointer
+                .as_ref()
+                .map(|checkpointer| checkpointer.restore(epoch))
+                .transpose()
+        };
+        let mut epoch = checkpoint;
+
+        let (state_model, state_optim, state_rng) = loop {
+            let states = restore(&self.checkpointer_model, epoch).and_then(|model| {
+                let optim = restore(&self.checkpointer_optimizer, epoch)?;
+                let rng = match self.rng {
+                    Some(_) => restore(&self.checkpointer_rng, epoch)?,
+                    None => None,
+                };
+                Ok((model, optim, rng))
+            });
+
+            match states {
+                Ok(states) => break states,
+                Err(CheckpointerError::InvalidChecksum(_)) if epoch > 1 => epoch -= 1,
+                Err(err) => panic!(
+                    "Failed to restore the checkpoint of epoch {}: {:?}",
+                    epoch, err
+                ),
+            }
+        };
+
+        if let Some(state) = state_model {
+            self.model.load(&state).unwrap();
+        }
+        if let Some(state) = state_optim {
             self.optim.load(&self.model, &state).unwrap();
         }
+        if let Some(state) = state_rng {
+            self.rng = Some(RngState::from_state(&state).unwrap());
+        }
+
+        epoch
+    }
+
+    pub(super) fn reseed(&mut self) {
+        if let Some(rng) = &mut self.rng {
+            rng.reseed::<M::Backend>();
+        }
     }
 }
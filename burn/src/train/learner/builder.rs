@@ -1,28 +1,68 @@
-use super::Learner;
+use super::{EarlyStopping, Learner, MetricDirection};
 use crate::module::ADModule;
-use crate::train::checkpoint::{AsyncCheckpointer, Checkpointer, FileCheckpointer};
-use crate::train::logger::FileMetricLogger;
+use crate::optim::MixedPrecision;
+use crate::train::checkpoint::{AsyncCheckpointer, Checkpointer, FileCheckpointer, RngState};
+use crate::train::logger::{FileMetricLogger, TensorBoardMetricLogger};
 use crate::train::metric::dashboard::cli::CLIDashboardRenderer;
 use crate::train::metric::dashboard::Dashboard;
 use crate::train::metric::{Metric, Numeric};
-use crate::train::AsyncTrainerCallback;
+use crate::train::{AsyncTrainerCallback, TrainingCallback};
 use burn_tensor::backend::ADBackend;
-use burn_tensor::Element;
-use std::sync::Arc;
+use burn_tensor::{f16, Element};
+use std::sync::{mpsc, Arc};
 
 /// Struct to configure and create a [learner](Learner).
-pub struct LearnerBuilder<B, T, V>
+pub struct LearnerBuilder<B, T, V, A = DefaultPrecision>
 where
     T: Send + Sync + 'static,
     V: Send + Sync + 'static,
     B: ADBackend,
 {
     dashboard: Dashboard<T, V>,
+    callbacks: Vec<Box<dyn TrainingCallback<T>>>,
     checkpointer_model: Option<Arc<dyn Checkpointer<B::Elem> + Send + Sync>>,
     checkpointer_optimizer: Option<Arc<dyn Checkpointer<B::Elem> + Send + Sync>>,
+    checkpointer_rng: Option<Arc<dyn Checkpointer<B::Elem> + Send + Sync>>,
+    seed: Option<u64>,
     num_epochs: usize,
     checkpoint: Option<usize>,
     directory: String,
+    early_stopping: Option<(usize, String, Option<MetricDirection>)>,
+    grad_accumulation: Option<usize>,
+    detect_anomaly: bool,
+    precision: A,
+}
+
+/// Precision in which a [learner](Learner) trains its model, which wraps the optimizer given to
+/// [build](LearnerBuilder::build).
+pub trait TrainingPrecision<B: ADBackend, O> {
+    type Optimizer;
+
+    fn optimizer(self, optim: O) -> Self::Optimizer;
+}
+
+/// Train in the precision of the backend, with the optimizer used as is.
+pub struct DefaultPrecision;
+
+/// Train with [mixed precision](MixedPrecision), set with [with_amp](LearnerBuilder::with_amp).
+pub struct Amp {
+    loss_scale: f64,
+}
+
+impl<B: ADBackend, O> TrainingPrecision<B, O> for DefaultPrecision {
+    type Optimizer = O;
+
+    fn optimizer(self, optim: O) -> Self::Optimizer {
+        optim
+    }
+}
+
+impl<B: ADBackend, O> TrainingPrecision<B, O> for Amp {
+    type Optimizer = MixedPrecision<B, O>;
+
+    fn optimizer(self, optim: O) -> Self::Optimizer {
+        MixedPrecision::new(optim, self.loss_scale)
+    }
 }
 
 impl<B, T, V> LearnerBuilder<B, T, V>
@@ -42,14 +82,28 @@ where
 
         Self {
             dashboard: Dashboard::new(renderer, logger_train, logger_valid),
+            callbacks: Vec::new(),
             num_epochs: 1,
             checkpoint: None,
             checkpointer_model: None,
             checkpointer_optimizer: None,
+            checkpointer_rng: None,
+            seed: None,
             directory: directory.to_string(),
+            early_stopping: None,
+            grad_accumulation: None,
+            detect_anomaly: false,
+            precision: DefaultPrecision,
         }
     }
+}
 
+impl<B, T, V, A> LearnerBuilder<B, T, V, A>
+where
+    T: Send + Sync + 'static,
+    V: Send + Sync + 'static,
+    B: ADBackend,
+{
     /// Register a training metric.
     pub fn metric_train<M: Metric<T> + 'static>(mut self, metric: M) -> Self {
         self.dashboard.register_train(metric);
@@ -86,21 +140,147 @@ where
         self
     }
 
+    /// Register a [callback](TrainingCallback) whose hooks are called during the training.
+    ///
+    /// Multiple callbacks can be registered, they are called in their registration order.
+    pub fn callback<C: TrainingCallback<T> + 'static>(mut self, callback: C) -> Self {
+        self.callbacks.push(Box::new(callback));
+        self
+    }
+
+    /// Replace the file metric loggers with [TensorBoard](TensorBoardMetricLogger) loggers.
+    ///
+    /// The event files of each split are written in the `train` and `valid` sub-directories of
+    /// the given path, so that both can be visualized on the same graphs.
+    ///
+    /// # Errors
+    ///
+    /// If the directories or the event files can't be created.
+    pub fn with_tensorboard_logger(mut self, path: &str) -> std::io::Result<Self> {
+        let logger_train = Box::new(TensorBoardMetricLogger::new(
+            format!("{}/train", path).as_str(),
+        )?);
+        let logger_valid = Box::new(TensorBoardMetricLogger::new(
+            format!("{}/valid", path).as_str(),
+        )?);
+        self.dashboard.replace_loggers(logger_train, logger_valid);
+        Ok(self)
+    }
+
     /// The number of epochs the training should last.
     pub fn num_epochs(mut self, num_epochs: usize) -> Self {
         self.num_epochs = num_epochs;
         self
     }
 
-    /// The epoch from which the training must resume.
+    /// Stop the training when the given validation metric hasn't improved for `patience` epochs.
+    ///
+    /// The value of the metric for an epoch is the mean of its values over all validation
+    /// iterations, weighted by their [number of samples](crate::train::metric::Numeric::count).
+    /// Whether a lower or an higher value is an improvement is inferred from the metric name
+    /// unless [set explicitly](Self::early_stopping_direction).
+    ///
+    /// # Notes
+    ///
+    /// The metric must be registered as a [validation plot](Self::metric_valid_plot), otherwise
+    /// building the learner panics.
+    pub fn early_stopping(mut self, patience: usize, metric_name: &str) -> Self {
+        self.early_stopping = Some((patience, metric_name.to_string(), None));
+        self
+    }
+
+    /// Set the direction in which the metric monitored by [early stopping](Self::early_stopping)
+    /// improves.
+    pub fn early_stopping_direction(mut self, direction: MetricDirection) -> Self {
+        if let Some((_, _, current)) = &mut self.early_stopping {
+            *current = Some(direction);
+        }
+        self
+    }
+
+    /// Accumulate the gradients over `steps` training iterations before updating the model, which
+    /// trains with an effective batch size `steps` times larger without the memory cost.
+    ///
+    /// The model is updated with the mean of the accumulated gradients, and the last iterations
+    /// of an epoch still update the model even when there are fewer than `steps` of them.
+    pub fn grad_accumulation(mut self, steps: usize) -> Self {
+        self.grad_accumulation = Some(steps);
+        self
+    }
+
+    /// Train with [mixed precision](MixedPrecision), starting with the given loss scale.
+    ///
+    /// The model runs in the precision of its backend while the optimizer given to
+    /// [build](Self::build) updates a full precision copy of its parameters. The
+    /// [training step](crate::train::TrainStep::step_scaled) must scale the loss for the scaling
+    /// to take effect, and the steps where the gradients overflow are skipped.
+    pub fn with_amp(self, loss_scale: f64) -> LearnerBuilder<B, T, V, Amp> {
+        LearnerBuilder {
+            dashboard: self.dashboard,
+            callbacks: self.callbacks,
+            checkpointer_model: self.checkpointer_model,
+            checkpointer_optimizer: self.checkpointer_optimizer,
+            checkpointer_rng: self.checkpointer_rng,
+            seed: self.seed,
+            num_epochs: self.num_epochs,
+            checkpoint: self.checkpoint,
+            directory: self.directory,
+            early_stopping: self.early_stopping,
+            grad_accumulation: self.grad_accumulation,
+            detect_anomaly: self.detect_anomaly,
+            precision: Amp { loss_scale },
+        }
+    }
+
+    /// Run the training steps with [anomaly detection](burn_tensor::detect_anomaly), panicking
+    /// with the name of the first operation producing NaN or infinite values during the backward
+    /// pass.
+    ///
+    /// This makes the training much slower, so it should only be enabled while debugging.
+    pub fn detect_anomaly(mut self, enabled: bool) -> Self {
+        self.detect_anomaly = enabled;
+        self
+    }
+
+    /// The epoch of the checkpoint from which the training must resume, the training continuing
+    /// with the next epoch.
+    ///
+    /// When a checkpoint of that epoch is corrupted, the training resumes from the checkpoints of
+    /// the previous epoch.
     pub fn checkpoint(mut self, checkpoint: usize) -> Self {
         self.checkpoint = Some(checkpoint);
         self
     }
 
+    /// Seed the backend at the beginning of each epoch, so that the training is reproducible even
+    /// when resumed from a [checkpoint](Self::checkpoint).
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Register the checkpointers that will save the [model](crate::module::Module) and the
+    /// [optimizer](crate::optim::Optimizer) [states](crate::module::State).
+    ///
+    /// Any [checkpointer](Checkpointer) implementation can be used, for instance one storing the
+    /// states in memory or on a remote storage. They are saved in a background thread, see
+    /// [with_file_checkpointer](Self::with_file_checkpointer) to also save the
+    /// [random state](RngState).
+    pub fn with_checkpointer(
+        mut self,
+        checkpointer_model: Arc<dyn Checkpointer<B::Elem> + Send + Sync>,
+        checkpointer_optimizer: Arc<dyn Checkpointer<B::Elem> + Send + Sync>,
+    ) -> Self {
+        self.checkpointer_model = Some(checkpointer_model);
+        self.checkpointer_optimizer = Some(checkpointer_optimizer);
+        self
+    }
+
     /// Register a checkpointer that will save the [optimizer](crate::optim::Optimizer) and the
     /// [model](crate::module::Module) [states](crate::module::State).
     ///
+    /// When a [seed](Self::seed) is set, the [random state](RngState) is also saved.
+    ///
     /// The number of checkpoints to be keep should be set to a minimum of two to be safe, since
     /// they are saved and deleted asynchronously and a crash during training might make a
     /// checkpoint non-usable.
@@ -118,15 +298,70 @@ where
             "optim",
             num_keep,
         )));
+        self.checkpointer_rng = Some(Arc::new(FileCheckpointer::<P>::new(
+            format!("{}/checkpoint", self.directory).as_str(),
+            "rng",
+            num_keep,
+        )));
+        self
+    }
+
+    /// Register a checkpointer like [with_file_checkpointer](Self::with_file_checkpointer), but
+    /// the [model](crate::module::Module) state is stored in half precision, which roughly halves
+    /// the size of its checkpoints.
+    ///
+    /// The model parameters are rounded to the nearest [f16](crate::tensor::f16) when saved and
+    /// converted back to the backend element type when restored. The
+    /// [optimizer](crate::optim::Optimizer) and [random](RngState) states are still saved with
+    /// the precision `P`.
+    pub fn with_file_checkpointer_fp16<
+        P: Element + serde::de::DeserializeOwned + serde::Serialize,
+    >(
+        mut self,
+        num_keep: usize,
+    ) -> Self {
+        self = self.with_file_checkpointer::<P>(num_keep);
+        self.checkpointer_model = Some(Arc::new(FileCheckpointer::<f16>::new(
+            format!("{}/checkpoint", self.directory).as_str(),
+            "model",
+            num_keep,
+        )));
         self
     }
 
     /// Create the [learner](Learner) from a [module](ADModule) and an
     /// [optimizer](crate::optim::Optimizer).
-    pub fn build<M, O>(self, model: M, optim: O) -> Learner<M, O, T, V>
+    ///
+    /// When training with [mixed precision](Self::with_amp), the optimizer is wrapped in
+    /// [MixedPrecision] and must work on the full precision backend.
+    ///
+    /// # Panics
+    ///
+    /// If [early stopping](Self::early_stopping) monitors a metric that isn't registered as a
+    /// validation plot.
+    pub fn build<M, O>(mut self, model: M, optim: O) -> Learner<M, A::Optimizer, T, V>
     where
         M: ADModule<ADBackend = B>,
+        A: TrainingPrecision<B, O>,
     {
+        let early_stopping = match self.early_stopping.take() {
+            Some((patience, metric_name, direction)) => {
+                if !self.dashboard.has_valid_numeric(&metric_name) {
+                    panic!(
+                        "Early stopping metric '{}' isn't a validation plot",
+                        metric_name
+                    );
+                }
+
+                let (sender, receiver) = mpsc::channel();
+                self.dashboard.monitor_valid(&metric_name, sender);
+                let direction = direction.unwrap_or_else(|| MetricDirection::infer(&metric_name));
+
+                Some(EarlyStopping::new(patience, direction, receiver))
+            }
+            None => None,
+        };
+
         let callack = Box::new(self.dashboard);
         let callback = Box::new(AsyncTrainerCallback::new(callack));
 
@@ -143,12 +378,18 @@ where
 
         Learner {
             model,
-            optim,
+            optim: self.precision.optimizer(optim),
             num_epochs: self.num_epochs,
             callback,
+            callbacks: self.callbacks,
             checkpoint: self.checkpoint,
             checkpointer_model: create_checkpointer(self.checkpointer_model),
             checkpointer_optimizer: create_checkpointer(self.checkpointer_optimizer),
+            checkpointer_rng: create_checkpointer(self.checkpointer_rng),
+            rng: self.seed.map(RngState::new),
+            early_stopping,
+            grad_accumulation: self.grad_accumulation,
+            detect_anomaly: self.detect_anomaly,
         }
     }
 }
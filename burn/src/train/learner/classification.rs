@@ -10,20 +10,45 @@ pub struct ClassificationOutput<B: Backend> {
 }
 
 impl<B: Backend> metric::Metric<ClassificationOutput<B>> for metric::LossMetric {
-    fn update(&mut self, item: &ClassificationOutput<B>) -> metric::MetricStateDyn {
-        self.update(&item.loss)
+    fn update(
+        &mut self,
+        item: &ClassificationOutput<B>,
+        metadata: &metric::MetricMetadata,
+    ) -> metric::MetricStateDyn {
+        let [batch_size, _] = *item.output.dims();
+        self.update(&(item.loss.clone(), batch_size), metadata)
     }
     fn clear(&mut self) {
-        <metric::LossMetric as metric::Metric<Tensor<B, 1>>>::clear(self);
+        <metric::LossMetric as metric::Metric<(Tensor<B, 1>, usize)>>::clear(self);
     }
 }
 
 impl<B: Backend> metric::Metric<ClassificationOutput<B>> for metric::AccuracyMetric {
-    fn update(&mut self, item: &ClassificationOutput<B>) -> metric::MetricStateDyn {
-        self.update(&(item.output.clone(), item.targets.clone()))
+    fn update(
+        &mut self,
+        item: &ClassificationOutput<B>,
+        metadata: &metric::MetricMetadata,
+    ) -> metric::MetricStateDyn {
+        self.update(&(item.output.clone(), item.targets.clone()), metadata)
     }
 
     fn clear(&mut self) {
         <metric::AccuracyMetric as metric::Metric<(Tensor<B, 2>, Tensor<B, 2>)>>::clear(self);
     }
 }
+
+impl<B: Backend> metric::Metric<ClassificationOutput<B>> for metric::PrecisionRecallMetric {
+    fn update(
+        &mut self,
+        item: &ClassificationOutput<B>,
+        metadata: &metric::MetricMetadata,
+    ) -> metric::MetricStateDyn {
+        self.update(&(item.output.clone(), item.targets.clone()), metadata)
+    }
+
+    fn clear(&mut self) {
+        <metric::PrecisionRecallMetric as metric::Metric<(Tensor<B, 2>, Tensor<B, 2>)>>::clear(
+            self,
+        );
+    }
+}
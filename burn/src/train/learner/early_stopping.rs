@@ -0,0 +1,127 @@
+use std::sync::mpsc;
+
+/// The direction in which a monitored metric improves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MetricDirection {
+    /// The metric improves when it decreases, like a loss.
+    Lowest,
+    /// The metric improves when it increases, like an accuracy.
+    Highest,
+}
+
+impl MetricDirection {
+    /// Infer the direction from the metric name: losses are minimized while every other metric
+    /// is maximized.
+    pub fn infer(metric_name: &str) -> Self {
+        match metric_name.to_lowercase().contains("loss") {
+            true => Self::Lowest,
+            false => Self::Highest,
+        }
+    }
+}
+
+/// Stop the training when a validation metric hasn't improved for a number of epochs.
+///
+/// The value of the metric for each epoch is received from the
+/// [dashboard](crate::train::metric::dashboard::Dashboard).
+pub(crate) struct EarlyStopping {
+    patience: usize,
+    direction: MetricDirection,
+    best: Option<f64>,
+    num_epochs_without_improvement: usize,
+    receiver: mpsc::Receiver<f64>,
+}
+
+impl EarlyStopping {
+    pub(crate) fn new(
+        patience: usize,
+        direction: MetricDirection,
+        receiver: mpsc::Receiver<f64>,
+    ) -> Self {
+        Self {
+            patience,
+            direction,
+            best: None,
+            num_epochs_without_improvement: 0,
+            receiver,
+        }
+    }
+
+    /// Wait for the value of the monitored metric of the last validation epoch and returns
+    /// whether the training should stop.
+    pub(crate) fn should_stop(&mut self) -> bool {
+        match self.receiver.recv() {
+            Ok(value) => self.update(value),
+            Err(_) => false,
+        }
+    }
+
+    fn update(&mut self, value: f64) -> bool {
+        let improved = match (self.best, self.direction) {
+            (None, _) => !value.is_nan(),
+            (Some(best), MetricDirection::Lowest) => value < best,
+            (Some(best), MetricDirection::Highest) => value > best,
+        };
+
+        if improved {
+            self.best = Some(value);
+            self.num_epochs_without_improvement = 0;
+        } else {
+            self.num_epochs_without_improvement += 1;
+        }
+
+        self.num_epochs_without_improvement >= self.patience
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_infer_direction_from_name() {
+        assert_eq!(MetricDirection::infer("Loss"), MetricDirection::Lowest);
+        assert_eq!(MetricDirection::infer("Accuracy"), MetricDirection::Highest);
+    }
+
+    #[test]
+    fn test_should_stop_early_with_stagnant_metric() {
+        let (sender, receiver) = mpsc::channel();
+        let mut early_stopping = EarlyStopping::new(3, MetricDirection::Lowest, receiver);
+        let values = [1.0, 0.8, 0.7, 0.7, 0.75, 0.7, 0.7, 0.6, 0.5];
+        let mut num_epochs = 0;
+
+        for value in values {
+            num_epochs += 1;
+            sender.send(value).unwrap();
+
+            if early_stopping.should_stop() {
+                break;
+            }
+        }
+
+        assert_eq!(num_epochs, 6);
+    }
+
+    #[test]
+    fn test_should_reset_patience_on_improvement() {
+        let (sender, receiver) = mpsc::channel();
+        let mut early_stopping = EarlyStopping::new(2, MetricDirection::Highest, receiver);
+
+        for value in [0.5, 0.5, 0.6, 0.6] {
+            sender.send(value).unwrap();
+            assert!(!early_stopping.should_stop());
+        }
+        sender.send(0.6).unwrap();
+        assert!(early_stopping.should_stop());
+    }
+
+    #[test]
+    fn test_should_not_stop_when_the_dashboard_is_gone() {
+        let (sender, receiver) = mpsc::channel();
+        let mut early_stopping = EarlyStopping::new(0, MetricDirection::Lowest, receiver);
+        core::mem::drop(sender);
+
+        assert!(!early_stopping.should_stop());
+    }
+}
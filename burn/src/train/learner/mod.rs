@@ -1,9 +1,11 @@
 mod base;
 mod builder;
 mod classification;
+mod early_stopping;
 mod train_val;
 
 pub use base::*;
 pub use builder::*;
 pub use classification::*;
+pub use early_stopping::*;
 pub use train_val::*;
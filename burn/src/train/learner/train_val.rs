@@ -1,9 +1,10 @@
 use super::Learner;
 use crate::data::dataloader::DataLoader;
 use crate::module::ADModule;
-use crate::optim::Optimizer;
+use crate::optim::{GradientsAccumulator, Optimizer};
+use crate::train::checkpoint::CheckpointerError;
 use crate::train::LearnerItem;
-use burn_tensor::Gradients;
+use burn_tensor::{detect_anomaly, Gradients};
 use std::sync::Arc;
 
 #[derive(new)]
@@ -14,6 +15,15 @@ pub struct TrainOutput<TO> {
 
 pub trait TrainStep<TI, TO> {
     fn step(&self, item: TI) -> TrainOutput<TO>;
+
+    /// Training step used with the optimizers [scaling the loss](Optimizer::loss_scale), like
+    /// [MixedPrecision](crate::optim::MixedPrecision).
+    ///
+    /// The loss should be multiplied by `loss_scale` before the backward pass. By default, the
+    /// loss isn't scaled and the [step](Self::step) is used.
+    fn step_scaled(&self, item: TI, _loss_scale: f64) -> TrainOutput<TO> {
+        self.step(item)
+    }
 }
 
 pub trait ValidStep<VI, VO> {
@@ -27,30 +37,52 @@ where
     M: ADModule,
     O: Optimizer<Backend = M::Backend>,
 {
+    /// Train the model, returning it once all its checkpoints are saved.
+    ///
+    /// # Errors
+    ///
+    /// If a checkpoint failed to be saved in the background.
     pub fn fit<TI, VI>(
         mut self,
         dataloader_train: Arc<dyn DataLoader<TI>>,
         dataloader_valid: Arc<dyn DataLoader<VI>>,
-    ) -> M
+    ) -> Result<M, CheckpointerError>
     where
         M: TrainStep<TI, TO>,
         M::InnerModule: ValidStep<VI, VO>,
     {
         let starting_epoch = match self.checkpoint {
-            Some(checkpoint) => {
-                self.load_checkpoint(checkpoint);
-                checkpoint
-            }
+            Some(checkpoint) => self.load_checkpoint(checkpoint) + 1,
             None => 1,
         };
 
         for epoch in starting_epoch..self.num_epochs + 1 {
+            self.reseed();
+            for callback in self.callbacks.iter_mut() {
+                callback.on_epoch_start(epoch);
+            }
+
             self.train_step(&dataloader_train, epoch);
             self.valid_step(&dataloader_valid, epoch);
             self.checkpoint(epoch);
+
+            for callback in self.callbacks.iter_mut() {
+                callback.on_epoch_end(epoch);
+            }
+
+            if let Some(early_stopping) = &mut self.early_stopping {
+                if early_stopping.should_stop() {
+                    break;
+                }
+            }
         }
 
-        self.model
+        let flushed = self.flush_checkpoints();
+        for callback in self.callbacks.iter_mut() {
+            callback.on_training_end();
+        }
+
+        flushed.map(|_| self.model)
     }
 
     fn train_step<TI>(&mut self, dataloader_train: &Arc<dyn DataLoader<TI>>, epoch: usize)
@@ -59,21 +91,50 @@ where
     {
         let mut iterator = dataloader_train.iter();
         let mut iteration = 0;
+        let mut accumulator = GradientsAccumulator::new();
 
         while let Some(item) = iterator.next() {
             let progress = iterator.progress();
             iteration += 1;
 
-            let item = self.model.step(item);
-            self.model.update_params(&item.grads, &mut self.optim);
+            let step = || match self.optim.loss_scale() {
+                Some(loss_scale) => self.model.step_scaled(item, loss_scale),
+                None => self.model.step(item),
+            };
+            let item = match self.detect_anomaly {
+                true => detect_anomaly(step),
+                false => step(),
+            };
+
+            match self.grad_accumulation {
+                Some(steps) => {
+                    accumulator.accumulate(&mut self.model, &item.grads);
 
-            self.callback.on_train_item(LearnerItem::new(
+                    if accumulator.count() >= steps {
+                        let grads = accumulator.mean(&mut self.model);
+                        self.optim.update_module(&mut self.model, &grads);
+                    }
+                }
+                None => self.optim.update_module(&mut self.model, &item.grads),
+            }
+
+            let item = LearnerItem::new(
                 item.item,
                 progress,
                 epoch,
                 self.num_epochs,
                 iteration,
-            ));
+                self.optim.learning_rate(),
+            );
+            for callback in self.callbacks.iter_mut() {
+                callback.on_batch_end(&item);
+            }
+            self.callback.on_train_item(item);
+        }
+
+        if accumulator.count() > 0 {
+            let grads = accumulator.mean(&mut self.model);
+            self.optim.update_module(&mut self.model, &grads);
         }
         self.callback.on_train_end_epoch(epoch);
     }
@@ -98,8 +159,268 @@ where
                 epoch,
                 self.num_epochs,
                 iteration,
+                None,
             ));
         }
         self.callback.on_valid_end_epoch(epoch);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as burn;
+    use crate::data::dataloader::batcher::TestBatcher;
+    use crate::data::dataloader::{BatchDataLoader, FixBatchStrategy};
+    use crate::data::dataset::FakeDataset;
+    use crate::module::{Module, Param};
+    use crate::optim::{Sgd, SgdConfig};
+    use crate::tensor::backend::{ADBackend, Backend};
+    use crate::tensor::{Data, Distribution, Tensor};
+    use crate::train::{LearnerBuilder, TrainingCallback};
+    use crate::{lock_rng, TestADBackend};
+    use std::sync::Mutex;
+
+    #[derive(Module, Debug)]
+    struct TestModel<B: Backend> {
+        weight: Param<Tensor<B, 1>>,
+    }
+
+    impl<B: ADBackend> TrainStep<Vec<String>, ()> for TestModel<B> {
+        fn step(&self, _item: Vec<String>) -> TrainOutput<()> {
+            TrainOutput::new(self.weight.sum().backward(), ())
+        }
+    }
+
+    impl<B: Backend> ValidStep<Vec<String>, ()> for TestModel<B> {
+        fn step(&self, _item: Vec<String>) {}
+    }
+
+    /// Model whose loss depends on random numbers drawn at each step, which are its output.
+    #[derive(Module, Debug)]
+    struct NoisyModel<B: Backend> {
+        weight: Param<Tensor<B, 1>>,
+    }
+
+    impl TrainStep<Vec<String>, Data<f32, 1>> for NoisyModel<TestADBackend> {
+        fn step(&self, _item: Vec<String>) -> TrainOutput<Data<f32, 1>> {
+            let noise = Tensor::random([4], Distribution::Standard);
+            let grads = self.weight.mul(&noise).sum().backward();
+
+            TrainOutput::new(grads, noise.into_data())
+        }
+    }
+
+    impl<B: Backend> ValidStep<Vec<String>, ()> for NoisyModel<B> {
+        fn step(&self, _item: Vec<String>) {}
+    }
+
+    /// Model whose loss is the sum of its squared weights, which must be scaled.
+    #[derive(Module, Debug)]
+    struct ScaledModel<B: Backend> {
+        weight: Param<Tensor<B, 1>>,
+    }
+
+    impl TrainStep<Vec<String>, Data<f32, 1>> for ScaledModel<TestADBackend> {
+        fn step(&self, _item: Vec<String>) -> TrainOutput<Data<f32, 1>> {
+            panic!("The loss should be scaled");
+        }
+
+        fn step_scaled(&self, _item: Vec<String>, loss_scale: f64) -> TrainOutput<Data<f32, 1>> {
+            let loss = self.weight.powf(2.0).sum();
+            let grads = loss.mul_scalar(loss_scale).backward();
+
+            TrainOutput::new(grads, loss.into_data())
+        }
+    }
+
+    impl<B: Backend> ValidStep<Vec<String>, ()> for ScaledModel<B> {
+        fn step(&self, _item: Vec<String>) {}
+    }
+
+    struct RecordingCallback {
+        outputs: Arc<Mutex<Vec<Data<f32, 1>>>>,
+    }
+
+    impl TrainingCallback<Data<f32, 1>> for RecordingCallback {
+        fn on_batch_end(&mut self, item: &LearnerItem<Data<f32, 1>>) {
+            self.outputs.lock().unwrap().push(item.item.clone());
+        }
+    }
+
+    #[derive(Default, Debug, PartialEq)]
+    struct HookCounts {
+        epoch_start: usize,
+        batch_end: usize,
+        epoch_end: usize,
+        training_end: usize,
+    }
+
+    struct CountingCallback {
+        counts: Arc<Mutex<HookCounts>>,
+    }
+
+    impl<T> TrainingCallback<T> for CountingCallback {
+        fn on_epoch_start(&mut self, _epoch: usize) {
+            self.counts.lock().unwrap().epoch_start += 1;
+        }
+
+        fn on_batch_end(&mut self, _item: &LearnerItem<T>) {
+            self.counts.lock().unwrap().batch_end += 1;
+        }
+
+        fn on_epoch_end(&mut self, _epoch: usize) {
+            self.counts.lock().unwrap().epoch_end += 1;
+        }
+
+        fn on_training_end(&mut self) {
+            self.counts.lock().unwrap().training_end += 1;
+        }
+    }
+
+    #[test]
+    fn should_call_the_training_hooks() {
+        let counts = Arc::new(Mutex::new(HookCounts::default()));
+        let dataset = Arc::new(FakeDataset::<String>::new(27));
+        let dataloader = || -> Arc<dyn DataLoader<Vec<String>>> {
+            Arc::new(BatchDataLoader::new(
+                Box::new(FixBatchStrategy::new(5)),
+                dataset.clone(),
+                Arc::new(TestBatcher::new()),
+            ))
+        };
+        let model = TestModel::<TestADBackend> {
+            weight: Param::new(Tensor::zeros([4])),
+        };
+        let directory =
+            std::env::temp_dir().join(format!("burn-training-hooks-test-{}", std::process::id()));
+        let learner = LearnerBuilder::<TestADBackend, (), ()>::new(directory.to_str().unwrap())
+            .num_epochs(2)
+            .callback(CountingCallback {
+                counts: counts.clone(),
+            })
+            .build(model, Sgd::new(&SgdConfig::new()));
+
+        learner.fit(dataloader(), dataloader()).unwrap();
+
+        assert_eq!(
+            *counts.lock().unwrap(),
+            HookCounts {
+                epoch_start: 2,
+                batch_end: 12,
+                epoch_end: 2,
+                training_end: 1,
+            }
+        );
+    }
+
+    /// Train a noisy model for 4 epochs of 2 iterations, returning its outputs and final weight.
+    fn fit_noisy(directory: &str, checkpoint: Option<usize>) -> (Vec<Data<f32, 1>>, Data<f32, 1>) {
+        let dataset = Arc::new(FakeDataset::<String>::new(10));
+        let dataloader = || -> Arc<dyn DataLoader<Vec<String>>> {
+            Arc::new(BatchDataLoader::new(
+                Box::new(FixBatchStrategy::new(5)),
+                dataset.clone(),
+                Arc::new(TestBatcher::new()),
+            ))
+        };
+        let outputs = Arc::new(Mutex::new(Vec::new()));
+        let mut builder = LearnerBuilder::<TestADBackend, Data<f32, 1>, ()>::new(directory)
+            .num_epochs(4)
+            .seed(42)
+            .with_file_checkpointer::<f32>(4)
+            .callback(RecordingCallback {
+                outputs: outputs.clone(),
+            });
+        if let Some(checkpoint) = checkpoint {
+            builder = builder.checkpoint(checkpoint);
+        }
+        let model = NoisyModel {
+            weight: Param::new(Tensor::zeros([4])),
+        };
+
+        let model = builder
+            .build(model, Sgd::new(&SgdConfig::new()))
+            .fit(dataloader(), dataloader())
+            .unwrap();
+        let outputs = outputs.lock().unwrap().clone();
+
+        (outputs, model.weight.to_data())
+    }
+
+    #[test]
+    fn resumed_training_should_draw_the_same_random_numbers_as_an_uninterrupted_one() {
+        let _rng = lock_rng();
+        let directory =
+            std::env::temp_dir().join(format!("burn-resumed-training-test-{}", std::process::id()));
+        let directory = directory.to_str().unwrap();
+
+        let (outputs, weight) = fit_noisy(directory, None);
+        let (outputs_resumed, weight_resumed) = fit_noisy(directory, Some(2));
+
+        // Two iterations per epoch, so the resumed training starts with the fifth iteration.
+        assert_eq!(outputs.len(), 8);
+        assert_eq!(outputs[4..], outputs_resumed);
+        assert_eq!(weight, weight_resumed);
+    }
+
+    #[test]
+    fn training_should_resume_from_the_previous_epoch_when_a_checkpoint_is_corrupted() {
+        let _rng = lock_rng();
+        let directory = std::env::temp_dir().join(format!(
+            "burn-corrupted-training-test-{}",
+            std::process::id()
+        ));
+        let directory = directory.to_str().unwrap();
+
+        let (outputs, weight) = fit_noisy(directory, None);
+        let file_path = format!("{}/checkpoint/model-3.json.gz", directory);
+        let mut content = std::fs::read(&file_path).unwrap();
+        let index = content.len() / 2;
+        content[index] = !content[index];
+        std::fs::write(&file_path, content).unwrap();
+        let (outputs_resumed, weight_resumed) = fit_noisy(directory, Some(3));
+
+        // The epoch 3 is trained again from the checkpoints of the epoch 2.
+        assert_eq!(outputs[4..], outputs_resumed);
+        assert_eq!(weight, weight_resumed);
+    }
+
+    #[test]
+    fn training_with_amp_should_scale_the_loss_and_unscale_the_gradients() {
+        let dataset = Arc::new(FakeDataset::<String>::new(10));
+        let dataloader = || -> Arc<dyn DataLoader<Vec<String>>> {
+            Arc::new(BatchDataLoader::new(
+                Box::new(FixBatchStrategy::new(5)),
+                dataset.clone(),
+                Arc::new(TestBatcher::new()),
+            ))
+        };
+        let outputs = Arc::new(Mutex::new(Vec::new()));
+        let directory =
+            std::env::temp_dir().join(format!("burn-amp-training-test-{}", std::process::id()));
+        let model = ScaledModel {
+            weight: Param::new(Tensor::ones([4])),
+        };
+
+        let model =
+            LearnerBuilder::<TestADBackend, Data<f32, 1>, ()>::new(directory.to_str().unwrap())
+                .callback(RecordingCallback {
+                    outputs: outputs.clone(),
+                })
+                .with_amp(1024.0)
+                .build(model, Sgd::new(&SgdConfig::new().with_learning_rate(0.1)))
+                .fit(dataloader(), dataloader())
+                .unwrap();
+
+        // Each step multiplies the weights by 1 - 2 * 0.1 once the gradients are unscaled.
+        let outputs = outputs.lock().unwrap();
+        assert_eq!(outputs.len(), 2);
+        outputs[0].assert_approx_eq(&Data::from([4.0]), 3);
+        outputs[1].assert_approx_eq(&Data::from([2.56]), 3);
+        model
+            .weight
+            .to_data()
+            .assert_approx_eq(&Data::from([0.64, 0.64, 0.64, 0.64]), 3);
+    }
+}
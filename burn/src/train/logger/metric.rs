@@ -4,6 +4,9 @@ use std::collections::HashMap;
 
 pub trait MetricLogger: Send {
     fn log(&mut self, item: &dyn MetricState);
+    fn log_numeric(&mut self, item: &dyn MetricState, _value: f64) {
+        self.log(item);
+    }
     fn epoch(&mut self, epoch: usize);
 }
 
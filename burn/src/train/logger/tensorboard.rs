@@ -0,0 +1,227 @@
+use super::MetricLogger;
+use crate::train::metric::MetricState;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Write;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Metric logger writing [TensorBoard](https://www.tensorflow.org/tensorboard) event files.
+///
+/// Only [numeric](crate::train::metric::Numeric) metrics are logged, each one as a scalar
+/// summary tagged with the metric name. The step of each scalar is incremented every time the
+/// metric is logged and is not reset between epochs.
+pub struct TensorBoardMetricLogger {
+    file: File,
+    steps: HashMap<String, i64>,
+}
+
+impl TensorBoardMetricLogger {
+    /// Create a new logger writing an event file in the given directory, which is created if it
+    /// doesn't exist.
+    pub fn new(directory: &str) -> std::io::Result<Self> {
+        std::fs::create_dir_all(directory)?;
+
+        let wall_time = wall_time();
+        let file_path = format!(
+            "{}/events.out.tfevents.{}.burn",
+            directory, wall_time as u64
+        );
+        let file = File::create(file_path)?;
+
+        let mut logger = Self {
+            file,
+            steps: HashMap::new(),
+        };
+        logger.write_event(&encode_file_version_event(wall_time))?;
+        Ok(logger)
+    }
+
+    fn write_event(&mut self, event: &[u8]) -> std::io::Result<()> {
+        self.file.write_all(&encode_record(event))?;
+        self.file.flush()
+    }
+}
+
+impl MetricLogger for TensorBoardMetricLogger {
+    fn log(&mut self, _item: &dyn MetricState) {}
+
+    fn log_numeric(&mut self, item: &dyn MetricState, value: f64) {
+        let tag = item.name();
+        let step = self.steps.entry(tag.clone()).or_insert(0);
+        *step += 1;
+        let event = encode_scalar_event(wall_time(), *step, &tag, value as f32);
+
+        self.write_event(&event).unwrap();
+    }
+
+    fn epoch(&mut self, _epoch: usize) {}
+}
+
+fn wall_time() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs_f64()
+}
+
+/// Encode an `Event` protobuf message containing the file version, which must be the first
+/// event of every event file.
+fn encode_file_version_event(wall_time: f64) -> Vec<u8> {
+    let mut event = Vec::new();
+
+    event.push(0x09); // wall_time: field 1, fixed 64 bits.
+    event.extend_from_slice(&wall_time.to_le_bytes());
+    event.push(0x1a); // file_version: field 3, length delimited.
+    encode_bytes(&mut event, b"brain.Event:2");
+
+    event
+}
+
+/// Encode an `Event` protobuf message containing a `Summary` with a single scalar value.
+fn encode_scalar_event(wall_time: f64, step: i64, tag: &str, value: f32) -> Vec<u8> {
+    let mut summary_value = Vec::new();
+    summary_value.push(0x0a); // tag: field 1, length delimited.
+    encode_bytes(&mut summary_value, tag.as_bytes());
+    summary_value.push(0x15); // simple_value: field 2, fixed 32 bits.
+    summary_value.extend_from_slice(&value.to_le_bytes());
+
+    let mut summary = Vec::new();
+    summary.push(0x0a); // value: field 1, length delimited.
+    encode_bytes(&mut summary, &summary_value);
+
+    let mut event = Vec::new();
+    event.push(0x09); // wall_time: field 1, fixed 64 bits.
+    event.extend_from_slice(&wall_time.to_le_bytes());
+    event.push(0x10); // step: field 2, varint.
+    encode_varint(&mut event, step as u64);
+    event.push(0x2a); // summary: field 5, length delimited.
+    encode_bytes(&mut event, &summary);
+
+    event
+}
+
+fn encode_bytes(buffer: &mut Vec<u8>, bytes: &[u8]) {
+    encode_varint(buffer, bytes.len() as u64);
+    buffer.extend_from_slice(bytes);
+}
+
+fn encode_varint(buffer: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buffer.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+/// Encode the data in the TFRecord format used by event files.
+fn encode_record(data: &[u8]) -> Vec<u8> {
+    let length = (data.len() as u64).to_le_bytes();
+    let mut record = Vec::with_capacity(data.len() + 16);
+
+    record.extend_from_slice(&length);
+    record.extend_from_slice(&masked_crc32c(&length).to_le_bytes());
+    record.extend_from_slice(data);
+    record.extend_from_slice(&masked_crc32c(data).to_le_bytes());
+
+    record
+}
+
+fn masked_crc32c(data: &[u8]) -> u32 {
+    let crc = crc32c(data);
+    crc.rotate_left(17).wrapping_add(0xa282ead8)
+}
+
+fn crc32c(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            let mask = (!(crc & 1)).wrapping_add(1);
+            crc = (crc >> 1) ^ (0x82f63b78 & mask);
+        }
+    }
+
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::train::metric::RunningMetricResult;
+
+    #[test]
+    fn test_crc32c_check_value() {
+        assert_eq!(crc32c(b"123456789"), 0xe3069283);
+    }
+
+    #[test]
+    fn test_should_write_scalar_events() {
+        let directory = std::env::temp_dir().join(format!(
+            "burn-tensorboard-logger-test-{}",
+            std::process::id()
+        ));
+        std::fs::remove_dir_all(&directory).ok();
+        let directory = directory.to_str().unwrap();
+
+        let mut logger = TensorBoardMetricLogger::new(directory).unwrap();
+        for step in 0..3 {
+            let state = RunningMetricResult::new(
+                "Loss".to_string(),
+                String::new(),
+                String::new(),
+                String::new(),
+            );
+            logger.log_numeric(&state, step as f64);
+        }
+        let state = RunningMetricResult::new(
+            "Accuracy".to_string(),
+            String::new(),
+            String::new(),
+            String::new(),
+        );
+        logger.log_numeric(&state, 0.5);
+
+        let file_path = std::fs::read_dir(directory)
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap()
+            .path();
+        let bytes = std::fs::read(file_path).unwrap();
+        let events = decode_records(&bytes);
+
+        assert_eq!(events.len(), 5);
+        assert!(contains(&events[0], b"brain.Event:2"));
+        for event in events[1..4].iter() {
+            assert!(contains(event, b"Loss"));
+        }
+        assert!(contains(&events[4], b"Accuracy"));
+        assert!(contains(&events[4], &0.5f32.to_le_bytes()));
+    }
+
+    fn decode_records(bytes: &[u8]) -> Vec<Vec<u8>> {
+        let mut records = Vec::new();
+        let mut position = 0;
+
+        while position < bytes.len() {
+            let length = &bytes[position..position + 8];
+            let length_crc = &bytes[position + 8..position + 12];
+            assert_eq!(length_crc, masked_crc32c(length).to_le_bytes());
+
+            let length = u64::from_le_bytes(length.try_into().unwrap()) as usize;
+            let data = &bytes[position + 12..position + 12 + length];
+            let data_crc = &bytes[position + 12 + length..position + 16 + length];
+            assert_eq!(data_crc, masked_crc32c(data).to_le_bytes());
+
+            records.push(data.to_vec());
+            position += 16 + length;
+        }
+
+        records
+    }
+
+    fn contains(data: &[u8], pattern: &[u8]) -> bool {
+        data.windows(pattern.len()).any(|window| window == pattern)
+    }
+}
@@ -1,56 +1,46 @@
 use super::RunningMetricResult;
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
-use crate::train::metric::{Metric, MetricStateDyn, Numeric};
+use crate::train::metric::{Metric, MetricMetadata, MetricStateDyn, Numeric};
 
+/// The accuracy metric.
+///
+/// The predicted class is the `argmax` of the outputs over the class dimension. The targets can
+/// either be one-hot encoded with the same shape as the outputs, or be the class indexes as an
+/// integer tensor of shape `[batch_size]`.
 pub struct AccuracyMetric {
     current: f64,
-    count: usize,
+    count_current: usize,
+    count_total: usize,
     total: usize,
 }
 
 impl AccuracyMetric {
     pub fn new() -> Self {
         Self {
-            count: 0,
+            count_total: 0,
+            count_current: 0,
             current: 0.0,
             total: 0,
         }
     }
-}
 
-impl Default for AccuracyMetric {
-    fn default() -> Self {
-        Self::new()
-    }
-}
+    fn update_classes<B: Backend>(
+        &mut self,
+        predictions: Tensor<B, 1>,
+        targets: Tensor<B, 1>,
+    ) -> MetricStateDyn {
+        let count_current = targets.shape().dims[0];
 
-impl Numeric for AccuracyMetric {
-    fn value(&self) -> f64 {
-        self.current * 100.0
-    }
-}
+        let total_current = predictions.equal(&targets).to_int().sum().to_data().value[0] as usize;
 
-impl<B: Backend> Metric<(Tensor<B, 2>, Tensor<B, 2>)> for AccuracyMetric {
-    fn update(&mut self, batch: &(Tensor<B, 2>, Tensor<B, 2>)) -> MetricStateDyn {
-        let (outputs, targets) = batch;
-        let logits_outputs = outputs.argmax(1).to_device(B::Device::default());
-        let logits_targets = targets.argmax(1).to_device(B::Device::default());
-        let count_current = logits_targets.shape().dims[0];
-
-        let total_current = logits_outputs
-            .equal(&logits_targets)
-            .to_int()
-            .sum()
-            .to_data()
-            .value[0] as usize;
-
-        self.count += count_current;
+        self.count_total += count_current;
+        self.count_current = count_current;
         self.total += total_current;
         self.current = total_current as f64 / count_current as f64;
 
-        let name = String::from("Accurracy");
-        let running = self.total as f64 / self.count as f64;
+        let name = self.name();
+        let running = self.total as f64 / self.count_total as f64;
         let raw_running = format!("{}", running);
         let raw_current = format!("{}", self.current);
         let formatted = format!(
@@ -67,9 +57,145 @@ impl<B: Backend> Metric<(Tensor<B, 2>, Tensor<B, 2>)> for AccuracyMetric {
         })
     }
 
-    fn clear(&mut self) {
-        self.count = 0;
+    fn clear_state(&mut self) {
+        self.count_total = 0;
+        self.count_current = 0;
         self.total = 0;
         self.current = 0.0;
     }
 }
+
+impl Default for AccuracyMetric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Numeric for AccuracyMetric {
+    fn name(&self) -> String {
+        String::from("Accuracy")
+    }
+
+    fn value(&self) -> f64 {
+        self.current * 100.0
+    }
+
+    fn count(&self) -> usize {
+        self.count_current
+    }
+}
+
+impl<B: Backend> Metric<(Tensor<B, 2>, Tensor<B, 2>)> for AccuracyMetric {
+    fn update(
+        &mut self,
+        batch: &(Tensor<B, 2>, Tensor<B, 2>),
+        _metadata: &MetricMetadata,
+    ) -> MetricStateDyn {
+        let (outputs, targets) = batch;
+        let [batch_size, _n_classes] = *outputs.dims();
+
+        let predictions = outputs.argmax(1).to_device(B::Device::default());
+        let targets = targets.argmax(1).to_device(B::Device::default());
+
+        self.update_classes(
+            predictions.reshape([batch_size]),
+            targets.reshape([batch_size]),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.clear_state();
+    }
+}
+
+impl<B: Backend> Metric<(Tensor<B, 2>, Tensor<B::IntegerBackend, 1>)> for AccuracyMetric {
+    fn update(
+        &mut self,
+        batch: &(Tensor<B, 2>, Tensor<B::IntegerBackend, 1>),
+        _metadata: &MetricMetadata,
+    ) -> MetricStateDyn {
+        let (outputs, targets) = batch;
+        let [batch_size, _n_classes] = *outputs.dims();
+
+        let predictions = outputs.argmax(1).to_device(B::Device::default());
+        let targets = targets.to_device(B::Device::default());
+
+        self.update_classes(predictions.reshape([batch_size]), targets)
+    }
+
+    fn clear(&mut self) {
+        self.clear_state();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Data;
+    use crate::TestBackend;
+
+    type IntTensor<const D: usize> = Tensor<<TestBackend as Backend>::IntegerBackend, D>;
+
+    fn update(metric: &mut AccuracyMetric, outputs: Tensor<TestBackend, 2>, targets: IntTensor<1>) {
+        let _state = <AccuracyMetric as Metric<(Tensor<TestBackend, 2>, IntTensor<1>)>>::update(
+            metric,
+            &(outputs, targets),
+            &MetricMetadata::fake(),
+        );
+    }
+
+    #[test]
+    fn test_accuracy_with_integer_targets_accumulates_over_batches() {
+        let mut metric = AccuracyMetric::new();
+
+        // 2 correct out of 3.
+        let outputs = Tensor::from_data(Data::from([[0.9, 0.1], [0.2, 0.8], [0.7, 0.3]]));
+        let targets = IntTensor::from_data(Data::from([0, 1, 1]));
+        update(&mut metric, outputs, targets);
+        assert_eq!(metric.value(), 2.0 / 3.0 * 100.0);
+
+        // 0 correct out of 1.
+        let outputs = Tensor::from_data(Data::from([[0.1, 0.5, 0.4]]));
+        let targets = IntTensor::from_data(Data::from([2]));
+        update(&mut metric, outputs, targets);
+        assert_eq!(metric.value(), 0.0);
+
+        // 4 correct out of 4.
+        let outputs = Tensor::from_data(Data::from([
+            [0.8, 0.1, 0.1],
+            [0.1, 0.8, 0.1],
+            [0.1, 0.1, 0.8],
+            [0.8, 0.1, 0.1],
+        ]));
+        let targets = IntTensor::from_data(Data::from([0, 1, 2, 0]));
+        update(&mut metric, outputs, targets);
+        assert_eq!(metric.value(), 100.0);
+
+        assert_eq!(metric.total, 6);
+        assert_eq!(metric.count_total, 8);
+    }
+
+    #[test]
+    fn test_accuracy_with_one_hot_targets() {
+        let mut metric = AccuracyMetric::new();
+        let outputs = Tensor::<TestBackend, 2>::from_data(Data::from([[0.9, 0.1], [0.2, 0.8]]));
+        let targets = Tensor::<TestBackend, 2>::from_data(Data::from([[1.0, 0.0], [1.0, 0.0]]));
+
+        let _state = metric.update(&(outputs, targets), &MetricMetadata::fake());
+
+        assert_eq!(metric.value(), 50.0);
+    }
+
+    #[test]
+    fn test_clear_should_reset_running_accuracy() {
+        let mut metric = AccuracyMetric::new();
+        let outputs = Tensor::from_data(Data::from([[0.9, 0.1]]));
+        let targets = IntTensor::from_data(Data::from([0]));
+        update(&mut metric, outputs, targets);
+
+        <AccuracyMetric as Metric<(Tensor<TestBackend, 2>, IntTensor<1>)>>::clear(&mut metric);
+
+        assert_eq!(metric.value(), 0.0);
+        assert_eq!(metric.count_total, 0);
+    }
+}
@@ -1,8 +1,51 @@
+use crate::data::dataloader::Progress;
+use crate::train::LearnerItem;
+
 pub trait Metric<T>: Send + Sync {
-    fn update(&mut self, item: &T) -> MetricStateDyn;
+    fn update(&mut self, item: &T, metadata: &MetricMetadata) -> MetricStateDyn;
     fn clear(&mut self);
 }
 
+/// Information about the training progress given to the metrics with each item.
+#[derive(new, Clone, Debug)]
+pub struct MetricMetadata {
+    pub progress: Progress,
+    pub epoch: usize,
+    pub epoch_total: usize,
+    pub iteration: usize,
+    /// The learning rate of the optimizer, only known for training items.
+    pub lr: Option<f64>,
+}
+
+impl<T> From<&LearnerItem<T>> for MetricMetadata {
+    fn from(item: &LearnerItem<T>) -> Self {
+        Self {
+            progress: item.progress.clone(),
+            epoch: item.epoch,
+            epoch_total: item.epoch_total,
+            iteration: item.iteration,
+            lr: item.lr,
+        }
+    }
+}
+
+#[cfg(test)]
+impl MetricMetadata {
+    /// Metadata of the first item of a training without learning rate.
+    pub fn fake() -> Self {
+        Self {
+            progress: Progress {
+                items_processed: 1,
+                items_total: 1,
+            },
+            epoch: 1,
+            epoch_total: 1,
+            iteration: 1,
+            lr: None,
+        }
+    }
+}
+
 pub trait MetricState {
     fn name(&self) -> String;
     fn pretty(&self) -> String;
@@ -10,7 +53,23 @@ pub trait MetricState {
 }
 
 pub trait Numeric {
+    /// The name of the metric, which is the same as the name of its [state](MetricState).
+    ///
+    /// Defaults to the name of the type, so the metrics monitored by
+    /// [early stopping](crate::train::LearnerBuilder::early_stopping) must override it.
+    fn name(&self) -> String {
+        std::any::type_name::<Self>().to_string()
+    }
     fn value(&self) -> f64;
+    /// The number of samples of the last update, by which its [value](Numeric::value) is
+    /// weighted when the metric is aggregated over an epoch.
+    ///
+    /// A metric whose value is the mean over the samples of the last batch should return the
+    /// batch size, so that the aggregate is the mean over all samples even when the batches
+    /// don't have the same size.
+    fn count(&self) -> usize {
+        1
+    }
 }
 
 pub type MetricStateDyn = Box<dyn MetricState>;
@@ -1,5 +1,5 @@
 use super::RunningMetricResult;
-use crate::train::metric::{Metric, MetricState};
+use crate::train::metric::{Metric, MetricMetadata, MetricState};
 use nvml_wrapper::Nvml;
 
 pub struct CUDAMetric {
@@ -21,7 +21,7 @@ impl Default for CUDAMetric {
 }
 
 impl<T> Metric<T> for CUDAMetric {
-    fn update(&mut self, _item: &T) -> Box<dyn MetricState> {
+    fn update(&mut self, _item: &T, _metadata: &MetricMetadata) -> Box<dyn MetricState> {
         let name = String::from("Cuda");
 
         let mut formatted = String::new();
@@ -2,10 +2,11 @@ use crate::{
     data::dataloader::Progress,
     train::{
         logger::MetricLogger,
-        metric::{Metric, MetricStateDyn, Numeric},
+        metric::{Metric, MetricMetadata, MetricStateDyn, Numeric},
         LearnerCallback, LearnerItem,
     },
 };
+use std::sync::mpsc;
 
 pub struct TrainingProgress {
     pub progress: Progress,
@@ -52,6 +53,14 @@ where
     logger_train: Box<dyn MetricLogger>,
     logger_valid: Box<dyn MetricLogger>,
     renderer: Box<dyn DashboardRenderer>,
+    early_stopping: Option<DashboardEarlyStopping>,
+}
+
+struct DashboardEarlyStopping {
+    metric_name: String,
+    sender: mpsc::Sender<f64>,
+    sum: f64,
+    count: usize,
 }
 
 impl<T, V> Dashboard<T, V>
@@ -72,9 +81,37 @@ where
             logger_train,
             logger_valid,
             renderer,
+            early_stopping: None,
         }
     }
 
+    pub fn replace_loggers(
+        &mut self,
+        logger_train: Box<dyn MetricLogger>,
+        logger_valid: Box<dyn MetricLogger>,
+    ) {
+        self.logger_train = logger_train;
+        self.logger_valid = logger_valid;
+    }
+
+    /// Whether a numeric validation metric with the given name is registered.
+    pub fn has_valid_numeric(&self, metric_name: &str) -> bool {
+        self.metrics_valid_numeric
+            .iter()
+            .any(|metric| metric.name() == metric_name)
+    }
+
+    /// Send the mean value of the given numeric validation metric at the end of each epoch,
+    /// weighted by the [number of samples](Numeric::count) of each item.
+    pub fn monitor_valid(&mut self, metric_name: &str, sender: mpsc::Sender<f64>) {
+        self.early_stopping = Some(DashboardEarlyStopping {
+            metric_name: metric_name.to_string(),
+            sender,
+            sum: 0.0,
+            count: 0,
+        });
+    }
+
     pub fn register_train<M: Metric<T> + 'static>(&mut self, metric: M) {
         self.metrics_train
             .push(Box::new(MetricWrapper::new(metric)));
@@ -121,7 +158,7 @@ where
         }
         for metric in self.metrics_train_numeric.iter_mut() {
             let (state, value) = metric.update(&item);
-            self.logger_train.log(state.as_ref());
+            self.logger_train.log_numeric(state.as_ref(), value);
 
             self.renderer
                 .update_train(DashboardMetricState::Numeric(state, value));
@@ -139,7 +176,14 @@ where
         }
         for metric in self.metrics_valid_numeric.iter_mut() {
             let (state, value) = metric.update(&item);
-            self.logger_valid.log(state.as_ref());
+            self.logger_valid.log_numeric(state.as_ref(), value);
+
+            if let Some(early_stopping) = &mut self.early_stopping {
+                if early_stopping.metric_name == state.name() {
+                    early_stopping.sum += value * metric.count() as f64;
+                    early_stopping.count += metric.count();
+                }
+            }
 
             self.renderer
                 .update_valid(DashboardMetricState::Numeric(state, value));
@@ -164,11 +208,19 @@ where
         for metric in self.metrics_valid_numeric.iter_mut() {
             metric.clear();
         }
+        if let Some(early_stopping) = &mut self.early_stopping {
+            let value = early_stopping.sum / early_stopping.count as f64;
+            early_stopping.sender.send(value).ok();
+            early_stopping.sum = 0.0;
+            early_stopping.count = 0;
+        }
         self.logger_valid.epoch(epoch + 1);
     }
 }
 
 trait DashboardNumericMetric<T>: Send + Sync {
+    fn name(&self) -> String;
+    fn count(&self) -> usize;
     fn update(&mut self, item: &LearnerItem<T>) -> (MetricStateDyn, f64);
     fn clear(&mut self);
 }
@@ -188,8 +240,16 @@ where
     T: 'static,
     M: Metric<T> + Numeric + 'static,
 {
+    fn name(&self) -> String {
+        self.metric.name()
+    }
+
+    fn count(&self) -> usize {
+        self.metric.count()
+    }
+
     fn update(&mut self, item: &LearnerItem<T>) -> (MetricStateDyn, f64) {
-        let update = self.metric.update(&item.item);
+        let update = self.metric.update(&item.item, &MetricMetadata::from(item));
         let numeric = self.metric.value();
 
         (update, numeric)
@@ -206,7 +266,7 @@ where
     M: Metric<T> + 'static,
 {
     fn update(&mut self, item: &LearnerItem<T>) -> MetricStateDyn {
-        self.metric.update(&item.item) as _
+        self.metric.update(&item.item, &MetricMetadata::from(item)) as _
     }
 
     fn clear(&mut self) {
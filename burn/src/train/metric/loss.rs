@@ -2,18 +2,24 @@ use super::RunningMetricResult;
 use crate::tensor::backend::Backend;
 use crate::tensor::ElementConversion;
 use crate::tensor::Tensor;
-use crate::train::metric::{Metric, MetricState, Numeric};
+use crate::train::metric::{Metric, MetricMetadata, MetricState, Numeric};
 
+/// The loss metric.
+///
+/// The loss of each batch can be given with its number of samples, so that the running loss is
+/// the mean over all samples, otherwise each batch has the same weight.
 pub struct LossMetric {
     current: f64,
-    count: usize,
+    count_current: usize,
+    count_total: usize,
     total: f64,
 }
 
 impl LossMetric {
     pub fn new() -> Self {
         Self {
-            count: 0,
+            count_total: 0,
+            count_current: 0,
             current: 0.0,
             total: 0.0,
         }
@@ -27,21 +33,46 @@ impl Default for LossMetric {
 }
 
 impl Numeric for LossMetric {
+    fn name(&self) -> String {
+        String::from("Loss")
+    }
+
     fn value(&self) -> f64 {
         self.current * 100.0
     }
+
+    fn count(&self) -> usize {
+        self.count_current
+    }
 }
 
 impl<B: Backend> Metric<Tensor<B, 1>> for LossMetric {
-    fn update(&mut self, loss: &Tensor<B, 1>) -> Box<dyn MetricState> {
-        let loss = f64::from_elem(loss.to_data().value[0]);
+    fn update(&mut self, loss: &Tensor<B, 1>, metadata: &MetricMetadata) -> Box<dyn MetricState> {
+        <LossMetric as Metric<(Tensor<B, 1>, usize)>>::update(self, &(loss.clone(), 1), metadata)
+    }
+
+    fn clear(&mut self) {
+        <LossMetric as Metric<(Tensor<B, 1>, usize)>>::clear(self);
+    }
+}
 
-        self.count += 1;
-        self.total += loss;
+/// The mean loss of a batch with its number of samples.
+impl<B: Backend> Metric<(Tensor<B, 1>, usize)> for LossMetric {
+    fn update(
+        &mut self,
+        batch: &(Tensor<B, 1>, usize),
+        _metadata: &MetricMetadata,
+    ) -> Box<dyn MetricState> {
+        let (loss, count) = batch;
+        let loss = f64::from_elem(loss.clone().into_scalar());
+
+        self.count_total += count;
+        self.count_current = *count;
+        self.total += loss * *count as f64;
         self.current = loss;
 
-        let name = String::from("Loss");
-        let running = self.total / self.count as f64;
+        let name = self.name();
+        let running = self.total / self.count_total as f64;
         let raw_running = format!("{}", running);
         let raw_current = format!("{}", self.current);
         let formatted = format!("running {:.3} current {:.3}", running, self.current);
@@ -55,8 +86,40 @@ impl<B: Backend> Metric<Tensor<B, 1>> for LossMetric {
     }
 
     fn clear(&mut self) {
-        self.count = 0;
+        self.count_total = 0;
+        self.count_current = 0;
         self.total = 0.0;
         self.current = 0.0;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Data;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_loss_weighted_by_batch_size_is_the_mean_over_samples() {
+        let mut metric = LossMetric::new();
+        let mut state = None;
+        let mut sum = 0.0;
+        let mut count = 0;
+
+        for (loss, batch_size) in [(1.0, 4), (2.0, 4), (4.0, 1)] {
+            let loss = Tensor::<TestBackend, 1>::from_data(Data::from([loss]));
+            state = Some(
+                <LossMetric as Metric<(Tensor<TestBackend, 1>, usize)>>::update(
+                    &mut metric,
+                    &(loss, batch_size),
+                    &MetricMetadata::fake(),
+                ),
+            );
+            sum += metric.value() * metric.count() as f64;
+            count += metric.count();
+        }
+
+        assert_eq!(sum / count as f64, 100.0 * 16.0 / 9.0);
+        assert_eq!(state.unwrap().pretty(), "running 1.778 current 4.000");
+    }
+}
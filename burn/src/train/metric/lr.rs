@@ -0,0 +1,87 @@
+use super::RunningMetricResult;
+use crate::train::metric::{Metric, MetricMetadata, MetricStateDyn, Numeric};
+
+/// Track the learning rate of the optimizer, which is given by the learner with each training item.
+///
+/// The last known learning rate is kept for items without one, such as validation items.
+pub struct LearningRateMetric {
+    current: f64,
+}
+
+impl LearningRateMetric {
+    pub fn new() -> Self {
+        Self { current: 0.0 }
+    }
+}
+
+impl Default for LearningRateMetric {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Numeric for LearningRateMetric {
+    fn name(&self) -> String {
+        String::from("Learning Rate")
+    }
+
+    fn value(&self) -> f64 {
+        self.current
+    }
+}
+
+impl<T> Metric<T> for LearningRateMetric {
+    fn update(&mut self, _item: &T, metadata: &MetricMetadata) -> MetricStateDyn {
+        if let Some(lr) = metadata.lr {
+            self.current = lr;
+        }
+
+        let name = self.name();
+        let raw = format!("{}", self.current);
+        let formatted = format!("{:.2e}", self.current);
+
+        Box::new(RunningMetricResult {
+            name,
+            formatted,
+            raw_running: raw.clone(),
+            raw_current: raw,
+        })
+    }
+
+    fn clear(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata(lr: Option<f64>) -> MetricMetadata {
+        MetricMetadata {
+            lr,
+            ..MetricMetadata::fake()
+        }
+    }
+
+    #[test]
+    fn should_report_the_learning_rates_it_is_fed() {
+        let mut metric = LearningRateMetric::new();
+
+        for lr in [0.1, 0.05, 0.001] {
+            let state = metric.update(&(), &metadata(Some(lr)));
+
+            assert_eq!(metric.value(), lr);
+            assert_eq!(state.serialize(), format!("{}", lr));
+        }
+    }
+
+    #[test]
+    fn should_keep_the_last_learning_rate_without_one() {
+        let mut metric = LearningRateMetric::new();
+        metric.update(&(), &metadata(Some(0.1)));
+
+        let state = metric.update(&(), &metadata(None));
+
+        assert_eq!(metric.value(), 0.1);
+        assert_eq!(state.pretty(), "1.00e-1");
+    }
+}
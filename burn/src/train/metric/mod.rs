@@ -4,8 +4,14 @@ mod acc;
 mod base;
 mod cuda;
 mod loss;
+mod lr;
+mod precision_recall;
+mod running;
 
 pub use acc::*;
 pub use base::*;
 pub use cuda::*;
 pub use loss::*;
+pub use lr::*;
+pub use precision_recall::*;
+pub use running::*;
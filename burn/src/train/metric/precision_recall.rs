@@ -0,0 +1,248 @@
+use super::RunningMetricResult;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+use crate::train::metric::{Metric, MetricMetadata, MetricStateDyn, Numeric};
+
+/// Metric computing the macro-averaged precision, recall and F1 score of a classifier.
+///
+/// The true positives, false positives and false negatives of each class are accumulated across
+/// batches until the metric is cleared. The predicted class is the `argmax` of the outputs over
+/// the class dimension.
+///
+/// # Panics
+///
+/// When updated with a target or a prediction that isn't a class index between 0 and
+/// `num_classes - 1`.
+pub struct PrecisionRecallMetric {
+    num_classes: usize,
+    true_positives: Vec<usize>,
+    false_positives: Vec<usize>,
+    false_negatives: Vec<usize>,
+}
+
+impl PrecisionRecallMetric {
+    pub fn new(num_classes: usize) -> Self {
+        Self {
+            num_classes,
+            true_positives: vec![0; num_classes],
+            false_positives: vec![0; num_classes],
+            false_negatives: vec![0; num_classes],
+        }
+    }
+
+    /// The macro-averaged precision.
+    pub fn precision(&self) -> f64 {
+        self.macro_average(|class| {
+            ratio(
+                self.true_positives[class],
+                self.true_positives[class] + self.false_positives[class],
+            )
+        })
+    }
+
+    /// The macro-averaged recall.
+    pub fn recall(&self) -> f64 {
+        self.macro_average(|class| {
+            ratio(
+                self.true_positives[class],
+                self.true_positives[class] + self.false_negatives[class],
+            )
+        })
+    }
+
+    /// The macro-averaged F1 score.
+    pub fn f1(&self) -> f64 {
+        self.macro_average(|class| {
+            ratio(
+                2 * self.true_positives[class],
+                2 * self.true_positives[class]
+                    + self.false_positives[class]
+                    + self.false_negatives[class],
+            )
+        })
+    }
+
+    fn macro_average<F: Fn(usize) -> f64>(&self, func: F) -> f64 {
+        let sum: f64 = (0..self.num_classes).map(func).sum();
+        sum / self.num_classes as f64
+    }
+
+    fn update_classes(&mut self, predictions: &[i64], targets: &[i64]) -> MetricStateDyn {
+        let predictions: Vec<usize> = predictions.iter().map(|p| self.class(*p)).collect();
+        let targets: Vec<usize> = targets.iter().map(|t| self.class(*t)).collect();
+
+        for (prediction, target) in predictions.into_iter().zip(targets) {
+            if prediction == target {
+                self.true_positives[target] += 1;
+            } else {
+                self.false_positives[prediction] += 1;
+                self.false_negatives[target] += 1;
+            }
+        }
+
+        let name = self.name();
+        let f1 = self.f1();
+        let raw = format!("{}", f1);
+        let formatted = format!(
+            "precision {:.2} % recall {:.2} % f1 {:.2} %",
+            100.0 * self.precision(),
+            100.0 * self.recall(),
+            100.0 * f1
+        );
+
+        Box::new(RunningMetricResult {
+            name,
+            formatted,
+            raw_running: raw.clone(),
+            raw_current: raw,
+        })
+    }
+
+    fn class(&self, index: i64) -> usize {
+        match usize::try_from(index) {
+            Ok(class) if class < self.num_classes => class,
+            _ => panic!(
+                "Invalid class index {}, the metric has {} classes",
+                index, self.num_classes
+            ),
+        }
+    }
+
+    fn clear_state(&mut self) {
+        self.true_positives = vec![0; self.num_classes];
+        self.false_positives = vec![0; self.num_classes];
+        self.false_negatives = vec![0; self.num_classes];
+    }
+}
+
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 {
+        return 0.0;
+    }
+
+    numerator as f64 / denominator as f64
+}
+
+impl Numeric for PrecisionRecallMetric {
+    fn name(&self) -> String {
+        String::from("F1")
+    }
+
+    fn value(&self) -> f64 {
+        self.f1() * 100.0
+    }
+}
+
+impl<B: Backend> Metric<(Tensor<B, 2>, Tensor<B, 2>)> for PrecisionRecallMetric {
+    fn update(
+        &mut self,
+        batch: &(Tensor<B, 2>, Tensor<B, 2>),
+        _metadata: &MetricMetadata,
+    ) -> MetricStateDyn {
+        let (outputs, targets) = batch;
+        let predictions = outputs.argmax(1).into_data();
+        let targets = targets.argmax(1).into_data();
+
+        self.update_classes(&predictions.value, &targets.value)
+    }
+
+    fn clear(&mut self) {
+        self.clear_state();
+    }
+}
+
+impl<B: Backend> Metric<(Tensor<B, 2>, Tensor<B::IntegerBackend, 1>)> for PrecisionRecallMetric {
+    fn update(
+        &mut self,
+        batch: &(Tensor<B, 2>, Tensor<B::IntegerBackend, 1>),
+        _metadata: &MetricMetadata,
+    ) -> MetricStateDyn {
+        let (outputs, targets) = batch;
+        let predictions = outputs.argmax(1).into_data();
+        let targets = targets.to_data();
+
+        self.update_classes(&predictions.value, &targets.value)
+    }
+
+    fn clear(&mut self) {
+        self.clear_state();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Data;
+    use crate::TestBackend;
+
+    type IntTensor<const D: usize> = Tensor<<TestBackend as Backend>::IntegerBackend, D>;
+
+    #[test]
+    fn test_macro_f1_over_multiple_batches() {
+        let mut metric = PrecisionRecallMetric::new(3);
+
+        let outputs = Tensor::<TestBackend, 2>::from_data(Data::from([
+            [0.8, 0.1, 0.1],
+            [0.1, 0.8, 0.1],
+            [0.1, 0.1, 0.8],
+        ]));
+        let targets = IntTensor::from_data(Data::from([0, 1, 1]));
+        let _state = metric.update(&(outputs, targets), &MetricMetadata::fake());
+
+        let outputs = Tensor::<TestBackend, 2>::from_data(Data::from([
+            [0.8, 0.1, 0.1],
+            [0.8, 0.1, 0.1],
+            [0.1, 0.1, 0.8],
+        ]));
+        let targets = IntTensor::from_data(Data::from([0, 2, 2]));
+        let _state = metric.update(&(outputs, targets), &MetricMetadata::fake());
+
+        // Predictions: [0, 1, 2, 0, 0, 2]
+        // Targets:     [0, 1, 1, 0, 2, 2]
+        //
+        // Class 0: tp = 2, fp = 1, fn = 0 => p = 2/3, r = 1,   f1 = 4/5
+        // Class 1: tp = 1, fp = 0, fn = 1 => p = 1,   r = 1/2, f1 = 2/3
+        // Class 2: tp = 1, fp = 1, fn = 1 => p = 1/2, r = 1/2, f1 = 1/2
+        let precision = (2.0 / 3.0 + 1.0 + 0.5) / 3.0;
+        let recall = (1.0 + 0.5 + 0.5) / 3.0;
+        let f1 = (0.8 + 2.0 / 3.0 + 0.5) / 3.0;
+
+        assert!((metric.precision() - precision).abs() < 1e-9);
+        assert!((metric.recall() - recall).abs() < 1e-9);
+        assert!((metric.f1() - f1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_clear_should_reset_counts() {
+        let mut metric = PrecisionRecallMetric::new(2);
+        let outputs = Tensor::<TestBackend, 2>::from_data(Data::from([[0.8, 0.2]]));
+        let targets = IntTensor::from_data(Data::from([0]));
+        let _state = metric.update(&(outputs, targets), &MetricMetadata::fake());
+
+        <PrecisionRecallMetric as Metric<(Tensor<TestBackend, 2>, IntTensor<1>)>>::clear(
+            &mut metric,
+        );
+
+        assert_eq!(metric.f1(), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid class index -1, the metric has 2 classes")]
+    fn test_negative_target_should_panic() {
+        let mut metric = PrecisionRecallMetric::new(2);
+        let outputs = Tensor::<TestBackend, 2>::from_data(Data::from([[0.8, 0.2]]));
+        let targets = IntTensor::from_data(Data::from([-1]));
+
+        let _state = metric.update(&(outputs, targets), &MetricMetadata::fake());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid class index 2, the metric has 2 classes")]
+    fn test_out_of_range_prediction_should_panic() {
+        let mut metric = PrecisionRecallMetric::new(2);
+        let outputs = Tensor::<TestBackend, 2>::from_data(Data::from([[0.1, 0.2, 0.7]]));
+        let targets = IntTensor::from_data(Data::from([0]));
+
+        let _state = metric.update(&(outputs, targets), &MetricMetadata::fake());
+    }
+}
@@ -0,0 +1,196 @@
+use crate::train::metric::{Metric, MetricMetadata, MetricState, MetricStateDyn, Numeric};
+use std::collections::VecDeque;
+
+/// Wrap a [numeric](Numeric) metric to track the minimum, the maximum and the moving average of
+/// its values across batches.
+///
+/// The minimum and maximum are kept when the metric is cleared at the end of an epoch, so they are
+/// the best values seen so far during training, while the moving average restarts with the wrapped
+/// metric.
+pub struct RunningMetric<M> {
+    metric: M,
+    window_size: usize,
+    window: VecDeque<f64>,
+    min: Option<f64>,
+    max: Option<f64>,
+}
+
+impl<M> RunningMetric<M> {
+    /// Create the wrapper, with a moving average over the last `window_size` values.
+    ///
+    /// # Panics
+    ///
+    /// If the window size is zero.
+    pub fn new(metric: M, window_size: usize) -> Self {
+        if window_size == 0 {
+            panic!("The window size of the moving average can't be zero");
+        }
+
+        Self {
+            metric,
+            window_size,
+            window: VecDeque::with_capacity(window_size),
+            min: None,
+            max: None,
+        }
+    }
+
+    /// The smallest value seen so far.
+    pub fn min(&self) -> Option<f64> {
+        self.min
+    }
+
+    /// The largest value seen so far.
+    pub fn max(&self) -> Option<f64> {
+        self.max
+    }
+
+    /// The average of the last values, up to the window size.
+    pub fn average(&self) -> Option<f64> {
+        if self.window.is_empty() {
+            return None;
+        }
+
+        Some(self.window.iter().sum::<f64>() / self.window.len() as f64)
+    }
+
+    fn register(&mut self, value: f64) {
+        if self.window.len() == self.window_size {
+            self.window.pop_front();
+        }
+        self.window.push_back(value);
+
+        self.min = Some(self.min.map_or(value, |min| f64::min(min, value)));
+        self.max = Some(self.max.map_or(value, |max| f64::max(max, value)));
+    }
+}
+
+impl<M: Numeric> Numeric for RunningMetric<M> {
+    fn name(&self) -> String {
+        self.metric.name()
+    }
+
+    fn value(&self) -> f64 {
+        self.metric.value()
+    }
+
+    fn count(&self) -> usize {
+        self.metric.count()
+    }
+}
+
+impl<T, M: Metric<T> + Numeric> Metric<T> for RunningMetric<M> {
+    fn update(&mut self, item: &T, metadata: &MetricMetadata) -> MetricStateDyn {
+        let state = self.metric.update(item, metadata);
+        self.register(self.metric.value());
+
+        Box::new(RunningStatsState {
+            state,
+            min: self.min.unwrap(),
+            max: self.max.unwrap(),
+            average: self.average().unwrap(),
+        })
+    }
+
+    fn clear(&mut self) {
+        self.metric.clear();
+        self.window.clear();
+    }
+}
+
+struct RunningStatsState {
+    state: MetricStateDyn,
+    min: f64,
+    max: f64,
+    average: f64,
+}
+
+impl MetricState for RunningStatsState {
+    fn name(&self) -> String {
+        self.state.name()
+    }
+
+    fn pretty(&self) -> String {
+        format!(
+            "{} min {:.3} max {:.3} average {:.3}",
+            self.state.pretty(),
+            self.min,
+            self.max,
+            self.average
+        )
+    }
+
+    fn serialize(&self) -> String {
+        self.state.serialize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::train::metric::RunningMetricResult;
+
+    struct ValueMetric {
+        current: f64,
+    }
+
+    impl Numeric for ValueMetric {
+        fn name(&self) -> String {
+            String::from("Value")
+        }
+
+        fn value(&self) -> f64 {
+            self.current
+        }
+    }
+
+    impl Metric<f64> for ValueMetric {
+        fn update(&mut self, item: &f64, _metadata: &MetricMetadata) -> MetricStateDyn {
+            self.current = *item;
+            let raw = format!("{}", item);
+
+            Box::new(RunningMetricResult::new(
+                self.name(),
+                raw.clone(),
+                raw.clone(),
+                raw,
+            ))
+        }
+
+        fn clear(&mut self) {
+            self.current = 0.0;
+        }
+    }
+
+    #[test]
+    fn should_track_min_max_and_moving_average() {
+        let mut metric = RunningMetric::new(ValueMetric { current: 0.0 }, 3);
+
+        let mut state = None;
+        for value in [4.0, 1.0, 6.0, 2.0, 5.0] {
+            state = Some(metric.update(&value, &MetricMetadata::fake()));
+        }
+
+        assert_eq!(metric.min(), Some(1.0));
+        assert_eq!(metric.max(), Some(6.0));
+        assert_eq!(metric.average(), Some(13.0 / 3.0));
+        assert_eq!(metric.value(), 5.0);
+        let state = state.unwrap();
+        assert_eq!(state.name(), "Value");
+        assert_eq!(state.pretty(), "5 min 1.000 max 6.000 average 4.333");
+        assert_eq!(state.serialize(), "5");
+    }
+
+    #[test]
+    fn clear_should_keep_min_and_max() {
+        let mut metric = RunningMetric::new(ValueMetric { current: 0.0 }, 3);
+        metric.update(&4.0, &MetricMetadata::fake());
+        metric.update(&1.0, &MetricMetadata::fake());
+
+        metric.clear();
+
+        assert_eq!(metric.min(), Some(1.0));
+        assert_eq!(metric.max(), Some(4.0));
+        assert_eq!(metric.average(), None);
+    }
+}
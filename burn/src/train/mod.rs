@@ -4,8 +4,10 @@ pub mod metric;
 
 mod base;
 mod callback;
+mod evaluator;
 mod learner;
 
 pub use base::*;
 pub use callback::*;
+pub use evaluator::*;
 pub use learner::*;
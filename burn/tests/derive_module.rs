@@ -1,4 +1,4 @@
-use burn::module::{Module, Param};
+use burn::module::{Module, Param, ParamId, ParamMapper, ParamVisitor};
 use burn::tensor::backend::Backend;
 use burn::tensor::{Distribution, Shape, Tensor};
 
@@ -96,3 +96,162 @@ mod num_params {
         assert_eq!(2 * 20 * 20, module.num_params());
     }
 }
+
+mod summary {
+    use super::*;
+
+    #[test]
+    fn should_prefix_param_names_with_the_field_names() {
+        let module = ModuleComposed::<TestBackend>::new();
+
+        let summary = module.summary();
+
+        let names: Vec<&str> = summary
+            .params
+            .iter()
+            .map(|param| param.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["weight", "basic.weight_basic"]);
+        assert_eq!(summary.num_params(), module.num_params());
+    }
+}
+
+mod visit_params {
+    use super::*;
+
+    struct NumElements {
+        num_visited: usize,
+        num_elements: usize,
+    }
+
+    impl<B: Backend> ParamVisitor<B> for NumElements {
+        fn visit<const D: usize>(&mut self, _id: &ParamId, tensor: &Tensor<B, D>) {
+            self.num_visited += 1;
+            self.num_elements += tensor.shape().num_elements();
+        }
+    }
+
+    #[test]
+    fn should_visit_all_params_composed() {
+        let module = ModuleComposed::<TestBackend>::new();
+        let mut visitor = NumElements {
+            num_visited: 0,
+            num_elements: 0,
+        };
+
+        module.visit_params(&mut visitor);
+
+        assert_eq!(visitor.num_visited, 2);
+        assert_eq!(visitor.num_elements, module.num_params());
+    }
+}
+
+mod map_params {
+    use super::*;
+
+    struct Zeros {
+        num_mapped: usize,
+    }
+
+    impl<B: Backend> ParamMapper<B> for Zeros {
+        fn map<const D: usize>(&mut self, _id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+            self.num_mapped += 1;
+            tensor.zeros_like()
+        }
+    }
+
+    #[test]
+    fn should_zero_all_params_composed() {
+        let mut module = ModuleComposed::<TestBackend>::new();
+        let mut mapper = Zeros { num_mapped: 0 };
+
+        module.map_params(&mut mapper);
+
+        assert_eq!(mapper.num_mapped, 2);
+        assert_eq!(
+            module.weight.to_data(),
+            Tensor::<TestBackend, 2>::zeros([20, 20]).to_data()
+        );
+        assert_eq!(
+            module.basic.weight_basic.to_data(),
+            Tensor::<TestBackend, 2>::zeros([20, 20]).to_data()
+        );
+    }
+}
+
+mod load_partial {
+    use super::*;
+    use burn::nn::{Linear, LinearConfig};
+
+    #[derive(Module, Debug)]
+    struct OneLayer<B: Backend> {
+        layer_1: Param<Linear<B>>,
+    }
+
+    #[derive(Module, Debug)]
+    struct TwoLayers<B: Backend> {
+        layer_1: Param<Linear<B>>,
+        layer_2: Param<Linear<B>>,
+    }
+
+    #[derive(Module, Debug)]
+    struct Renamed<B: Backend> {
+        backbone: Param<Linear<B>>,
+    }
+
+    #[derive(Module, Debug)]
+    struct WithParamKeys<B: Backend> {
+        data: Param<Linear<B>>,
+        id: Param<Linear<B>>,
+    }
+
+    fn linear() -> Param<Linear<TestBackend>> {
+        Param::new(Linear::new(&LinearConfig::new(4, 4)))
+    }
+
+    #[test]
+    fn should_load_matching_layer_and_keep_the_others() {
+        let one_layer = OneLayer::<TestBackend> { layer_1: linear() };
+        let mut two_layers = TwoLayers::<TestBackend> {
+            layer_1: linear(),
+            layer_2: linear(),
+        };
+        let layer_2_before = two_layers.layer_2.state();
+        assert_ne!(two_layers.layer_1.state(), one_layer.layer_1.state());
+
+        let skipped = two_layers.load_partial(&one_layer.state()).unwrap();
+
+        assert_eq!(skipped, vec!["layer_2".to_string()]);
+        assert_eq!(two_layers.layer_1.state(), one_layer.layer_1.state());
+        assert_eq!(two_layers.layer_2.state(), layer_2_before);
+    }
+
+    #[test]
+    fn should_key_state_by_param_name() {
+        let one_layer = OneLayer::<TestBackend> { layer_1: linear() };
+        let mut renamed = Renamed::<TestBackend> {
+            backbone: linear().with_name("layer_1"),
+        };
+
+        let skipped = renamed.load_partial(&one_layer.state()).unwrap();
+
+        assert!(skipped.is_empty());
+        assert_eq!(renamed.backbone.name(), Some("layer_1"));
+        assert_eq!(renamed.state(), one_layer.state());
+    }
+
+    #[test]
+    fn should_not_mistake_fields_named_like_param_entries_for_params() {
+        let one_layer = OneLayer::<TestBackend> { layer_1: linear() };
+        let mut with_param_keys = WithParamKeys::<TestBackend> {
+            data: linear(),
+            id: linear(),
+        };
+        let id_before = with_param_keys.id.state();
+
+        let skipped = with_param_keys.load_partial(&one_layer.state()).unwrap();
+
+        assert_eq!(skipped, vec!["data".to_string(), "id".to_string()]);
+        assert_eq!(with_param_keys.id.state(), id_before);
+    }
+}
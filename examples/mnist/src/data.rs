@@ -1,6 +1,6 @@
 use burn::{
-    data::{dataloader::batcher::Batcher, dataset::source::huggingface::MNISTItem},
-    tensor::{backend::Backend, Data, Tensor},
+    data::{dataloader::batcher::Batcher, dataset::MnistItem},
+    tensor::{backend::Backend, Tensor},
 };
 
 pub struct MNISTBatcher<B: Backend> {
@@ -19,19 +19,18 @@ impl<B: Backend> MNISTBatcher<B> {
     }
 }
 
-impl<B: Backend> Batcher<MNISTItem, MNISTBatch<B>> for MNISTBatcher<B> {
-    fn batch(&self, items: Vec<MNISTItem>) -> MNISTBatch<B> {
+impl<B: Backend> Batcher<MnistItem, MNISTBatch<B>> for MNISTBatcher<B> {
+    fn batch(&self, items: Vec<MnistItem>) -> MNISTBatch<B> {
         let images = items
             .iter()
-            .map(|item| Data::<f32, 2>::from(item.image))
-            .map(|data| Tensor::<B, 2>::from_data(data.convert()))
+            .map(|item| Tensor::<B, 2>::from_data(item.image.clone().convert()))
             .map(|tensor| tensor.reshape([1, 784]))
             .map(|tensor| tensor / 255)
             .collect();
 
         let targets = items
             .iter()
-            .map(|item| Tensor::<B, 2>::one_hot(item.label, 10))
+            .map(|item| Tensor::<B, 2>::one_hot(item.label as usize, 10))
             .collect();
 
         let images = Tensor::cat(images, 0).to_device(self.device).detach();
@@ -2,7 +2,7 @@ use crate::data::MNISTBatcher;
 use crate::model::{MlpConfig, MnistConfig, Model};
 use burn::{
     config::Config,
-    data::{dataloader::DataLoaderBuilder, dataset::source::huggingface::MNISTDataset},
+    data::{dataloader::DataLoaderBuilder, dataset::MnistDataset},
     module::Module,
     optim::{decay::WeightDecayConfig, momentum::MomentumConfig, Sgd, SgdConfig},
     tensor::backend::ADBackend,
@@ -32,11 +32,11 @@ pub fn run<B: ADBackend>(device: B::Device) {
         .batch_size(config.batch_size)
         .shuffle(config.seed)
         .num_workers(config.num_workers)
-        .build(Arc::new(MNISTDataset::train()));
+        .build(Arc::new(MnistDataset::train().unwrap()));
     let dataloader_test = DataLoaderBuilder::new(batcher_valid)
         .batch_size(config.batch_size)
         .num_workers(config.num_workers)
-        .build(Arc::new(MNISTDataset::test()));
+        .build(Arc::new(MnistDataset::test().unwrap()));
 
     // Model
     let optim = Sgd::new(&config.optimizer);
@@ -52,7 +52,7 @@ pub fn run<B: ADBackend>(device: B::Device) {
         .num_epochs(config.num_epochs)
         .build(model, optim);
 
-    let _model_trained = learner.fit(dataloader_train, dataloader_test);
+    let _model_trained = learner.fit(dataloader_train, dataloader_test).unwrap();
 
     config
         .save(format!("{}/config.json", ARTIFACT_DIR).as_str())